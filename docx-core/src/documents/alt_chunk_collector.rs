@@ -0,0 +1,45 @@
+//! Assigns the parts of altChunks for packaging.
+//!
+//! Each altChunk gets its own part under `word/` and a relationship from the
+//! body, numbered in document order so repeated builds name them the same.
+
+use super::document_tree::{visit_document_part, DocumentTreeVisitorMut};
+use crate::{AltChunk, ContentTypes, Document, DocumentRels};
+
+/// The payload of an altChunk and its part name relative to `word/`.
+pub(crate) type AltChunkPart = (String, Vec<u8>);
+
+#[derive(Default)]
+struct AltChunkCollector {
+    relationships: Vec<(String, String)>,
+    content_types: Vec<(String, &'static str)>,
+    parts: Vec<AltChunkPart>,
+}
+
+impl DocumentTreeVisitorMut for AltChunkCollector {
+    fn visit_alt_chunk(&mut self, chunk: &mut AltChunk) {
+        let n = self.parts.len() + 1;
+        let path = format!("afchunk{}.{}", n, chunk.format.extension());
+        chunk.id = format!("rIdAltChunk{n}");
+        self.relationships.push((chunk.id.clone(), path.clone()));
+        self.content_types
+            .push((format!("/word/{path}"), chunk.format.content_type()));
+        self.parts.push((path, chunk.data.clone()));
+    }
+}
+
+/// Gives every altChunk of the body a relationship id and registers its part,
+/// returning the parts to write.
+pub(crate) fn collect_alt_chunks(
+    document: &mut Document,
+    rels: &mut DocumentRels,
+    content_types: &mut ContentTypes,
+) -> Vec<AltChunkPart> {
+    let mut collector = AltChunkCollector::default();
+    visit_document_part(document, &mut collector);
+    rels.alt_chunks = collector.relationships;
+    for (path, content_type) in collector.content_types {
+        *content_types = std::mem::take(content_types).add_content(path, content_type);
+    }
+    collector.parts
+}
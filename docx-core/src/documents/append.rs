@@ -0,0 +1,823 @@
+//! Appends the body of one document to another.
+//!
+//! Every id space of the appended document is moved past the destination's:
+//! styles, abstract and concrete numbering definitions, picture bullets,
+//! bookmarks, comments, footnotes and the relationships of hyperlinks and
+//! header/footer parts. Images travel as bytes and get their relationships
+//! when the package is written.
+
+use std::collections::{HashMap, HashSet};
+
+use super::document_tree::{
+    visit_document_part, visit_footer_part, visit_header_part, DocumentTreeVisitorMut,
+};
+use super::image_collector::fill_document_header_footer_images;
+use super::split::section_child;
+use crate::documents::*;
+use crate::types::*;
+
+/// How [`Docx::append_document`] resolves a style of the appended document
+/// whose id the destination already uses for a different definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StyleConflict {
+    /// Appended content is formatted with the destination's style.
+    #[default]
+    UseDestination,
+    /// The appended document's definition replaces the destination's one.
+    KeepSource,
+    /// The appended document's style is added under a new id, e.g.
+    /// `Heading1_1`, and the appended content refers to it.
+    Rename,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AppendDocumentOptions {
+    pub style_conflict: StyleConflict,
+    /// Starts the appended content in a new section of this type. The
+    /// appended document then keeps the page setup, headers and footers of
+    /// its last section.
+    pub section_break: Option<SectionType>,
+}
+
+impl AppendDocumentOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn style_conflict(mut self, c: StyleConflict) -> Self {
+        self.style_conflict = c;
+        self
+    }
+
+    pub fn section_break(mut self, t: SectionType) -> Self {
+        self.section_break = Some(t);
+        self
+    }
+}
+
+impl Docx {
+    /// Appends the body of `other` to this document.
+    ///
+    /// Styles, numbering definitions, bookmarks, comments, footnotes,
+    /// hyperlinks and images of `other` are renumbered so they do not collide
+    /// with the ones of this document. Style conflicts are resolved as set in
+    /// `options`.
+    pub fn append_document(mut self, mut other: Docx, options: AppendDocumentOptions) -> Self {
+        // Pictures the reader left empty in headers and footers are filled
+        // while the parts still have the ids their images are kept by.
+        let images = std::mem::take(&mut self.header_footer_images);
+        fill_document_header_footer_images(&mut self.document, &images);
+        let images = std::mem::take(&mut other.header_footer_images);
+        fill_document_header_footer_images(&mut other.document, &images);
+
+        let mut used = IdCollector::default();
+        visit_document_part(&mut self.document, &mut used);
+        for comment in &self.comments.comments {
+            used.comment(comment.id);
+        }
+        let mut source = IdCollector::default();
+        visit_document_part(&mut other.document, &mut source);
+
+        let (styles, added) = self.merge_styles(&mut other.styles.styles, options.style_conflict);
+        let numberings = self.merge_numberings(other.numberings, &styles);
+        let first_added = self.styles.styles.len() - added;
+        for style in &mut self.styles.styles[first_added..] {
+            if let Some(id) = style
+                .paragraph_property
+                .numbering_property
+                .as_mut()
+                .and_then(|n| n.id.as_mut())
+            {
+                if let Some(new_id) = numberings.get(&id.id) {
+                    id.id = *new_id;
+                }
+            }
+        }
+
+        let bookmark_names = source
+            .bookmark_names
+            .iter()
+            .map(|name| {
+                let mut renamed = name.clone();
+                let mut n = 1;
+                while used.bookmark_names.contains(&renamed) {
+                    renamed = format!("{name}_{n}");
+                    n += 1;
+                }
+                used.bookmark_names.insert(renamed.clone());
+                (name.clone(), renamed)
+            })
+            .collect();
+        let mut remapper = Remapper {
+            styles: &styles,
+            numberings: &numberings,
+            bookmark_names: &bookmark_names,
+            bookmark_ids: IdMap::after(used.max_bookmark_id),
+            comment_ids: IdMap::after(used.max_comment_id),
+            // Footnote id 0 is reserved for the separator.
+            footnote_ids: IdMap::after(used.max_footnote_id.max(Some(0))),
+            images: other
+                .images
+                .iter()
+                .map(|(id, _, image, _)| (id.as_str(), &image.0))
+                .collect(),
+            hyperlinks: other
+                .hyperlinks
+                .iter()
+                .map(|(id, path, _)| (id.as_str(), path.as_str()))
+                .collect(),
+        };
+        visit_document_part(&mut other.document, &mut remapper);
+        // Pictures of headers and footers are looked up in their own parts.
+        remapper.images.clear();
+        let mut section = other.document.section_property;
+        for (_, header) in section.headers_mut() {
+            visit_header_part(header, &mut remapper);
+        }
+        for (_, footer) in section.footers_mut() {
+            visit_footer_part(footer, &mut remapper);
+        }
+        for child in &mut other.document.children {
+            if let DocumentChild::Section(s) = child {
+                for (_, header) in s.property.headers_mut() {
+                    visit_header_part(header, &mut remapper);
+                }
+                for (_, footer) in s.property.footers_mut() {
+                    visit_footer_part(footer, &mut remapper);
+                }
+            }
+        }
+
+        if other.document_rels.has_numberings || !numberings.is_empty() {
+            self.document_rels.has_numberings = true;
+        }
+
+        let section_break = options
+            .section_break
+            .filter(|_| !self.document.children.is_empty());
+        if let Some(section_type) = section_break {
+            self.close_last_section();
+            section.section_type = Some(section_type);
+        }
+
+        for mut child in other.document.children {
+            if let DocumentChild::Section(s) = &mut child {
+                self.attach_section_parts(s);
+            }
+            self.document.children.push(child);
+        }
+
+        if section_break.is_some() {
+            let mut last = Section {
+                property: section,
+                ..Default::default()
+            };
+            self.attach_section_parts(&mut last);
+            self.document.section_property = last.property;
+        }
+        self.document.renumber_header_footer_rids();
+        self
+    }
+
+    /// Adds the styles of another document to the end of the style list.
+    /// Returns the new id of every style whose id changed and the number of
+    /// styles added.
+    fn merge_styles(
+        &mut self,
+        source: &mut Vec<Style>,
+        conflict: StyleConflict,
+    ) -> (HashMap<String, String>, usize) {
+        let mut renamed = HashMap::new();
+        let mut added = vec![];
+        for style in source.drain(..) {
+            let existing = self
+                .styles
+                .styles
+                .iter()
+                .position(|s| s.style_id == style.style_id);
+            match (existing, conflict) {
+                (None, _) => added.push(style),
+                (Some(i), _) if self.styles.styles[i] == style => {}
+                (Some(_), StyleConflict::UseDestination) => {}
+                (Some(i), StyleConflict::KeepSource) => {
+                    self.styles.styles.remove(i);
+                    added.push(style);
+                }
+                (Some(_), StyleConflict::Rename) => {
+                    let mut n = 1;
+                    let id = loop {
+                        let id = format!("{}_{n}", style.style_id);
+                        if !self.styles.styles.iter().any(|s| s.style_id == id) {
+                            break id;
+                        }
+                        n += 1;
+                    };
+                    let name = style.name.with_suffix(&format!("_{n}"));
+                    renamed.insert(style.style_id.clone(), id.clone());
+                    added.push(Style {
+                        style_id: id,
+                        name,
+                        ..style
+                    });
+                }
+            }
+        }
+        let count = added.len();
+        for mut style in added {
+            let rename = |v: &mut String| {
+                if let Some(id) = renamed.get(v) {
+                    v.clone_from(id);
+                }
+            };
+            if let Some(b) = &mut style.based_on {
+                rename(b.val_mut());
+            }
+            if let Some(n) = &mut style.next {
+                rename(n.val_mut());
+            }
+            if let Some(l) = &mut style.link {
+                rename(l.val_mut());
+            }
+            self.styles.styles.push(style);
+        }
+        (renamed, count)
+    }
+
+    /// Adds the numbering definitions of another document under new ids and
+    /// returns the new id of every concrete numbering.
+    fn merge_numberings(
+        &mut self,
+        source: Numberings,
+        styles: &HashMap<String, String>,
+    ) -> HashMap<usize, usize> {
+        let numberings = &mut self.numberings;
+        // The default numbering written with every package uses id 1.
+        let mut bullets = IdMap::after(numberings.num_pic_bullets.iter().map(|b| b.id).max());
+        let mut abstract_nums = IdMap::after(
+            numberings
+                .abstract_nums
+                .iter()
+                .map(|a| a.id)
+                .chain(Some(1))
+                .max(),
+        );
+        let mut nums = IdMap::after(
+            numberings
+                .numberings
+                .iter()
+                .map(|n| n.id)
+                .chain(Some(1))
+                .max(),
+        );
+        let style = |s: &mut String| {
+            if let Some(id) = styles.get(s) {
+                s.clone_from(id);
+            }
+        };
+
+        // Ids the source uses without defining, such as the default
+        // numbering, keep referring to the destination's definition.
+        for bullet in &source.num_pic_bullets {
+            bullets.get(bullet.id);
+        }
+        for abstract_num in &source.abstract_nums {
+            abstract_nums.get(abstract_num.id);
+        }
+
+        for mut bullet in source.num_pic_bullets {
+            bullet.id = bullets.get(bullet.id);
+            numberings.num_pic_bullets.push(bullet);
+        }
+        for mut abstract_num in source.abstract_nums {
+            abstract_num.id = abstract_nums.get(abstract_num.id);
+            if let Some(s) = &mut abstract_num.style_link {
+                style(s);
+            }
+            if let Some(s) = &mut abstract_num.num_style_link {
+                style(s);
+            }
+            for level in &mut abstract_num.levels {
+                if let Some(p) = &mut level.pstyle {
+                    style(&mut p.val);
+                }
+                if let Some(id) = &mut level.pic_bullet_id {
+                    *id = bullets.existing(*id);
+                }
+            }
+            numberings.abstract_nums.push(abstract_num);
+        }
+        for mut numbering in source.numberings {
+            numbering.id = nums.get(numbering.id);
+            numbering.abstract_num_id = abstract_nums.existing(numbering.abstract_num_id);
+            numberings.numberings.push(numbering);
+        }
+        nums.map
+    }
+
+    /// Moves the content of the last section into a section of its own so
+    /// that appended content starts a new one.
+    fn close_last_section(&mut self) {
+        let start = self
+            .document
+            .children
+            .iter()
+            .rposition(|c| matches!(c, DocumentChild::Section(_)))
+            .map_or(0, |i| i + 1);
+        let children = self
+            .document
+            .children
+            .drain(start..)
+            .filter_map(section_child)
+            .collect();
+        let section = Section {
+            property: std::mem::take(&mut self.document.section_property),
+            children,
+            ..Default::default()
+        };
+        self.document
+            .children
+            .push(DocumentChild::Section(Box::new(section)));
+    }
+
+    /// Registers the headers and footers of a section taken from another
+    /// document.
+    pub(crate) fn attach_section_parts(&mut self, section: &mut Section) {
+        let p = &mut section.property;
+        p.header_reference = None;
+        p.first_header_reference = None;
+        p.even_header_reference = None;
+        p.footer_reference = None;
+        p.first_footer_reference = None;
+        p.even_footer_reference = None;
+        let header = p.header.take();
+        let first_header = p.first_header.take();
+        let even_header = p.even_header.take();
+        let footer = p.footer.take();
+        let first_footer = p.first_footer.take();
+        let even_footer = p.even_footer.take();
+
+        if let Some((_, h)) = header {
+            self.attach_section_header(section, h, SectionProperty::header);
+        }
+        if let Some((_, h)) = first_header {
+            self.attach_section_header(section, h, SectionProperty::first_header_without_title_pg);
+        }
+        if let Some((_, h)) = even_header {
+            self.attach_section_header(section, h, SectionProperty::even_header);
+        }
+        if let Some((_, f)) = footer {
+            self.attach_section_footer(section, f, SectionProperty::footer);
+        }
+        if let Some((_, f)) = first_footer {
+            self.attach_section_footer(section, f, SectionProperty::first_footer_without_title_pg);
+        }
+        if let Some((_, f)) = even_footer {
+            self.attach_section_footer(section, f, SectionProperty::even_footer);
+        }
+    }
+}
+
+impl Document {
+    /// Gives header and footer parts the relationship ids the package writer
+    /// expects: `header{n}.xml` is written for the n-th header in
+    /// [`Document::headers`] order.
+    pub(crate) fn renumber_header_footer_rids(&mut self) {
+        let mut headers = 0;
+        let mut footers = 0;
+        let sections = std::iter::once(&mut self.section_property).chain(
+            self.children.iter_mut().filter_map(|child| match child {
+                DocumentChild::Section(section) => Some(&mut section.property),
+                _ => None,
+            }),
+        );
+        let mut all: Vec<&mut SectionProperty> = sections.collect();
+        for p in &mut all {
+            let header_refs = [
+                (&mut p.header, &mut p.header_reference),
+                (&mut p.first_header, &mut p.first_header_reference),
+                (&mut p.even_header, &mut p.even_header_reference),
+            ];
+            for (header, reference) in header_refs {
+                if let Some((rid, _)) = header {
+                    headers += 1;
+                    *rid = create_header_rid(headers);
+                    if let Some(r) = reference {
+                        r.id.clone_from(rid);
+                    }
+                }
+            }
+        }
+        for p in &mut all {
+            let footer_refs = [
+                (&mut p.footer, &mut p.footer_reference),
+                (&mut p.first_footer, &mut p.first_footer_reference),
+                (&mut p.even_footer, &mut p.even_footer_reference),
+            ];
+            for (footer, reference) in footer_refs {
+                if let Some((rid, _)) = footer {
+                    footers += 1;
+                    *rid = create_footer_rid(footers);
+                    if let Some(r) = reference {
+                        r.id.clone_from(rid);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Hands out new ids after the largest one in use, giving every old id the
+/// same new one each time it is seen.
+struct IdMap {
+    next: usize,
+    map: HashMap<usize, usize>,
+}
+
+impl IdMap {
+    fn after(max: Option<usize>) -> Self {
+        Self {
+            next: max.map_or(0, |m| m + 1),
+            map: HashMap::new(),
+        }
+    }
+
+    /// The new id of `id`, or `id` itself when it was never mapped.
+    fn existing(&self, id: usize) -> usize {
+        self.map.get(&id).copied().unwrap_or(id)
+    }
+
+    fn get(&mut self, id: usize) -> usize {
+        *self.map.entry(id).or_insert_with(|| {
+            self.next += 1;
+            self.next - 1
+        })
+    }
+}
+
+#[derive(Default)]
+struct IdCollector {
+    max_bookmark_id: Option<usize>,
+    max_comment_id: Option<usize>,
+    max_footnote_id: Option<usize>,
+    bookmark_names: HashSet<String>,
+}
+
+impl IdCollector {
+    fn comment(&mut self, id: usize) {
+        self.max_comment_id = self.max_comment_id.max(Some(id));
+    }
+}
+
+impl DocumentTreeVisitorMut for IdCollector {
+    fn visit_bookmark_start(&mut self, bookmark: &mut BookmarkStart) {
+        self.max_bookmark_id = self.max_bookmark_id.max(Some(bookmark.id));
+        self.bookmark_names.insert(bookmark.name.clone());
+    }
+
+    fn visit_bookmark_end(&mut self, bookmark: &mut BookmarkEnd) {
+        self.max_bookmark_id = self.max_bookmark_id.max(Some(bookmark.id));
+    }
+
+    fn visit_comment_start(&mut self, comment: &mut CommentRangeStart) {
+        self.comment(comment.id);
+        self.comment(comment.comment.id);
+    }
+
+    fn visit_comment_end(&mut self, comment: &mut CommentRangeEnd) {
+        self.comment(comment.get_id());
+    }
+
+    fn visit_footnote_reference(&mut self, reference: &mut FootnoteReference) {
+        self.max_footnote_id = self.max_footnote_id.max(Some(reference.id));
+    }
+}
+
+/// Rewrites the ids of appended content.
+struct Remapper<'a> {
+    styles: &'a HashMap<String, String>,
+    numberings: &'a HashMap<usize, usize>,
+    bookmark_names: &'a HashMap<String, String>,
+    bookmark_ids: IdMap,
+    comment_ids: IdMap,
+    footnote_ids: IdMap,
+    images: HashMap<&'a str, &'a Vec<u8>>,
+    hyperlinks: HashMap<&'a str, &'a str>,
+}
+
+impl Remapper<'_> {
+    fn style(&self, val: &mut String) {
+        if let Some(id) = self.styles.get(val) {
+            val.clone_from(id);
+        }
+    }
+}
+
+impl DocumentTreeVisitorMut for Remapper<'_> {
+    fn visit_paragraph(&mut self, paragraph: &mut Paragraph) {
+        let property = &mut paragraph.property;
+        if let Some(s) = &mut property.style {
+            self.style(&mut s.val);
+        }
+        if let Some(s) = &mut property.run_property.style {
+            self.style(&mut s.val);
+        }
+        if let Some(id) = property
+            .numbering_property
+            .as_mut()
+            .and_then(|n| n.id.as_mut())
+        {
+            if let Some(new_id) = self.numberings.get(&id.id) {
+                id.id = *new_id;
+            }
+        }
+    }
+
+    fn visit_run(&mut self, run: &mut Run) {
+        if let Some(s) = &mut run.run_property.style {
+            self.style(&mut s.val);
+        }
+    }
+
+    fn visit_table(&mut self, table: &mut Table) {
+        if let Some(s) = table.property.style_mut() {
+            self.style(s.val_mut());
+        }
+    }
+
+    fn visit_picture(&mut self, picture: &mut Pic) {
+        if picture.image.is_empty() {
+            if let Some(image) = self.images.get(picture.id.as_str()) {
+                picture.image = (*image).clone();
+            }
+        }
+    }
+
+    fn visit_footnote_reference(&mut self, reference: &mut FootnoteReference) {
+        reference.id = self.footnote_ids.get(reference.id);
+    }
+
+    fn visit_hyperlink(&mut self, link: &mut Hyperlink) {
+        match &mut link.link {
+            HyperlinkData::External { rid, path } => {
+                if path.is_empty() {
+                    if let Some(p) = self.hyperlinks.get(rid.as_str()) {
+                        *path = (*p).to_owned();
+                    }
+                }
+                *rid = create_hyperlink_rid(generate_hyperlink_id());
+            }
+            HyperlinkData::Anchor { anchor } => {
+                if let Some(name) = self.bookmark_names.get(anchor) {
+                    anchor.clone_from(name);
+                }
+            }
+        }
+    }
+
+    fn visit_bookmark_start(&mut self, bookmark: &mut BookmarkStart) {
+        bookmark.id = self.bookmark_ids.get(bookmark.id);
+        if let Some(name) = self.bookmark_names.get(&bookmark.name) {
+            bookmark.name.clone_from(name);
+        }
+    }
+
+    fn visit_bookmark_end(&mut self, bookmark: &mut BookmarkEnd) {
+        bookmark.id = self.bookmark_ids.get(bookmark.id);
+    }
+
+    fn visit_comment_start(&mut self, comment: &mut CommentRangeStart) {
+        comment.id = self.comment_ids.get(comment.id);
+        comment.comment.id = self.comment_ids.get(comment.comment.id);
+        if let Some(parent) = &mut comment.comment.parent_comment_id {
+            *parent = self.comment_ids.get(*parent);
+        }
+    }
+
+    fn visit_comment_end(&mut self, comment: &mut CommentRangeEnd) {
+        comment.set_id(self.comment_ids.get(comment.get_id()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::image_collector::packed_header_images;
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn part(text: &str) -> Docx {
+        Docx::new()
+            .add_style(
+                Style::new("Heading1", StyleType::Paragraph)
+                    .name("Heading 1")
+                    .bold(),
+            )
+            .add_abstract_numbering(AbstractNumbering::new(2).add_level(Level::new(
+                0,
+                Start::new(1),
+                NumberFormat::new("decimal"),
+                LevelText::new("%1."),
+                LevelJc::new("left"),
+            )))
+            .add_numbering(Numbering::new(2, 2))
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading1")
+                    .numbering(NumberingId::new(2), IndentLevel::new(0))
+                    .add_bookmark_start(0, "intro")
+                    .add_comment_start(Comment::new(1).author("bokuweb"))
+                    .add_run(
+                        Run::new()
+                            .add_text(text)
+                            .add_footnote_reference(Footnote::new()),
+                    )
+                    .add_comment_end(1)
+                    .add_bookmark_end(0)
+                    .add_hyperlink(
+                        Hyperlink::new("intro", HyperlinkType::Anchor)
+                            .add_run(Run::new().add_text("top")),
+                    ),
+            )
+    }
+
+    #[derive(Default)]
+    struct Ids {
+        bookmarks: Vec<(usize, String)>,
+        comments: Vec<usize>,
+        footnotes: Vec<usize>,
+        anchors: Vec<String>,
+        numberings: Vec<usize>,
+    }
+
+    impl DocumentTreeVisitorMut for Ids {
+        fn visit_paragraph(&mut self, paragraph: &mut Paragraph) {
+            if let Some(id) = paragraph
+                .property
+                .numbering_property
+                .as_ref()
+                .and_then(|n| n.id.as_ref())
+            {
+                self.numberings.push(id.id);
+            }
+        }
+
+        fn visit_bookmark_start(&mut self, bookmark: &mut BookmarkStart) {
+            self.bookmarks.push((bookmark.id, bookmark.name.clone()));
+        }
+
+        fn visit_comment_start(&mut self, comment: &mut CommentRangeStart) {
+            self.comments.push(comment.comment.id);
+        }
+
+        fn visit_footnote_reference(&mut self, reference: &mut FootnoteReference) {
+            self.footnotes.push(reference.id);
+        }
+
+        fn visit_hyperlink(&mut self, link: &mut Hyperlink) {
+            if let HyperlinkData::Anchor { anchor } = &link.link {
+                self.anchors.push(anchor.clone());
+            }
+        }
+    }
+
+    #[test]
+    fn test_append_document_renumbers_ids() {
+        let mut docx = part("first").append_document(part("second"), AppendDocumentOptions::new());
+        let mut ids = Ids::default();
+        visit_document_part(&mut docx.document, &mut ids);
+
+        assert_eq!(
+            ids.bookmarks,
+            vec![(0, "intro".to_owned()), (1, "intro_1".to_owned())]
+        );
+        assert_eq!(ids.anchors, vec!["intro", "intro_1"]);
+        assert_eq!(ids.comments, vec![1, 2]);
+        assert_eq!(ids.footnotes, vec![1, 2]);
+        assert_eq!(ids.numberings, vec![2, 3]);
+        let numbering = docx.numberings.numberings.iter().find(|n| n.id == 3);
+        assert_eq!(numbering.map(|n| n.abstract_num_id), Some(3));
+        assert!(docx.numberings.abstract_nums.iter().any(|a| a.id == 3));
+        // Both documents define the same style, so it is not duplicated.
+        assert_eq!(docx.styles.styles.len(), 1);
+    }
+
+    #[test]
+    fn test_append_document_style_conflicts() {
+        let other = || {
+            Docx::new()
+                .add_style(
+                    Style::new("Quote", StyleType::Paragraph)
+                        .name("Quote")
+                        .italic(),
+                )
+                .add_paragraph(Paragraph::new().style("Quote"))
+        };
+        let dest = || {
+            Docx::new()
+                .add_style(
+                    Style::new("Quote", StyleType::Paragraph)
+                        .name("Quote")
+                        .bold(),
+                )
+                .add_paragraph(Paragraph::new().style("Quote"))
+        };
+        let styles = |docx: &Docx| -> Vec<(String, bool)> {
+            docx.styles
+                .styles
+                .iter()
+                .map(|s| (s.style_id.clone(), s.run_property.italic.is_some()))
+                .collect()
+        };
+        let paragraph_styles = |docx: &Docx| -> Vec<String> {
+            docx.document
+                .children
+                .iter()
+                .filter_map(|c| match c {
+                    DocumentChild::Paragraph(p) => p.property.style.as_ref().map(|s| s.val.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let docx = dest().append_document(other(), AppendDocumentOptions::new());
+        assert_eq!(styles(&docx), vec![("Quote".to_owned(), false)]);
+
+        let docx = dest().append_document(
+            other(),
+            AppendDocumentOptions::new().style_conflict(StyleConflict::KeepSource),
+        );
+        assert_eq!(styles(&docx), vec![("Quote".to_owned(), true)]);
+
+        let docx = dest().append_document(
+            other(),
+            AppendDocumentOptions::new().style_conflict(StyleConflict::Rename),
+        );
+        assert_eq!(
+            styles(&docx),
+            vec![("Quote".to_owned(), false), ("Quote_1".to_owned(), true)]
+        );
+        assert_eq!(paragraph_styles(&docx), vec!["Quote", "Quote_1"]);
+    }
+
+    #[test]
+    fn test_append_document_with_section_break() {
+        let header = |text: &str| {
+            Header::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text(text)))
+        };
+        let docx = Docx::new()
+            .header(header("first header"))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("first")))
+            .append_document(
+                Docx::new()
+                    .page_size(16838, 11906)
+                    .header(header("second header"))
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text("second"))),
+                AppendDocumentOptions::new().section_break(SectionType::NextPage),
+            );
+
+        assert_eq!(docx.document.children.len(), 2);
+        let DocumentChild::Section(first) = &docx.document.children[0] else {
+            panic!("the first document should be closed by a section");
+        };
+        assert_eq!(first.children.len(), 1);
+        let (first_rid, _) = first.property.header.as_ref().unwrap();
+        let last = &docx.document.section_property;
+        let (last_rid, _) = last.header.as_ref().unwrap();
+        assert_eq!(last.section_type, Some(SectionType::NextPage));
+        assert_eq!(last.page_size, PageSize::new().size(16838, 11906));
+        // Headers are written in document order starting with the last section.
+        assert_eq!(last_rid, &create_header_rid(1));
+        assert_eq!(first_rid, &create_header_rid(2));
+        assert_eq!(
+            last.header_reference.as_ref().map(|r| r.id.as_str()),
+            Some(last_rid.as_str())
+        );
+        assert_eq!(docx.document_rels.header_count, 2);
+    }
+
+    #[test]
+    fn test_append_document_keeps_header_images() {
+        let read = |header_image: Vec<u8>, body_image: Vec<u8>| {
+            let picture = |image| {
+                Paragraph::new()
+                    .add_run(Run::new().add_image(Pic::new_with_dimensions(image, 1, 1)))
+            };
+            let mut buf = std::io::Cursor::new(vec![]);
+            Docx::new()
+                .header(Header::new().add_paragraph(picture(header_image)))
+                .add_paragraph(picture(body_image))
+                .build()
+                .pack(&mut buf)
+                .unwrap();
+            crate::read_docx(&buf.into_inner()).unwrap()
+        };
+        let docx = read(vec![1; 3], vec![2; 3]).append_document(
+            read(vec![3; 3], vec![4; 3]),
+            AppendDocumentOptions::new().section_break(SectionType::NextPage),
+        );
+        // The appended document's header belongs to the final section, which
+        // is written first.
+        assert_eq!(packed_header_images(docx), vec![vec![3; 3], vec![1; 3]]);
+    }
+}
@@ -0,0 +1,680 @@
+//! Comments with the text they are anchored to, grouped into reply threads.
+
+use std::collections::{HashMap, HashSet};
+
+use super::find::BlockNode;
+use super::paragraph_text::*;
+use crate::documents::*;
+use crate::escape::unescape_text;
+
+/// A comment and the replies to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentThread {
+    pub comment: ThreadComment,
+    /// The replies in document order, replies to replies included.
+    pub replies: Vec<ThreadComment>,
+    /// Whether the thread is marked done.
+    pub resolved: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreadComment {
+    pub id: usize,
+    pub author: String,
+    pub date: String,
+    /// The text of the paragraphs of the comment, one per line.
+    pub text: String,
+    pub done: bool,
+    /// The comment a reply answers.
+    pub parent_id: Option<usize>,
+    /// The range of the comment, if its start is in the body, a header or a
+    /// footer.
+    pub anchor: Option<CommentAnchor>,
+}
+
+/// The text between the start and the end of a comment range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentAnchor {
+    /// The paragraph the range starts in.
+    pub start: BlockLocation,
+    pub start_position: TextPosition,
+    /// The paragraph the range ends in.
+    pub end: BlockLocation,
+    pub end_position: TextPosition,
+    /// The paragraph text in the range, paragraphs separated by newlines.
+    pub text: String,
+}
+
+impl Docx {
+    /// Returns the comments as threads, in the order their ranges start.
+    ///
+    /// Replies are found by the parent comment id, or else by the parent
+    /// paragraph in `commentsExtended.xml`, which also tells which comments
+    /// are done. A range without an end reaches to the end of its part.
+    pub fn comment_threads(&self) -> Vec<CommentThread> {
+        let mut collector = AnchorCollector {
+            part: DocumentPart::Body,
+            path: vec![],
+            open: vec![],
+            last: None,
+            anchors: HashMap::new(),
+            comments: vec![],
+            seen: HashSet::new(),
+        };
+        collector.blocks(self.document.children.iter().map(BlockNode::from));
+        collector.end_part();
+        for (rid, header) in self.document.headers() {
+            collector.part = DocumentPart::Header(rid.clone());
+            collector.blocks(header.children.iter().map(BlockNode::from));
+            collector.end_part();
+        }
+        for (rid, footer) in self.document.footers() {
+            collector.part = DocumentPart::Footer(rid.clone());
+            collector.blocks(footer.children.iter().map(BlockNode::from));
+            collector.end_part();
+        }
+        let AnchorCollector {
+            mut anchors,
+            mut comments,
+            seen,
+            ..
+        } = collector;
+        // Comments whose range is not in the tree.
+        comments.extend(
+            self.comments
+                .comments
+                .iter()
+                .filter(|c| !seen.contains(&c.id)),
+        );
+
+        let mut comment_by_paragraph = HashMap::new();
+        for comment in &comments {
+            for child in &comment.children {
+                if let CommentChild::Paragraph(p) = child {
+                    comment_by_paragraph.insert(p.id.as_str(), comment.id);
+                }
+            }
+        }
+        let mut parent_by_comment = HashMap::new();
+        let mut done = HashSet::new();
+        for extended in &self.comments_extended.children {
+            let Some(id) = comment_by_paragraph.get(extended.paragraph_id.as_str()) else {
+                continue;
+            };
+            if extended.done {
+                done.insert(*id);
+            }
+            if let Some(parent) = extended
+                .parent_paragraph_id
+                .as_deref()
+                .and_then(|p| comment_by_paragraph.get(p))
+            {
+                parent_by_comment.insert(*id, *parent);
+            }
+        }
+        let ids: HashSet<usize> = comments.iter().map(|c| c.id).collect();
+        let parent = |comment: &Comment| {
+            comment
+                .parent_comment_id
+                .or_else(|| parent_by_comment.get(&comment.id).copied())
+                .filter(|p| *p != comment.id && ids.contains(p))
+        };
+        let parents: HashMap<usize, usize> = comments
+            .iter()
+            .filter_map(|c| Some((c.id, parent(c)?)))
+            .collect();
+        let root = |mut id: usize| {
+            // Guards against cycles in broken files.
+            for _ in 0..=parents.len() {
+                match parents.get(&id) {
+                    Some(p) => id = *p,
+                    None => break,
+                }
+            }
+            id
+        };
+
+        let mut threads: Vec<CommentThread> = vec![];
+        let mut thread_by_root = HashMap::new();
+        let mut replies = vec![];
+        for comment in comments {
+            let thread_comment = ThreadComment {
+                id: comment.id,
+                author: comment.author.clone(),
+                date: comment.date.clone(),
+                text: comment_text(comment),
+                done: done.contains(&comment.id),
+                parent_id: parents.get(&comment.id).copied(),
+                anchor: anchors.remove(&comment.id),
+            };
+            let root = root(comment.id);
+            if root == comment.id {
+                thread_by_root.insert(root, threads.len());
+                threads.push(CommentThread {
+                    resolved: thread_comment.done,
+                    comment: thread_comment,
+                    replies: vec![],
+                });
+            } else {
+                replies.push((root, thread_comment));
+            }
+        }
+        for (root, reply) in replies {
+            match thread_by_root.get(&root) {
+                Some(i) => threads[*i].replies.push(reply),
+                // The root is its own ancestor in a cycle.
+                None => threads.push(CommentThread {
+                    resolved: reply.done,
+                    comment: reply,
+                    replies: vec![],
+                }),
+            }
+        }
+        threads
+    }
+}
+
+fn comment_text(comment: &Comment) -> String {
+    let mut lines = vec![];
+    for child in &comment.children {
+        if let CommentChild::Paragraph(p) = child {
+            let mut runs = vec![];
+            collect_runs(&p.children, &mut runs);
+            lines.push(text_segments(&runs).0);
+        }
+    }
+    lines.join("\n")
+}
+
+/// A point in a paragraph.
+type Point = (BlockLocation, TextPosition);
+
+/// A comment range that has started but not ended yet.
+struct OpenRange {
+    id: usize,
+    /// Unset until the paragraph after a range start between blocks.
+    start: Option<Point>,
+    text: String,
+}
+
+struct AnchorCollector<'a> {
+    part: DocumentPart,
+    path: Vec<usize>,
+    open: Vec<OpenRange>,
+    /// The end of the last paragraph of the part.
+    last: Option<Point>,
+    anchors: HashMap<usize, CommentAnchor>,
+    comments: Vec<&'a Comment>,
+    seen: HashSet<usize>,
+}
+
+impl<'a> AnchorCollector<'a> {
+    fn blocks(&mut self, nodes: impl Iterator<Item = BlockNode<'a>>) {
+        for (i, node) in nodes.enumerate() {
+            self.path.push(i);
+            match node {
+                BlockNode::Paragraph(paragraph) => self.paragraph(paragraph),
+                BlockNode::Table(table) => {
+                    for (r, TableChild::TableRow(row)) in table.rows.iter().enumerate() {
+                        for (c, TableRowChild::TableCell(cell)) in row.cells.iter().enumerate() {
+                            self.path.extend([r, c]);
+                            self.blocks(cell.children.iter().map(BlockNode::from));
+                            self.path.truncate(self.path.len() - 2);
+                        }
+                    }
+                }
+                BlockNode::StructuredDataTag(tag) => {
+                    self.blocks(tag.children.iter().map(BlockNode::from))
+                }
+                BlockNode::TableOfContents(toc) => self.blocks(
+                    toc.before_contents
+                        .iter()
+                        .chain(&toc.after_contents)
+                        .map(BlockNode::from),
+                ),
+                BlockNode::Section(section) => {
+                    self.blocks(section.children.iter().map(BlockNode::from))
+                }
+                BlockNode::CommentStart(start) => self.start(start, None),
+                BlockNode::CommentEnd(end) => self.end(end.get_id(), None),
+                BlockNode::Other => {}
+            }
+            self.path.pop();
+        }
+    }
+
+    fn start(&mut self, start: &'a CommentRangeStart, at: Option<Point>) {
+        if self.seen.insert(start.id) {
+            self.comments.push(&start.comment);
+        }
+        self.open.push(OpenRange {
+            id: start.id,
+            start: at,
+            text: String::new(),
+        });
+    }
+
+    fn end(&mut self, id: usize, at: Option<Point>) {
+        let Some(i) = self.open.iter().position(|o| o.id == id) else {
+            return;
+        };
+        let range = self.open.remove(i);
+        let Some(end) = at.or_else(|| self.last.clone()) else {
+            return;
+        };
+        let start = range.start.unwrap_or_else(|| end.clone());
+        self.anchors.entry(id).or_insert(CommentAnchor {
+            start: start.0,
+            start_position: start.1,
+            end: end.0,
+            end_position: end.1,
+            text: range.text,
+        });
+    }
+
+    /// Ends the ranges left open at the end of a part.
+    fn end_part(&mut self) {
+        for id in self.open.iter().map(|o| o.id).collect::<Vec<_>>() {
+            self.end(id, None);
+        }
+        self.last = None;
+    }
+
+    fn paragraph(&mut self, paragraph: &'a Paragraph) {
+        let location = BlockLocation::new(self.part.clone(), self.path.clone());
+        let mut runs = vec![];
+        collect_runs(&paragraph.children, &mut runs);
+        let lengths: Vec<usize> = runs
+            .iter()
+            .map(|r| {
+                r.children
+                    .iter()
+                    .map(|c| match c {
+                        RunChild::Text(t) => unescape_text(&t.text).len(),
+                        _ => 0,
+                    })
+                    .sum()
+            })
+            .collect();
+        for range in &mut self.open {
+            match range.start {
+                Some(_) => range.text.push('\n'),
+                None => range.start = Some((location.clone(), TextPosition { run: 0, offset: 0 })),
+            }
+        }
+        let mut walker = ParagraphWalker {
+            collector: self,
+            location: &location,
+            lengths: &lengths,
+            run: 0,
+        };
+        walker.children(&paragraph.children);
+        let end = match lengths.last() {
+            Some(len) => TextPosition {
+                run: lengths.len() - 1,
+                offset: *len,
+            },
+            None => TextPosition { run: 0, offset: 0 },
+        };
+        self.last = Some((location, end));
+    }
+}
+
+/// Walks the content of a paragraph in the order of [`collect_runs`],
+/// adding its text to the open ranges.
+struct ParagraphWalker<'c, 'a> {
+    collector: &'c mut AnchorCollector<'a>,
+    location: &'c BlockLocation,
+    /// The text length of each run.
+    lengths: &'c [usize],
+    /// The number of runs passed.
+    run: usize,
+}
+
+impl<'a> ParagraphWalker<'_, 'a> {
+    /// A range starting between runs starts with the next run.
+    fn start_between(&self) -> TextPosition {
+        match self.lengths.len() {
+            0 => TextPosition { run: 0, offset: 0 },
+            n if self.run < n => TextPosition {
+                run: self.run,
+                offset: 0,
+            },
+            n => TextPosition {
+                run: n - 1,
+                offset: self.lengths[n - 1],
+            },
+        }
+    }
+
+    /// A range ending between runs ends with the run before.
+    fn end_between(&self) -> TextPosition {
+        match self.run {
+            0 => TextPosition { run: 0, offset: 0 },
+            run => TextPosition {
+                run: run - 1,
+                offset: self.lengths[run - 1],
+            },
+        }
+    }
+
+    fn start(&mut self, start: &'a CommentRangeStart) {
+        let at = (self.location.clone(), self.start_between());
+        self.collector.start(start, Some(at));
+    }
+
+    fn end(&mut self, id: usize) {
+        let at = (self.location.clone(), self.end_between());
+        self.collector.end(id, Some(at));
+    }
+
+    fn children(&mut self, children: &'a [ParagraphChild]) {
+        for child in children {
+            match child {
+                ParagraphChild::Run(run) => self.run(run),
+                ParagraphChild::Hyperlink(link) => self.children(&link.children),
+                ParagraphChild::StructuredDataTag(tag) => self.tag_children(&tag.children),
+                ParagraphChild::Insert(insert) => {
+                    for child in &insert.children {
+                        match child {
+                            InsertChild::Run(run) => self.run(run),
+                            InsertChild::Delete(delete) => self.delete(delete),
+                            InsertChild::CommentStart(start) => self.start(start),
+                            InsertChild::CommentEnd(end) => self.end(end.get_id()),
+                        }
+                    }
+                }
+                ParagraphChild::Delete(delete) => self.delete(delete),
+                ParagraphChild::MoveFrom(moved) => {
+                    for child in &moved.children {
+                        match child {
+                            MoveFromChild::CommentStart(start) => self.start(start),
+                            MoveFromChild::CommentEnd(end) => self.end(end.get_id()),
+                            _ => {}
+                        }
+                    }
+                }
+                ParagraphChild::MoveTo(moved) => {
+                    for child in &moved.children {
+                        match child {
+                            MoveToChild::CommentStart(start) => self.start(start),
+                            MoveToChild::CommentEnd(end) => self.end(end.get_id()),
+                            _ => {}
+                        }
+                    }
+                }
+                ParagraphChild::CommentStart(start) => self.start(start),
+                ParagraphChild::CommentEnd(end) => self.end(end.get_id()),
+                _ => {}
+            }
+        }
+    }
+
+    fn tag_children(&mut self, children: &'a [StructuredDataTagChild]) {
+        for child in children {
+            match child {
+                StructuredDataTagChild::Run(run) => self.run(run),
+                StructuredDataTagChild::StructuredDataTag(tag) => self.tag_children(&tag.children),
+                StructuredDataTagChild::CommentStart(start) => self.start(start),
+                StructuredDataTagChild::CommentEnd(end) => self.end(end.get_id()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Deleted text is not part of the paragraph text, but the markers in it
+    /// are.
+    fn delete(&mut self, delete: &'a Delete) {
+        for child in &delete.children {
+            match child {
+                DeleteChild::CommentStart(start) => self.start(start),
+                DeleteChild::CommentEnd(end) => self.end(end.get_id()),
+                DeleteChild::Run(_) => {}
+            }
+        }
+    }
+
+    fn run(&mut self, run: &'a Run) {
+        let mut offset = 0;
+        for child in &run.children {
+            match child {
+                RunChild::Text(t) => {
+                    let text = unescape_text(&t.text);
+                    for range in &mut self.collector.open {
+                        range.text.push_str(&text);
+                    }
+                    offset += text.len();
+                }
+                RunChild::CommentStart(start) => {
+                    let at = TextPosition {
+                        run: self.run,
+                        offset,
+                    };
+                    self.collector
+                        .start(start, Some((self.location.clone(), at)));
+                }
+                RunChild::CommentEnd(end) => {
+                    let at = TextPosition {
+                        run: self.run,
+                        offset,
+                    };
+                    self.collector
+                        .end(end.get_id(), Some((self.location.clone(), at)));
+                }
+                _ => {}
+            }
+        }
+        self.run += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn comment(id: usize, author: &str, text: &str) -> Comment {
+        Comment::new(id)
+            .author(author)
+            .date("2024-01-01T00:00:00Z")
+            .add_paragraph(
+                Paragraph::new()
+                    .id(format!("0000000{id}"))
+                    .add_run(Run::new().add_text(text)),
+            )
+    }
+
+    #[test]
+    fn test_comment_threads() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Hello "))
+                    .add_comment_start(comment(1, "Alice", "Why?"))
+                    .add_comment_start(comment(2, "Bob", "Because").parent_comment_id(1))
+                    .add_run(Run::new().add_text("big & "))
+                    .add_run(Run::new().add_text("wide")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("world"))
+                    .add_comment_end(1)
+                    .add_comment_end(2)
+                    .add_run(Run::new().add_text("!")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_comment_start(comment(3, "Carol", "Typo"))
+                    .add_run(Run::new().add_text("Fine"))
+                    .add_comment_end(3),
+            )
+            .comments_extended(CommentsExtended {
+                children: vec![CommentExtended::new("00000003").done()],
+            });
+        let threads = docx.comment_threads();
+        assert_eq!(threads.len(), 2);
+
+        let thread = &threads[0];
+        assert_eq!(thread.comment.author, "Alice");
+        assert_eq!(thread.comment.text, "Why?");
+        assert!(!thread.resolved);
+        let anchor = thread.comment.anchor.as_ref().unwrap();
+        assert_eq!(anchor.text, "big & wide\nworld");
+        assert_eq!(anchor.start, BlockLocation::body(0));
+        assert_eq!(anchor.start_position, TextPosition { run: 1, offset: 0 });
+        assert_eq!(anchor.end, BlockLocation::body(1));
+        assert_eq!(anchor.end_position, TextPosition { run: 0, offset: 5 });
+        assert_eq!(thread.replies.len(), 1);
+        assert_eq!(thread.replies[0].author, "Bob");
+        assert_eq!(thread.replies[0].parent_id, Some(1));
+        assert_eq!(
+            thread.replies[0].anchor.as_ref().map(|a| a.text.as_str()),
+            Some("big & wide\nworld")
+        );
+
+        let thread = &threads[1];
+        assert_eq!(thread.comment.id, 3);
+        assert!(thread.resolved);
+        let anchor = thread.comment.anchor.as_ref().unwrap();
+        assert_eq!(anchor.text, "Fine");
+        assert_eq!(anchor.start_position, TextPosition { run: 0, offset: 0 });
+        assert_eq!(anchor.end_position, TextPosition { run: 0, offset: 4 });
+    }
+
+    #[test]
+    fn test_replies_to_replies() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_comment_start(comment(1, "Alice", "Why?"))
+                    .add_comment_start(comment(2, "Bob", "Because").parent_comment_id(1))
+                    .add_comment_start(comment(3, "Alice", "Why not?").parent_comment_id(2))
+                    .add_comment_start(comment(4, "Carol", "Agreed"))
+                    .add_run(Run::new().add_text("Text"))
+                    .add_comment_end(1)
+                    .add_comment_end(2)
+                    .add_comment_end(3)
+                    .add_comment_end(4),
+            )
+            .comments_extended(CommentsExtended {
+                children: vec![
+                    CommentExtended::new("00000004").parent_paragraph_id("00000003"),
+                    CommentExtended::new("00000003").done(),
+                ],
+            });
+        let threads = docx.comment_threads();
+        assert_eq!(threads.len(), 1);
+        let thread = &threads[0];
+        assert_eq!(thread.comment.id, 1);
+        assert!(!thread.resolved);
+        let replies: Vec<_> = thread
+            .replies
+            .iter()
+            .map(|r| (r.id, r.parent_id, r.done))
+            .collect();
+        assert_eq!(
+            replies,
+            vec![(2, Some(1), false), (3, Some(2), true), (4, Some(3), false),]
+        );
+    }
+
+    #[test]
+    fn test_resolved_thread() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_comment_start(comment(1, "Alice", "Fix this"))
+                    .add_comment_start(comment(2, "Bob", "Done").parent_comment_id(1))
+                    .add_run(Run::new().add_text("Text"))
+                    .add_comment_end(1)
+                    .add_comment_end(2),
+            )
+            .comments_extended(CommentsExtended {
+                children: vec![
+                    CommentExtended::new("00000001").done(),
+                    CommentExtended::new("00000002").done(),
+                ],
+            });
+        let threads = docx.comment_threads();
+        assert!(threads[0].resolved);
+        assert!(threads[0].comment.done);
+        assert!(threads[0].replies[0].done);
+    }
+
+    #[test]
+    fn test_anchor_across_paragraphs() {
+        let mut docx = Docx::new();
+        docx.document = Document::new()
+            .add_comment_start(comment(1, "Alice", "All of it"))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("One")))
+            .add_paragraph(Paragraph::new())
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Three"))
+                    .add_run(Run::new().add_text("!")),
+            )
+            .add_comment_end(1);
+        let threads = docx.comment_threads();
+        let anchor = threads[0].comment.anchor.as_ref().unwrap();
+        assert_eq!(anchor.text, "One\n\nThree!");
+        assert_eq!(anchor.start, BlockLocation::body(1));
+        assert_eq!(anchor.start_position, TextPosition { run: 0, offset: 0 });
+        assert_eq!(anchor.end, BlockLocation::body(3));
+        assert_eq!(anchor.end_position, TextPosition { run: 1, offset: 1 });
+    }
+
+    #[test]
+    fn test_anchor_in_header() {
+        let docx = Docx::new()
+            .header(
+                Header::new().add_paragraph(
+                    Paragraph::new()
+                        .add_run(Run::new().add_text("Draft "))
+                        .add_comment_start(comment(1, "Alice", "Remove"))
+                        .add_run(Run::new().add_text("copy"))
+                        .add_comment_end(1),
+                ),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Body")));
+        let (rid, _) = docx.document.headers().next().unwrap();
+        let threads = docx.comment_threads();
+        assert_eq!(threads.len(), 1);
+        let anchor = threads[0].comment.anchor.as_ref().unwrap();
+        assert_eq!(anchor.text, "copy");
+        assert_eq!(
+            anchor.start,
+            BlockLocation::new(DocumentPart::Header(rid.clone()), vec![0])
+        );
+        assert_eq!(anchor.start_position, TextPosition { run: 1, offset: 0 });
+        assert_eq!(anchor.end_position, TextPosition { run: 1, offset: 4 });
+    }
+
+    #[test]
+    fn test_anchor_with_escaped_characters() {
+        let mut run = Run::new().add_text("R&D <b>");
+        run.children
+            .push(RunChild::CommentEnd(CommentRangeEnd::new(1)));
+        let docx = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_comment_start(comment(1, "Alice", "Rename"))
+                .add_run(run)
+                .add_run(Run::new().add_text(" & more")),
+        );
+        let threads = docx.comment_threads();
+        let anchor = threads[0].comment.anchor.as_ref().unwrap();
+        assert_eq!(anchor.text, "R&D <b>");
+        assert_eq!(anchor.end_position, TextPosition { run: 0, offset: 7 });
+
+        let docx = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("A & B"))
+                .add_comment_start(comment(2, "Bob", "Open")),
+        );
+        let anchor = docx.comment_threads()[0].comment.anchor.clone().unwrap();
+        // A range without an end reaches to the end of the paragraph.
+        assert_eq!(anchor.start_position, TextPosition { run: 0, offset: 5 });
+        assert_eq!(anchor.end_position, TextPosition { run: 0, offset: 5 });
+    }
+}
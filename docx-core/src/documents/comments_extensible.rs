@@ -0,0 +1,69 @@
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// i.e.    <w16cex:commentExtensible w16cex:durableId="3F2B6A1C" w16cex:dateUtc="2024-01-01T09:00:00Z"/>
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentsExtensible {
+    pub children: Vec<CommentExtensible>,
+}
+
+impl CommentsExtensible {
+    pub fn new() -> CommentsExtensible {
+        Default::default()
+    }
+
+    pub fn add_comment_extensible(mut self, c: CommentExtensible) -> CommentsExtensible {
+        self.children.push(c);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Drops the entries of comments that are gone and adds one for each new
+    /// comment whose date is in UTC.
+    pub(crate) fn update(&mut self, ids: &CommentsIds, comments: &[Comment]) {
+        let durable_ids: HashSet<&str> =
+            ids.children.iter().map(|c| c.durable_id.as_str()).collect();
+        self.children
+            .retain(|c| durable_ids.contains(c.durable_id.as_str()));
+        let known: HashSet<String> = self.children.iter().map(|c| c.durable_id.clone()).collect();
+        let dates: HashMap<&str, &str> = comments
+            .iter()
+            .filter_map(|c| Some((last_paragraph_id(c)?, c.date.as_str())))
+            .collect();
+        for id in &ids.children {
+            if known.contains(&id.durable_id) {
+                continue;
+            }
+            if let Some(date) = dates
+                .get(id.paragraph_id.as_str())
+                .filter(|d| d.ends_with('Z'))
+            {
+                self.children
+                    .push(CommentExtensible::new(&id.durable_id).date_utc(*date));
+            }
+        }
+    }
+}
+
+impl BuildXML for CommentsExtensible {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .declaration(Some(true))?
+            .open_comments_extensible()?
+            .add_children(&self.children)?
+            .close()?
+            .into_inner()
+    }
+}
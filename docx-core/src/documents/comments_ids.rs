@@ -0,0 +1,135 @@
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// i.e.    <w16cid:commentId w16cid:paraId="00000001" w16cid:durableId="3F2B6A1C"/>
+//
+// Word identifies a comment by a durable id that survives renumbering of the
+// comment ids, keyed by the last paragraph of the comment.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentsIds {
+    pub children: Vec<CommentId>,
+}
+
+impl CommentsIds {
+    pub fn new() -> CommentsIds {
+        Default::default()
+    }
+
+    pub fn add_comment_id(mut self, c: CommentId) -> CommentsIds {
+        self.children.push(c);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// The durable id of the comment whose last paragraph has the id.
+    pub fn durable_id(&self, paragraph_id: &str) -> Option<&str> {
+        self.children
+            .iter()
+            .find(|c| c.paragraph_id == paragraph_id)
+            .map(|c| c.durable_id.as_str())
+    }
+
+    /// Lists the comments, keeping the durable ids already known for their
+    /// paragraphs and giving the others new ones.
+    pub(crate) fn update(&mut self, comments: &[Comment]) {
+        let known: HashMap<String, String> = std::mem::take(&mut self.children)
+            .into_iter()
+            .map(|c| (c.paragraph_id, c.durable_id))
+            .collect();
+        let mut used = HashSet::new();
+        let mut seen = HashSet::new();
+        for comment in comments {
+            if !seen.insert(comment.id) {
+                continue;
+            }
+            let Some(paragraph_id) = last_paragraph_id(comment) else {
+                continue;
+            };
+            let durable_id = known
+                .get(paragraph_id)
+                .filter(|id| used.insert(id.to_string()))
+                .cloned()
+                .unwrap_or_default();
+            self.children.push(CommentId::new(paragraph_id, durable_id));
+        }
+
+        let mut next = 1;
+        for c in self.children.iter_mut().filter(|c| c.durable_id.is_empty()) {
+            // Paragraph ids are valid durable ids and keep the output stable.
+            let from_paragraph = u32::from_str_radix(&c.paragraph_id, 16)
+                .ok()
+                .filter(|id| *id > 0 && *id < 0x7FFF_FFFF)
+                .map(|id| format!("{id:08X}"))
+                .filter(|id| !used.contains(id));
+            let id = from_paragraph.unwrap_or_else(|| loop {
+                let id = format!("{next:08X}");
+                next += 1;
+                if !used.contains(&id) {
+                    break id;
+                }
+            });
+            used.insert(id.clone());
+            c.durable_id = id;
+        }
+    }
+}
+
+/// The paragraph Word keys the extended comment parts by.
+pub(crate) fn last_paragraph_id(comment: &Comment) -> Option<&str> {
+    comment.children.iter().rev().find_map(|c| match c {
+        CommentChild::Paragraph(p) => Some(p.id.as_str()),
+        _ => None,
+    })
+}
+
+impl BuildXML for CommentsIds {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .declaration(Some(true))?
+            .open_comments_ids()?
+            .add_children(&self.children)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_update_comments_ids() {
+        let comment =
+            |id: usize, para_id: &str| Comment::new(id).add_paragraph(Paragraph::new().id(para_id));
+        let mut ids = CommentsIds::new().add_comment_id(CommentId::new("0000000a", "1234ABCD"));
+        ids.update(&[
+            comment(1, "0000000a"),
+            comment(2, "0000000b"),
+            comment(2, "0000000b"),
+            comment(3, "ffffffff"),
+        ]);
+        assert_eq!(
+            ids.children,
+            vec![
+                CommentId::new("0000000a", "1234ABCD"),
+                CommentId::new("0000000b", "0000000B"),
+                CommentId::new("ffffffff", "00000001"),
+            ]
+        );
+    }
+}
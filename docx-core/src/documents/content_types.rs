@@ -26,6 +26,12 @@ impl ContentTypes {
         self
     }
 
+    /// The content type overridden for a part, by its name such as
+    /// `/word/document.xml`.
+    pub(crate) fn content_type(&self, part_name: &str) -> Option<&str> {
+        self.types.get(part_name).map(|t| t.as_str())
+    }
+
     pub fn set_default(mut self) -> ContentTypes {
         self.types.insert(
             "/_rels/.rels".to_owned(),
@@ -130,6 +136,40 @@ impl ContentTypes {
         );
         self
     }
+    pub fn add_theme(mut self) -> Self {
+        self.types.insert(
+            "/word/theme/theme1.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.theme+xml".to_owned(),
+        );
+        self
+    }
+
+    pub fn add_comments_ids(mut self) -> Self {
+        self.types.insert(
+            "/word/commentsIds.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.commentsIds+xml"
+                .to_owned(),
+        );
+        self
+    }
+
+    pub fn add_comments_extensible(mut self) -> Self {
+        self.types.insert(
+            "/word/commentsExtensible.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.commentsExtensible+xml"
+                .to_owned(),
+        );
+        self
+    }
+
+    pub fn add_people(mut self) -> Self {
+        self.types.insert(
+            "/word/people.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.people+xml".to_owned(),
+        );
+        self
+    }
+
     pub fn add_footnotes(mut self) -> Self {
         self.types.insert(
             "/word/footnotes.xml".to_owned(),
@@ -0,0 +1,1284 @@
+//! Addresses blocks of a document and edits around them.
+//!
+//! A block is addressed by its part and the indices leading to it from the
+//! top of the part, the same scheme [`TextMatch`] uses. A [`Cursor`] edits
+//! the container of a block: it inserts, removes and wraps blocks and splits
+//! and merges paragraphs, and keeps bookmark and comment ranges whole.
+
+use std::collections::HashSet;
+
+use super::find::BlockNode;
+use super::paragraph_text::*;
+use crate::documents::*;
+use crate::escape::{escaped_offset, unescape_text};
+use crate::EditError;
+
+/// Runs `$body` with the children of a container and the index in them.
+macro_rules! with_children {
+    ($container:expr, $index:expr, |$children:ident, $i:ident| $body:expr) => {
+        match $container {
+            Container::Body($children) => {
+                let $i = $index;
+                $body
+            }
+            Container::Section($children) => {
+                let $i = $index;
+                $body
+            }
+            Container::Cell($children) => {
+                let $i = $index;
+                $body
+            }
+            Container::Tag($children) => {
+                let $i = $index;
+                $body
+            }
+            Container::Toc(toc) => {
+                let ($children, $i) = toc_contents(toc, $index);
+                $body
+            }
+            Container::Header($children) => {
+                let $i = $index;
+                $body
+            }
+            Container::Footer($children) => {
+                let $i = $index;
+                $body
+            }
+        }
+    };
+}
+
+/// Where a block is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlockLocation {
+    pub part: DocumentPart,
+    /// The indices leading to the block from the top of the part. Table
+    /// cells add the row and cell index, and the contents of a table of
+    /// contents are counted as one list.
+    pub path: Vec<usize>,
+}
+
+impl BlockLocation {
+    pub fn new(part: DocumentPart, path: Vec<usize>) -> Self {
+        Self { part, path }
+    }
+
+    /// A block at the top of the body.
+    pub fn body(index: usize) -> Self {
+        Self::new(DocumentPart::Body, vec![index])
+    }
+}
+
+impl From<&TextMatch> for BlockLocation {
+    fn from(m: &TextMatch) -> Self {
+        Self::new(m.part.clone(), m.paragraph.clone())
+    }
+}
+
+/// Which paragraphs [`Docx::locate`] returns.
+#[derive(Debug, Clone)]
+pub enum ParagraphSelector {
+    /// Paragraphs whose text matches.
+    Text(SearchPattern),
+    /// Paragraphs with a style, by id.
+    Style(String),
+    /// The paragraph a bookmark starts in, by bookmark name.
+    Bookmark(String),
+    /// The paragraph at an index among the paragraphs of the body, nested
+    /// ones included, in document order.
+    Index(usize),
+}
+
+impl ParagraphSelector {
+    pub fn text(pattern: impl Into<SearchPattern>) -> Self {
+        ParagraphSelector::Text(pattern.into())
+    }
+
+    pub fn style(style_id: impl Into<String>) -> Self {
+        ParagraphSelector::Style(style_id.into())
+    }
+
+    pub fn bookmark(name: impl Into<String>) -> Self {
+        ParagraphSelector::Bookmark(name.into())
+    }
+
+    pub fn index(index: usize) -> Self {
+        ParagraphSelector::Index(index)
+    }
+}
+
+impl Docx {
+    /// Locates paragraphs in the body, headers and footers, in that order.
+    pub fn locate(&self, selector: &ParagraphSelector) -> Vec<BlockLocation> {
+        let mut locator = Locator {
+            selector,
+            part: DocumentPart::Body,
+            path: vec![],
+            count: 0,
+            found: vec![],
+        };
+        locator.blocks(self.document.children.iter().map(BlockNode::from));
+        if matches!(selector, ParagraphSelector::Index(_)) {
+            return locator.found;
+        }
+        for (rid, header) in self.document.headers() {
+            locator.part = DocumentPart::Header(rid.clone());
+            locator.blocks(header.children.iter().map(BlockNode::from));
+        }
+        for (rid, footer) in self.document.footers() {
+            locator.part = DocumentPart::Footer(rid.clone());
+            locator.blocks(footer.children.iter().map(BlockNode::from));
+        }
+        locator.found
+    }
+
+    /// A cursor at a block of the body, a header or a footer.
+    pub fn cursor(&mut self, location: BlockLocation) -> Cursor<'_> {
+        Cursor {
+            docx: self,
+            location,
+        }
+    }
+}
+
+struct Locator<'s> {
+    selector: &'s ParagraphSelector,
+    part: DocumentPart,
+    path: Vec<usize>,
+    /// Paragraphs seen, for [`ParagraphSelector::Index`].
+    count: usize,
+    found: Vec<BlockLocation>,
+}
+
+impl<'a> Locator<'_> {
+    fn blocks(&mut self, nodes: impl Iterator<Item = BlockNode<'a>>) {
+        for (i, node) in nodes.enumerate() {
+            self.path.push(i);
+            match node {
+                BlockNode::Paragraph(paragraph) => self.paragraph(paragraph),
+                BlockNode::Table(table) => {
+                    for (r, TableChild::TableRow(row)) in table.rows.iter().enumerate() {
+                        for (c, TableRowChild::TableCell(cell)) in row.cells.iter().enumerate() {
+                            self.path.extend([r, c]);
+                            self.blocks(cell.children.iter().map(BlockNode::from));
+                            self.path.truncate(self.path.len() - 2);
+                        }
+                    }
+                }
+                BlockNode::StructuredDataTag(tag) => {
+                    self.blocks(tag.children.iter().map(BlockNode::from))
+                }
+                BlockNode::TableOfContents(toc) => self.blocks(
+                    toc.before_contents
+                        .iter()
+                        .chain(&toc.after_contents)
+                        .map(BlockNode::from),
+                ),
+                BlockNode::Section(section) => {
+                    self.blocks(section.children.iter().map(BlockNode::from))
+                }
+                BlockNode::CommentStart(_) | BlockNode::CommentEnd(_) | BlockNode::Other => {}
+            }
+            self.path.pop();
+        }
+    }
+
+    fn paragraph(&mut self, paragraph: &Paragraph) {
+        let found = match self.selector {
+            ParagraphSelector::Text(pattern) => {
+                let mut runs = vec![];
+                collect_runs(&paragraph.children, &mut runs);
+                pattern.is_match(&text_segments(&runs).0)
+            }
+            ParagraphSelector::Style(style_id) => paragraph
+                .property
+                .style
+                .as_ref()
+                .is_some_and(|s| s.val == *style_id),
+            ParagraphSelector::Bookmark(name) => paragraph
+                .children
+                .iter()
+                .any(|c| matches!(c, ParagraphChild::BookmarkStart(b) if b.name == *name)),
+            ParagraphSelector::Index(index) => self.count == *index,
+        };
+        self.count += 1;
+        if found {
+            self.found
+                .push(BlockLocation::new(self.part.clone(), self.path.clone()));
+        }
+    }
+}
+
+/// Edits around a block.
+///
+/// Blocks are inserted, removed and wrapped in the container of the block
+/// the cursor is at, such as the body or a table cell. After an edit the
+/// cursor is still at the same block, or at the block that took its place.
+pub struct Cursor<'a> {
+    docx: &'a mut Docx,
+    location: BlockLocation,
+}
+
+impl Cursor<'_> {
+    pub fn location(&self) -> &BlockLocation {
+        &self.location
+    }
+
+    /// Inserts a paragraph before the block and returns its location.
+    pub fn insert_paragraph_before(
+        &mut self,
+        paragraph: Paragraph,
+    ) -> Result<BlockLocation, EditError> {
+        self.insert(0, NewBlock::Paragraph(Box::new(paragraph)))
+    }
+
+    /// Inserts a paragraph after the block and returns its location.
+    pub fn insert_paragraph_after(
+        &mut self,
+        paragraph: Paragraph,
+    ) -> Result<BlockLocation, EditError> {
+        self.insert(1, NewBlock::Paragraph(Box::new(paragraph)))
+    }
+
+    /// Inserts a table before the block and returns its location.
+    pub fn insert_table_before(&mut self, table: Table) -> Result<BlockLocation, EditError> {
+        self.insert(0, NewBlock::Table(Box::new(table)))
+    }
+
+    /// Inserts a table after the block and returns its location.
+    pub fn insert_table_after(&mut self, table: Table) -> Result<BlockLocation, EditError> {
+        self.insert(1, NewBlock::Table(Box::new(table)))
+    }
+
+    /// Removes `count` blocks starting at the cursor. Bookmarks and comments
+    /// that lose one end of their range are removed entirely.
+    pub fn remove(&mut self, count: usize) -> Result<(), EditError> {
+        let unpaired = self.unpaired_markers()?;
+        let (container, index) = self.container()?;
+        with_children!(container, index, |children, i| {
+            if i + count > children.len() {
+                return Err(EditError::OutOfRange);
+            }
+            children.drain(i..i + count);
+        });
+        let broken: HashSet<Marker> = self
+            .unpaired_markers()?
+            .difference(&unpaired)
+            .cloned()
+            .collect();
+        if !broken.is_empty() {
+            self.remove_markers(&broken)?;
+        }
+        Ok(())
+    }
+
+    /// Moves `count` blocks starting at the cursor into a content control,
+    /// after the content it already has, and puts the control in their
+    /// place.
+    pub fn wrap_in_sdt(
+        &mut self,
+        count: usize,
+        mut tag: StructuredDataTag,
+    ) -> Result<(), EditError> {
+        let (container, index) = self.container()?;
+        with_children!(container, index, |children, i| {
+            if count == 0 || i + count > children.len() {
+                return Err(EditError::OutOfRange);
+            }
+            if !children[i..i + count].iter().all(BlockChild::can_wrap) {
+                return Err(EditError::Unsupported);
+            }
+            let wrapped: Vec<StructuredDataTagChild> = children
+                .drain(i..i + count)
+                .filter_map(BlockChild::into_tag_child)
+                .collect();
+            tag.children.extend(wrapped);
+            tag.has_numbering |= tag.children.iter().any(|c| match c {
+                StructuredDataTagChild::Paragraph(p) => p.has_numbering,
+                StructuredDataTagChild::Table(t) => t.has_numbering,
+                _ => false,
+            });
+            let tag = BlockChild::tag(tag).ok_or(EditError::Unsupported)?;
+            children.insert(i, tag);
+        });
+        Ok(())
+    }
+
+    /// Splits the paragraph at a position in its text, as [`Docx::find`]
+    /// reports them. The text from the position on goes to a new paragraph
+    /// with the same properties, whose location is returned; a section break
+    /// at the end of the paragraph moves with it.
+    pub fn split_paragraph(&mut self, at: TextPosition) -> Result<BlockLocation, EditError> {
+        let (container, index) = self.container()?;
+        with_children!(container, index, |children, i| {
+            let paragraph = children
+                .get_mut(i)
+                .ok_or(EditError::NotFound)?
+                .paragraph_mut()
+                .ok_or(EditError::NotParagraph)?;
+            let mut run = at.run;
+            let tail = split_children(&mut paragraph.children, &mut run, at.offset)?
+                .ok_or(EditError::OutOfRange)?;
+            let mut second = Paragraph {
+                id: generate_para_id(),
+                children: tail,
+                property: paragraph.property.clone(),
+                has_numbering: paragraph.has_numbering,
+            };
+            second.property.section_property = paragraph.property.section_property.take();
+            children.insert(i + 1, BlockChild::paragraph(second));
+        });
+        Ok(self.sibling(1))
+    }
+
+    /// Appends the content of the next paragraph to this one and removes the
+    /// next. The merged paragraph keeps its properties and takes over a
+    /// section break of the next one.
+    pub fn merge_with_next(&mut self) -> Result<(), EditError> {
+        let (container, index) = self.container()?;
+        with_children!(container, index, |children, i| {
+            if children
+                .get_mut(i)
+                .ok_or(EditError::NotFound)?
+                .paragraph_mut()
+                .is_none()
+            {
+                return Err(EditError::NotParagraph);
+            }
+            let next = children
+                .get_mut(i + 1)
+                .ok_or(EditError::OutOfRange)?
+                .paragraph_mut()
+                .ok_or(EditError::NotParagraph)?;
+            let next = std::mem::take(next);
+            children.remove(i + 1);
+            if let Some(paragraph) = children[i].paragraph_mut() {
+                paragraph.children.extend(next.children);
+                paragraph.has_numbering |= next.has_numbering;
+                if paragraph.property.section_property.is_none() {
+                    paragraph.property.section_property = next.property.section_property;
+                }
+            }
+        });
+        Ok(())
+    }
+
+    fn insert(&mut self, offset: usize, block: NewBlock) -> Result<BlockLocation, EditError> {
+        let (container, index) = self.container()?;
+        with_children!(container, index, |children, i| {
+            if i >= children.len() {
+                return Err(EditError::NotFound);
+            }
+            children.insert(
+                i + offset,
+                match block {
+                    NewBlock::Paragraph(p) => BlockChild::paragraph(*p),
+                    NewBlock::Table(t) => BlockChild::table(*t),
+                },
+            );
+        });
+        if offset == 0 {
+            // The block the cursor is at moved one on.
+            let location = self.location.clone();
+            self.location = self.sibling(1);
+            Ok(location)
+        } else {
+            Ok(self.sibling(1))
+        }
+    }
+
+    fn sibling(&self, offset: usize) -> BlockLocation {
+        let mut location = self.location.clone();
+        if let Some(last) = location.path.last_mut() {
+            *last += offset;
+        }
+        location
+    }
+
+    fn container(&mut self) -> Result<(Container<'_>, usize), EditError> {
+        let (last, parents) = self.location.path.split_last().ok_or(EditError::NotFound)?;
+        let mut container = match &self.location.part {
+            DocumentPart::Body => Container::Body(&mut self.docx.document.children),
+            DocumentPart::Header(rid) => self
+                .docx
+                .document
+                .headers_mut()
+                .find(|(id, _)| id == rid)
+                .map(|(_, header)| Container::Header(&mut header.children))
+                .ok_or(EditError::NotFound)?,
+            DocumentPart::Footer(rid) => self
+                .docx
+                .document
+                .footers_mut()
+                .find(|(id, _)| id == rid)
+                .map(|(_, footer)| Container::Footer(&mut footer.children))
+                .ok_or(EditError::NotFound)?,
+            DocumentPart::Footnote(_) | DocumentPart::Comment(_) => {
+                return Err(EditError::Unsupported)
+            }
+        };
+        let mut rest = parents;
+        while let Some((&index, tail)) = rest.split_first() {
+            let inner = with_children!(container, index, |children, i| children
+                .get_mut(i)
+                .and_then(BlockChild::inner))
+            .ok_or(EditError::NotFound)?;
+            (container, rest) = match inner {
+                Inner::Table(table) => {
+                    let [r, c, tail @ ..] = tail else {
+                        return Err(EditError::NotFound);
+                    };
+                    let TableChild::TableRow(row) =
+                        table.rows.get_mut(*r).ok_or(EditError::NotFound)?;
+                    let TableRowChild::TableCell(cell) =
+                        row.cells.get_mut(*c).ok_or(EditError::NotFound)?;
+                    (Container::Cell(&mut cell.children), tail)
+                }
+                Inner::Tag(tag) => (Container::Tag(&mut tag.children), tail),
+                Inner::Toc(toc) => (Container::Toc(toc), tail),
+                Inner::Section(section) => (Container::Section(&mut section.children), tail),
+            };
+        }
+        Ok((container, *last))
+    }
+
+    /// Bookmark and comment ends without their other end in the part.
+    fn unpaired_markers(&mut self) -> Result<HashSet<Marker>, EditError> {
+        let mut markers = Markers::default();
+        match &self.location.part {
+            DocumentPart::Body => visit_document(&self.docx.document, &mut markers),
+            DocumentPart::Header(rid) => {
+                for (_, header) in self.docx.document.headers().filter(|(id, _)| id == rid) {
+                    visit_header(header, &mut markers);
+                }
+            }
+            DocumentPart::Footer(rid) => {
+                for (_, footer) in self.docx.document.footers().filter(|(id, _)| id == rid) {
+                    visit_footer(footer, &mut markers);
+                }
+            }
+            DocumentPart::Footnote(_) | DocumentPart::Comment(_) => {
+                return Err(EditError::Unsupported)
+            }
+        }
+        Ok(markers
+            .starts
+            .symmetric_difference(&markers.ends)
+            .cloned()
+            .collect())
+    }
+
+    fn remove_markers(&mut self, markers: &HashSet<Marker>) -> Result<(), EditError> {
+        let mut remover = MarkerRemover(markers);
+        match &self.location.part {
+            DocumentPart::Body => {
+                let document = &mut self.docx.document;
+                document.children.retain(|c| match c {
+                    DocumentChild::BookmarkStart(b) => !markers.contains(&Marker::Bookmark(b.id)),
+                    DocumentChild::BookmarkEnd(b) => !markers.contains(&Marker::Bookmark(b.id)),
+                    DocumentChild::CommentStart(c) => !markers.contains(&Marker::Comment(c.id)),
+                    DocumentChild::CommentEnd(c) => !markers.contains(&Marker::Comment(c.get_id())),
+                    _ => true,
+                });
+                visit_document_mut(document, &mut remover);
+            }
+            DocumentPart::Header(rid) => {
+                for (_, header) in self.docx.document.headers_mut().filter(|(id, _)| id == rid) {
+                    visit_header_mut(header, &mut remover);
+                }
+            }
+            DocumentPart::Footer(rid) => {
+                for (_, footer) in self.docx.document.footers_mut().filter(|(id, _)| id == rid) {
+                    visit_footer_mut(footer, &mut remover);
+                }
+            }
+            DocumentPart::Footnote(_) | DocumentPart::Comment(_) => {
+                return Err(EditError::Unsupported)
+            }
+        }
+        Ok(())
+    }
+}
+
+enum NewBlock {
+    Paragraph(Box<Paragraph>),
+    Table(Box<Table>),
+}
+
+/// The children of the container a block is in, as a list of blocks.
+enum Container<'a> {
+    Body(&'a mut Vec<DocumentChild>),
+    Section(&'a mut Vec<SectionChild>),
+    Cell(&'a mut Vec<TableCellContent>),
+    Tag(&'a mut Vec<StructuredDataTagChild>),
+    /// Counted as one list, the content before the field and after it.
+    Toc(&'a mut TableOfContents),
+    Header(&'a mut Vec<HeaderChild>),
+    Footer(&'a mut Vec<FooterChild>),
+}
+
+/// The list of a table of contents an index of the combined list is in.
+fn toc_contents(toc: &mut TableOfContents, index: usize) -> (&mut Vec<TocContent>, usize) {
+    if index < toc.before_contents.len() {
+        (&mut toc.before_contents, index)
+    } else {
+        let before = toc.before_contents.len();
+        (&mut toc.after_contents, index - before)
+    }
+}
+
+/// A block with children a cursor can step into.
+enum Inner<'a> {
+    Table(&'a mut Table),
+    Tag(&'a mut StructuredDataTag),
+    Toc(&'a mut TableOfContents),
+    Section(&'a mut Section),
+}
+
+/// The child type of a container.
+trait BlockChild: Sized {
+    fn paragraph(paragraph: Paragraph) -> Self;
+    fn table(table: Table) -> Self;
+    fn tag(tag: StructuredDataTag) -> Option<Self>;
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph>;
+    fn inner(&mut self) -> Option<Inner<'_>>;
+    /// Whether the child can move into a content control.
+    fn can_wrap(&self) -> bool;
+    fn into_tag_child(self) -> Option<StructuredDataTagChild>;
+}
+
+impl BlockChild for DocumentChild {
+    fn paragraph(paragraph: Paragraph) -> Self {
+        DocumentChild::Paragraph(Box::new(paragraph))
+    }
+
+    fn table(table: Table) -> Self {
+        DocumentChild::Table(Box::new(table))
+    }
+
+    fn tag(tag: StructuredDataTag) -> Option<Self> {
+        Some(DocumentChild::StructuredDataTag(Box::new(tag)))
+    }
+
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            DocumentChild::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    fn inner(&mut self) -> Option<Inner<'_>> {
+        match self {
+            DocumentChild::Table(t) => Some(Inner::Table(t)),
+            DocumentChild::StructuredDataTag(t) => Some(Inner::Tag(t)),
+            DocumentChild::TableOfContents(t) => Some(Inner::Toc(t)),
+            DocumentChild::Section(s) => Some(Inner::Section(s)),
+            _ => None,
+        }
+    }
+
+    fn can_wrap(&self) -> bool {
+        !matches!(
+            self,
+            DocumentChild::TableOfContents(_)
+                | DocumentChild::AltChunk(_)
+                | DocumentChild::Section(_)
+        )
+    }
+
+    fn into_tag_child(self) -> Option<StructuredDataTagChild> {
+        match self {
+            DocumentChild::Paragraph(p) => Some(StructuredDataTagChild::Paragraph(p)),
+            DocumentChild::Table(t) => Some(StructuredDataTagChild::Table(t)),
+            DocumentChild::StructuredDataTag(t) => {
+                Some(StructuredDataTagChild::StructuredDataTag(t))
+            }
+            DocumentChild::BookmarkStart(b) => Some(StructuredDataTagChild::BookmarkStart(b)),
+            DocumentChild::BookmarkEnd(b) => Some(StructuredDataTagChild::BookmarkEnd(b)),
+            DocumentChild::CommentStart(c) => Some(StructuredDataTagChild::CommentStart(c)),
+            DocumentChild::CommentEnd(c) => Some(StructuredDataTagChild::CommentEnd(c)),
+            _ => None,
+        }
+    }
+}
+
+impl BlockChild for SectionChild {
+    fn paragraph(paragraph: Paragraph) -> Self {
+        SectionChild::Paragraph(Box::new(paragraph))
+    }
+
+    fn table(table: Table) -> Self {
+        SectionChild::Table(Box::new(table))
+    }
+
+    fn tag(tag: StructuredDataTag) -> Option<Self> {
+        Some(SectionChild::StructuredDataTag(Box::new(tag)))
+    }
+
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            SectionChild::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    fn inner(&mut self) -> Option<Inner<'_>> {
+        match self {
+            SectionChild::Table(t) => Some(Inner::Table(t)),
+            SectionChild::StructuredDataTag(t) => Some(Inner::Tag(t)),
+            SectionChild::TableOfContents(t) => Some(Inner::Toc(t)),
+            _ => None,
+        }
+    }
+
+    fn can_wrap(&self) -> bool {
+        !matches!(
+            self,
+            SectionChild::TableOfContents(_) | SectionChild::AltChunk(_)
+        )
+    }
+
+    fn into_tag_child(self) -> Option<StructuredDataTagChild> {
+        match self {
+            SectionChild::Paragraph(p) => Some(StructuredDataTagChild::Paragraph(p)),
+            SectionChild::Table(t) => Some(StructuredDataTagChild::Table(t)),
+            SectionChild::StructuredDataTag(t) => {
+                Some(StructuredDataTagChild::StructuredDataTag(t))
+            }
+            SectionChild::BookmarkStart(b) => Some(StructuredDataTagChild::BookmarkStart(b)),
+            SectionChild::BookmarkEnd(b) => Some(StructuredDataTagChild::BookmarkEnd(b)),
+            SectionChild::CommentStart(c) => Some(StructuredDataTagChild::CommentStart(c)),
+            SectionChild::CommentEnd(c) => Some(StructuredDataTagChild::CommentEnd(c)),
+            _ => None,
+        }
+    }
+}
+
+impl BlockChild for TableCellContent {
+    fn paragraph(paragraph: Paragraph) -> Self {
+        TableCellContent::Paragraph(Box::new(paragraph))
+    }
+
+    fn table(table: Table) -> Self {
+        TableCellContent::Table(Box::new(table))
+    }
+
+    fn tag(tag: StructuredDataTag) -> Option<Self> {
+        Some(TableCellContent::StructuredDataTag(Box::new(tag)))
+    }
+
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            TableCellContent::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    fn inner(&mut self) -> Option<Inner<'_>> {
+        match self {
+            TableCellContent::Table(t) => Some(Inner::Table(t)),
+            TableCellContent::StructuredDataTag(t) => Some(Inner::Tag(t)),
+            TableCellContent::TableOfContents(t) => Some(Inner::Toc(t)),
+            TableCellContent::Paragraph(_) => None,
+        }
+    }
+
+    fn can_wrap(&self) -> bool {
+        !matches!(self, TableCellContent::TableOfContents(_))
+    }
+
+    fn into_tag_child(self) -> Option<StructuredDataTagChild> {
+        match self {
+            TableCellContent::Paragraph(p) => Some(StructuredDataTagChild::Paragraph(p)),
+            TableCellContent::Table(t) => Some(StructuredDataTagChild::Table(t)),
+            TableCellContent::StructuredDataTag(t) => {
+                Some(StructuredDataTagChild::StructuredDataTag(t))
+            }
+            TableCellContent::TableOfContents(_) => None,
+        }
+    }
+}
+
+impl BlockChild for StructuredDataTagChild {
+    fn paragraph(paragraph: Paragraph) -> Self {
+        StructuredDataTagChild::Paragraph(Box::new(paragraph))
+    }
+
+    fn table(table: Table) -> Self {
+        StructuredDataTagChild::Table(Box::new(table))
+    }
+
+    fn tag(tag: StructuredDataTag) -> Option<Self> {
+        Some(StructuredDataTagChild::StructuredDataTag(Box::new(tag)))
+    }
+
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            StructuredDataTagChild::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    fn inner(&mut self) -> Option<Inner<'_>> {
+        match self {
+            StructuredDataTagChild::Table(t) => Some(Inner::Table(t)),
+            StructuredDataTagChild::StructuredDataTag(t) => Some(Inner::Tag(t)),
+            _ => None,
+        }
+    }
+
+    fn can_wrap(&self) -> bool {
+        true
+    }
+
+    fn into_tag_child(self) -> Option<StructuredDataTagChild> {
+        Some(self)
+    }
+}
+
+impl BlockChild for TocContent {
+    fn paragraph(paragraph: Paragraph) -> Self {
+        TocContent::Paragraph(Box::new(paragraph))
+    }
+
+    fn table(table: Table) -> Self {
+        TocContent::Table(Box::new(table))
+    }
+
+    fn tag(_tag: StructuredDataTag) -> Option<Self> {
+        None
+    }
+
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            TocContent::Paragraph(p) => Some(p),
+            TocContent::Table(_) => None,
+        }
+    }
+
+    fn inner(&mut self) -> Option<Inner<'_>> {
+        match self {
+            TocContent::Table(t) => Some(Inner::Table(t)),
+            TocContent::Paragraph(_) => None,
+        }
+    }
+
+    fn can_wrap(&self) -> bool {
+        false
+    }
+
+    fn into_tag_child(self) -> Option<StructuredDataTagChild> {
+        None
+    }
+}
+
+impl BlockChild for HeaderChild {
+    fn paragraph(paragraph: Paragraph) -> Self {
+        HeaderChild::Paragraph(Box::new(paragraph))
+    }
+
+    fn table(table: Table) -> Self {
+        HeaderChild::Table(Box::new(table))
+    }
+
+    fn tag(tag: StructuredDataTag) -> Option<Self> {
+        Some(HeaderChild::StructuredDataTag(Box::new(tag)))
+    }
+
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            HeaderChild::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    fn inner(&mut self) -> Option<Inner<'_>> {
+        match self {
+            HeaderChild::Table(t) => Some(Inner::Table(t)),
+            HeaderChild::StructuredDataTag(t) => Some(Inner::Tag(t)),
+            HeaderChild::Paragraph(_) => None,
+        }
+    }
+
+    fn can_wrap(&self) -> bool {
+        true
+    }
+
+    fn into_tag_child(self) -> Option<StructuredDataTagChild> {
+        Some(match self {
+            HeaderChild::Paragraph(p) => StructuredDataTagChild::Paragraph(p),
+            HeaderChild::Table(t) => StructuredDataTagChild::Table(t),
+            HeaderChild::StructuredDataTag(t) => StructuredDataTagChild::StructuredDataTag(t),
+        })
+    }
+}
+
+impl BlockChild for FooterChild {
+    fn paragraph(paragraph: Paragraph) -> Self {
+        FooterChild::Paragraph(Box::new(paragraph))
+    }
+
+    fn table(table: Table) -> Self {
+        FooterChild::Table(Box::new(table))
+    }
+
+    fn tag(tag: StructuredDataTag) -> Option<Self> {
+        Some(FooterChild::StructuredDataTag(Box::new(tag)))
+    }
+
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            FooterChild::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    fn inner(&mut self) -> Option<Inner<'_>> {
+        match self {
+            FooterChild::Table(t) => Some(Inner::Table(t)),
+            FooterChild::StructuredDataTag(t) => Some(Inner::Tag(t)),
+            FooterChild::Paragraph(_) => None,
+        }
+    }
+
+    fn can_wrap(&self) -> bool {
+        true
+    }
+
+    fn into_tag_child(self) -> Option<StructuredDataTagChild> {
+        Some(match self {
+            FooterChild::Paragraph(p) => StructuredDataTagChild::Paragraph(p),
+            FooterChild::Table(t) => StructuredDataTagChild::Table(t),
+            FooterChild::StructuredDataTag(t) => StructuredDataTagChild::StructuredDataTag(t),
+        })
+    }
+}
+
+/// Splits paragraph content at a byte offset in the text of the `run`th run
+/// [`collect_runs`] finds, returning the content after it. `run` counts down
+/// the runs passed; `None` means the run is not in `children`.
+pub(crate) fn split_children(
+    children: &mut Vec<ParagraphChild>,
+    run: &mut usize,
+    offset: usize,
+) -> Result<Option<Vec<ParagraphChild>>, EditError> {
+    for k in 0..children.len() {
+        let head = match &mut children[k] {
+            ParagraphChild::Run(r) => {
+                if *run > 0 {
+                    *run -= 1;
+                    continue;
+                }
+                ParagraphChild::Run(Box::new(split_run(r, offset)?))
+            }
+            ParagraphChild::Hyperlink(link) => {
+                let Some(tail) = split_children(&mut link.children, run, offset)? else {
+                    continue;
+                };
+                ParagraphChild::Hyperlink(Hyperlink {
+                    children: tail,
+                    ..link.clone()
+                })
+            }
+            ParagraphChild::StructuredDataTag(tag) => {
+                let Some(tail) = split_tag_children(&mut tag.children, run, offset)? else {
+                    continue;
+                };
+                ParagraphChild::StructuredDataTag(Box::new(StructuredDataTag {
+                    children: tail,
+                    ..(**tag).clone()
+                }))
+            }
+            ParagraphChild::Insert(insert) => {
+                let Some(at) = insert
+                    .children
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| matches!(c, InsertChild::Run(_)))
+                    .nth(*run)
+                    .map(|(at, _)| at)
+                else {
+                    *run -= insert
+                        .children
+                        .iter()
+                        .filter(|c| matches!(c, InsertChild::Run(_)))
+                        .count();
+                    continue;
+                };
+                let InsertChild::Run(r) = &mut insert.children[at] else {
+                    unreachable!("the index is of a run");
+                };
+                let mut tail = vec![InsertChild::Run(Box::new(split_run(r, offset)?))];
+                tail.extend(insert.children.drain(at + 1..));
+                ParagraphChild::Insert(Insert {
+                    children: tail,
+                    ..insert.clone()
+                })
+            }
+            _ => continue,
+        };
+        let mut tail = vec![head];
+        tail.extend(children.drain(k + 1..));
+        return Ok(Some(tail));
+    }
+    Ok(None)
+}
+
+fn split_tag_children(
+    children: &mut Vec<StructuredDataTagChild>,
+    run: &mut usize,
+    offset: usize,
+) -> Result<Option<Vec<StructuredDataTagChild>>, EditError> {
+    for k in 0..children.len() {
+        let head = match &mut children[k] {
+            StructuredDataTagChild::Run(r) => {
+                if *run > 0 {
+                    *run -= 1;
+                    continue;
+                }
+                StructuredDataTagChild::Run(Box::new(split_run(r, offset)?))
+            }
+            StructuredDataTagChild::StructuredDataTag(tag) => {
+                let Some(tail) = split_tag_children(&mut tag.children, run, offset)? else {
+                    continue;
+                };
+                StructuredDataTagChild::StructuredDataTag(Box::new(StructuredDataTag {
+                    children: tail,
+                    ..(**tag).clone()
+                }))
+            }
+            _ => continue,
+        };
+        let mut tail = vec![head];
+        tail.extend(children.drain(k + 1..));
+        return Ok(Some(tail));
+    }
+    Ok(None)
+}
+
+/// Splits a run at a byte offset in its unescaped text and returns the part after it.
+fn split_run(run: &mut Run, offset: usize) -> Result<Run, EditError> {
+    let mut start = 0;
+    for k in 0..run.children.len() {
+        let at = match &mut run.children[k] {
+            RunChild::Text(text) if offset < start + unescape_text(&text.text).len() => {
+                // The offset is in the unescaped text; cutting at a character
+                // boundary of it never lands inside an entity of the storage.
+                let at = offset - start;
+                if !unescape_text(&text.text).is_char_boundary(at) {
+                    return Err(EditError::OutOfRange);
+                }
+                let rest = text.text.split_off(escaped_offset(&text.text, at));
+                Some(RunChild::Text(Text {
+                    text: rest,
+                    ..text.clone()
+                }))
+            }
+            RunChild::Text(text) => {
+                start += unescape_text(&text.text).len();
+                continue;
+            }
+            _ if offset == start => None,
+            _ => continue,
+        };
+        let mut children: Vec<RunChild> = at.into_iter().collect();
+        let from = if children.is_empty() { k } else { k + 1 };
+        children.extend(run.children.drain(from..));
+        // Text split off at its start leaves an empty text node behind.
+        run.children
+            .retain(|c| !matches!(c, RunChild::Text(t) if t.text.is_empty()));
+        return Ok(Run {
+            run_property: run.run_property.clone(),
+            children,
+        });
+    }
+    if offset == start {
+        Ok(Run {
+            run_property: run.run_property.clone(),
+            children: vec![],
+        })
+    } else {
+        Err(EditError::OutOfRange)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Marker {
+    Bookmark(usize),
+    Comment(usize),
+}
+
+#[derive(Default)]
+struct Markers {
+    starts: HashSet<Marker>,
+    ends: HashSet<Marker>,
+}
+
+impl DocumentTreeVisitor for Markers {
+    fn visit_bookmark_start(&mut self, bookmark: &BookmarkStart) {
+        self.starts.insert(Marker::Bookmark(bookmark.id));
+    }
+
+    fn visit_bookmark_end(&mut self, bookmark: &BookmarkEnd) {
+        self.ends.insert(Marker::Bookmark(bookmark.id));
+    }
+
+    fn visit_comment_start(&mut self, comment: &CommentRangeStart) {
+        self.starts.insert(Marker::Comment(comment.id));
+    }
+
+    fn visit_comment_end(&mut self, comment: &CommentRangeEnd) {
+        self.ends.insert(Marker::Comment(comment.get_id()));
+    }
+}
+
+/// Removes the bookmark and comment ends of the given ranges.
+struct MarkerRemover<'a>(&'a HashSet<Marker>);
+
+impl MarkerRemover<'_> {
+    fn keeps(&self, marker: Marker) -> bool {
+        !self.0.contains(&marker)
+    }
+}
+
+impl DocumentTreeVisitorMut for MarkerRemover<'_> {
+    fn visit_section(&mut self, section: &mut Section) {
+        section.children.retain(|c| match c {
+            SectionChild::BookmarkStart(b) => self.keeps(Marker::Bookmark(b.id)),
+            SectionChild::BookmarkEnd(b) => self.keeps(Marker::Bookmark(b.id)),
+            SectionChild::CommentStart(c) => self.keeps(Marker::Comment(c.get_id())),
+            SectionChild::CommentEnd(c) => self.keeps(Marker::Comment(c.get_id())),
+            _ => true,
+        });
+    }
+
+    fn visit_structured_data_tag(&mut self, tag: &mut StructuredDataTag) {
+        tag.children.retain(|c| match c {
+            StructuredDataTagChild::BookmarkStart(b) => self.keeps(Marker::Bookmark(b.id)),
+            StructuredDataTagChild::BookmarkEnd(b) => self.keeps(Marker::Bookmark(b.id)),
+            StructuredDataTagChild::CommentStart(c) => self.keeps(Marker::Comment(c.get_id())),
+            StructuredDataTagChild::CommentEnd(c) => self.keeps(Marker::Comment(c.get_id())),
+            _ => true,
+        });
+    }
+
+    fn visit_paragraph(&mut self, paragraph: &mut Paragraph) {
+        self.paragraph_children(&mut paragraph.children);
+    }
+
+    fn visit_hyperlink(&mut self, link: &mut Hyperlink) {
+        self.paragraph_children(&mut link.children);
+    }
+
+    fn visit_insert(&mut self, insert: &mut Insert) {
+        insert.children.retain(|c| match c {
+            InsertChild::CommentStart(c) => self.keeps(Marker::Comment(c.get_id())),
+            InsertChild::CommentEnd(c) => self.keeps(Marker::Comment(c.get_id())),
+            _ => true,
+        });
+    }
+
+    fn visit_delete(&mut self, delete: &mut Delete) {
+        delete.children.retain(|c| match c {
+            DeleteChild::CommentStart(c) => self.keeps(Marker::Comment(c.get_id())),
+            DeleteChild::CommentEnd(c) => self.keeps(Marker::Comment(c.get_id())),
+            _ => true,
+        });
+    }
+
+    fn visit_move_from(&mut self, moved: &mut MoveFrom) {
+        moved.children.retain(|c| match c {
+            MoveFromChild::CommentStart(c) => self.keeps(Marker::Comment(c.get_id())),
+            MoveFromChild::CommentEnd(c) => self.keeps(Marker::Comment(c.get_id())),
+            _ => true,
+        });
+    }
+
+    fn visit_move_to(&mut self, moved: &mut MoveTo) {
+        moved.children.retain(|c| match c {
+            MoveToChild::CommentStart(c) => self.keeps(Marker::Comment(c.get_id())),
+            MoveToChild::CommentEnd(c) => self.keeps(Marker::Comment(c.get_id())),
+            _ => true,
+        });
+    }
+
+    fn visit_run(&mut self, run: &mut Run) {
+        run.children.retain(|c| match c {
+            RunChild::CommentStart(c) => self.keeps(Marker::Comment(c.get_id())),
+            RunChild::CommentEnd(c) => self.keeps(Marker::Comment(c.get_id())),
+            _ => true,
+        });
+    }
+}
+
+impl MarkerRemover<'_> {
+    fn paragraph_children(&self, children: &mut Vec<ParagraphChild>) {
+        children.retain(|c| match c {
+            ParagraphChild::BookmarkStart(b) => self.keeps(Marker::Bookmark(b.id)),
+            ParagraphChild::BookmarkEnd(b) => self.keeps(Marker::Bookmark(b.id)),
+            ParagraphChild::CommentStart(c) => self.keeps(Marker::Comment(c.id)),
+            ParagraphChild::CommentEnd(c) => self.keeps(Marker::Comment(c.get_id())),
+            _ => true,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn text(docx: &Docx) -> Vec<String> {
+        docx.document
+            .children
+            .iter()
+            .map(|child| match child {
+                DocumentChild::Paragraph(p) => {
+                    let mut runs = vec![];
+                    collect_runs(&p.children, &mut runs);
+                    text_segments(&runs).0
+                }
+                DocumentChild::Table(_) => "<table>".to_owned(),
+                DocumentChild::StructuredDataTag(_) => "<sdt>".to_owned(),
+                _ => "<other>".to_owned(),
+            })
+            .collect()
+    }
+
+    fn paragraph(text: &str) -> Paragraph {
+        Paragraph::new().add_run(Run::new().add_text(text))
+    }
+
+    #[test]
+    fn test_locate() {
+        let docx = Docx::new()
+            .add_paragraph(paragraph("Intro"))
+            .add_table(Table::new(vec![TableRow::new(vec![
+                TableCell::new().add_paragraph(paragraph("Cell").style("Quote"))
+            ])]))
+            .add_paragraph(
+                paragraph("Target")
+                    .add_bookmark_start(1, "mark")
+                    .add_bookmark_end(1),
+            );
+        assert_eq!(
+            docx.locate(&ParagraphSelector::text("Cell")),
+            vec![BlockLocation::new(DocumentPart::Body, vec![1, 0, 0, 0])]
+        );
+        assert_eq!(
+            docx.locate(&ParagraphSelector::style("Quote")),
+            docx.locate(&ParagraphSelector::index(1))
+        );
+        assert_eq!(
+            docx.locate(&ParagraphSelector::bookmark("mark")),
+            vec![BlockLocation::body(2)]
+        );
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut docx = Docx::new()
+            .add_paragraph(paragraph("A"))
+            .add_paragraph(paragraph("B").add_comment_start(Comment::new(1)))
+            .add_paragraph(paragraph("C").add_comment_end(1));
+        let mut cursor = docx.cursor(BlockLocation::body(1));
+        assert_eq!(
+            cursor.insert_paragraph_before(paragraph("before")),
+            Ok(BlockLocation::body(1))
+        );
+        assert_eq!(cursor.location(), &BlockLocation::body(2));
+        assert_eq!(
+            cursor.insert_table_after(Table::new(vec![])),
+            Ok(BlockLocation::body(3))
+        );
+        assert_eq!(text(&docx), vec!["A", "before", "B", "<table>", "C"]);
+
+        // The comment loses its start, so its end goes too.
+        docx.cursor(BlockLocation::body(2)).remove(2).unwrap();
+        assert_eq!(text(&docx), vec!["A", "before", "C"]);
+        let DocumentChild::Paragraph(c) = &docx.document.children[2] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(c.children.len(), 1);
+        assert_eq!(
+            docx.cursor(BlockLocation::body(2)).remove(2),
+            Err(EditError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_wrap_in_sdt() {
+        let mut docx = Docx::new()
+            .add_paragraph(paragraph("A"))
+            .add_paragraph(paragraph("B"))
+            .add_paragraph(paragraph("C"));
+        docx.cursor(BlockLocation::body(1))
+            .wrap_in_sdt(2, StructuredDataTag::new().alias("Wrapped"))
+            .unwrap();
+        assert_eq!(text(&docx), vec!["A", "<sdt>"]);
+        let DocumentChild::StructuredDataTag(tag) = &docx.document.children[1] else {
+            panic!("expected a content control");
+        };
+        assert_eq!(tag.children.len(), 2);
+    }
+
+    #[test]
+    fn test_split_and_merge_paragraphs() {
+        let mut docx = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Hello "))
+                .add_hyperlink(
+                    Hyperlink::new("https://example.com", HyperlinkType::External)
+                        .add_run(Run::new().add_text("wide world")),
+                ),
+        );
+        let location = docx.locate(&ParagraphSelector::text("wide")).remove(0);
+        let second = docx
+            .cursor(location.clone())
+            .split_paragraph(TextPosition { run: 1, offset: 5 })
+            .unwrap();
+        assert_eq!(second, BlockLocation::body(1));
+        assert_eq!(text(&docx), vec!["Hello wide ", "world"]);
+
+        docx.cursor(location).merge_with_next().unwrap();
+        assert_eq!(text(&docx), vec!["Hello wide world"]);
+        assert_eq!(
+            docx.cursor(BlockLocation::body(0))
+                .split_paragraph(TextPosition { run: 3, offset: 0 }),
+            Err(EditError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_split_paragraph_with_escaped_characters() {
+        let mut docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("AT&T <b> R&D")));
+        let location = docx.locate(&ParagraphSelector::text("R&D")).remove(0);
+        let at = docx.find("R&D").remove(0).start;
+        assert_eq!(at, TextPosition { run: 0, offset: 9 });
+        docx.cursor(location).split_paragraph(at).unwrap();
+        assert_eq!(text(&docx), vec!["AT&T <b> ", "R&D"]);
+        let DocumentChild::Paragraph(p) = &docx.document.children[0] else {
+            panic!("expected a paragraph");
+        };
+        let ParagraphChild::Run(run) = &p.children[0] else {
+            panic!("expected a run");
+        };
+        let RunChild::Text(t) = &run.children[0] else {
+            panic!("expected a text");
+        };
+        assert_eq!(t.text, "AT&amp;T &lt;b&gt; ");
+
+        let mut docx =
+            Docx::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("a\u{a0}b")));
+        assert_eq!(
+            docx.cursor(BlockLocation::body(0))
+                .split_paragraph(TextPosition { run: 0, offset: 2 }),
+            Err(EditError::OutOfRange)
+        );
+    }
+}
@@ -24,6 +24,7 @@ pub enum DocumentChild {
     CommentEnd(CommentRangeEnd),
     StructuredDataTag(Box<StructuredDataTag>),
     TableOfContents(Box<TableOfContents>),
+    AltChunk(Box<AltChunk>),
     Section(Box<Section>),
 }
 
@@ -81,6 +82,12 @@ impl Serialize for DocumentChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            DocumentChild::AltChunk(ref r) => {
+                let mut t = serializer.serialize_struct("AltChunk", 2)?;
+                t.serialize_field("type", "altChunk")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
             DocumentChild::Section(ref r) => {
                 let mut t = serializer.serialize_struct("Section", 2)?;
                 t.serialize_field("type", "section")?;
@@ -237,6 +244,11 @@ impl Document {
         self
     }
 
+    pub fn add_alt_chunk(mut self, c: AltChunk) -> Self {
+        self.children.push(DocumentChild::AltChunk(Box::new(c)));
+        self
+    }
+
     pub fn columns(mut self, col: usize) -> Self {
         self.section_property.columns = col;
         self
@@ -323,6 +335,7 @@ impl BuildXML for DocumentChild {
             DocumentChild::CommentEnd(v) => v.build_to(stream),
             DocumentChild::StructuredDataTag(v) => v.build_to(stream),
             DocumentChild::TableOfContents(v) => v.build_to(stream),
+            DocumentChild::AltChunk(v) => v.build_to(stream),
             DocumentChild::Section(v) => v.build_to(stream),
         }
     }
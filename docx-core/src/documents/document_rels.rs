@@ -11,8 +11,18 @@ pub struct DocumentRels {
     pub has_comments: bool,
     pub has_numberings: bool,
     pub has_footnotes: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub has_theme: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub has_comments_ids: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub has_comments_extensible: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub has_people: bool,
     pub images: Vec<(String, String)>,
     pub hyperlinks: Vec<(String, String, String)>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub alt_chunks: Vec<(String, String)>,
     pub custom_xml_count: usize,
     pub header_count: usize,
     pub footer_count: usize,
@@ -68,6 +78,13 @@ impl BuildXML for DocumentRels {
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/settings",
                 "settings.xml",
             )?
+            .apply_if(self.has_theme, |b| {
+                b.relationship(
+                    "rId4",
+                    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme",
+                    "theme/theme1.xml",
+                )
+            })?
             .relationship(
                 "rId5",
                 "http://schemas.microsoft.com/office/2011/relationships/commentsExtended",
@@ -80,6 +97,27 @@ impl BuildXML for DocumentRels {
                     "comments.xml",
                 )
             })?
+            .apply_if(self.has_comments_ids, |b| {
+                b.relationship(
+                    "rIdCommentsIds",
+                    "http://schemas.microsoft.com/office/2016/09/relationships/commentsIds",
+                    "commentsIds.xml",
+                )
+            })?
+            .apply_if(self.has_comments_extensible, |b| {
+                b.relationship(
+                    "rIdCommentsExtensible",
+                    "http://schemas.microsoft.com/office/2018/08/relationships/commentsExtensible",
+                    "commentsExtensible.xml",
+                )
+            })?
+            .apply_if(self.has_people, |b| {
+                b.relationship(
+                    "rIdPeople",
+                    "http://schemas.microsoft.com/office/2011/relationships/people",
+                    "people.xml",
+                )
+            })?
             .apply_if(self.has_numberings, |b| {
                 b.relationship(
                     "rId7",
@@ -122,6 +160,13 @@ impl BuildXML for DocumentRels {
                     path,
                 )
             })?
+            .apply_each(self.alt_chunks.iter(), |(id, path), b| {
+                b.relationship(
+                    id,
+                    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/aFChunk",
+                    path,
+                )
+            })?
             .apply_each(self.hyperlinks.iter(), |(id, path, r#type), b| {
                 b.relationship_with_mode(
                     id,
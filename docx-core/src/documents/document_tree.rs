@@ -1,237 +1,941 @@
-//! Shared traversal for package-relevant nodes in a DOCX document tree.
+//! Shared traversal of a DOCX document tree.
 //!
 //! Document content can be nested under tables, sections, structured data
-//! tags, hyperlinks, and tracked changes. Centralizing that recursion keeps
-//! dependency collectors complete when new package metadata is added.
+//! tags, hyperlinks, tracked changes and text boxes. Centralizing that
+//! recursion keeps dependency collectors complete when new package metadata
+//! is added, and lets callers inspect or rewrite every element without
+//! re-implementing it.
+//!
+//! The public walks also descend into the comments that comment range starts
+//! carry and the footnotes that footnote references carry. Package
+//! collectors use the `_part` walks instead, which stay inside one
+//! relationship scope.
 
 use crate::{
-    Delete, DeleteChild, Document, DocumentChild, DrawingData, Footer, FooterChild, Header,
-    HeaderChild, Hyperlink, Insert, InsertChild, MoveFrom, MoveFromChild, MoveTo, MoveToChild,
-    Paragraph, ParagraphChild, Pic, Run, RunChild, Section, SectionChild, StructuredDataTag,
-    StructuredDataTagChild, Table, TableCellContent, TableChild, TableOfContents, TableRowChild,
+    AltChunk, BookmarkEnd, BookmarkStart, Comment, CommentChild, CommentRangeEnd,
+    CommentRangeStart, Delete, DeleteChild, DeleteInstrText, DeleteText, Document, DocumentChild,
+    Docx, Drawing, DrawingData, FieldChar, Footer, FooterChild, Footnote, FootnoteReference,
+    Header, HeaderChild, Hyperlink, Insert, InsertChild, InstrText, MoveFrom, MoveFromChild,
+    MoveTo, MoveToChild, Paragraph, ParagraphChild, Pic, Run, RunChild, Section, SectionChild,
+    StructuredDataTag, StructuredDataTagChild, Table, TableCell, TableCellContent, TableChild,
+    TableOfContents, TableRow, TableRowChild, Text, TextBox, TextBoxContentChild, TocContent,
 };
 
-/// Receives package-relevant nodes while the document tree is traversed.
-///
-/// Keeping traversal in one module prevents media and relationship collectors
-/// from silently disagreeing about nested containers such as tables, sections,
-/// structured data tags, hyperlinks, and tracked changes. Default hooks make
-/// it possible to collect only the data needed by a particular package part.
-pub(crate) trait DocumentTreeVisitor {
-    /// Visits a paragraph before its children are traversed.
-    fn visit_paragraph(&mut self, _paragraph: &mut Paragraph) {}
+/// Generates a visitor trait and the walk that drives it, once for shared
+/// and once for mutable references.
+macro_rules! document_tree {
+    ($(#[$attr:meta])* $visitor:ident, $walker:ident $(, $m:tt)?) => {
+        $(#[$attr])*
+        pub trait $visitor {
+            /// Visits a section before its children are traversed.
+            fn visit_section(&mut self, _section: &$($m)? Section) {}
+
+            /// Visits a paragraph before its children are traversed.
+            fn visit_paragraph(&mut self, _paragraph: &$($m)? Paragraph) {}
+
+            /// Visits a run before its children are traversed.
+            fn visit_run(&mut self, _run: &$($m)? Run) {}
+
+            /// Visits the text of a run.
+            fn visit_text(&mut self, _text: &$($m)? Text) {}
+
+            /// Visits the text of a run inside a deletion.
+            fn visit_delete_text(&mut self, _text: &$($m)? DeleteText) {}
+
+            /// Visits a table before its rows are traversed.
+            fn visit_table(&mut self, _table: &$($m)? Table) {}
+
+            /// Visits a table row before its cells are traversed.
+            fn visit_table_row(&mut self, _row: &$($m)? TableRow) {}
+
+            /// Visits a table cell before its content is traversed.
+            fn visit_table_cell(&mut self, _cell: &$($m)? TableCell) {}
+
+            /// Visits a table of contents before its content is traversed.
+            fn visit_table_of_contents(&mut self, _toc: &$($m)? TableOfContents) {}
+
+            /// Visits a structured data tag before its children are traversed.
+            fn visit_structured_data_tag(&mut self, _tag: &$($m)? StructuredDataTag) {}
+
+            /// Visits a hyperlink before its children are traversed.
+            fn visit_hyperlink(&mut self, _link: &$($m)? Hyperlink) {}
+
+            /// Visits a tracked insertion before its children are traversed.
+            fn visit_insert(&mut self, _insert: &$($m)? Insert) {}
+
+            /// Visits a tracked deletion before its children are traversed.
+            fn visit_delete(&mut self, _delete: &$($m)? Delete) {}
+
+            /// Visits the source of a tracked move before its children are
+            /// traversed.
+            fn visit_move_from(&mut self, _moved: &$($m)? MoveFrom) {}
+
+            /// Visits the destination of a tracked move before its children
+            /// are traversed.
+            fn visit_move_to(&mut self, _moved: &$($m)? MoveTo) {}
+
+            /// Visits the start of a bookmark.
+            fn visit_bookmark_start(&mut self, _bookmark: &$($m)? BookmarkStart) {}
+
+            /// Visits the end of a bookmark.
+            fn visit_bookmark_end(&mut self, _bookmark: &$($m)? BookmarkEnd) {}
+
+            /// Visits the start of a comment range together with its comment.
+            fn visit_comment_start(&mut self, _comment: &$($m)? CommentRangeStart) {}
+
+            /// Visits the end of a comment range.
+            fn visit_comment_end(&mut self, _comment: &$($m)? CommentRangeEnd) {}
+
+            /// Visits a comment before its content is traversed.
+            fn visit_comment(&mut self, _comment: &$($m)? Comment) {}
+
+            /// Visits a footnote reference stored in a run.
+            fn visit_footnote_reference(&mut self, _reference: &$($m)? FootnoteReference) {}
+
+            /// Visits a field character that begins, separates or ends a field.
+            fn visit_field_char(&mut self, _field_char: &$($m)? FieldChar) {}
+
+            /// Visits a field instruction.
+            fn visit_instr_text(&mut self, _instr: &$($m)? InstrText) {}
+
+            /// Visits a field instruction inside a deletion.
+            fn visit_delete_instr_text(&mut self, _instr: &$($m)? DeleteInstrText) {}
+
+            /// Visits a field instruction kept as text by the reader.
+            #[allow(clippy::ptr_arg)]
+            fn visit_instr_text_string(&mut self, _instr: &$($m)? String) {}
+
+            /// Visits a drawing before its picture or text box.
+            fn visit_drawing(&mut self, _drawing: &$($m)? Drawing) {}
 
-    /// Visits a run before its children are traversed.
-    fn visit_run(&mut self, _run: &mut Run) {}
+            /// Visits an embedded DrawingML picture.
+            fn visit_picture(&mut self, _picture: &$($m)? Pic) {}
 
-    /// Visits an embedded DrawingML picture.
-    fn visit_picture(&mut self, _picture: &mut Pic) {}
+            /// Visits a text box before its content is traversed.
+            fn visit_text_box(&mut self, _text_box: &$($m)? TextBox) {}
+
+            /// Visits content in another format that Word converts on open.
+            fn visit_alt_chunk(&mut self, _chunk: &$($m)? AltChunk) {}
+        }
+
+        struct $walker<'v, V> {
+            visitor: &'v mut V,
+            /// Whether comments and footnotes carried in the tree are walked.
+            notes: bool,
+        }
+
+        impl<V: $visitor> $walker<'_, V> {
+            fn document(&mut self, document: &$($m)? Document) {
+                for child in &$($m)? document.children {
+                    match child {
+                        DocumentChild::Paragraph(paragraph) => self.paragraph(paragraph),
+                        DocumentChild::Table(table) => self.table(table),
+                        DocumentChild::StructuredDataTag(tag) => self.structured_data_tag(tag),
+                        DocumentChild::TableOfContents(toc) => self.table_of_contents(toc),
+                        DocumentChild::Section(section) => self.section(section),
+                        DocumentChild::BookmarkStart(bookmark) => {
+                            self.visitor.visit_bookmark_start(bookmark)
+                        }
+                        DocumentChild::BookmarkEnd(bookmark) => {
+                            self.visitor.visit_bookmark_end(bookmark)
+                        }
+                        DocumentChild::CommentStart(comment) => self.comment_start(comment),
+                        DocumentChild::CommentEnd(comment) => self.visitor.visit_comment_end(comment),
+                        DocumentChild::AltChunk(chunk) => self.visitor.visit_alt_chunk(chunk),
+                    }
+                }
+            }
+
+            fn header(&mut self, header: &$($m)? Header) {
+                for child in &$($m)? header.children {
+                    match child {
+                        HeaderChild::Paragraph(paragraph) => self.paragraph(paragraph),
+                        HeaderChild::Table(table) => self.table(table),
+                        HeaderChild::StructuredDataTag(tag) => self.structured_data_tag(tag),
+                    }
+                }
+            }
+
+            fn footer(&mut self, footer: &$($m)? Footer) {
+                for child in &$($m)? footer.children {
+                    match child {
+                        FooterChild::Paragraph(paragraph) => self.paragraph(paragraph),
+                        FooterChild::Table(table) => self.table(table),
+                        FooterChild::StructuredDataTag(tag) => self.structured_data_tag(tag),
+                    }
+                }
+            }
+
+            fn comment(&mut self, comment: &$($m)? Comment) {
+                self.visitor.visit_comment(comment);
+                for child in &$($m)? comment.children {
+                    match child {
+                        CommentChild::Paragraph(paragraph) => self.paragraph(paragraph),
+                        CommentChild::Table(table) => self.table(table),
+                    }
+                }
+            }
+
+            fn footnote(&mut self, paragraphs: &$($m)? [Paragraph]) {
+                for paragraph in paragraphs {
+                    self.paragraph(paragraph);
+                }
+            }
+
+            fn section(&mut self, section: &$($m)? Section) {
+                self.visitor.visit_section(section);
+                for child in &$($m)? section.children {
+                    match child {
+                        SectionChild::Paragraph(paragraph) => self.paragraph(paragraph),
+                        SectionChild::Table(table) => self.table(table),
+                        SectionChild::StructuredDataTag(tag) => self.structured_data_tag(tag),
+                        SectionChild::TableOfContents(toc) => self.table_of_contents(toc),
+                        SectionChild::BookmarkStart(bookmark) => {
+                            self.visitor.visit_bookmark_start(bookmark)
+                        }
+                        SectionChild::BookmarkEnd(bookmark) => {
+                            self.visitor.visit_bookmark_end(bookmark)
+                        }
+                        SectionChild::CommentStart(comment) => self.comment_start(comment),
+                        SectionChild::CommentEnd(comment) => self.visitor.visit_comment_end(comment),
+                        SectionChild::AltChunk(chunk) => self.visitor.visit_alt_chunk(chunk),
+                    }
+                }
+            }
 
-    /// Visits a footnote reference stored in a run.
-    fn visit_footnote_reference(&mut self, _reference: &crate::FootnoteReference) {}
+            fn table(&mut self, table: &$($m)? Table) {
+                self.visitor.visit_table(table);
+                for TableChild::TableRow(row) in &$($m)? table.rows {
+                    self.visitor.visit_table_row(row);
+                    for TableRowChild::TableCell(cell) in &$($m)? row.cells {
+                        self.visitor.visit_table_cell(cell);
+                        for content in &$($m)? cell.children {
+                            match content {
+                                TableCellContent::Paragraph(paragraph) => self.paragraph(paragraph),
+                                TableCellContent::Table(table) => self.table(table),
+                                TableCellContent::StructuredDataTag(tag) => {
+                                    self.structured_data_tag(tag)
+                                }
+                                TableCellContent::TableOfContents(toc) => {
+                                    self.table_of_contents(toc)
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            fn table_of_contents(&mut self, toc: &$($m)? TableOfContents) {
+                self.visitor.visit_table_of_contents(toc);
+                for content in (&$($m)? toc.before_contents)
+                    .into_iter()
+                    .chain(&$($m)? toc.after_contents)
+                {
+                    match content {
+                        TocContent::Paragraph(paragraph) => self.paragraph(paragraph),
+                        TocContent::Table(table) => self.table(table),
+                    }
+                }
+            }
+
+            fn structured_data_tag(&mut self, tag: &$($m)? StructuredDataTag) {
+                self.visitor.visit_structured_data_tag(tag);
+                for child in &$($m)? tag.children {
+                    match child {
+                        StructuredDataTagChild::Run(run) => self.run(run),
+                        StructuredDataTagChild::Paragraph(paragraph) => self.paragraph(paragraph),
+                        StructuredDataTagChild::Table(table) => self.table(table),
+                        StructuredDataTagChild::StructuredDataTag(tag) => {
+                            self.structured_data_tag(tag)
+                        }
+                        StructuredDataTagChild::BookmarkStart(bookmark) => {
+                            self.visitor.visit_bookmark_start(bookmark)
+                        }
+                        StructuredDataTagChild::BookmarkEnd(bookmark) => {
+                            self.visitor.visit_bookmark_end(bookmark)
+                        }
+                        StructuredDataTagChild::CommentStart(comment) => self.comment_start(comment),
+                        StructuredDataTagChild::CommentEnd(comment) => {
+                            self.visitor.visit_comment_end(comment)
+                        }
+                    }
+                }
+            }
+
+            fn paragraph(&mut self, paragraph: &$($m)? Paragraph) {
+                self.visitor.visit_paragraph(paragraph);
+                self.paragraph_children(&$($m)? paragraph.children);
+            }
+
+            fn paragraph_children(&mut self, children: &$($m)? [ParagraphChild]) {
+                for child in children {
+                    match child {
+                        ParagraphChild::Run(run) => self.run(run),
+                        ParagraphChild::Insert(insert) => self.insert(insert),
+                        ParagraphChild::Delete(delete) => self.delete(delete),
+                        ParagraphChild::MoveFrom(moved) => self.move_from(moved),
+                        ParagraphChild::MoveTo(moved) => self.move_to(moved),
+                        ParagraphChild::Hyperlink(link) => {
+                            self.visitor.visit_hyperlink(link);
+                            self.paragraph_children(&$($m)? link.children);
+                        }
+                        ParagraphChild::StructuredDataTag(tag) => self.structured_data_tag(tag),
+                        ParagraphChild::BookmarkStart(bookmark) => {
+                            self.visitor.visit_bookmark_start(bookmark)
+                        }
+                        ParagraphChild::BookmarkEnd(bookmark) => {
+                            self.visitor.visit_bookmark_end(bookmark)
+                        }
+                        ParagraphChild::CommentStart(comment) => self.comment_start(comment),
+                        ParagraphChild::CommentEnd(comment) => self.visitor.visit_comment_end(comment),
+                        ParagraphChild::PageNum(_) | ParagraphChild::NumPages(_) => {}
+                    }
+                }
+            }
+
+            fn insert(&mut self, insert: &$($m)? Insert) {
+                self.visitor.visit_insert(insert);
+                for child in &$($m)? insert.children {
+                    match child {
+                        InsertChild::Run(run) => self.run(run),
+                        InsertChild::Delete(delete) => self.delete(delete),
+                        InsertChild::CommentStart(comment) => self.comment_start(comment),
+                        InsertChild::CommentEnd(comment) => self.visitor.visit_comment_end(comment),
+                    }
+                }
+            }
+
+            fn delete(&mut self, delete: &$($m)? Delete) {
+                self.visitor.visit_delete(delete);
+                for child in &$($m)? delete.children {
+                    match child {
+                        DeleteChild::Run(run) => self.run(run),
+                        DeleteChild::CommentStart(comment) => self.comment_start(comment),
+                        DeleteChild::CommentEnd(comment) => self.visitor.visit_comment_end(comment),
+                    }
+                }
+            }
+
+            fn move_from(&mut self, moved: &$($m)? MoveFrom) {
+                self.visitor.visit_move_from(moved);
+                for child in &$($m)? moved.children {
+                    match child {
+                        MoveFromChild::Run(run) => self.run(run),
+                        MoveFromChild::CommentStart(comment) => self.comment_start(comment),
+                        MoveFromChild::CommentEnd(comment) => self.visitor.visit_comment_end(comment),
+                    }
+                }
+            }
+
+            fn move_to(&mut self, moved: &$($m)? MoveTo) {
+                self.visitor.visit_move_to(moved);
+                for child in &$($m)? moved.children {
+                    match child {
+                        MoveToChild::Run(run) => self.run(run),
+                        MoveToChild::Delete(delete) => self.delete(delete),
+                        MoveToChild::CommentStart(comment) => self.comment_start(comment),
+                        MoveToChild::CommentEnd(comment) => self.visitor.visit_comment_end(comment),
+                    }
+                }
+            }
+
+            fn comment_start(&mut self, start: &$($m)? CommentRangeStart) {
+                self.visitor.visit_comment_start(start);
+                if self.notes {
+                    self.comment(&$($m)? start.comment);
+                }
+            }
+
+            fn run(&mut self, run: &$($m)? Run) {
+                self.visitor.visit_run(run);
+                for child in &$($m)? run.children {
+                    match child {
+                        RunChild::Text(text) => self.visitor.visit_text(text),
+                        RunChild::DeleteText(text) => self.visitor.visit_delete_text(text),
+                        RunChild::Drawing(drawing) => self.drawing(drawing),
+                        RunChild::FootnoteReference(reference) => {
+                            self.visitor.visit_footnote_reference(reference);
+                            if self.notes {
+                                self.footnote(&$($m)? reference.content);
+                            }
+                        }
+                        RunChild::CommentStart(comment) => self.comment_start(comment),
+                        RunChild::CommentEnd(comment) => self.visitor.visit_comment_end(comment),
+                        RunChild::FieldChar(field_char) => self.visitor.visit_field_char(field_char),
+                        RunChild::InstrText(instr) => self.visitor.visit_instr_text(instr),
+                        RunChild::DeleteInstrText(instr) => {
+                            self.visitor.visit_delete_instr_text(instr)
+                        }
+                        RunChild::InstrTextString(instr) => {
+                            self.visitor.visit_instr_text_string(instr)
+                        }
+                        RunChild::Sym(_)
+                        | RunChild::Tab(_)
+                        | RunChild::PTab(_)
+                        | RunChild::Break(_)
+                        | RunChild::CarriageReturn(_)
+                        | RunChild::Shape(_)
+                        | RunChild::Shading(_) => {}
+                    }
+                }
+            }
+
+            fn drawing(&mut self, drawing: &$($m)? Drawing) {
+                self.visitor.visit_drawing(drawing);
+                match &$($m)? drawing.data {
+                    Some(DrawingData::Pic(picture)) => self.visitor.visit_picture(picture),
+                    Some(DrawingData::TextBox(text_box)) => {
+                        self.visitor.visit_text_box(text_box);
+                        for child in &$($m)? text_box.children {
+                            match child {
+                                TextBoxContentChild::Paragraph(paragraph) => {
+                                    self.paragraph(paragraph)
+                                }
+                                TextBoxContentChild::Table(table) => self.table(table),
+                            }
+                        }
+                    }
+                    None => {}
+                }
+            }
+        }
+    };
 }
 
-/// Traverses the main document body without crossing into header/footer parts.
-///
-/// Header and footer relationships have their own scopes, so callers must walk
-/// those parts separately with [`visit_header`] and [`visit_footer`].
-pub(crate) fn visit_document(document: &mut Document, visitor: &mut impl DocumentTreeVisitor) {
-    for child in &mut document.children {
-        visit_document_child(child, visitor);
+document_tree!(
+    /// Receives every element while a document tree is traversed.
+    ///
+    /// Each hook runs before the children of its element, and all of them
+    /// default to doing nothing, so a visitor implements only the elements it
+    /// cares about. [`Docx::visit`] walks every part of a document; the
+    /// `visit_*` functions walk one part.
+    ///
+    /// ```
+    /// use docx_rs::*;
+    ///
+    /// #[derive(Default)]
+    /// struct Words(usize);
+    ///
+    /// impl DocumentTreeVisitor for Words {
+    ///     fn visit_text(&mut self, text: &Text) {
+    ///         self.0 += text.text.split_whitespace().count();
+    ///     }
+    /// }
+    ///
+    /// let docx = Docx::new().add_paragraph(
+    ///     Paragraph::new().add_run(Run::new().add_text("Hello world")),
+    /// );
+    /// let mut words = Words::default();
+    /// docx.visit(&mut words);
+    /// assert_eq!(words.0, 2);
+    /// ```
+    DocumentTreeVisitor,
+    Walker
+);
+
+document_tree!(
+    /// Receives every element mutably while a document tree is traversed.
+    ///
+    /// This is the mutable counterpart of [`DocumentTreeVisitor`], driven by
+    /// [`Docx::visit_mut`] and the `visit_*_mut` functions. Hooks run before
+    /// the children of their element are traversed, so children replaced in
+    /// a hook are the ones that are visited.
+    DocumentTreeVisitorMut,
+    WalkerMut,
+    mut
+);
+
+impl Docx {
+    /// Walks the body, headers and footers, including the comments and
+    /// footnotes they carry.
+    ///
+    /// The comments and footnotes parts are not walked, as they are copied
+    /// from the tree when the document is built.
+    pub fn visit(&self, visitor: &mut impl DocumentTreeVisitor) {
+        visit_document(&self.document, visitor);
+        for (_, header) in self.document.headers() {
+            visit_header(header, visitor);
+        }
+        for (_, footer) in self.document.footers() {
+            visit_footer(footer, visitor);
+        }
     }
-}
 
-/// Traverses every package-relevant node in one header part.
-pub(crate) fn visit_header(header: &mut Header, visitor: &mut impl DocumentTreeVisitor) {
-    for child in &mut header.children {
-        match child {
-            HeaderChild::Paragraph(paragraph) => visit_paragraph(paragraph, visitor),
-            HeaderChild::Table(table) => visit_table(table, visitor),
-            HeaderChild::StructuredDataTag(tag) => visit_structured_data_tag(tag, visitor),
+    /// Walks the body, headers and footers mutably, including the comments
+    /// and footnotes they carry.
+    pub fn visit_mut(&mut self, visitor: &mut impl DocumentTreeVisitorMut) {
+        visit_document_mut(&mut self.document, visitor);
+        for (_, header) in self.document.headers_mut() {
+            visit_header_mut(header, visitor);
+        }
+        for (_, footer) in self.document.footers_mut() {
+            visit_footer_mut(footer, visitor);
         }
     }
 }
 
-/// Traverses every package-relevant node in one footer part.
-pub(crate) fn visit_footer(footer: &mut Footer, visitor: &mut impl DocumentTreeVisitor) {
-    for child in &mut footer.children {
-        match child {
-            FooterChild::Paragraph(paragraph) => visit_paragraph(paragraph, visitor),
-            FooterChild::Table(table) => visit_table(table, visitor),
-            FooterChild::StructuredDataTag(tag) => visit_structured_data_tag(tag, visitor),
-        }
+/// Walks the main document body, including the comments and footnotes it
+/// carries but not its headers and footers.
+pub fn visit_document(document: &Document, visitor: &mut impl DocumentTreeVisitor) {
+    Walker {
+        visitor,
+        notes: true,
     }
+    .document(document);
 }
 
-fn visit_document_child(child: &mut DocumentChild, visitor: &mut impl DocumentTreeVisitor) {
-    match child {
-        DocumentChild::Paragraph(paragraph) => visit_paragraph(paragraph, visitor),
-        DocumentChild::Table(table) => visit_table(table, visitor),
-        DocumentChild::StructuredDataTag(tag) => visit_structured_data_tag(tag, visitor),
-        DocumentChild::TableOfContents(toc) => visit_table_of_contents(toc, visitor),
-        DocumentChild::Section(section) => visit_section(section, visitor),
-        DocumentChild::BookmarkStart(_)
-        | DocumentChild::BookmarkEnd(_)
-        | DocumentChild::CommentStart(_)
-        | DocumentChild::CommentEnd(_) => {}
+/// Walks one header, including the comments and footnotes it carries.
+pub fn visit_header(header: &Header, visitor: &mut impl DocumentTreeVisitor) {
+    Walker {
+        visitor,
+        notes: true,
     }
+    .header(header);
 }
 
-fn visit_section(section: &mut Section, visitor: &mut impl DocumentTreeVisitor) {
-    for child in &mut section.children {
-        match child {
-            SectionChild::Paragraph(paragraph) => visit_paragraph(paragraph, visitor),
-            SectionChild::Table(table) => visit_table(table, visitor),
-            SectionChild::StructuredDataTag(tag) => visit_structured_data_tag(tag, visitor),
-            SectionChild::TableOfContents(toc) => visit_table_of_contents(toc, visitor),
-            SectionChild::BookmarkStart(_)
-            | SectionChild::BookmarkEnd(_)
-            | SectionChild::CommentStart(_)
-            | SectionChild::CommentEnd(_) => {}
-        }
+/// Walks one footer, including the comments and footnotes it carries.
+pub fn visit_footer(footer: &Footer, visitor: &mut impl DocumentTreeVisitor) {
+    Walker {
+        visitor,
+        notes: true,
     }
+    .footer(footer);
 }
 
-fn visit_table(table: &mut Table, visitor: &mut impl DocumentTreeVisitor) {
-    for TableChild::TableRow(row) in &mut table.rows {
-        for TableRowChild::TableCell(cell) in &mut row.cells {
-            for content in &mut cell.children {
-                match content {
-                    TableCellContent::Paragraph(paragraph) => visit_paragraph(paragraph, visitor),
-                    TableCellContent::Table(table) => visit_table(table, visitor),
-                    TableCellContent::StructuredDataTag(tag) => {
-                        visit_structured_data_tag(tag, visitor)
-                    }
-                    TableCellContent::TableOfContents(toc) => visit_table_of_contents(toc, visitor),
-                }
-            }
-        }
+/// Walks the content of a comment.
+pub fn visit_comment(comment: &Comment, visitor: &mut impl DocumentTreeVisitor) {
+    Walker {
+        visitor,
+        notes: true,
     }
+    .comment(comment);
 }
 
-fn visit_table_of_contents(toc: &mut TableOfContents, visitor: &mut impl DocumentTreeVisitor) {
-    for content in toc
-        .before_contents
-        .iter_mut()
-        .chain(toc.after_contents.iter_mut())
-    {
-        match content {
-            crate::TocContent::Paragraph(paragraph) => visit_paragraph(paragraph, visitor),
-            crate::TocContent::Table(table) => visit_table(table, visitor),
-        }
+/// Walks the content of a footnote.
+pub fn visit_footnote(footnote: &Footnote, visitor: &mut impl DocumentTreeVisitor) {
+    Walker {
+        visitor,
+        notes: true,
     }
+    .footnote(&footnote.content);
 }
 
-fn visit_structured_data_tag(tag: &mut StructuredDataTag, visitor: &mut impl DocumentTreeVisitor) {
-    for child in &mut tag.children {
-        match child {
-            StructuredDataTagChild::Run(run) => visit_run(run, visitor),
-            StructuredDataTagChild::Paragraph(paragraph) => visit_paragraph(paragraph, visitor),
-            StructuredDataTagChild::Table(table) => visit_table(table, visitor),
-            StructuredDataTagChild::StructuredDataTag(tag) => {
-                visit_structured_data_tag(tag, visitor)
-            }
-            StructuredDataTagChild::BookmarkStart(_)
-            | StructuredDataTagChild::BookmarkEnd(_)
-            | StructuredDataTagChild::CommentStart(_)
-            | StructuredDataTagChild::CommentEnd(_) => {}
-        }
+/// Mutable counterpart of [`visit_document`].
+pub fn visit_document_mut(document: &mut Document, visitor: &mut impl DocumentTreeVisitorMut) {
+    WalkerMut {
+        visitor,
+        notes: true,
     }
+    .document(document);
 }
 
-fn visit_paragraph(paragraph: &mut Paragraph, visitor: &mut impl DocumentTreeVisitor) {
-    visitor.visit_paragraph(paragraph);
-    visit_paragraph_children(&mut paragraph.children, visitor);
+/// Mutable counterpart of [`visit_header`].
+pub fn visit_header_mut(header: &mut Header, visitor: &mut impl DocumentTreeVisitorMut) {
+    WalkerMut {
+        visitor,
+        notes: true,
+    }
+    .header(header);
 }
 
-fn visit_paragraph_children(
-    children: &mut [ParagraphChild],
-    visitor: &mut impl DocumentTreeVisitor,
-) {
-    for child in children {
-        match child {
-            ParagraphChild::Run(run) => visit_run(run, visitor),
-            ParagraphChild::Insert(insert) => visit_insert(insert, visitor),
-            ParagraphChild::Delete(delete) => visit_delete(delete, visitor),
-            ParagraphChild::MoveFrom(moved) => visit_move_from(moved, visitor),
-            ParagraphChild::MoveTo(moved) => visit_move_to(moved, visitor),
-            ParagraphChild::Hyperlink(link) => visit_hyperlink(link, visitor),
-            ParagraphChild::StructuredDataTag(tag) => visit_structured_data_tag(tag, visitor),
-            ParagraphChild::BookmarkStart(_)
-            | ParagraphChild::BookmarkEnd(_)
-            | ParagraphChild::CommentStart(_)
-            | ParagraphChild::CommentEnd(_)
-            | ParagraphChild::PageNum(_)
-            | ParagraphChild::NumPages(_) => {}
-        }
+/// Mutable counterpart of [`visit_footer`].
+pub fn visit_footer_mut(footer: &mut Footer, visitor: &mut impl DocumentTreeVisitorMut) {
+    WalkerMut {
+        visitor,
+        notes: true,
     }
+    .footer(footer);
 }
 
-fn visit_hyperlink(link: &mut Hyperlink, visitor: &mut impl DocumentTreeVisitor) {
-    visit_paragraph_children(&mut link.children, visitor);
+/// Mutable counterpart of [`visit_comment`].
+pub fn visit_comment_mut(comment: &mut Comment, visitor: &mut impl DocumentTreeVisitorMut) {
+    WalkerMut {
+        visitor,
+        notes: true,
+    }
+    .comment(comment);
 }
 
-fn visit_insert(insert: &mut Insert, visitor: &mut impl DocumentTreeVisitor) {
-    for child in &mut insert.children {
-        match child {
-            InsertChild::Run(run) => visit_run(run, visitor),
-            InsertChild::Delete(delete) => visit_delete(delete, visitor),
-            InsertChild::CommentStart(_) | InsertChild::CommentEnd(_) => {}
-        }
+/// Mutable counterpart of [`visit_footnote`].
+pub fn visit_footnote_mut(footnote: &mut Footnote, visitor: &mut impl DocumentTreeVisitorMut) {
+    WalkerMut {
+        visitor,
+        notes: true,
     }
+    .footnote(&mut footnote.content);
 }
 
-fn visit_delete(delete: &mut Delete, visitor: &mut impl DocumentTreeVisitor) {
-    for child in &mut delete.children {
-        if let DeleteChild::Run(run) = child {
-            visit_run(run, visitor);
-        }
+/// Traverses the main document body without crossing into other parts.
+///
+/// Header, footer, comment and footnote relationships have their own scopes,
+/// so the comments and footnotes carried in the body are not walked and
+/// callers must walk headers and footers separately with
+/// [`visit_header_part`] and [`visit_footer_part`].
+pub(crate) fn visit_document_part(
+    document: &mut Document,
+    visitor: &mut impl DocumentTreeVisitorMut,
+) {
+    WalkerMut {
+        visitor,
+        notes: false,
     }
+    .document(document);
 }
 
-fn visit_move_from(moved: &mut MoveFrom, visitor: &mut impl DocumentTreeVisitor) {
-    for child in &mut moved.children {
-        if let MoveFromChild::Run(run) = child {
-            visit_run(run, visitor);
-        }
+/// Traverses one header part.
+pub(crate) fn visit_header_part(header: &mut Header, visitor: &mut impl DocumentTreeVisitorMut) {
+    WalkerMut {
+        visitor,
+        notes: false,
     }
+    .header(header);
 }
 
-fn visit_move_to(moved: &mut MoveTo, visitor: &mut impl DocumentTreeVisitor) {
-    for child in &mut moved.children {
-        match child {
-            MoveToChild::Run(run) => visit_run(run, visitor),
-            MoveToChild::Delete(delete) => visit_delete(delete, visitor),
-            MoveToChild::CommentStart(_) | MoveToChild::CommentEnd(_) => {}
-        }
+/// Traverses one footer part.
+pub(crate) fn visit_footer_part(footer: &mut Footer, visitor: &mut impl DocumentTreeVisitorMut) {
+    WalkerMut {
+        visitor,
+        notes: false,
     }
+    .footer(footer);
 }
 
-fn visit_run(run: &mut Run, visitor: &mut impl DocumentTreeVisitor) {
-    visitor.visit_run(run);
-    for child in &mut run.children {
-        match child {
-            RunChild::Drawing(drawing) => {
-                if let Some(DrawingData::Pic(picture)) = &mut drawing.data {
-                    visitor.visit_picture(picture);
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    use crate::{AltChunkFormat, FieldCharType, HyperlinkType};
+
+    #[derive(Default)]
+    struct Elements(Vec<&'static str>);
+
+    impl DocumentTreeVisitor for Elements {
+        fn visit_paragraph(&mut self, _paragraph: &Paragraph) {
+            self.0.push("paragraph");
+        }
+
+        fn visit_text(&mut self, _text: &Text) {
+            self.0.push("text");
+        }
+
+        fn visit_table_cell(&mut self, _cell: &TableCell) {
+            self.0.push("cell");
+        }
+
+        fn visit_insert(&mut self, _insert: &Insert) {
+            self.0.push("insert");
+        }
+
+        fn visit_comment(&mut self, _comment: &Comment) {
+            self.0.push("comment");
+        }
+
+        fn visit_footnote_reference(&mut self, _reference: &FootnoteReference) {
+            self.0.push("footnote");
+        }
+    }
+
+    #[test]
+    fn test_visit_every_part() {
+        let docx = Docx::new()
+            .header(Header::new().add_paragraph(Paragraph::new()))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_comment_start(
+                        Comment::new(1)
+                            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("note"))),
+                    )
+                    .add_insert(Insert::new(Run::new().add_text("new")))
+                    .add_run(
+                        Run::new()
+                            .add_footnote_reference(Footnote::new().add_content(
+                                Paragraph::new().add_run(Run::new().add_text("foot")),
+                            )),
+                    ),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![
+                TableCell::new().add_paragraph(Paragraph::new())
+            ])]));
+        let mut elements = Elements::default();
+        docx.visit(&mut elements);
+        assert_eq!(
+            elements.0,
+            vec![
+                "paragraph",
+                "comment",
+                "paragraph",
+                "text",
+                "insert",
+                "text",
+                "footnote",
+                "paragraph",
+                "text",
+                "cell",
+                "paragraph",
+                "paragraph",
+            ]
+        );
+    }
+
+    // Records the name of every hook that fires, for both visitor flavors.
+    #[derive(Default)]
+    struct Hooks(Vec<&'static str>);
+
+    macro_rules! record_hooks {
+        ($visitor:ident $(, $m:tt)?) => {
+            impl $visitor for Hooks {
+                fn visit_section(&mut self, _: &$($m)? Section) {
+                    self.0.push("section");
+                }
+
+                fn visit_paragraph(&mut self, _: &$($m)? Paragraph) {
+                    self.0.push("paragraph");
+                }
+
+                fn visit_run(&mut self, _: &$($m)? Run) {
+                    self.0.push("run");
+                }
+
+                fn visit_text(&mut self, _: &$($m)? Text) {
+                    self.0.push("text");
+                }
+
+                fn visit_delete_text(&mut self, _: &$($m)? DeleteText) {
+                    self.0.push("delete_text");
+                }
+
+                fn visit_table(&mut self, _: &$($m)? Table) {
+                    self.0.push("table");
+                }
+
+                fn visit_table_row(&mut self, _: &$($m)? TableRow) {
+                    self.0.push("table_row");
+                }
+
+                fn visit_table_cell(&mut self, _: &$($m)? TableCell) {
+                    self.0.push("table_cell");
+                }
+
+                fn visit_table_of_contents(&mut self, _: &$($m)? TableOfContents) {
+                    self.0.push("table_of_contents");
+                }
+
+                fn visit_structured_data_tag(&mut self, _: &$($m)? StructuredDataTag) {
+                    self.0.push("structured_data_tag");
+                }
+
+                fn visit_hyperlink(&mut self, _: &$($m)? Hyperlink) {
+                    self.0.push("hyperlink");
+                }
+
+                fn visit_insert(&mut self, _: &$($m)? Insert) {
+                    self.0.push("insert");
+                }
+
+                fn visit_delete(&mut self, _: &$($m)? Delete) {
+                    self.0.push("delete");
+                }
+
+                fn visit_move_from(&mut self, _: &$($m)? MoveFrom) {
+                    self.0.push("move_from");
+                }
+
+                fn visit_move_to(&mut self, _: &$($m)? MoveTo) {
+                    self.0.push("move_to");
+                }
+
+                fn visit_bookmark_start(&mut self, _: &$($m)? BookmarkStart) {
+                    self.0.push("bookmark_start");
+                }
+
+                fn visit_bookmark_end(&mut self, _: &$($m)? BookmarkEnd) {
+                    self.0.push("bookmark_end");
+                }
+
+                fn visit_comment_start(&mut self, _: &$($m)? CommentRangeStart) {
+                    self.0.push("comment_start");
+                }
+
+                fn visit_comment_end(&mut self, _: &$($m)? CommentRangeEnd) {
+                    self.0.push("comment_end");
+                }
+
+                fn visit_comment(&mut self, _: &$($m)? Comment) {
+                    self.0.push("comment");
+                }
+
+                fn visit_footnote_reference(&mut self, _: &$($m)? FootnoteReference) {
+                    self.0.push("footnote_reference");
+                }
+
+                fn visit_field_char(&mut self, _: &$($m)? FieldChar) {
+                    self.0.push("field_char");
                 }
+
+                fn visit_instr_text(&mut self, _: &$($m)? InstrText) {
+                    self.0.push("instr_text");
+                }
+
+                fn visit_delete_instr_text(&mut self, _: &$($m)? DeleteInstrText) {
+                    self.0.push("delete_instr_text");
+                }
+
+                fn visit_instr_text_string(&mut self, _: &$($m)? String) {
+                    self.0.push("instr_text_string");
+                }
+
+                fn visit_drawing(&mut self, _: &$($m)? Drawing) {
+                    self.0.push("drawing");
+                }
+
+                fn visit_picture(&mut self, _: &$($m)? Pic) {
+                    self.0.push("picture");
+                }
+
+                fn visit_text_box(&mut self, _: &$($m)? TextBox) {
+                    self.0.push("text_box");
+                }
+
+                fn visit_alt_chunk(&mut self, _: &$($m)? AltChunk) {
+                    self.0.push("alt_chunk");
+                }
+            }
+        };
+    }
+
+    record_hooks!(DocumentTreeVisitor);
+    record_hooks!(DocumentTreeVisitorMut, mut);
+
+    const HOOKS: [&str; 29] = [
+        "section",
+        "paragraph",
+        "run",
+        "text",
+        "delete_text",
+        "table",
+        "table_row",
+        "table_cell",
+        "table_of_contents",
+        "structured_data_tag",
+        "hyperlink",
+        "insert",
+        "delete",
+        "move_from",
+        "move_to",
+        "bookmark_start",
+        "bookmark_end",
+        "comment_start",
+        "comment_end",
+        "comment",
+        "footnote_reference",
+        "field_char",
+        "instr_text",
+        "delete_instr_text",
+        "instr_text_string",
+        "drawing",
+        "picture",
+        "text_box",
+        "alt_chunk",
+    ];
+
+    #[test]
+    fn test_every_hook_fires_in_both_visitors() {
+        let mut text_box = TextBox::new();
+        text_box
+            .children
+            .push(TextBoxContentChild::Paragraph(Box::new(
+                Paragraph::new().add_run(Run::new().add_text("boxed")),
+            )));
+        let mut fields = Run::new()
+            .add_field_char(FieldCharType::Begin, false)
+            .add_instr_text(InstrText::Unsupported("DATE".to_owned()))
+            .add_delete_instr_text(DeleteInstrText::Unsupported("TIME".to_owned()))
+            .add_field_char(FieldCharType::End, false);
+        fields
+            .children
+            .push(RunChild::InstrTextString("PAGE".to_owned()));
+        let mut docx = Docx::new()
+            .header(Header::new().add_paragraph(
+                Paragraph::new().add_run(Run::new().add_image(Pic::new_with_dimensions(
+                    vec![0; 4],
+                    1,
+                    1,
+                ))),
+            ))
+            .add_bookmark_start(0, "start")
+            .add_paragraph(
+                Paragraph::new()
+                    .add_hyperlink(
+                        Hyperlink::new("https://example.com", HyperlinkType::External)
+                            .add_run(Run::new().add_text("link")),
+                    )
+                    .add_structured_data_tag(
+                        StructuredDataTag::new().add_run(Run::new().add_text("tag")),
+                    )
+                    .add_delete(Delete::new().add_run(Run::new().add_delete_text("old")))
+                    .add_move_from(MoveFrom::new().add_run(Run::new().add_delete_text("from")))
+                    .add_move_to(MoveTo::new(Run::new().add_text("to")))
+                    .add_run(fields)
+                    .add_run(Run::new().add_drawing(Drawing::new().text_box(text_box))),
+            )
+            .add_bookmark_end(0)
+            .add_table_of_contents(TableOfContents::new().add_before_paragraph(Paragraph::new()))
+            .add_alt_chunk(AltChunk::new(AltChunkFormat::Text, "chunk"))
+            .add_section(Section::new().add_paragraph(Paragraph::new()))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_comment_start(Comment::new(1).add_paragraph(Paragraph::new()))
+                    .add_insert(Insert::new(Run::new().add_text("new")))
+                    .add_run(
+                        Run::new()
+                            .add_footnote_reference(Footnote::new().add_content(Paragraph::new())),
+                    )
+                    .add_comment_end(1),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![
+                TableCell::new().add_paragraph(Paragraph::new())
+            ])]));
+        let mut shared = Hooks::default();
+        docx.visit(&mut shared);
+        let mut mutable = Hooks::default();
+        docx.visit_mut(&mut mutable);
+        assert_eq!(shared.0, mutable.0);
+        for hook in HOOKS {
+            assert!(shared.0.contains(&hook), "{hook}");
+        }
+    }
+
+    #[test]
+    fn test_visit_mut_reaches_text_boxes() {
+        struct Upper;
+
+        impl DocumentTreeVisitorMut for Upper {
+            fn visit_text(&mut self, text: &mut Text) {
+                text.text = text.text.to_uppercase();
+            }
+        }
+
+        let mut text_box = TextBox::new();
+        text_box
+            .children
+            .push(TextBoxContentChild::Paragraph(Box::new(
+                Paragraph::new().add_run(Run::new().add_text("boxed")),
+            )));
+        let mut docx = Docx::new().add_paragraph(
+            Paragraph::new().add_run(Run::new().add_drawing(Drawing::new().text_box(text_box))),
+        );
+        docx.visit_mut(&mut Upper);
+        let mut texts = vec![];
+        struct Texts<'a>(&'a mut Vec<String>);
+        impl DocumentTreeVisitor for Texts<'_> {
+            fn visit_text(&mut self, text: &Text) {
+                self.0.push(text.text.clone());
             }
-            RunChild::FootnoteReference(reference) => visitor.visit_footnote_reference(reference),
-            RunChild::Text(_)
-            | RunChild::Sym(_)
-            | RunChild::DeleteText(_)
-            | RunChild::Tab(_)
-            | RunChild::PTab(_)
-            | RunChild::Break(_)
-            | RunChild::CarriageReturn(_)
-            | RunChild::Shape(_)
-            | RunChild::CommentStart(_)
-            | RunChild::CommentEnd(_)
-            | RunChild::FieldChar(_)
-            | RunChild::InstrText(_)
-            | RunChild::DeleteInstrText(_)
-            | RunChild::InstrTextString(_)
-            | RunChild::Shading(_) => {}
         }
+        docx.visit(&mut Texts(&mut texts));
+        assert_eq!(texts, vec!["BOXED".to_owned()]);
     }
 }
@@ -0,0 +1,79 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+/// Content in another format that Word converts and shows in place of
+/// `w:altChunk` when it opens the document. The payload is written to its own
+/// part with a relationship from the body.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AltChunk {
+    /// The relationship id, assigned when the document is built.
+    pub id: String,
+    pub format: AltChunkFormat,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub data: Vec<u8>,
+}
+
+impl AltChunk {
+    pub fn new(format: AltChunkFormat, data: impl Into<Vec<u8>>) -> AltChunk {
+        AltChunk {
+            id: String::new(),
+            format,
+            data: data.into(),
+        }
+    }
+
+    pub fn html(html: impl Into<String>) -> AltChunk {
+        Self::new(AltChunkFormat::Html, html.into())
+    }
+
+    pub fn mht(mht: impl Into<Vec<u8>>) -> AltChunk {
+        Self::new(AltChunkFormat::Mht, mht)
+    }
+
+    pub fn rtf(rtf: impl Into<Vec<u8>>) -> AltChunk {
+        Self::new(AltChunkFormat::Rtf, rtf)
+    }
+
+    pub fn text(text: impl Into<String>) -> AltChunk {
+        Self::new(AltChunkFormat::Text, text.into())
+    }
+
+    /// A whole `.docx` package, whose body is merged into the document.
+    pub fn docx(docx: impl Into<Vec<u8>>) -> AltChunk {
+        Self::new(AltChunkFormat::Docx, docx)
+    }
+}
+
+impl BuildXML for AltChunk {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).alt_chunk(&self.id)?.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_alt_chunk() {
+        let mut c = AltChunk::html("<p>Hi</p>");
+        c.id = "rIdAltChunk1".to_owned();
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:altChunk r:id="rIdAltChunk1" />"#
+        );
+    }
+}
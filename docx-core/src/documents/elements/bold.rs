@@ -14,6 +14,10 @@ impl Bold {
         Default::default()
     }
 
+    pub(crate) fn get_val(&self) -> bool {
+        self.val
+    }
+
     pub fn disable(mut self) -> Bold {
         self.val = false;
         self
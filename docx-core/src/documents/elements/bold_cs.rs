@@ -13,6 +13,10 @@ impl BoldCs {
     pub fn new() -> BoldCs {
         Default::default()
     }
+
+    pub(crate) fn get_val(&self) -> bool {
+        self.val
+    }
     pub fn disable(mut self) -> BoldCs {
         self.val = false;
         self
@@ -16,6 +16,10 @@ impl Caps {
         Default::default()
     }
 
+    pub(crate) fn get_val(&self) -> bool {
+        self.val
+    }
+
     pub fn disable(mut self) -> Caps {
         self.val = false;
         self
@@ -0,0 +1,58 @@
+use serde::{Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Conditional table formatting applied to a paragraph (`w:cnfStyle`).
+///
+/// `val` is the 12 character bitmask Word writes (`100000000000` for the
+/// first row, ...). The first character stands for the first row, followed by
+/// last row, first column, last column, odd/even vertical bands, odd/even
+/// horizontal bands and the four corner cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CnfStyle {
+    pub val: String,
+}
+
+impl CnfStyle {
+    pub fn new(val: impl Into<String>) -> CnfStyle {
+        CnfStyle { val: val.into() }
+    }
+}
+
+impl Serialize for CnfStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val)
+    }
+}
+
+impl BuildXML for CnfStyle {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).cnf_style(&self.val)?.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_cnf_style() {
+        let b = CnfStyle::new("100000000000").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:cnfStyle w:val="100000000000" />"#
+        );
+    }
+}
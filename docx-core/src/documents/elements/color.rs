@@ -46,6 +46,10 @@ impl Color {
         }
     }
 
+    pub(crate) fn get_val(&self) -> &str {
+        &self.val
+    }
+
     /// Sets the theme color reference (`w:themeColor`).
     pub fn theme_color(mut self, theme_color: ThemeColor) -> Color {
         // Stored as the serialized ST_ThemeColor token (e.g. "accent1") so the
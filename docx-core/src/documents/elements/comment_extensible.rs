@@ -0,0 +1,70 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// i.e.    <w16cex:commentExtensible w16cex:durableId="3F2B6A1C" w16cex:dateUtc="2024-01-01T09:00:00Z"/>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentExtensible {
+    pub durable_id: String,
+    pub date_utc: Option<String>,
+    /// Whether the comment is a placeholder inserted by an Office feature.
+    pub intelligent_placeholder: bool,
+}
+
+impl CommentExtensible {
+    pub fn new(durable_id: impl Into<String>) -> CommentExtensible {
+        Self {
+            durable_id: durable_id.into(),
+            date_utc: None,
+            intelligent_placeholder: false,
+        }
+    }
+
+    pub fn date_utc(mut self, date: impl Into<String>) -> CommentExtensible {
+        self.date_utc = Some(date.into());
+        self
+    }
+
+    pub fn intelligent_placeholder(mut self) -> CommentExtensible {
+        self.intelligent_placeholder = true;
+        self
+    }
+}
+
+impl BuildXML for CommentExtensible {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .comment_extensible(
+                &self.durable_id,
+                &self.date_utc,
+                self.intelligent_placeholder,
+            )?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_comment_extensible() {
+        let b = CommentExtensible::new("3F2B6A1C")
+            .date_utc("2024-01-01T09:00:00Z")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w16cex:commentExtensible w16cex:durableId="3F2B6A1C" w16cex:dateUtc="2024-01-01T09:00:00Z" />"#
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// i.e.    <w16cid:commentId w16cid:paraId="00000001" w16cid:durableId="3F2B6A1C"/>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentId {
+    pub paragraph_id: String,
+    pub durable_id: String,
+}
+
+impl CommentId {
+    pub fn new(paragraph_id: impl Into<String>, durable_id: impl Into<String>) -> CommentId {
+        Self {
+            paragraph_id: paragraph_id.into(),
+            durable_id: durable_id.into(),
+        }
+    }
+}
+
+impl BuildXML for CommentId {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .comment_id(&self.paragraph_id, &self.durable_id)?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_comment_id() {
+        let b = CommentId::new("00000001", "3F2B6A1C").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w16cid:commentId w16cid:paraId="00000001" w16cid:durableId="3F2B6A1C" />"#
+        );
+    }
+}
@@ -13,6 +13,14 @@ impl CommentRangeEnd {
     pub fn new(id: usize) -> CommentRangeEnd {
         CommentRangeEnd { id }
     }
+
+    pub(crate) fn get_id(&self) -> usize {
+        self.id
+    }
+
+    pub(crate) fn set_id(&mut self, id: usize) {
+        self.id = id;
+    }
 }
 
 impl BuildXML for CommentRangeEnd {
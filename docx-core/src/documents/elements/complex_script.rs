@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Formats the run contents with complex script properties (`w:cs`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ComplexScript {
+    val: bool,
+}
+
+impl ComplexScript {
+    pub fn new() -> ComplexScript {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> ComplexScript {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for ComplexScript {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for ComplexScript {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for ComplexScript {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .complex_script(self.val)?
+            .into_inner()
+    }
+}
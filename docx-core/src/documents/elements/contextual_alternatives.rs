@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Enables OpenType contextual alternates on the run (`w14:cntxtAlts`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ContextualAlternatives {
+    val: bool,
+}
+
+impl ContextualAlternatives {
+    pub fn new() -> ContextualAlternatives {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> ContextualAlternatives {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for ContextualAlternatives {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for ContextualAlternatives {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for ContextualAlternatives {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .w14_contextual_alternatives(self.val)?
+            .into_inner()
+    }
+}
@@ -26,6 +26,10 @@ impl DeleteText {
             preserve_space: true,
         }
     }
+
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
 }
 
 impl BuildXML for DeleteText {
@@ -48,6 +48,14 @@ impl DocDefaults {
         self.paragraph_property_default = self.paragraph_property_default.paragraph_property(p);
         self
     }
+
+    pub(crate) fn get_run_property(&self) -> &RunProperty {
+        self.run_property_default.get_run_property()
+    }
+
+    pub(crate) fn get_paragraph_property(&self) -> &ParagraphProperty {
+        self.paragraph_property_default.get_paragraph_property()
+    }
 }
 
 impl Default for DocDefaults {
@@ -0,0 +1,86 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// East Asian typography settings such as two-lines-in-one and horizontal in
+/// vertical text (`w:eastAsianLayout`).
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EastAsianLayout {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub combine: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub combine_brackets: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vert: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vert_compress: Option<bool>,
+}
+
+impl EastAsianLayout {
+    pub fn new() -> EastAsianLayout {
+        Default::default()
+    }
+
+    pub fn id(mut self, id: i32) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn combine(mut self, v: bool) -> Self {
+        self.combine = Some(v);
+        self
+    }
+
+    pub fn combine_brackets(mut self, brackets: impl Into<String>) -> Self {
+        self.combine_brackets = Some(brackets.into());
+        self
+    }
+
+    pub fn vert(mut self, v: bool) -> Self {
+        self.vert = Some(v);
+        self
+    }
+
+    pub fn vert_compress(mut self, v: bool) -> Self {
+        self.vert_compress = Some(v);
+        self
+    }
+}
+
+impl BuildXML for EastAsianLayout {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .east_asian_layout(self)?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_east_asian_layout() {
+        let b = EastAsianLayout::new()
+            .id(1)
+            .combine(true)
+            .combine_brackets("round")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:eastAsianLayout w:id="1" w:combine="true" w:combineBrackets="round" />"#
+        );
+    }
+}
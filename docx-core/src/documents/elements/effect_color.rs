@@ -0,0 +1,97 @@
+use serde::Serialize;
+
+/// Color model of a Word 2010 text effect color (`w14:srgbClr` or
+/// `w14:schemeClr`).
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum EffectColorType {
+    Srgb,
+    Scheme,
+}
+
+/// Color used by the Word 2010 text effects such as glow, shadow and outline.
+///
+/// `val` is either a hex RGB value (`FF0000`) or a scheme color name
+/// (`accent1`, `tx1`, ...). Modifiers are expressed in thousandths of a
+/// percent, so `alpha(60000)` means 60% opacity.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectColor {
+    pub color_type: EffectColorType,
+    pub val: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tint: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shade: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sat_mod: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lum_mod: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lum_off: Option<u32>,
+}
+
+impl EffectColor {
+    pub fn new(color_type: EffectColorType, val: impl Into<String>) -> EffectColor {
+        EffectColor {
+            color_type,
+            val: val.into(),
+            tint: None,
+            shade: None,
+            alpha: None,
+            sat_mod: None,
+            lum_mod: None,
+            lum_off: None,
+        }
+    }
+
+    pub fn srgb(val: impl Into<String>) -> EffectColor {
+        Self::new(EffectColorType::Srgb, val)
+    }
+
+    pub fn scheme(val: impl Into<String>) -> EffectColor {
+        Self::new(EffectColorType::Scheme, val)
+    }
+
+    pub fn tint(mut self, v: u32) -> Self {
+        self.tint = Some(v);
+        self
+    }
+
+    pub fn shade(mut self, v: u32) -> Self {
+        self.shade = Some(v);
+        self
+    }
+
+    pub fn alpha(mut self, v: u32) -> Self {
+        self.alpha = Some(v);
+        self
+    }
+
+    pub fn sat_mod(mut self, v: u32) -> Self {
+        self.sat_mod = Some(v);
+        self
+    }
+
+    pub fn lum_mod(mut self, v: u32) -> Self {
+        self.lum_mod = Some(v);
+        self
+    }
+
+    pub fn lum_off(mut self, v: u32) -> Self {
+        self.lum_off = Some(v);
+        self
+    }
+}
+
+/// Fill of a Word 2010 text effect (`w14:noFill` or `w14:solidFill`).
+///
+/// Gradient fills are not supported yet.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+pub enum EffectFill {
+    NoFill,
+    Solid(EffectColor),
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Renders the run as if it were raised off the page (`w:emboss`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Emboss {
+    val: bool,
+}
+
+impl Emboss {
+    pub fn new() -> Emboss {
+        Default::default()
+    }
+
+    pub(crate) fn get_val(&self) -> bool {
+        self.val
+    }
+
+    pub fn disable(mut self) -> Emboss {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for Emboss {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for Emboss {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for Emboss {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).emboss(self.val)?.into_inner()
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+/// Emphasis mark drawn for each non-space character in the run (`w:em`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmphasisMark {
+    val: EmphasisMarkType,
+}
+
+impl EmphasisMark {
+    pub fn new(val: EmphasisMarkType) -> EmphasisMark {
+        Self { val }
+    }
+}
+
+impl Serialize for EmphasisMark {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val.to_string())
+    }
+}
+
+impl BuildXML for EmphasisMark {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .emphasis_mark(&self.val.to_string())?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_emphasis_mark() {
+        let b = EmphasisMark::new(EmphasisMarkType::UnderDot).build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:em w:val="underDot" />"#);
+    }
+}
@@ -0,0 +1,61 @@
+use serde::Serialize;
+use std::io::Write;
+
+use super::Font;
+use crate::documents::BuildXML;
+use crate::types::*;
+
+/// A font declared in the font table (`w:font`). Unlike [`Font`], it owns its
+/// values, so that it can be read from a package and kept in a document.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FontDeclaration {
+    pub name: String,
+    pub charset: String,
+    pub family: String,
+    pub pitch: FontPitchType,
+}
+
+impl FontDeclaration {
+    pub fn new(
+        name: impl Into<String>,
+        charset: impl Into<String>,
+        family: impl Into<String>,
+        pitch: FontPitchType,
+    ) -> FontDeclaration {
+        FontDeclaration {
+            name: name.into(),
+            charset: charset.into(),
+            family: family.into(),
+            pitch,
+        }
+    }
+}
+
+impl BuildXML for FontDeclaration {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        Font::new(&self.name, &self.charset, &self.family, self.pitch).build_to(stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let c = FontDeclaration::new("Arial", "00", "swiss", FontPitchType::Variable);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:font w:name="Arial"><w:charset w:val="00" /><w:family w:val="swiss" /><w:pitch w:val="variable" /></w:font>"#
+        );
+    }
+}
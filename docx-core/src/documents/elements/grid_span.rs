@@ -13,6 +13,10 @@ impl GridSpan {
     pub fn new(v: usize) -> GridSpan {
         GridSpan { val: v }
     }
+
+    pub(crate) fn get_val(&self) -> usize {
+        self.val
+    }
 }
 
 impl BuildXML for GridSpan {
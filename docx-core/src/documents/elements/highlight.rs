@@ -13,6 +13,10 @@ impl Highlight {
     pub fn new(val: impl Into<String>) -> Highlight {
         Highlight { val: val.into() }
     }
+
+    pub(crate) fn get_val(&self) -> &str {
+        &self.val
+    }
 }
 
 impl BuildXML for Highlight {
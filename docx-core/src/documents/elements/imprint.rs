@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Renders the run as if it were pressed into the page (`w:imprint`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Imprint {
+    val: bool,
+}
+
+impl Imprint {
+    pub fn new() -> Imprint {
+        Default::default()
+    }
+
+    pub(crate) fn get_val(&self) -> bool {
+        self.val
+    }
+
+    pub fn disable(mut self) -> Imprint {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for Imprint {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for Imprint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for Imprint {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).imprint(self.val)?.into_inner()
+    }
+}
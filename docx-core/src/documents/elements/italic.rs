@@ -14,6 +14,10 @@ impl Italic {
         Default::default()
     }
 
+    pub(crate) fn get_val(&self) -> bool {
+        self.val
+    }
+
     pub fn disable(mut self) -> Self {
         self.val = false;
         self
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Minimum font size, in half-points, at which kerning is applied (`w:kern`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Kern {
+    val: usize,
+}
+
+impl Kern {
+    pub fn new(val: usize) -> Kern {
+        Self { val }
+    }
+}
+
+impl Serialize for Kern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.val as u64)
+    }
+}
+
+impl BuildXML for Kern {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).kern(self.val)?.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_kern() {
+        let b = Kern::new(28).build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:kern w:val="28" />"#);
+    }
+}
@@ -0,0 +1,74 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Languages used for spelling, grammar and font selection (`w:lang`).
+///
+/// Each attribute is a BCP 47 tag such as `en-US` and applies to the matching
+/// script class of the run contents.
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Lang {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub val: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub east_asia: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bidi: Option<String>,
+}
+
+impl Lang {
+    pub fn new() -> Lang {
+        Default::default()
+    }
+
+    pub fn val(mut self, val: impl Into<String>) -> Lang {
+        self.val = Some(val.into());
+        self
+    }
+
+    pub fn east_asia(mut self, val: impl Into<String>) -> Lang {
+        self.east_asia = Some(val.into());
+        self
+    }
+
+    pub fn bidi(mut self, val: impl Into<String>) -> Lang {
+        self.bidi = Some(val.into());
+        self
+    }
+}
+
+impl BuildXML for Lang {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .lang(
+                self.val.as_ref(),
+                self.east_asia.as_ref(),
+                self.bidi.as_ref(),
+            )?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_lang() {
+        let b = Lang::new().val("en-US").east_asia("ja-JP").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:lang w:val="en-US" w:eastAsia="ja-JP" />"#
+        );
+    }
+}
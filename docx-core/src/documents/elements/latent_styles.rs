@@ -0,0 +1,202 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Default behavior of the built-in styles a document does not define
+/// (`w:latentStyles`).
+///
+/// Word lists every built-in style in its style gallery. The defaults below
+/// apply to all of them; `exceptions` adjusts single styles, e.g. to show
+/// "Heading 1" in the gallery.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatentStyles {
+    pub def_locked_state: bool,
+    pub def_ui_priority: usize,
+    pub def_semi_hidden: bool,
+    pub def_unhide_when_used: bool,
+    pub def_q_format: bool,
+    pub count: usize,
+    pub exceptions: Vec<LatentStyleException>,
+}
+
+impl Default for LatentStyles {
+    fn default() -> Self {
+        Self {
+            def_locked_state: false,
+            def_ui_priority: 99,
+            def_semi_hidden: false,
+            def_unhide_when_used: false,
+            def_q_format: false,
+            count: 0,
+            exceptions: vec![],
+        }
+    }
+}
+
+impl LatentStyles {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn def_locked_state(mut self, v: bool) -> Self {
+        self.def_locked_state = v;
+        self
+    }
+
+    pub fn def_ui_priority(mut self, v: usize) -> Self {
+        self.def_ui_priority = v;
+        self
+    }
+
+    pub fn def_semi_hidden(mut self, v: bool) -> Self {
+        self.def_semi_hidden = v;
+        self
+    }
+
+    pub fn def_unhide_when_used(mut self, v: bool) -> Self {
+        self.def_unhide_when_used = v;
+        self
+    }
+
+    pub fn def_q_format(mut self, v: bool) -> Self {
+        self.def_q_format = v;
+        self
+    }
+
+    /// Number of built-in styles the document knows about. Word 2016 writes
+    /// 376.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn add_exception(mut self, e: LatentStyleException) -> Self {
+        self.exceptions.push(e);
+        self
+    }
+}
+
+impl BuildXML for LatentStyles {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_latent_styles(
+                self.def_locked_state,
+                self.def_ui_priority,
+                self.def_semi_hidden,
+                self.def_unhide_when_used,
+                self.def_q_format,
+                self.count.max(self.exceptions.len()),
+            )?
+            .add_children(&self.exceptions)?
+            .close()?
+            .into_inner()
+    }
+}
+
+/// Overrides the latent style defaults for one built-in style
+/// (`w:lsdException`). `name` is the style name, e.g. `heading 1`.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LatentStyleException {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_priority: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semi_hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unhide_when_used: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q_format: Option<bool>,
+}
+
+impl LatentStyleException {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn locked(mut self, v: bool) -> Self {
+        self.locked = Some(v);
+        self
+    }
+
+    pub fn ui_priority(mut self, v: usize) -> Self {
+        self.ui_priority = Some(v);
+        self
+    }
+
+    pub fn semi_hidden(mut self, v: bool) -> Self {
+        self.semi_hidden = Some(v);
+        self
+    }
+
+    pub fn unhide_when_used(mut self, v: bool) -> Self {
+        self.unhide_when_used = Some(v);
+        self
+    }
+
+    pub fn q_format(mut self, v: bool) -> Self {
+        self.q_format = Some(v);
+        self
+    }
+}
+
+impl BuildXML for LatentStyleException {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .lsd_exception(
+                &self.name,
+                self.locked,
+                self.ui_priority,
+                self.semi_hidden,
+                self.unhide_when_used,
+                self.q_format,
+            )?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_latent_styles() {
+        let b = LatentStyles::new()
+            .count(376)
+            .add_exception(
+                LatentStyleException::new("Normal")
+                    .ui_priority(0)
+                    .q_format(true),
+            )
+            .add_exception(
+                LatentStyleException::new("heading 2")
+                    .ui_priority(9)
+                    .semi_hidden(true)
+                    .unhide_when_used(true)
+                    .q_format(true),
+            )
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:latentStyles w:defLockedState="0" w:defUIPriority="99" w:defSemiHidden="0" w:defUnhideWhenUsed="0" w:defQFormat="0" w:count="376"><w:lsdException w:name="Normal" w:uiPriority="0" w:qFormat="1" /><w:lsdException w:name="heading 2" w:uiPriority="9" w:semiHidden="1" w:unhideWhenUsed="1" w:qFormat="1" /></w:latentStyles>"#
+        );
+    }
+}
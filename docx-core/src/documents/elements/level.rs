@@ -20,6 +20,8 @@ pub struct Level {
     pub level_restart: Option<LevelRestart>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_lgl: Option<IsLgl>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pic_bullet_id: Option<usize>,
 }
 
 impl Level {
@@ -42,6 +44,7 @@ impl Level {
             pstyle: None,
             level_restart: None,
             is_lgl: None,
+            pic_bullet_id: None,
         }
     }
 
@@ -63,6 +66,12 @@ impl Level {
         self
     }
 
+    /// Uses the `w:numPicBullet` with the given id as this level's bullet.
+    pub fn pic_bullet(mut self, id: usize) -> Self {
+        self.pic_bullet_id = Some(id);
+        self
+    }
+
     pub fn suffix(mut self, s: LevelSuffixType) -> Self {
         self.suffix = s;
         self
@@ -153,6 +162,171 @@ impl Level {
         self.is_lgl = Some(IsLgl::new());
         self
     }
+
+    pub fn caps(mut self) -> Self {
+        self.run_property = self.run_property.caps();
+        self
+    }
+
+    pub fn disable_caps(mut self) -> Self {
+        self.run_property = self.run_property.disable_caps();
+        self
+    }
+
+    pub fn small_caps(mut self) -> Self {
+        self.run_property = self.run_property.small_caps();
+        self
+    }
+
+    pub fn disable_small_caps(mut self) -> Self {
+        self.run_property = self.run_property.disable_small_caps();
+        self
+    }
+
+    pub fn emboss(mut self) -> Self {
+        self.run_property = self.run_property.emboss();
+        self
+    }
+
+    pub fn imprint(mut self) -> Self {
+        self.run_property = self.run_property.imprint();
+        self
+    }
+
+    pub fn outline(mut self) -> Self {
+        self.run_property = self.run_property.outline();
+        self
+    }
+
+    pub fn shadow(mut self) -> Self {
+        self.run_property = self.run_property.shadow();
+        self
+    }
+
+    pub fn no_proof(mut self) -> Self {
+        self.run_property = self.run_property.no_proof();
+        self
+    }
+
+    pub fn snap_to_grid(mut self, v: bool) -> Self {
+        self.run_property = self.run_property.snap_to_grid(v);
+        self
+    }
+
+    pub fn web_hidden(mut self) -> Self {
+        self.run_property = self.run_property.web_hidden();
+        self
+    }
+
+    pub fn kern(mut self, size: usize) -> Self {
+        self.run_property = self.run_property.kern(size);
+        self
+    }
+
+    pub fn position(mut self, v: i32) -> Self {
+        self.run_property = self.run_property.position(v);
+        self
+    }
+
+    pub fn effect(mut self, effect: TextEffectType) -> Self {
+        self.run_property = self.run_property.effect(effect);
+        self
+    }
+
+    pub fn rtl(mut self) -> Self {
+        self.run_property = self.run_property.rtl();
+        self
+    }
+
+    pub fn complex_script(mut self) -> Self {
+        self.run_property = self.run_property.complex_script();
+        self
+    }
+
+    pub fn emphasis_mark(mut self, mark: EmphasisMarkType) -> Self {
+        self.run_property = self.run_property.emphasis_mark(mark);
+        self
+    }
+
+    pub fn lang(mut self, lang: Lang) -> Self {
+        self.run_property = self.run_property.lang(lang);
+        self
+    }
+
+    pub fn east_asian_layout(mut self, layout: EastAsianLayout) -> Self {
+        self.run_property = self.run_property.east_asian_layout(layout);
+        self
+    }
+
+    pub fn o_math(mut self) -> Self {
+        self.run_property = self.run_property.o_math();
+        self
+    }
+
+    pub fn spec_vanish(mut self) -> Self {
+        self.run_property = self.run_property.spec_vanish();
+        self
+    }
+
+    pub fn stretch(mut self, v: i32) -> Self {
+        self.run_property = self.run_property.stretch(v);
+        self
+    }
+
+    pub fn glow(mut self, glow: TextGlow) -> Self {
+        self.run_property = self.run_property.glow(glow);
+        self
+    }
+
+    pub fn text_shadow(mut self, shadow: TextShadow) -> Self {
+        self.run_property = self.run_property.text_shadow(shadow);
+        self
+    }
+
+    pub fn reflection(mut self, reflection: TextReflection) -> Self {
+        self.run_property = self.run_property.reflection(reflection);
+        self
+    }
+
+    pub fn text_outline(mut self, outline: TextOutline) -> Self {
+        self.run_property = self.run_property.text_outline(outline);
+        self
+    }
+
+    pub fn text_fill(mut self, fill: EffectFill) -> Self {
+        self.run_property = self.run_property.text_fill(fill);
+        self
+    }
+
+    pub fn ligatures(mut self, v: LigaturesType) -> Self {
+        self.run_property = self.run_property.ligatures(v);
+        self
+    }
+
+    pub fn number_form(mut self, v: NumberFormType) -> Self {
+        self.run_property = self.run_property.number_form(v);
+        self
+    }
+
+    pub fn number_spacing(mut self, v: NumberSpacingType) -> Self {
+        self.run_property = self.run_property.number_spacing(v);
+        self
+    }
+
+    pub fn stylistic_sets(mut self, sets: StylisticSets) -> Self {
+        self.run_property = self.run_property.stylistic_sets(sets);
+        self
+    }
+
+    pub fn contextual_alternatives(mut self) -> Self {
+        self.run_property = self.run_property.contextual_alternatives();
+        self
+    }
+
+    pub fn run_property_change(mut self, c: RunPropertyChange) -> Self {
+        self.run_property = self.run_property.run_property_change(c);
+        self
+    }
 }
 
 impl BuildXML for Level {
@@ -165,6 +339,7 @@ impl BuildXML for Level {
             .add_child(&self.start)?
             .add_child(&self.format)?
             .add_child(&self.text)?
+            .apply_opt(self.pic_bullet_id, |id, b| b.level_pic_bullet_id(id))?
             .add_child(&self.jc)?
             .add_child(&self.paragraph_property)?
             .add_child(&self.run_property)?
@@ -251,4 +426,20 @@ mod tests {
             r#"<w:lvl w:ilvl="1"><w:start w:val="1" /><w:numFmt w:val="decimal" /><w:lvlText w:val="%4." /><w:lvlJc w:val="left" /><w:pPr><w:rPr /></w:pPr><w:rPr /><w:pStyle w:val="a-style" /></w:lvl>"#
         );
     }
+    #[test]
+    fn test_level_with_pic_bullet() {
+        let b = Level::new(
+            0,
+            Start::new(1),
+            NumberFormat::new("bullet"),
+            LevelText::new("o"),
+            LevelJc::new("left"),
+        )
+        .pic_bullet(0)
+        .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:lvl w:ilvl="0"><w:start w:val="1" /><w:numFmt w:val="bullet" /><w:lvlText w:val="o" /><w:lvlPicBulletId w:val="0" /><w:lvlJc w:val="left" /><w:pPr><w:rPr /></w:pPr><w:rPr /></w:lvl>"#
+        );
+    }
 }
@@ -13,6 +13,10 @@ impl LevelText {
     pub fn new(val: impl Into<String>) -> Self {
         Self { val: val.into() }
     }
+
+    pub(crate) fn get_val(&self) -> &str {
+        &self.val
+    }
 }
 
 impl BuildXML for LevelText {
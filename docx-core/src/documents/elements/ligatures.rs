@@ -0,0 +1,56 @@
+use serde::{Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+/// OpenType ligatures enabled on the run (`w14:ligatures`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ligatures {
+    val: LigaturesType,
+}
+
+impl Ligatures {
+    pub fn new(val: LigaturesType) -> Ligatures {
+        Self { val }
+    }
+}
+
+impl Serialize for Ligatures {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val.to_string())
+    }
+}
+
+impl BuildXML for Ligatures {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .w14_ligatures(&self.val.to_string())?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_ligatures() {
+        let b = Ligatures::new(LigaturesType::StandardContextual).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w14:ligatures w14:val="standardContextual" />"#
+        );
+    }
+}
@@ -20,6 +20,10 @@ pub struct LineSpacing {
     after_lines: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     line: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before_autospacing: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after_autospacing: Option<bool>,
 }
 
 impl LineSpacing {
@@ -56,6 +60,48 @@ impl LineSpacing {
         self.line = Some(line);
         self
     }
+
+    /// Let the application pick the space before the paragraph, as HTML
+    /// `<p>` margins do. `before` is ignored while this is on.
+    pub fn before_autospacing(mut self, v: bool) -> Self {
+        self.before_autospacing = Some(v);
+        self
+    }
+
+    /// Let the application pick the space after the paragraph. `after` is
+    /// ignored while this is on.
+    pub fn after_autospacing(mut self, v: bool) -> Self {
+        self.after_autospacing = Some(v);
+        self
+    }
+
+    pub(crate) fn get_line_rule(&self) -> Option<LineSpacingType> {
+        self.line_rule
+    }
+
+    pub(crate) fn get_before(&self) -> Option<u32> {
+        self.before
+    }
+
+    pub(crate) fn get_after(&self) -> Option<u32> {
+        self.after
+    }
+
+    pub(crate) fn get_line(&self) -> Option<i32> {
+        self.line
+    }
+
+    // Sets the values given in `top`, keeping the rest.
+    pub(crate) fn overlay(&mut self, top: &LineSpacing) {
+        self.line_rule = top.line_rule.or(self.line_rule);
+        self.before = top.before.or(self.before);
+        self.after = top.after.or(self.after);
+        self.before_lines = top.before_lines.or(self.before_lines);
+        self.after_lines = top.after_lines.or(self.after_lines);
+        self.line = top.line.or(self.line);
+        self.before_autospacing = top.before_autospacing.or(self.before_autospacing);
+        self.after_autospacing = top.after_autospacing.or(self.after_autospacing);
+    }
 }
 
 impl BuildXML for LineSpacing {
@@ -71,6 +117,8 @@ impl BuildXML for LineSpacing {
                 self.before_lines,
                 self.after_lines,
                 self.line_rule,
+                self.before_autospacing,
+                self.after_autospacing,
             )?
             .into_inner()
     }
@@ -108,6 +156,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_spacing_autospacing() {
+        let b = LineSpacing::new()
+            .before(100)
+            .before_autospacing(true)
+            .after_autospacing(false)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:spacing w:before="100" w:beforeAutospacing="1" w:afterAutospacing="0" />"#
+        );
+    }
+
     #[test]
     fn test_spacing_json() {
         let s = LineSpacing {
@@ -117,6 +178,8 @@ mod tests {
             before_lines: None,
             after_lines: None,
             line: Some(100),
+            before_autospacing: None,
+            after_autospacing: None,
         };
         assert_eq!(
             serde_json::to_string(&s).unwrap(),
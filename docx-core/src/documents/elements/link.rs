@@ -16,6 +16,14 @@ impl Link {
             val: escape_owned(val.into()),
         }
     }
+
+    pub(crate) fn get_val(&self) -> &str {
+        &self.val
+    }
+
+    pub(crate) fn val_mut(&mut self) -> &mut String {
+        &mut self.val
+    }
 }
 
 impl Serialize for Link {
@@ -2,6 +2,7 @@ mod a_graphic;
 mod a_graphic_data;
 mod abstract_numbering;
 mod adjust_right_ind;
+mod alt_chunk;
 mod based_on;
 mod bold;
 mod bold_cs;
@@ -12,11 +13,16 @@ mod cant_split;
 mod caps;
 mod cell_margins;
 mod character_spacing;
+mod cnf_style;
 mod color;
 mod comment;
 mod comment_extended;
+mod comment_extensible;
+mod comment_id;
 mod comment_range_end;
 mod comment_range_start;
+mod complex_script;
+mod contextual_alternatives;
 mod cr;
 mod data_binding;
 mod default_tab_stop;
@@ -30,9 +36,14 @@ mod doc_id;
 mod doc_var;
 mod drawing;
 mod dstrike;
+mod east_asian_layout;
+mod effect_color;
+mod emboss;
+mod emphasis_mark;
 mod fit_text;
 mod fld_char;
 mod font;
+mod font_declaration;
 mod font_scheme;
 mod footer_reference;
 mod footnote;
@@ -42,6 +53,7 @@ mod grid_span;
 mod header_reference;
 mod highlight;
 mod hyperlink;
+mod imprint;
 mod indent;
 mod indent_level;
 mod insert;
@@ -56,11 +68,15 @@ mod is_lgl;
 mod italic;
 mod italic_cs;
 mod justification;
+mod kern;
+mod lang;
+mod latent_styles;
 mod level;
 mod level_jc;
 mod level_override;
 mod level_restart;
 mod level_text;
+mod ligatures;
 mod line_spacing;
 mod link;
 mod mc_fallback;
@@ -68,11 +84,17 @@ mod move_from;
 mod move_to;
 mod name;
 mod next;
+mod no_proof;
 mod num_pages;
+mod num_pic_bullet;
+mod number_form;
 mod number_format;
+mod number_spacing;
 mod numbering;
 mod numbering_id;
 mod numbering_property;
+mod o_math;
+mod outline;
 mod outline_lvl;
 mod page_margin;
 mod page_num;
@@ -85,18 +107,24 @@ mod paragraph_property;
 mod paragraph_property_change;
 mod paragraph_property_default;
 mod paragraph_style;
+mod person;
 mod pic;
+mod position;
 mod positional_tab;
 mod q_format;
+mod rtl;
 mod run;
 mod run_fonts;
 mod run_property;
+mod run_property_change;
 mod run_property_default;
 mod run_style;
 mod section;
 mod section_property;
 mod shading;
+mod shadow;
 mod shape;
+mod small_caps;
 mod spec_vanish;
 mod start;
 mod stretch;
@@ -105,6 +133,7 @@ mod structured_data_tag;
 mod structured_data_tag_property;
 mod style;
 mod style_ext;
+mod stylistic_sets;
 mod sym;
 mod sz;
 mod sz_cs;
@@ -119,6 +148,7 @@ mod table_cell_width;
 mod table_grid;
 mod table_indent;
 mod table_layout;
+mod table_look;
 mod table_of_contents;
 mod table_of_contents_item;
 mod table_position_property;
@@ -126,6 +156,7 @@ mod table_property;
 mod table_row;
 mod table_row_property;
 mod table_style;
+mod table_style_override;
 mod table_width;
 mod tabs;
 mod text;
@@ -134,11 +165,18 @@ mod text_border;
 mod text_box;
 mod text_box_content;
 mod text_direction;
+mod text_effect;
+mod text_fill;
+mod text_glow;
+mod text_outline;
+mod text_reflection;
+mod text_shadow;
 mod underline;
 mod v_align;
 mod vanish;
 mod vert_align;
 mod vertical_merge;
+mod web_hidden;
 mod wp_anchor;
 mod wps_shape;
 mod wps_text_box;
@@ -148,6 +186,7 @@ pub use a_graphic::*;
 pub use a_graphic_data::*;
 pub use abstract_numbering::*;
 pub use adjust_right_ind::*;
+pub use alt_chunk::*;
 pub use based_on::*;
 pub use bold::*;
 pub use bold_cs::*;
@@ -158,11 +197,16 @@ pub use cant_split::*;
 pub use caps::*;
 pub use cell_margins::*;
 pub use character_spacing::*;
+pub use cnf_style::*;
 pub use color::*;
 pub use comment::*;
 pub use comment_extended::*;
+pub use comment_extensible::*;
+pub use comment_id::*;
 pub use comment_range_end::*;
 pub use comment_range_start::*;
+pub use complex_script::*;
+pub use contextual_alternatives::*;
 pub use cr::*;
 pub use data_binding::*;
 pub use default_tab_stop::*;
@@ -176,9 +220,14 @@ pub use doc_id::*;
 pub use doc_var::*;
 pub use drawing::*;
 pub use dstrike::*;
+pub use east_asian_layout::*;
+pub use effect_color::*;
+pub use emboss::*;
+pub use emphasis_mark::*;
 pub use fit_text::*;
 pub use fld_char::*;
 pub use font::*;
+pub use font_declaration::*;
 pub use font_scheme::*;
 pub use footer_reference::*;
 pub use footnote::*;
@@ -188,6 +237,7 @@ pub use grid_span::*;
 pub use header_reference::*;
 pub use highlight::*;
 pub use hyperlink::*;
+pub use imprint::*;
 pub use indent::*;
 pub use indent_level::*;
 pub use insert::*;
@@ -202,11 +252,15 @@ pub use is_lgl::*;
 pub use italic::*;
 pub use italic_cs::*;
 pub use justification::*;
+pub use kern::*;
+pub use lang::*;
+pub use latent_styles::*;
 pub use level::*;
 pub use level_jc::*;
 pub use level_override::*;
 pub use level_restart::*;
 pub use level_text::*;
+pub use ligatures::*;
 pub use line_spacing::*;
 pub use link::*;
 pub use mc_fallback::*;
@@ -214,11 +268,17 @@ pub use move_from::*;
 pub use move_to::*;
 pub use name::*;
 pub use next::*;
+pub use no_proof::*;
 pub use num_pages::*;
+pub use num_pic_bullet::*;
+pub use number_form::*;
 pub use number_format::*;
+pub use number_spacing::*;
 pub use numbering::*;
 pub use numbering_id::*;
 pub use numbering_property::*;
+pub use o_math::*;
+pub use outline::*;
 pub use outline_lvl::*;
 pub use page_num::*;
 pub use page_num_type::*;
@@ -229,18 +289,24 @@ pub use paragraph_property::*;
 pub use paragraph_property_change::*;
 pub use paragraph_property_default::*;
 pub use paragraph_style::*;
+pub use person::*;
 pub use pic::*;
+pub use position::*;
 pub use positional_tab::*;
 pub use q_format::*;
+pub use rtl::*;
 pub use run::*;
 pub use run_fonts::*;
 pub use run_property::*;
+pub use run_property_change::*;
 pub use run_property_default::*;
 pub use run_style::*;
 pub use section::*;
 pub use section_property::*;
 pub use shading::*;
+pub use shadow::*;
 pub use shape::*;
+pub use small_caps::*;
 pub use spec_vanish::*;
 pub use start::*;
 pub use stretch::*;
@@ -248,6 +314,7 @@ pub use strike::*;
 pub use structured_data_tag::*;
 pub use structured_data_tag_property::*;
 pub use style::*;
+pub use stylistic_sets::*;
 pub use sym::*;
 pub use sz::*;
 pub use sz_cs::*;
@@ -262,6 +329,7 @@ pub use table_cell_width::*;
 pub use table_grid::*;
 pub use table_indent::*;
 pub use table_layout::*;
+pub use table_look::*;
 pub use table_of_contents::*;
 pub use table_of_contents_item::*;
 pub use table_position_property::*;
@@ -269,6 +337,7 @@ pub use table_property::*;
 pub use table_row::*;
 pub use table_row_property::*;
 pub use table_style::*;
+pub use table_style_override::*;
 pub use table_width::*;
 pub use tabs::*;
 pub use text::*;
@@ -277,11 +346,18 @@ pub use text_border::*;
 pub use text_box::*;
 pub use text_box_content::*;
 pub use text_direction::*;
+pub use text_effect::*;
+pub use text_fill::*;
+pub use text_glow::*;
+pub use text_outline::*;
+pub use text_reflection::*;
+pub use text_shadow::*;
 pub use underline::*;
 pub use v_align::*;
 pub use vanish::*;
 pub use vert_align::*;
 pub use vertical_merge::*;
+pub use web_hidden::*;
 pub use wp_anchor::*;
 pub use wps_shape::*;
 pub use wps_text_box::*;
@@ -18,6 +18,18 @@ impl Name {
         }
     }
 
+    /// The name followed by `suffix`, used for renamed copies of a style.
+    pub(crate) fn with_suffix(&self, suffix: &str) -> Name {
+        Name {
+            name: format!("{}{}", self.name, escape::escape_owned(suffix.to_owned())),
+        }
+    }
+
+    /// The name with XML markup characters escaped, as it is stored.
+    pub(crate) fn escaped(&self) -> &str {
+        &self.name
+    }
+
     pub fn starts_with(&self, s: &str) -> bool {
         self.name.starts_with(s)
     }
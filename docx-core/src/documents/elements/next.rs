@@ -13,6 +13,14 @@ impl Next {
     pub fn new(val: impl Into<String>) -> Next {
         Next { val: val.into() }
     }
+
+    pub(crate) fn get_val(&self) -> &str {
+        &self.val
+    }
+
+    pub(crate) fn val_mut(&mut self) -> &mut String {
+        &mut self.val
+    }
 }
 
 impl Serialize for Next {
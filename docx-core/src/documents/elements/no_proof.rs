@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Excludes the run from spelling and grammar checking (`w:noProof`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct NoProof {
+    val: bool,
+}
+
+impl NoProof {
+    pub fn new() -> NoProof {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> NoProof {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for NoProof {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for NoProof {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for NoProof {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).no_proof(self.val)?.into_inner()
+    }
+}
@@ -0,0 +1,88 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::*;
+use crate::xml_builder::*;
+
+/// A picture used as a list bullet (`w:numPicBullet`).
+///
+/// Levels refer to it by `id` through [`Level::pic_bullet`]. The image itself
+/// is packaged under `word/media` and referenced from `numbering.xml.rels`.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct NumPicBullet {
+    pub id: usize,
+    pub pic_id: String,
+    // For writer only
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub image: Vec<u8>,
+    // (width, height). unit is pt
+    pub size: (f32, f32),
+}
+
+impl NumPicBullet {
+    /// Make a picture bullet from PNG bytes. The bullet is 9pt square by default.
+    pub fn new(id: usize, buf: Vec<u8>) -> NumPicBullet {
+        Self {
+            id,
+            pic_id: create_pic_rid(generate_pic_id()),
+            image: buf,
+            size: (9.0, 9.0),
+        }
+    }
+
+    pub fn pic_id(mut self, id: impl Into<String>) -> NumPicBullet {
+        self.pic_id = id.into();
+        self
+    }
+
+    // unit is pt
+    pub fn size(mut self, w_pt: f32, h_pt: f32) -> NumPicBullet {
+        self.size = (w_pt, h_pt);
+        self
+    }
+}
+
+impl BuildXML for NumPicBullet {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_num_pic_bullet(self.id)?
+            .open_pict()?
+            .open_bullet_shape(
+                &format!("_x0000_i{}", 1025 + self.id),
+                self.size.0,
+                self.size.1,
+            )?
+            .image_data(&self.pic_id)?
+            .close()?
+            .close()?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_num_pic_bullet() {
+        let b = NumPicBullet::new(0, vec![1, 2, 3])
+            .pic_id("rIdImage1")
+            .size(10.5, 9.0)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:numPicBullet w:numPicBulletId="0"><w:pict><v:shape id="_x0000_i1025" style="width:10.5pt;height:9pt" o:bullet="t"><v:imagedata r:id="rIdImage1" o:title="" /></v:shape></w:pict></w:numPicBullet>"#
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+/// OpenType number form of the run digits (`w14:numForm`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberForm {
+    val: NumberFormType,
+}
+
+impl NumberForm {
+    pub fn new(val: NumberFormType) -> NumberForm {
+        Self { val }
+    }
+}
+
+impl Serialize for NumberForm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val.to_string())
+    }
+}
+
+impl BuildXML for NumberForm {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .w14_num_form(&self.val.to_string())?
+            .into_inner()
+    }
+}
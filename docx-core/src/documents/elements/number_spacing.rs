@@ -0,0 +1,38 @@
+use serde::{Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+/// OpenType number spacing of the run digits (`w14:numSpacing`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberSpacing {
+    val: NumberSpacingType,
+}
+
+impl NumberSpacing {
+    pub fn new(val: NumberSpacingType) -> NumberSpacing {
+        Self { val }
+    }
+}
+
+impl Serialize for NumberSpacing {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val.to_string())
+    }
+}
+
+impl BuildXML for NumberSpacing {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .w14_num_spacing(&self.val.to_string())?
+            .into_inner()
+    }
+}
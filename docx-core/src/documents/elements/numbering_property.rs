@@ -2,7 +2,7 @@ use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 use std::io::Write;
 
-use super::{IndentLevel, NumberingId};
+use super::{IndentLevel, Insert, NumberingId};
 use crate::documents::BuildXML;
 use crate::xml_builder::*;
 
@@ -10,6 +10,8 @@ use crate::xml_builder::*;
 pub struct NumberingProperty {
     pub id: Option<NumberingId>,
     pub level: Option<IndentLevel>,
+    // Marks the numbering itself as a tracked insertion (`w:ins` in `w:numPr`).
+    pub ins: Option<Insert>,
 }
 
 impl NumberingProperty {
@@ -32,6 +34,11 @@ impl NumberingProperty {
         self.level = Some(level);
         self
     }
+
+    pub fn insert(mut self, ins: Insert) -> NumberingProperty {
+        self.ins = Some(ins);
+        self
+    }
 }
 
 impl BuildXML for NumberingProperty {
@@ -43,6 +50,7 @@ impl BuildXML for NumberingProperty {
             .open_numbering_property()?
             .add_optional_child(&self.id)?
             .add_optional_child(&self.level)?
+            .add_optional_child(&self.ins)?
             .close()?
             .into_inner()
     }
@@ -53,7 +61,7 @@ impl Serialize for NumberingProperty {
     where
         S: Serializer,
     {
-        let mut t = serializer.serialize_struct("NumberProperty", 2)?;
+        let mut t = serializer.serialize_struct("NumberProperty", 3)?;
         let mut id: Option<usize> = None;
         if let Some(n) = &self.id {
             id = Some(n.id);
@@ -65,6 +73,11 @@ impl Serialize for NumberingProperty {
             level = Some(n.val);
         }
         t.serialize_field("level", &level)?;
+        if let Some(ins) = &self.ins {
+            t.serialize_field("ins", ins)?;
+        } else {
+            t.skip_field("ins")?;
+        }
         t.end()
     }
 }
@@ -87,6 +100,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_num_property_with_insert() {
+        let c = NumberingProperty::new()
+            .add_num(NumberingId::new(1), IndentLevel::new(0))
+            .insert(Insert::new_with_empty().author("bokuweb"));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:numPr><w:numId w:val="1" /><w:ilvl w:val="0" /><w:ins w:id="123" w:author="bokuweb" w:date="1970-01-01T00:00:00Z" /></w:numPr>"#
+        );
+    }
+
     #[test]
     fn test_empty_num_property() {
         let c = NumberingProperty::new();
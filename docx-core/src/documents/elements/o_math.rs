@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Marks the run as Office Open XML Math content (`w:oMath`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct OMath {
+    val: bool,
+}
+
+impl OMath {
+    pub fn new() -> OMath {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> OMath {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for OMath {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for OMath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for OMath {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).o_math(self.val)?.into_inner()
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Draws only the inner and outer borders of each character (`w:outline`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Outline {
+    val: bool,
+}
+
+impl Outline {
+    pub fn new() -> Outline {
+        Default::default()
+    }
+
+    pub(crate) fn get_val(&self) -> bool {
+        self.val
+    }
+
+    pub fn disable(mut self) -> Outline {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for Outline {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for Outline {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for Outline {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).outline(self.val)?.into_inner()
+    }
+}
@@ -8,9 +8,9 @@ use serde::Serialize;
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PageSize {
-    w: u32,
-    h: u32,
-    orient: Option<PageOrientationType>,
+    pub(crate) w: u32,
+    pub(crate) h: u32,
+    pub(crate) orient: Option<PageOrientationType>,
 }
 
 // These values were based on microsoft office word2019 windows edition.
@@ -573,6 +573,81 @@ impl Paragraph {
         });
         self
     }
+
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.property = self.property.contextual_spacing(v);
+        self
+    }
+
+    pub fn mirror_indents(mut self, v: bool) -> Self {
+        self.property = self.property.mirror_indents(v);
+        self
+    }
+
+    pub fn suppress_auto_hyphens(mut self, v: bool) -> Self {
+        self.property = self.property.suppress_auto_hyphens(v);
+        self
+    }
+
+    pub fn suppress_line_numbers(mut self, v: bool) -> Self {
+        self.property = self.property.suppress_line_numbers(v);
+        self
+    }
+
+    pub fn suppress_overlap(mut self, v: bool) -> Self {
+        self.property = self.property.suppress_overlap(v);
+        self
+    }
+
+    pub fn word_wrap(mut self, v: bool) -> Self {
+        self.property = self.property.word_wrap(v);
+        self
+    }
+
+    pub fn kinsoku(mut self, v: bool) -> Self {
+        self.property = self.property.kinsoku(v);
+        self
+    }
+
+    pub fn overflow_punct(mut self, v: bool) -> Self {
+        self.property = self.property.overflow_punct(v);
+        self
+    }
+
+    pub fn top_line_punct(mut self, v: bool) -> Self {
+        self.property = self.property.top_line_punct(v);
+        self
+    }
+
+    pub fn auto_space_de(mut self, v: bool) -> Self {
+        self.property = self.property.auto_space_de(v);
+        self
+    }
+
+    pub fn auto_space_dn(mut self, v: bool) -> Self {
+        self.property = self.property.auto_space_dn(v);
+        self
+    }
+
+    pub fn text_direction(mut self, t: TextDirectionType) -> Self {
+        self.property = self.property.text_direction(t);
+        self
+    }
+
+    pub fn textbox_tight_wrap(mut self, t: TextboxTightWrapType) -> Self {
+        self.property = self.property.textbox_tight_wrap(t);
+        self
+    }
+
+    pub fn cnf_style(mut self, s: CnfStyle) -> Self {
+        self.property = self.property.cnf_style(s);
+        self
+    }
+
+    pub fn div_id(mut self, id: impl Into<String>) -> Self {
+        self.property = self.property.div_id(id);
+        self
+    }
 }
 
 impl BuildXML for Paragraph {
@@ -3,7 +3,7 @@ use std::io::Write;
 
 use super::*;
 use crate::documents::BuildXML;
-use crate::types::{AlignmentType, SpecialIndentType};
+use crate::types::{AlignmentType, SpecialIndentType, TextDirectionType, TextboxTightWrapType};
 use crate::ParagraphBorderPosition;
 use crate::{xml_builder::*, TextAlignmentType};
 
@@ -50,9 +50,36 @@ pub struct ParagraphProperty {
     pub snap_to_grid: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shading: Option<Shading>,
-    // read only
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) div_id: Option<String>,
+    pub contextual_spacing: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirror_indents: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppress_auto_hyphens: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppress_line_numbers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppress_overlap: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word_wrap: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kinsoku: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overflow_punct: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_line_punct: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_space_de: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_space_dn: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_direction: Option<TextDirection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub textbox_tight_wrap: Option<TextboxTightWrapType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cnf_style: Option<CnfStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub div_id: Option<String>,
 }
 
 // 17.3.1.26
@@ -149,6 +176,81 @@ impl ParagraphProperty {
         self
     }
 
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.contextual_spacing = Some(v);
+        self
+    }
+
+    pub fn mirror_indents(mut self, v: bool) -> Self {
+        self.mirror_indents = Some(v);
+        self
+    }
+
+    pub fn suppress_auto_hyphens(mut self, v: bool) -> Self {
+        self.suppress_auto_hyphens = Some(v);
+        self
+    }
+
+    pub fn suppress_line_numbers(mut self, v: bool) -> Self {
+        self.suppress_line_numbers = Some(v);
+        self
+    }
+
+    pub fn suppress_overlap(mut self, v: bool) -> Self {
+        self.suppress_overlap = Some(v);
+        self
+    }
+
+    pub fn word_wrap(mut self, v: bool) -> Self {
+        self.word_wrap = Some(v);
+        self
+    }
+
+    pub fn kinsoku(mut self, v: bool) -> Self {
+        self.kinsoku = Some(v);
+        self
+    }
+
+    pub fn overflow_punct(mut self, v: bool) -> Self {
+        self.overflow_punct = Some(v);
+        self
+    }
+
+    pub fn top_line_punct(mut self, v: bool) -> Self {
+        self.top_line_punct = Some(v);
+        self
+    }
+
+    pub fn auto_space_de(mut self, v: bool) -> Self {
+        self.auto_space_de = Some(v);
+        self
+    }
+
+    pub fn auto_space_dn(mut self, v: bool) -> Self {
+        self.auto_space_dn = Some(v);
+        self
+    }
+
+    pub fn text_direction(mut self, t: TextDirectionType) -> Self {
+        self.text_direction = Some(TextDirection::new(t));
+        self
+    }
+
+    pub fn textbox_tight_wrap(mut self, t: TextboxTightWrapType) -> Self {
+        self.textbox_tight_wrap = Some(t);
+        self
+    }
+
+    pub fn cnf_style(mut self, s: CnfStyle) -> Self {
+        self.cnf_style = Some(s);
+        self
+    }
+
+    pub fn div_id(mut self, id: impl Into<String>) -> Self {
+        self.div_id = Some(id.into());
+        self
+    }
+
     pub fn add_tab(mut self, t: Tab) -> Self {
         self.tabs.push(t);
         self
@@ -240,6 +342,25 @@ impl BuildXML for ParagraphProperty {
             .add_optional_child(&self.text_alignment)?
             .add_optional_child(&self.adjust_right_ind)?
             .apply_opt(self.snap_to_grid, |v, b| b.snap_to_grid(v))?
+            .apply_opt(self.suppress_line_numbers, |v, b| {
+                b.suppress_line_numbers(v)
+            })?
+            .apply_opt(self.suppress_auto_hyphens, |v, b| {
+                b.suppress_auto_hyphens(v)
+            })?
+            .apply_opt(self.kinsoku, |v, b| b.kinsoku(v))?
+            .apply_opt(self.word_wrap, |v, b| b.word_wrap(v))?
+            .apply_opt(self.overflow_punct, |v, b| b.overflow_punct(v))?
+            .apply_opt(self.top_line_punct, |v, b| b.top_line_punct(v))?
+            .apply_opt(self.auto_space_de, |v, b| b.auto_space_de(v))?
+            .apply_opt(self.auto_space_dn, |v, b| b.auto_space_dn(v))?
+            .apply_opt(self.contextual_spacing, |v, b| b.contextual_spacing(v))?
+            .apply_opt(self.mirror_indents, |v, b| b.mirror_indents(v))?
+            .apply_opt(self.suppress_overlap, |v, b| b.suppress_overlap(v))?
+            .add_optional_child(&self.text_direction)?
+            .apply_opt(self.textbox_tight_wrap, |v, b| {
+                b.textbox_tight_wrap(&v.to_string())
+            })?
             .apply_if(self.keep_next, |b| b.keep_next())?
             .apply_if(self.keep_lines, |b| b.keep_lines())?
             .apply_if(self.page_break_before, |b| b.page_break_before())?
@@ -252,6 +373,8 @@ impl BuildXML for ParagraphProperty {
                     .apply_each(&self.tabs, |tab, b| b.tab(tab.val, tab.leader, tab.pos))?
                     .close()
             })?
+            .apply_opt(self.div_id.as_ref(), |v, b| b.div_id(v))?
+            .add_optional_child(&self.cnf_style)?
             .add_optional_child(&self.section_property)?
             .close()?
             .into_inner()
@@ -324,6 +447,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_extended_properties() {
+        let props = ParagraphProperty::new()
+            .contextual_spacing(true)
+            .mirror_indents(true)
+            .suppress_auto_hyphens(true)
+            .suppress_line_numbers(true)
+            .suppress_overlap(true)
+            .word_wrap(false)
+            .textbox_tight_wrap(TextboxTightWrapType::FirstAndLastLine)
+            .cnf_style(CnfStyle::new("100000000000"))
+            .div_id("1234");
+        let bytes = props.build();
+        assert_eq!(
+            str::from_utf8(&bytes).unwrap(),
+            r#"<w:pPr><w:rPr /><w:suppressLineNumbers /><w:suppressAutoHyphens /><w:wordWrap w:val="false" /><w:contextualSpacing /><w:mirrorIndents /><w:suppressOverlap /><w:textboxTightWrap w:val="firstAndLastLine" /><w:divId w:val="1234" /><w:cnfStyle w:val="100000000000" /></w:pPr>"#
+        )
+    }
+
     #[test]
     fn test_indent_json() {
         let c = ParagraphProperty::new();
@@ -3,6 +3,7 @@ use std::io::Write;
 
 use super::*;
 use crate::documents::BuildXML;
+use crate::types::{TextDirectionType, TextboxTightWrapType};
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -25,6 +26,85 @@ impl ParagraphPropertyDefault {
         self.paragraph_property = p;
         self
     }
+
+    pub(crate) fn get_paragraph_property(&self) -> &ParagraphProperty {
+        &self.paragraph_property
+    }
+
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.contextual_spacing(v);
+        self
+    }
+
+    pub fn mirror_indents(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.mirror_indents(v);
+        self
+    }
+
+    pub fn suppress_auto_hyphens(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.suppress_auto_hyphens(v);
+        self
+    }
+
+    pub fn suppress_line_numbers(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.suppress_line_numbers(v);
+        self
+    }
+
+    pub fn suppress_overlap(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.suppress_overlap(v);
+        self
+    }
+
+    pub fn word_wrap(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.word_wrap(v);
+        self
+    }
+
+    pub fn kinsoku(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.kinsoku(v);
+        self
+    }
+
+    pub fn overflow_punct(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.overflow_punct(v);
+        self
+    }
+
+    pub fn top_line_punct(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.top_line_punct(v);
+        self
+    }
+
+    pub fn auto_space_de(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.auto_space_de(v);
+        self
+    }
+
+    pub fn auto_space_dn(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.auto_space_dn(v);
+        self
+    }
+
+    pub fn text_direction(mut self, t: TextDirectionType) -> Self {
+        self.paragraph_property = self.paragraph_property.text_direction(t);
+        self
+    }
+
+    pub fn textbox_tight_wrap(mut self, t: TextboxTightWrapType) -> Self {
+        self.paragraph_property = self.paragraph_property.textbox_tight_wrap(t);
+        self
+    }
+
+    pub fn cnf_style(mut self, s: CnfStyle) -> Self {
+        self.paragraph_property = self.paragraph_property.cnf_style(s);
+        self
+    }
+
+    pub fn div_id(mut self, id: impl Into<String>) -> Self {
+        self.paragraph_property = self.paragraph_property.div_id(id);
+        self
+    }
 }
 
 impl Default for ParagraphPropertyDefault {
@@ -0,0 +1,79 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// i.e.    <w15:person w15:author="Alice">
+//           <w15:presenceInfo w15:providerId="AD" w15:userId="S::alice@example.com::8f3c..."/>
+//         </w15:person>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    pub author: String,
+    pub presence_info: Option<PresenceInfo>,
+}
+
+/// Identifies the account of an author, e.g. for the provider `AD`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresenceInfo {
+    pub provider_id: String,
+    pub user_id: String,
+}
+
+impl Person {
+    pub fn new(author: impl Into<String>) -> Person {
+        Self {
+            author: author.into(),
+            presence_info: None,
+        }
+    }
+
+    pub fn presence_info(
+        mut self,
+        provider_id: impl Into<String>,
+        user_id: impl Into<String>,
+    ) -> Person {
+        self.presence_info = Some(PresenceInfo {
+            provider_id: provider_id.into(),
+            user_id: user_id.into(),
+        });
+        self
+    }
+}
+
+impl BuildXML for Person {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_person(&self.author)?
+            .apply_opt(self.presence_info.as_ref(), |info, b| {
+                b.presence_info(&info.provider_id, &info.user_id)
+            })?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_person() {
+        let b = Person::new("Alice")
+            .presence_info("AD", "S::alice@example.com::1")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w15:person w15:author="Alice"><w15:presenceInfo w15:providerId="AD" w15:userId="S::alice@example.com::1" /></w15:person>"#
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Raises (positive) or lowers (negative) the run relative to the baseline,
+/// in half-points (`w:position`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Position {
+    val: i32,
+}
+
+impl Position {
+    pub fn new(val: i32) -> Position {
+        Self { val }
+    }
+}
+
+impl Serialize for Position {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i32(self.val)
+    }
+}
+
+impl BuildXML for Position {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .position(self.val as isize)?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_position() {
+        let b = Position::new(-6).build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:position w:val="-6" />"#);
+    }
+}
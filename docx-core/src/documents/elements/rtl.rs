@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Lays out the run contents right to left (`w:rtl`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Rtl {
+    val: bool,
+}
+
+impl Rtl {
+    pub fn new() -> Rtl {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> Rtl {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for Rtl {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for Rtl {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for Rtl {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).rtl(self.val)?.into_inner()
+    }
+}
@@ -374,6 +374,166 @@ impl Run {
         self.run_property = self.run_property.shading(shading);
         self
     }
+
+    pub fn caps(mut self) -> Run {
+        self.run_property = self.run_property.caps();
+        self
+    }
+
+    pub fn disable_caps(mut self) -> Run {
+        self.run_property = self.run_property.disable_caps();
+        self
+    }
+
+    pub fn small_caps(mut self) -> Run {
+        self.run_property = self.run_property.small_caps();
+        self
+    }
+
+    pub fn disable_small_caps(mut self) -> Run {
+        self.run_property = self.run_property.disable_small_caps();
+        self
+    }
+
+    pub fn emboss(mut self) -> Run {
+        self.run_property = self.run_property.emboss();
+        self
+    }
+
+    pub fn imprint(mut self) -> Run {
+        self.run_property = self.run_property.imprint();
+        self
+    }
+
+    pub fn outline(mut self) -> Run {
+        self.run_property = self.run_property.outline();
+        self
+    }
+
+    pub fn shadow(mut self) -> Run {
+        self.run_property = self.run_property.shadow();
+        self
+    }
+
+    pub fn no_proof(mut self) -> Run {
+        self.run_property = self.run_property.no_proof();
+        self
+    }
+
+    pub fn snap_to_grid(mut self, v: bool) -> Run {
+        self.run_property = self.run_property.snap_to_grid(v);
+        self
+    }
+
+    pub fn web_hidden(mut self) -> Run {
+        self.run_property = self.run_property.web_hidden();
+        self
+    }
+
+    pub fn kern(mut self, size: usize) -> Run {
+        self.run_property = self.run_property.kern(size);
+        self
+    }
+
+    pub fn position(mut self, v: i32) -> Run {
+        self.run_property = self.run_property.position(v);
+        self
+    }
+
+    pub fn effect(mut self, effect: TextEffectType) -> Run {
+        self.run_property = self.run_property.effect(effect);
+        self
+    }
+
+    pub fn rtl(mut self) -> Run {
+        self.run_property = self.run_property.rtl();
+        self
+    }
+
+    pub fn complex_script(mut self) -> Run {
+        self.run_property = self.run_property.complex_script();
+        self
+    }
+
+    pub fn emphasis_mark(mut self, mark: EmphasisMarkType) -> Run {
+        self.run_property = self.run_property.emphasis_mark(mark);
+        self
+    }
+
+    pub fn lang(mut self, lang: Lang) -> Run {
+        self.run_property = self.run_property.lang(lang);
+        self
+    }
+
+    pub fn east_asian_layout(mut self, layout: EastAsianLayout) -> Run {
+        self.run_property = self.run_property.east_asian_layout(layout);
+        self
+    }
+
+    pub fn o_math(mut self) -> Run {
+        self.run_property = self.run_property.o_math();
+        self
+    }
+
+    pub fn spec_vanish(mut self) -> Run {
+        self.run_property = self.run_property.spec_vanish();
+        self
+    }
+
+    pub fn glow(mut self, glow: TextGlow) -> Run {
+        self.run_property = self.run_property.glow(glow);
+        self
+    }
+
+    pub fn text_shadow(mut self, shadow: TextShadow) -> Run {
+        self.run_property = self.run_property.text_shadow(shadow);
+        self
+    }
+
+    pub fn reflection(mut self, reflection: TextReflection) -> Run {
+        self.run_property = self.run_property.reflection(reflection);
+        self
+    }
+
+    pub fn text_outline(mut self, outline: TextOutline) -> Run {
+        self.run_property = self.run_property.text_outline(outline);
+        self
+    }
+
+    pub fn text_fill(mut self, fill: EffectFill) -> Run {
+        self.run_property = self.run_property.text_fill(fill);
+        self
+    }
+
+    pub fn ligatures(mut self, v: LigaturesType) -> Run {
+        self.run_property = self.run_property.ligatures(v);
+        self
+    }
+
+    pub fn number_form(mut self, v: NumberFormType) -> Run {
+        self.run_property = self.run_property.number_form(v);
+        self
+    }
+
+    pub fn number_spacing(mut self, v: NumberSpacingType) -> Run {
+        self.run_property = self.run_property.number_spacing(v);
+        self
+    }
+
+    pub fn stylistic_sets(mut self, sets: StylisticSets) -> Run {
+        self.run_property = self.run_property.stylistic_sets(sets);
+        self
+    }
+
+    pub fn contextual_alternatives(mut self) -> Run {
+        self.run_property = self.run_property.contextual_alternatives();
+        self
+    }
+
+    pub fn run_property_change(mut self, c: RunPropertyChange) -> Run {
+        self.run_property = self.run_property.run_property_change(c);
+        self
+    }
 }
 
 impl BuildXML for RunChild {
@@ -41,6 +41,35 @@ impl RunFonts {
         Default::default()
     }
 
+    pub(crate) fn get_ascii(&self) -> Option<&String> {
+        self.ascii.as_ref()
+    }
+
+    pub(crate) fn get_hi_ansi(&self) -> Option<&String> {
+        self.hi_ansi.as_ref()
+    }
+
+    pub(crate) fn get_east_asia(&self) -> Option<&String> {
+        self.east_asia.as_ref()
+    }
+
+    pub(crate) fn get_cs(&self) -> Option<&String> {
+        self.cs.as_ref()
+    }
+
+    // Sets the fonts given in `top`, keeping the rest.
+    pub(crate) fn overlay(&mut self, top: &RunFonts) {
+        self.ascii = top.ascii.clone().or(self.ascii.take());
+        self.hi_ansi = top.hi_ansi.clone().or(self.hi_ansi.take());
+        self.east_asia = top.east_asia.clone().or(self.east_asia.take());
+        self.cs = top.cs.clone().or(self.cs.take());
+        self.ascii_theme = top.ascii_theme.clone().or(self.ascii_theme.take());
+        self.hi_ansi_theme = top.hi_ansi_theme.clone().or(self.hi_ansi_theme.take());
+        self.east_asia_theme = top.east_asia_theme.clone().or(self.east_asia_theme.take());
+        self.cs_theme = top.cs_theme.clone().or(self.cs_theme.take());
+        self.hint = top.hint.clone().or(self.hint.take());
+    }
+
     pub fn ascii(mut self, f: impl Into<String>) -> Self {
         self.ascii = Some(escape_owned(f.into()));
         self
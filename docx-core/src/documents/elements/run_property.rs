@@ -59,6 +59,62 @@ pub struct RunProperty {
     pub positional_tab: Option<PositionalTab>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shading: Option<Shading>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub small_caps: Option<SmallCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emboss: Option<Emboss>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imprint: Option<Imprint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outline: Option<Outline>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<Shadow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_proof: Option<NoProof>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snap_to_grid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_hidden: Option<WebHidden>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kern: Option<Kern>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect: Option<TextEffect>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rtl: Option<Rtl>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub complex_script: Option<ComplexScript>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emphasis_mark: Option<EmphasisMark>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<Lang>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub east_asian_layout: Option<EastAsianLayout>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub o_math: Option<OMath>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glow: Option<TextGlow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_shadow: Option<TextShadow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reflection: Option<TextReflection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_outline: Option<TextOutline>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_fill: Option<TextFill>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ligatures: Option<Ligatures>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_form: Option<NumberForm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_spacing: Option<NumberSpacing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stylistic_sets: Option<StylisticSets>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contextual_alternatives: Option<ContextualAlternatives>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_property_change: Option<RunPropertyChange>,
 }
 
 impl RunProperty {
@@ -235,6 +291,158 @@ impl RunProperty {
         self.shading = Some(s);
         self
     }
+
+    pub fn disable_caps(mut self) -> RunProperty {
+        self.caps = Some(Caps::new().disable());
+        self
+    }
+
+    pub fn small_caps(mut self) -> RunProperty {
+        self.small_caps = Some(SmallCaps::new());
+        self
+    }
+
+    pub fn disable_small_caps(mut self) -> RunProperty {
+        self.small_caps = Some(SmallCaps::new().disable());
+        self
+    }
+
+    pub fn emboss(mut self) -> RunProperty {
+        self.emboss = Some(Emboss::new());
+        self
+    }
+
+    pub fn imprint(mut self) -> RunProperty {
+        self.imprint = Some(Imprint::new());
+        self
+    }
+
+    pub fn outline(mut self) -> RunProperty {
+        self.outline = Some(Outline::new());
+        self
+    }
+
+    pub fn shadow(mut self) -> RunProperty {
+        self.shadow = Some(Shadow::new());
+        self
+    }
+
+    pub fn no_proof(mut self) -> RunProperty {
+        self.no_proof = Some(NoProof::new());
+        self
+    }
+
+    pub fn snap_to_grid(mut self, v: bool) -> RunProperty {
+        self.snap_to_grid = Some(v);
+        self
+    }
+
+    pub fn web_hidden(mut self) -> RunProperty {
+        self.web_hidden = Some(WebHidden::new());
+        self
+    }
+
+    /// Enables kerning for font sizes at or above `size` half-points.
+    pub fn kern(mut self, size: usize) -> RunProperty {
+        self.kern = Some(Kern::new(size));
+        self
+    }
+
+    /// Raises or lowers the text by `v` half-points.
+    pub fn position(mut self, v: i32) -> RunProperty {
+        self.position = Some(Position::new(v));
+        self
+    }
+
+    pub fn effect(mut self, effect: TextEffectType) -> RunProperty {
+        self.effect = Some(TextEffect::new(effect));
+        self
+    }
+
+    pub fn rtl(mut self) -> RunProperty {
+        self.rtl = Some(Rtl::new());
+        self
+    }
+
+    pub fn complex_script(mut self) -> RunProperty {
+        self.complex_script = Some(ComplexScript::new());
+        self
+    }
+
+    pub fn emphasis_mark(mut self, mark: EmphasisMarkType) -> RunProperty {
+        self.emphasis_mark = Some(EmphasisMark::new(mark));
+        self
+    }
+
+    pub fn lang(mut self, lang: Lang) -> RunProperty {
+        self.lang = Some(lang);
+        self
+    }
+
+    pub fn east_asian_layout(mut self, layout: EastAsianLayout) -> RunProperty {
+        self.east_asian_layout = Some(layout);
+        self
+    }
+
+    pub fn o_math(mut self) -> RunProperty {
+        self.o_math = Some(OMath::new());
+        self
+    }
+
+    pub fn glow(mut self, glow: TextGlow) -> RunProperty {
+        self.glow = Some(glow);
+        self
+    }
+
+    pub fn text_shadow(mut self, shadow: TextShadow) -> RunProperty {
+        self.text_shadow = Some(shadow);
+        self
+    }
+
+    pub fn reflection(mut self, reflection: TextReflection) -> RunProperty {
+        self.reflection = Some(reflection);
+        self
+    }
+
+    pub fn text_outline(mut self, outline: TextOutline) -> RunProperty {
+        self.text_outline = Some(outline);
+        self
+    }
+
+    pub fn text_fill(mut self, fill: EffectFill) -> RunProperty {
+        self.text_fill = Some(TextFill::new(fill));
+        self
+    }
+
+    pub fn ligatures(mut self, v: LigaturesType) -> RunProperty {
+        self.ligatures = Some(Ligatures::new(v));
+        self
+    }
+
+    pub fn number_form(mut self, v: NumberFormType) -> RunProperty {
+        self.number_form = Some(NumberForm::new(v));
+        self
+    }
+
+    pub fn number_spacing(mut self, v: NumberSpacingType) -> RunProperty {
+        self.number_spacing = Some(NumberSpacing::new(v));
+        self
+    }
+
+    pub fn stylistic_sets(mut self, sets: StylisticSets) -> RunProperty {
+        self.stylistic_sets = Some(sets);
+        self
+    }
+
+    pub fn contextual_alternatives(mut self) -> RunProperty {
+        self.contextual_alternatives = Some(ContextualAlternatives::new());
+        self
+    }
+
+    pub fn run_property_change(mut self, c: RunPropertyChange) -> RunProperty {
+        self.run_property_change = Some(c);
+        self
+    }
 }
 
 impl BuildXML for RunProperty {
@@ -242,6 +450,8 @@ impl BuildXML for RunProperty {
         &self,
         stream: crate::xml::writer::EventWriter<W>,
     ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        // The original elements keep their historical order; the others sit
+        // next to their neighbours in the CT_RPr sequence.
         XMLBuilder::from(stream)
             .open_run_property()?
             .add_optional_child(&self.sz)?
@@ -250,25 +460,53 @@ impl BuildXML for RunProperty {
             .add_optional_child(&self.bold)?
             .add_optional_child(&self.bold_cs)?
             .add_optional_child(&self.caps)?
+            .add_optional_child(&self.small_caps)?
             .add_optional_child(&self.italic)?
             .add_optional_child(&self.italic_cs)?
             .add_optional_child(&self.strike)?
             .add_optional_child(&self.dstrike)?
+            .add_optional_child(&self.outline)?
+            .add_optional_child(&self.shadow)?
+            .add_optional_child(&self.emboss)?
+            .add_optional_child(&self.imprint)?
+            .add_optional_child(&self.no_proof)?
+            .apply_opt(self.snap_to_grid, |v, b| b.snap_to_grid(v))?
             .add_optional_child(&self.highlight)?
             .add_optional_child(&self.underline)?
+            .add_optional_child(&self.effect)?
             .add_optional_child(&self.vanish)?
+            .add_optional_child(&self.web_hidden)?
             .add_optional_child(&self.spec_vanish)?
+            .add_optional_child(&self.o_math)?
             .add_optional_child(&self.fonts)?
             .add_optional_child(&self.text_border)?
             .add_optional_child(&self.ins)?
             .add_optional_child(&self.del)?
             .add_optional_child(&self.vert_align)?
+            .add_optional_child(&self.rtl)?
+            .add_optional_child(&self.complex_script)?
+            .add_optional_child(&self.emphasis_mark)?
+            .add_optional_child(&self.lang)?
+            .add_optional_child(&self.east_asian_layout)?
             .add_optional_child(&self.character_spacing)?
             .add_optional_child(&self.fit_text)?
             .add_optional_child(&self.stretch)?
+            .add_optional_child(&self.kern)?
+            .add_optional_child(&self.position)?
             .add_optional_child(&self.style)?
             .add_optional_child(&self.positional_tab)?
             .add_optional_child(&self.shading)?
+            .add_optional_child(&self.glow)?
+            .add_optional_child(&self.text_shadow)?
+            .add_optional_child(&self.reflection)?
+            .add_optional_child(&self.text_outline)?
+            .add_optional_child(&self.text_fill)?
+            .add_optional_child(&self.ligatures)?
+            .add_optional_child(&self.number_form)?
+            .add_optional_child(&self.number_spacing)?
+            .add_optional_child(&self.stylistic_sets)?
+            .add_optional_child(&self.contextual_alternatives)?
+            .add_optional_child(&self.run_property_change)?
             .close()?
             .into_inner()
     }
@@ -411,6 +649,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_small_caps_and_kern() {
+        let c = RunProperty::new().small_caps().kern(28).position(-4);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:smallCaps /><w:kern w:val="28" /><w:position w:val="-4" /></w:rPr>"#
+        );
+    }
+
+    #[test]
+    fn test_new_properties_follow_schema_order() {
+        let c = RunProperty::new()
+            .kern(28)
+            .small_caps()
+            .italic()
+            .caps()
+            .vanish()
+            .web_hidden()
+            .character_spacing(20)
+            .lang(Lang::new().val("en-US"))
+            .vert_align(VertAlignType::SuperScript);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:caps w:val="true" /><w:smallCaps /><w:i /><w:iCs /><w:vanish /><w:webHidden /><w:vertAlign w:val="superscript" /><w:lang w:val="en-US" /><w:spacing w:val="20" /><w:kern w:val="28" /></w:rPr>"#
+        );
+    }
+
+    #[test]
+    fn test_legacy_effects() {
+        let c = RunProperty::new()
+            .outline()
+            .shadow()
+            .emboss()
+            .imprint()
+            .effect(TextEffectType::Shimmer);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:outline /><w:shadow /><w:emboss /><w:imprint /><w:effect w:val="shimmer" /></w:rPr>"#
+        );
+    }
+
+    #[test]
+    fn test_lang_and_no_proof() {
+        let c = RunProperty::new()
+            .no_proof()
+            .web_hidden()
+            .snap_to_grid(false)
+            .lang(Lang::new().val("en-US"));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:noProof /><w:snapToGrid w:val="false" /><w:webHidden /><w:lang w:val="en-US" /></w:rPr>"#
+        );
+    }
+
+    #[test]
+    fn test_w14_effects() {
+        let c = RunProperty::new()
+            .glow(TextGlow::new(63500, EffectColor::scheme("accent1")))
+            .ligatures(LigaturesType::Standard)
+            .number_form(NumberFormType::OldStyle);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w14:glow w14:rad="63500"><w14:schemeClr w14:val="accent1" /></w14:glow><w14:ligatures w14:val="standard" /><w14:numForm w14:val="oldStyle" /></w:rPr>"#
+        );
+    }
+
+    #[test]
+    fn test_run_property_change() {
+        let c = RunProperty::new().bold().run_property_change(
+            RunPropertyChange::new()
+                .author("bokuweb")
+                .property(RunProperty::new().italic()),
+        );
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:b /><w:bCs /><w:rPrChange w:id="123" w:author="bokuweb" w:date="1970-01-01T00:00:00Z"><w:rPr><w:i /><w:iCs /></w:rPr></w:rPrChange></w:rPr>"#
+        );
+    }
+
     #[test]
     fn test_dstrike() {
         let c = RunProperty::new().dstrike();
@@ -0,0 +1,87 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::*;
+use crate::escape;
+use crate::xml_builder::*;
+
+/// Tracked revision of run properties (`w:rPrChange`).
+///
+/// `property` holds the formatting the run had before the revision.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RunPropertyChange {
+    pub author: String,
+    pub date: String,
+    pub property: Box<RunProperty>,
+}
+
+impl Default for RunPropertyChange {
+    fn default() -> RunPropertyChange {
+        Self {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            property: Default::default(),
+        }
+    }
+}
+
+impl RunPropertyChange {
+    pub fn new() -> RunPropertyChange {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn property(mut self, p: RunProperty) -> RunPropertyChange {
+        self.property = Box::new(p);
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> RunPropertyChange {
+        self.author = escape::escape_owned(author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> RunPropertyChange {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for RunPropertyChange {}
+
+impl BuildXML for RunPropertyChange {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        let id = self.generate();
+        XMLBuilder::from(stream)
+            .open_run_property_change(id.as_ref(), &self.author, &self.date)?
+            .add_child(self.property.as_ref())?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_rpr_change() {
+        let b = RunPropertyChange::new()
+            .author("bokuweb")
+            .property(RunProperty::new().bold())
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPrChange w:id="123" w:author="bokuweb" w:date="1970-01-01T00:00:00Z"><w:rPr><w:b /><w:bCs /></w:rPr></w:rPrChange>"#
+        );
+    }
+}
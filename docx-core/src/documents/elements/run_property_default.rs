@@ -3,6 +3,7 @@ use std::io::Write;
 
 use super::*;
 use crate::documents::BuildXML;
+use crate::types::*;
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -35,6 +36,175 @@ impl RunPropertyDefault {
         self.run_property = p;
         self
     }
+
+    pub(crate) fn get_run_property(&self) -> &RunProperty {
+        &self.run_property
+    }
+
+    pub fn caps(mut self) -> Self {
+        self.run_property = self.run_property.caps();
+        self
+    }
+
+    pub fn disable_caps(mut self) -> Self {
+        self.run_property = self.run_property.disable_caps();
+        self
+    }
+
+    pub fn small_caps(mut self) -> Self {
+        self.run_property = self.run_property.small_caps();
+        self
+    }
+
+    pub fn disable_small_caps(mut self) -> Self {
+        self.run_property = self.run_property.disable_small_caps();
+        self
+    }
+
+    pub fn emboss(mut self) -> Self {
+        self.run_property = self.run_property.emboss();
+        self
+    }
+
+    pub fn imprint(mut self) -> Self {
+        self.run_property = self.run_property.imprint();
+        self
+    }
+
+    pub fn outline(mut self) -> Self {
+        self.run_property = self.run_property.outline();
+        self
+    }
+
+    pub fn shadow(mut self) -> Self {
+        self.run_property = self.run_property.shadow();
+        self
+    }
+
+    pub fn no_proof(mut self) -> Self {
+        self.run_property = self.run_property.no_proof();
+        self
+    }
+
+    pub fn snap_to_grid(mut self, v: bool) -> Self {
+        self.run_property = self.run_property.snap_to_grid(v);
+        self
+    }
+
+    pub fn web_hidden(mut self) -> Self {
+        self.run_property = self.run_property.web_hidden();
+        self
+    }
+
+    pub fn kern(mut self, size: usize) -> Self {
+        self.run_property = self.run_property.kern(size);
+        self
+    }
+
+    pub fn position(mut self, v: i32) -> Self {
+        self.run_property = self.run_property.position(v);
+        self
+    }
+
+    pub fn effect(mut self, effect: TextEffectType) -> Self {
+        self.run_property = self.run_property.effect(effect);
+        self
+    }
+
+    pub fn rtl(mut self) -> Self {
+        self.run_property = self.run_property.rtl();
+        self
+    }
+
+    pub fn complex_script(mut self) -> Self {
+        self.run_property = self.run_property.complex_script();
+        self
+    }
+
+    pub fn emphasis_mark(mut self, mark: EmphasisMarkType) -> Self {
+        self.run_property = self.run_property.emphasis_mark(mark);
+        self
+    }
+
+    pub fn lang(mut self, lang: Lang) -> Self {
+        self.run_property = self.run_property.lang(lang);
+        self
+    }
+
+    pub fn east_asian_layout(mut self, layout: EastAsianLayout) -> Self {
+        self.run_property = self.run_property.east_asian_layout(layout);
+        self
+    }
+
+    pub fn o_math(mut self) -> Self {
+        self.run_property = self.run_property.o_math();
+        self
+    }
+
+    pub fn spec_vanish(mut self) -> Self {
+        self.run_property = self.run_property.spec_vanish();
+        self
+    }
+
+    pub fn stretch(mut self, v: i32) -> Self {
+        self.run_property = self.run_property.stretch(v);
+        self
+    }
+
+    pub fn glow(mut self, glow: TextGlow) -> Self {
+        self.run_property = self.run_property.glow(glow);
+        self
+    }
+
+    pub fn text_shadow(mut self, shadow: TextShadow) -> Self {
+        self.run_property = self.run_property.text_shadow(shadow);
+        self
+    }
+
+    pub fn reflection(mut self, reflection: TextReflection) -> Self {
+        self.run_property = self.run_property.reflection(reflection);
+        self
+    }
+
+    pub fn text_outline(mut self, outline: TextOutline) -> Self {
+        self.run_property = self.run_property.text_outline(outline);
+        self
+    }
+
+    pub fn text_fill(mut self, fill: EffectFill) -> Self {
+        self.run_property = self.run_property.text_fill(fill);
+        self
+    }
+
+    pub fn ligatures(mut self, v: LigaturesType) -> Self {
+        self.run_property = self.run_property.ligatures(v);
+        self
+    }
+
+    pub fn number_form(mut self, v: NumberFormType) -> Self {
+        self.run_property = self.run_property.number_form(v);
+        self
+    }
+
+    pub fn number_spacing(mut self, v: NumberSpacingType) -> Self {
+        self.run_property = self.run_property.number_spacing(v);
+        self
+    }
+
+    pub fn stylistic_sets(mut self, sets: StylisticSets) -> Self {
+        self.run_property = self.run_property.stylistic_sets(sets);
+        self
+    }
+
+    pub fn contextual_alternatives(mut self) -> Self {
+        self.run_property = self.run_property.contextual_alternatives();
+        self
+    }
+
+    pub fn run_property_change(mut self, c: RunPropertyChange) -> Self {
+        self.run_property = self.run_property.run_property_change(c);
+        self
+    }
 }
 
 impl Default for RunPropertyDefault {
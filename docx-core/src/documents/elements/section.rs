@@ -19,6 +19,7 @@ pub enum SectionChild {
     CommentEnd(CommentRangeEnd),
     StructuredDataTag(Box<StructuredDataTag>),
     TableOfContents(Box<TableOfContents>),
+    AltChunk(Box<AltChunk>),
 }
 
 impl Serialize for SectionChild {
@@ -75,6 +76,12 @@ impl Serialize for SectionChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            SectionChild::AltChunk(ref r) => {
+                let mut t = serializer.serialize_struct("AltChunk", 2)?;
+                t.serialize_field("type", "altChunk")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
         }
     }
 }
@@ -93,6 +100,7 @@ impl BuildXML for SectionChild {
             SectionChild::CommentEnd(v) => v.build_to(stream),
             SectionChild::StructuredDataTag(v) => v.build_to(stream),
             SectionChild::TableOfContents(v) => v.build_to(stream),
+            SectionChild::AltChunk(v) => v.build_to(stream),
         }
     }
 }
@@ -180,6 +188,11 @@ impl Section {
         self
     }
 
+    pub fn add_alt_chunk(mut self, c: AltChunk) -> Self {
+        self.children.push(SectionChild::AltChunk(Box::new(c)));
+        self
+    }
+
     pub fn header(mut self, header: Header) -> Self {
         self.temp_header = Some(header);
         self
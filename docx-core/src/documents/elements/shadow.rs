@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Draws a shadow behind each character (`w:shadow`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Shadow {
+    val: bool,
+}
+
+impl Shadow {
+    pub fn new() -> Shadow {
+        Default::default()
+    }
+
+    pub(crate) fn get_val(&self) -> bool {
+        self.val
+    }
+
+    pub fn disable(mut self) -> Shadow {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for Shadow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for Shadow {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).shadow(self.val)?.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_shadow() {
+        let b = Shadow::new().build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:shadow />"#);
+    }
+
+    #[test]
+    fn test_shadow_disabled() {
+        let b = Shadow::new().disable().build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:shadow w:val="false" />"#);
+    }
+}
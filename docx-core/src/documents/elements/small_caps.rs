@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Displays lowercase characters as small capital letters (`w:smallCaps`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SmallCaps {
+    val: bool,
+}
+
+impl SmallCaps {
+    pub fn new() -> SmallCaps {
+        Default::default()
+    }
+
+    pub(crate) fn get_val(&self) -> bool {
+        self.val
+    }
+
+    pub fn disable(mut self) -> SmallCaps {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for SmallCaps {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for SmallCaps {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for SmallCaps {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).small_caps(self.val)?.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_small_caps() {
+        let b = SmallCaps::new().build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:smallCaps />"#);
+    }
+
+    #[test]
+    fn test_small_caps_disabled() {
+        let b = SmallCaps::new().disable().build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:smallCaps w:val="false" />"#
+        );
+    }
+}
@@ -13,6 +13,10 @@ impl Start {
     pub fn new(val: usize) -> Start {
         Start { val }
     }
+
+    pub(crate) fn get_val(&self) -> usize {
+        self.val
+    }
 }
 
 impl BuildXML for Start {
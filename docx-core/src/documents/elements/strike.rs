@@ -14,6 +14,10 @@ impl Strike {
         Default::default()
     }
 
+    pub(crate) fn get_val(&self) -> bool {
+        self.val
+    }
+
     pub fn disable(mut self) -> Strike {
         self.val = false;
         self
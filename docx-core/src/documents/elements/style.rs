@@ -31,6 +31,11 @@ pub struct Style {
     pub semi_hidden: bool,
     #[serde(skip_serializing_if = "is_false")]
     pub unhide_when_used: bool,
+    /// Whether this is the default style of its type (`w:default`).
+    #[serde(skip_serializing_if = "is_false")]
+    pub is_default: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub table_style_overrides: Vec<TableStyleOverride>,
 }
 
 const fn is_true(v: &bool) -> bool {
@@ -61,6 +66,8 @@ impl Default for Style {
             ui_priority: None,
             semi_hidden: false,
             unhide_when_used: false,
+            is_default: false,
+            table_style_overrides: vec![],
         }
     }
 }
@@ -163,6 +170,16 @@ impl Style {
         self
     }
 
+    pub fn vert_align(mut self, a: VertAlignType) -> Self {
+        self.run_property = self.run_property.vert_align(a);
+        self
+    }
+
+    pub fn spacing(mut self, spacing: i32) -> Self {
+        self.run_property = self.run_property.spacing(spacing);
+        self
+    }
+
     pub fn vanish(mut self) -> Self {
         self.run_property = self.run_property.vanish();
         self
@@ -226,6 +243,33 @@ impl Style {
         self
     }
 
+    pub fn keep_next(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.keep_next(v);
+        self
+    }
+
+    pub fn keep_lines(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.keep_lines(v);
+        self
+    }
+
+    /// Marks this style as the default for its type, e.g. the paragraph style
+    /// used by paragraphs without `w:pStyle`.
+    pub fn as_default(mut self) -> Self {
+        self.is_default = true;
+        self
+    }
+
+    pub fn add_table_style_override(mut self, o: TableStyleOverride) -> Self {
+        self.table_style_overrides.push(o);
+        self
+    }
+
+    pub fn numbering(mut self, id: NumberingId, level: IndentLevel) -> Self {
+        self.paragraph_property = self.paragraph_property.numbering(id, level);
+        self
+    }
+
     pub fn table_property(mut self, p: TableProperty) -> Self {
         self.table_property = p;
         self
@@ -382,6 +426,247 @@ impl Style {
         });
         self
     }
+
+    pub fn caps(mut self) -> Self {
+        self.run_property = self.run_property.caps();
+        self
+    }
+
+    pub fn disable_caps(mut self) -> Self {
+        self.run_property = self.run_property.disable_caps();
+        self
+    }
+
+    pub fn small_caps(mut self) -> Self {
+        self.run_property = self.run_property.small_caps();
+        self
+    }
+
+    pub fn disable_small_caps(mut self) -> Self {
+        self.run_property = self.run_property.disable_small_caps();
+        self
+    }
+
+    pub fn emboss(mut self) -> Self {
+        self.run_property = self.run_property.emboss();
+        self
+    }
+
+    pub fn imprint(mut self) -> Self {
+        self.run_property = self.run_property.imprint();
+        self
+    }
+
+    pub fn outline(mut self) -> Self {
+        self.run_property = self.run_property.outline();
+        self
+    }
+
+    pub fn shadow(mut self) -> Self {
+        self.run_property = self.run_property.shadow();
+        self
+    }
+
+    pub fn no_proof(mut self) -> Self {
+        self.run_property = self.run_property.no_proof();
+        self
+    }
+
+    // `snap_to_grid` is taken by the paragraph-level `w:snapToGrid`.
+    pub fn run_snap_to_grid(mut self, v: bool) -> Self {
+        self.run_property = self.run_property.snap_to_grid(v);
+        self
+    }
+
+    pub fn web_hidden(mut self) -> Self {
+        self.run_property = self.run_property.web_hidden();
+        self
+    }
+
+    pub fn kern(mut self, size: usize) -> Self {
+        self.run_property = self.run_property.kern(size);
+        self
+    }
+
+    pub fn position(mut self, v: i32) -> Self {
+        self.run_property = self.run_property.position(v);
+        self
+    }
+
+    pub fn effect(mut self, effect: TextEffectType) -> Self {
+        self.run_property = self.run_property.effect(effect);
+        self
+    }
+
+    pub fn rtl(mut self) -> Self {
+        self.run_property = self.run_property.rtl();
+        self
+    }
+
+    pub fn complex_script(mut self) -> Self {
+        self.run_property = self.run_property.complex_script();
+        self
+    }
+
+    pub fn emphasis_mark(mut self, mark: EmphasisMarkType) -> Self {
+        self.run_property = self.run_property.emphasis_mark(mark);
+        self
+    }
+
+    pub fn lang(mut self, lang: Lang) -> Self {
+        self.run_property = self.run_property.lang(lang);
+        self
+    }
+
+    pub fn east_asian_layout(mut self, layout: EastAsianLayout) -> Self {
+        self.run_property = self.run_property.east_asian_layout(layout);
+        self
+    }
+
+    pub fn o_math(mut self) -> Self {
+        self.run_property = self.run_property.o_math();
+        self
+    }
+
+    pub fn spec_vanish(mut self) -> Self {
+        self.run_property = self.run_property.spec_vanish();
+        self
+    }
+
+    pub fn stretch(mut self, v: i32) -> Self {
+        self.run_property = self.run_property.stretch(v);
+        self
+    }
+
+    pub fn glow(mut self, glow: TextGlow) -> Self {
+        self.run_property = self.run_property.glow(glow);
+        self
+    }
+
+    pub fn text_shadow(mut self, shadow: TextShadow) -> Self {
+        self.run_property = self.run_property.text_shadow(shadow);
+        self
+    }
+
+    pub fn reflection(mut self, reflection: TextReflection) -> Self {
+        self.run_property = self.run_property.reflection(reflection);
+        self
+    }
+
+    pub fn text_outline(mut self, outline: TextOutline) -> Self {
+        self.run_property = self.run_property.text_outline(outline);
+        self
+    }
+
+    pub fn text_fill(mut self, fill: EffectFill) -> Self {
+        self.run_property = self.run_property.text_fill(fill);
+        self
+    }
+
+    pub fn ligatures(mut self, v: LigaturesType) -> Self {
+        self.run_property = self.run_property.ligatures(v);
+        self
+    }
+
+    pub fn number_form(mut self, v: NumberFormType) -> Self {
+        self.run_property = self.run_property.number_form(v);
+        self
+    }
+
+    pub fn number_spacing(mut self, v: NumberSpacingType) -> Self {
+        self.run_property = self.run_property.number_spacing(v);
+        self
+    }
+
+    pub fn stylistic_sets(mut self, sets: StylisticSets) -> Self {
+        self.run_property = self.run_property.stylistic_sets(sets);
+        self
+    }
+
+    pub fn contextual_alternatives(mut self) -> Self {
+        self.run_property = self.run_property.contextual_alternatives();
+        self
+    }
+
+    pub fn run_property_change(mut self, c: RunPropertyChange) -> Self {
+        self.run_property = self.run_property.run_property_change(c);
+        self
+    }
+
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.contextual_spacing(v);
+        self
+    }
+
+    pub fn mirror_indents(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.mirror_indents(v);
+        self
+    }
+
+    pub fn suppress_auto_hyphens(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.suppress_auto_hyphens(v);
+        self
+    }
+
+    pub fn suppress_line_numbers(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.suppress_line_numbers(v);
+        self
+    }
+
+    pub fn suppress_overlap(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.suppress_overlap(v);
+        self
+    }
+
+    pub fn word_wrap(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.word_wrap(v);
+        self
+    }
+
+    pub fn kinsoku(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.kinsoku(v);
+        self
+    }
+
+    pub fn overflow_punct(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.overflow_punct(v);
+        self
+    }
+
+    pub fn top_line_punct(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.top_line_punct(v);
+        self
+    }
+
+    pub fn auto_space_de(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.auto_space_de(v);
+        self
+    }
+
+    pub fn auto_space_dn(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.auto_space_dn(v);
+        self
+    }
+
+    pub fn text_direction(mut self, t: TextDirectionType) -> Self {
+        self.paragraph_property = self.paragraph_property.text_direction(t);
+        self
+    }
+
+    pub fn textbox_tight_wrap(mut self, t: TextboxTightWrapType) -> Self {
+        self.paragraph_property = self.paragraph_property.textbox_tight_wrap(t);
+        self
+    }
+
+    pub fn cnf_style(mut self, s: CnfStyle) -> Self {
+        self.paragraph_property = self.paragraph_property.cnf_style(s);
+        self
+    }
+
+    pub fn div_id(mut self, id: impl Into<String>) -> Self {
+        self.paragraph_property = self.paragraph_property.div_id(id);
+        self
+    }
 }
 
 impl BuildXML for Style {
@@ -391,7 +676,7 @@ impl BuildXML for Style {
     ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
         // Set "Normal" as default if you need change these values please fix it
         XMLBuilder::from(stream)
-            .open_style(self.style_type, &self.style_id)?
+            .open_style(self.style_type, &self.style_id, self.is_default)?
             .add_child(&self.name)?
             .add_child(&self.run_property)?
             .add_child(&self.paragraph_property)?
@@ -408,6 +693,7 @@ impl BuildXML for Style {
             .apply_if(self.semi_hidden, |b| b.semi_hidden())?
             .apply_if(self.unhide_when_used, |b| b.unhide_when_used())?
             .add_optional_child(&self.based_on)?
+            .add_children(&self.table_style_overrides)?
             .close()?
             .into_inner()
     }
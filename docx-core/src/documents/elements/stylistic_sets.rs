@@ -0,0 +1,66 @@
+use serde::{Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// OpenType stylistic sets enabled on the run (`w14:stylisticSets`).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StylisticSets {
+    ids: Vec<usize>,
+}
+
+impl StylisticSets {
+    pub fn new() -> StylisticSets {
+        Default::default()
+    }
+
+    pub fn add_set(mut self, id: usize) -> StylisticSets {
+        self.ids.push(id);
+        self
+    }
+
+    pub fn ids(&self) -> &[usize] {
+        &self.ids
+    }
+}
+
+impl Serialize for StylisticSets {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.ids.serialize(serializer)
+    }
+}
+
+impl BuildXML for StylisticSets {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_w14_stylistic_sets()?
+            .apply_each(&self.ids, |id, b| b.w14_style_set(*id))?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_stylistic_sets() {
+        let b = StylisticSets::new().add_set(1).add_set(4).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w14:stylisticSets><w14:styleSet w14:id="1" /><w14:styleSet w14:id="4" /></w14:stylisticSets>"#
+        );
+    }
+}
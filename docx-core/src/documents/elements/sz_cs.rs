@@ -12,6 +12,10 @@ impl SzCs {
     pub fn new(val: usize) -> SzCs {
         SzCs { val }
     }
+
+    pub(crate) fn get_val(&self) -> usize {
+        self.val
+    }
 }
 
 impl BuildXML for SzCs {
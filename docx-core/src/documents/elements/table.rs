@@ -94,6 +94,11 @@ impl Table {
         self
     }
 
+    pub fn look(mut self, look: TableLook) -> Self {
+        self.property = self.property.look(look);
+        self
+    }
+
     pub fn width(mut self, w: usize, t: WidthType) -> Table {
         self.property = self.property.width(w, t);
         self
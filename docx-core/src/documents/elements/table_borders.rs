@@ -152,6 +152,23 @@ impl TableBorders {
             Some(TableBorder::new(TableBorderPosition::InsideV).border_type(BorderType::Nil));
         self
     }
+
+    /// Whether any of the borders is drawn.
+    pub(crate) fn has_visible(&self) -> bool {
+        [
+            &self.top,
+            &self.left,
+            &self.bottom,
+            &self.right,
+            &self.inside_h,
+            &self.inside_v,
+        ]
+        .iter()
+        .any(|b| {
+            b.as_ref()
+                .is_some_and(|b| !matches!(b.border_type, BorderType::Nil | BorderType::None))
+        })
+    }
 }
 
 impl BuildXML for TableBorders {
@@ -53,6 +53,14 @@ impl TableCellProperty {
         self
     }
 
+    pub(crate) fn get_grid_span(&self) -> usize {
+        self.grid_span.as_ref().map_or(1, |s| s.get_val().max(1))
+    }
+
+    pub(crate) fn get_vertical_merge(&self) -> Option<&VMergeType> {
+        self.vertical_merge.as_ref().map(|m| m.get_val())
+    }
+
     pub fn shading(mut self, s: Shading) -> Self {
         self.shading = Some(s);
         self
@@ -0,0 +1,157 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Which conditional formatting of the table style a table shows
+/// (`w:tblLook`). The default is Word's look for new tables: an emphasized
+/// header row and first column with banded rows.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableLook {
+    first_row: bool,
+    last_row: bool,
+    first_column: bool,
+    last_column: bool,
+    no_h_band: bool,
+    no_v_band: bool,
+}
+
+impl Default for TableLook {
+    fn default() -> Self {
+        TableLook {
+            first_row: true,
+            last_row: false,
+            first_column: true,
+            last_column: false,
+            no_h_band: false,
+            no_v_band: true,
+        }
+    }
+}
+
+impl TableLook {
+    pub fn new() -> TableLook {
+        Default::default()
+    }
+
+    /// Reads the legacy `w:val` bitmask, e.g. `04A0`.
+    pub(crate) fn from_bits(bits: u16) -> TableLook {
+        TableLook {
+            first_row: bits & 0x0020 != 0,
+            last_row: bits & 0x0040 != 0,
+            first_column: bits & 0x0080 != 0,
+            last_column: bits & 0x0100 != 0,
+            no_h_band: bits & 0x0200 != 0,
+            no_v_band: bits & 0x0400 != 0,
+        }
+    }
+
+    fn bits(&self) -> u16 {
+        [
+            (self.first_row, 0x0020),
+            (self.last_row, 0x0040),
+            (self.first_column, 0x0080),
+            (self.last_column, 0x0100),
+            (self.no_h_band, 0x0200),
+            (self.no_v_band, 0x0400),
+        ]
+        .iter()
+        .filter(|(on, _)| *on)
+        .fold(0, |bits, (_, bit)| bits | bit)
+    }
+
+    pub fn first_row(mut self, v: bool) -> TableLook {
+        self.first_row = v;
+        self
+    }
+
+    pub fn last_row(mut self, v: bool) -> TableLook {
+        self.last_row = v;
+        self
+    }
+
+    pub fn first_column(mut self, v: bool) -> TableLook {
+        self.first_column = v;
+        self
+    }
+
+    pub fn last_column(mut self, v: bool) -> TableLook {
+        self.last_column = v;
+        self
+    }
+
+    pub fn no_h_band(mut self, v: bool) -> TableLook {
+        self.no_h_band = v;
+        self
+    }
+
+    pub fn no_v_band(mut self, v: bool) -> TableLook {
+        self.no_v_band = v;
+        self
+    }
+
+    pub(crate) fn get_first_row(&self) -> bool {
+        self.first_row
+    }
+
+    pub(crate) fn get_last_row(&self) -> bool {
+        self.last_row
+    }
+
+    pub(crate) fn get_first_column(&self) -> bool {
+        self.first_column
+    }
+
+    pub(crate) fn get_last_column(&self) -> bool {
+        self.last_column
+    }
+
+    pub(crate) fn get_no_h_band(&self) -> bool {
+        self.no_h_band
+    }
+
+    pub(crate) fn get_no_v_band(&self) -> bool {
+        self.no_v_band
+    }
+}
+
+impl BuildXML for TableLook {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        let flag = |v: bool| if v { "1" } else { "0" };
+        XMLBuilder::from(stream)
+            .table_look(
+                &format!("{:04X}", self.bits()),
+                flag(self.first_row),
+                flag(self.last_row),
+                flag(self.first_column),
+                flag(self.last_column),
+                flag(self.no_h_band),
+                flag(self.no_v_band),
+            )?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_table_look() {
+        let b = TableLook::new().last_row(true).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblLook w:val="04E0" w:firstRow="1" w:lastRow="1" w:firstColumn="1" w:lastColumn="0" w:noHBand="0" w:noVBand="1" />"#
+        );
+        assert_eq!(TableLook::from_bits(0x04A0), TableLook::new());
+    }
+}
@@ -25,6 +25,8 @@ pub struct TableProperty {
     layout: Option<TableLayout>,
     #[serde(skip_serializing_if = "Option::is_none")]
     position: Option<TablePositionProperty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    look: Option<TableLook>,
 }
 
 impl Default for TableProperty {
@@ -38,6 +40,7 @@ impl Default for TableProperty {
             style: None,
             layout: None,
             position: None,
+            look: None,
         }
     }
 }
@@ -134,11 +137,23 @@ impl TableProperty {
         self
     }
 
+    pub(crate) fn has_borders(&self) -> bool {
+        self.borders.has_visible()
+    }
+
     pub fn style(mut self, s: impl Into<String>) -> Self {
         self.style = Some(TableStyle::new(s));
         self
     }
 
+    pub(crate) fn get_style(&self) -> Option<&TableStyle> {
+        self.style.as_ref()
+    }
+
+    pub(crate) fn style_mut(&mut self) -> Option<&mut TableStyle> {
+        self.style.as_mut()
+    }
+
     pub fn layout(mut self, t: TableLayoutType) -> Self {
         self.layout = Some(TableLayout::new(t));
         self
@@ -148,6 +163,15 @@ impl TableProperty {
         self.position = Some(p);
         self
     }
+
+    pub fn look(mut self, look: TableLook) -> Self {
+        self.look = Some(look);
+        self
+    }
+
+    pub(crate) fn get_look(&self) -> Option<&TableLook> {
+        self.look.as_ref()
+    }
 }
 
 impl BuildXML for TableProperty {
@@ -165,6 +189,7 @@ impl BuildXML for TableProperty {
             .add_optional_child(&self.style)?
             .add_optional_child(&self.layout)?
             .add_optional_child(&self.position)?
+            .add_optional_child(&self.look)?
             .close()?
             .into_inner()
     }
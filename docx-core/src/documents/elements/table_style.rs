@@ -13,6 +13,14 @@ impl TableStyle {
     pub fn new(val: impl Into<String>) -> TableStyle {
         TableStyle { val: val.into() }
     }
+
+    pub(crate) fn get_val(&self) -> &str {
+        &self.val
+    }
+
+    pub(crate) fn val_mut(&mut self) -> &mut String {
+        &mut self.val
+    }
 }
 
 impl BuildXML for TableStyle {
@@ -0,0 +1,72 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::*;
+use crate::types::*;
+use crate::xml_builder::*;
+
+/// Conditional formatting of a table style (`w:tblStylePr`).
+///
+/// Applies on top of the table style's own properties to the part of the
+/// table selected by `override_type`, e.g. the header row or odd bands.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableStyleOverride {
+    pub override_type: TableStyleOverrideType,
+    pub paragraph_property: ParagraphProperty,
+    pub run_property: RunProperty,
+}
+
+impl TableStyleOverride {
+    pub fn new(override_type: TableStyleOverrideType) -> Self {
+        Self {
+            override_type,
+            paragraph_property: ParagraphProperty::new(),
+            run_property: RunProperty::new(),
+        }
+    }
+
+    pub fn paragraph_property(mut self, p: ParagraphProperty) -> Self {
+        self.paragraph_property = p;
+        self
+    }
+
+    pub fn run_property(mut self, p: RunProperty) -> Self {
+        self.run_property = p;
+        self
+    }
+}
+
+impl BuildXML for TableStyleOverride {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_table_style_override(&self.override_type.to_string())?
+            .add_child(&self.paragraph_property)?
+            .add_child(&self.run_property)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_table_style_override() {
+        let b = TableStyleOverride::new(TableStyleOverrideType::FirstRow)
+            .run_property(RunProperty::new().bold())
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblStylePr w:type="firstRow"><w:pPr><w:rPr /></w:pPr><w:rPr><w:b /><w:bCs /></w:rPr></w:tblStylePr>"#
+        );
+    }
+}
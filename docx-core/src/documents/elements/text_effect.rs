@@ -0,0 +1,38 @@
+use serde::{Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+/// Legacy animated text effect (`w:effect`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEffect {
+    val: TextEffectType,
+}
+
+impl TextEffect {
+    pub fn new(val: TextEffectType) -> TextEffect {
+        Self { val }
+    }
+}
+
+impl Serialize for TextEffect {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val.to_string())
+    }
+}
+
+impl BuildXML for TextEffect {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .effect(&self.val.to_string())?
+            .into_inner()
+    }
+}
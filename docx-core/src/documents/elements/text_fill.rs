@@ -0,0 +1,41 @@
+use serde::{Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+use super::EffectFill;
+
+/// Word 2010 text fill effect (`w14:textFill`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextFill {
+    pub fill: EffectFill,
+}
+
+impl TextFill {
+    pub fn new(fill: EffectFill) -> TextFill {
+        TextFill { fill }
+    }
+}
+
+impl Serialize for TextFill {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.fill.serialize(serializer)
+    }
+}
+
+impl BuildXML for TextFill {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_w14_text_fill()?
+            .w14_effect_fill(&self.fill)?
+            .close()?
+            .into_inner()
+    }
+}
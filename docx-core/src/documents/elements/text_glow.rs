@@ -0,0 +1,52 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+use super::EffectColor;
+
+/// Word 2010 glow text effect (`w14:glow`). The radius is in EMUs.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TextGlow {
+    pub radius: u64,
+    pub color: EffectColor,
+}
+
+impl TextGlow {
+    pub fn new(radius: u64, color: EffectColor) -> TextGlow {
+        TextGlow { radius, color }
+    }
+}
+
+impl BuildXML for TextGlow {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_w14_glow(self.radius)?
+            .w14_effect_color(&self.color)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_glow() {
+        let b = TextGlow::new(63500, EffectColor::scheme("accent1").alpha(60000)).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w14:glow w14:rad="63500"><w14:schemeClr w14:val="accent1"><w14:alpha w14:val="60000" /></w14:schemeClr></w14:glow>"#
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+use super::EffectFill;
+
+/// Word 2010 text outline effect (`w14:textOutline`).
+///
+/// `width` is in EMUs. `cap`, `compound`, `align`, `preset_dash` and
+/// `line_join` take the DrawingML tokens (`rnd`, `sng`, `ctr`, `solid`,
+/// `round`, ...).
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TextOutline {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cap: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compound: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill: Option<EffectFill>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset_dash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_join: Option<String>,
+}
+
+impl TextOutline {
+    pub fn new() -> TextOutline {
+        Default::default()
+    }
+
+    pub fn width(mut self, w: u64) -> Self {
+        self.width = Some(w);
+        self
+    }
+
+    pub fn cap(mut self, cap: impl Into<String>) -> Self {
+        self.cap = Some(cap.into());
+        self
+    }
+
+    pub fn compound(mut self, cmpd: impl Into<String>) -> Self {
+        self.compound = Some(cmpd.into());
+        self
+    }
+
+    pub fn align(mut self, align: impl Into<String>) -> Self {
+        self.align = Some(align.into());
+        self
+    }
+
+    pub fn fill(mut self, fill: EffectFill) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    pub fn preset_dash(mut self, dash: impl Into<String>) -> Self {
+        self.preset_dash = Some(dash.into());
+        self
+    }
+
+    pub fn line_join(mut self, join: impl Into<String>) -> Self {
+        self.line_join = Some(join.into());
+        self
+    }
+}
+
+impl BuildXML for TextOutline {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_w14_text_outline(self)?
+            .apply_opt(self.fill.as_ref(), |fill, b| b.w14_effect_fill(fill))?
+            .apply_opt(self.preset_dash.as_ref(), |dash, b| b.w14_preset_dash(dash))?
+            .apply_opt(self.line_join.as_ref(), |join, b| b.w14_line_join(join))?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::EffectColor;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_text_outline() {
+        let b = TextOutline::new()
+            .width(9525)
+            .cap("rnd")
+            .compound("sng")
+            .align("ctr")
+            .fill(EffectFill::Solid(EffectColor::srgb("FF0000")))
+            .preset_dash("solid")
+            .line_join("round")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w14:textOutline w14:w="9525" w14:cap="rnd" w14:cmpd="sng" w14:algn="ctr"><w14:solidFill><w14:srgbClr w14:val="FF0000" /></w14:solidFill><w14:prstDash w14:val="solid" /><w14:round /></w14:textOutline>"#
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Word 2010 reflection text effect (`w14:reflection`).
+///
+/// Distances are in EMUs, angles in 60000ths of a degree, and positions,
+/// opacities and scales in thousandths of a percent.
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TextReflection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blur_rad: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_alpha: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_pos: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_alpha: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_pos: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dist: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fade_dir: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sx: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sy: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kx: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ky: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align: Option<String>,
+}
+
+impl TextReflection {
+    pub fn new() -> TextReflection {
+        Default::default()
+    }
+
+    pub fn blur_rad(mut self, v: i64) -> Self {
+        self.blur_rad = Some(v);
+        self
+    }
+
+    pub fn start(mut self, alpha: i64, pos: i64) -> Self {
+        self.start_alpha = Some(alpha);
+        self.start_pos = Some(pos);
+        self
+    }
+
+    pub fn end(mut self, alpha: i64, pos: i64) -> Self {
+        self.end_alpha = Some(alpha);
+        self.end_pos = Some(pos);
+        self
+    }
+
+    pub fn dist(mut self, v: i64) -> Self {
+        self.dist = Some(v);
+        self
+    }
+
+    pub fn dir(mut self, v: i64) -> Self {
+        self.dir = Some(v);
+        self
+    }
+
+    pub fn fade_dir(mut self, v: i64) -> Self {
+        self.fade_dir = Some(v);
+        self
+    }
+
+    pub fn scale(mut self, sx: i64, sy: i64) -> Self {
+        self.sx = Some(sx);
+        self.sy = Some(sy);
+        self
+    }
+
+    pub fn skew(mut self, kx: i64, ky: i64) -> Self {
+        self.kx = Some(kx);
+        self.ky = Some(ky);
+        self
+    }
+
+    pub fn align(mut self, align: impl Into<String>) -> Self {
+        self.align = Some(align.into());
+        self
+    }
+}
+
+impl BuildXML for TextReflection {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).w14_reflection(self)?.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_reflection() {
+        let b = TextReflection::new()
+            .blur_rad(6350)
+            .start(55000, 0)
+            .end(300, 45500)
+            .scale(100000, -100000)
+            .align("bl")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w14:reflection w14:blurRad="6350" w14:stA="55000" w14:stPos="0" w14:endA="300" w14:endPos="45500" w14:sx="100000" w14:sy="-100000" w14:algn="bl" />"#
+        );
+    }
+}
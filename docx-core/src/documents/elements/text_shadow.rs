@@ -0,0 +1,117 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+use super::EffectColor;
+
+/// Word 2010 outer shadow text effect (`w14:shadow`).
+///
+/// Distances are in EMUs, angles in 60000ths of a degree and scales in
+/// thousandths of a percent.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TextShadow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blur_rad: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dist: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sx: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sy: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kx: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ky: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align: Option<String>,
+    pub color: EffectColor,
+}
+
+impl TextShadow {
+    pub fn new(color: EffectColor) -> TextShadow {
+        TextShadow {
+            blur_rad: None,
+            dist: None,
+            dir: None,
+            sx: None,
+            sy: None,
+            kx: None,
+            ky: None,
+            align: None,
+            color,
+        }
+    }
+
+    pub fn blur_rad(mut self, v: i64) -> Self {
+        self.blur_rad = Some(v);
+        self
+    }
+
+    pub fn dist(mut self, v: i64) -> Self {
+        self.dist = Some(v);
+        self
+    }
+
+    pub fn dir(mut self, v: i64) -> Self {
+        self.dir = Some(v);
+        self
+    }
+
+    pub fn scale(mut self, sx: i64, sy: i64) -> Self {
+        self.sx = Some(sx);
+        self.sy = Some(sy);
+        self
+    }
+
+    pub fn skew(mut self, kx: i64, ky: i64) -> Self {
+        self.kx = Some(kx);
+        self.ky = Some(ky);
+        self
+    }
+
+    pub fn align(mut self, align: impl Into<String>) -> Self {
+        self.align = Some(align.into());
+        self
+    }
+}
+
+impl BuildXML for TextShadow {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_w14_shadow(self)?
+            .w14_effect_color(&self.color)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_shadow() {
+        let b = TextShadow::new(EffectColor::srgb("000000").alpha(60000))
+            .blur_rad(38100)
+            .dist(19050)
+            .dir(2700000)
+            .align("tl")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w14:shadow w14:blurRad="38100" w14:dist="19050" w14:dir="2700000" w14:algn="tl"><w14:srgbClr w14:val="000000"><w14:alpha w14:val="60000" /></w14:srgbClr></w14:shadow>"#
+        );
+    }
+}
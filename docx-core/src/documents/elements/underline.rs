@@ -13,6 +13,10 @@ impl Underline {
     pub fn new(val: impl Into<String>) -> Underline {
         Underline { val: val.into() }
     }
+
+    pub(crate) fn get_val(&self) -> &str {
+        &self.val
+    }
 }
 
 impl BuildXML for Underline {
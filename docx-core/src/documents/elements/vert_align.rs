@@ -14,6 +14,10 @@ impl VertAlign {
     pub fn new(val: VertAlignType) -> VertAlign {
         Self { val }
     }
+
+    pub(crate) fn get_val(&self) -> VertAlignType {
+        self.val
+    }
 }
 
 impl Serialize for VertAlign {
@@ -14,6 +14,10 @@ impl VMerge {
     pub fn new(v: VMergeType) -> VMerge {
         VMerge { val: v }
     }
+
+    pub(crate) fn get_val(&self) -> &VMergeType {
+        &self.val
+    }
 }
 
 impl BuildXML for VMerge {
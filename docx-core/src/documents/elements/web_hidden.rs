@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// Hides the run when the document is displayed in web layout (`w:webHidden`).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct WebHidden {
+    val: bool,
+}
+
+impl WebHidden {
+    pub fn new() -> WebHidden {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> WebHidden {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for WebHidden {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for WebHidden {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for WebHidden {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).web_hidden(self.val)?.into_inner()
+    }
+}
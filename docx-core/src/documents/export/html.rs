@@ -0,0 +1,843 @@
+use std::collections::HashMap;
+
+use base64::Engine;
+
+use super::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HtmlOptions {
+    revisions: Option<RevisionView>,
+    comments: bool,
+    standalone: bool,
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        Self {
+            revisions: None,
+            comments: true,
+            standalone: true,
+        }
+    }
+}
+
+impl HtmlOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Shows one side of tracked changes instead of marking insertions with
+    /// `<ins>` and deletions with `<del>`.
+    pub fn revisions(mut self, view: RevisionView) -> Self {
+        self.revisions = Some(view);
+        self
+    }
+
+    /// Highlights commented text with `<mark>` and appends the comments.
+    pub fn comments(mut self, include: bool) -> Self {
+        self.comments = include;
+        self
+    }
+
+    /// Whether to write a page with the style sheet in its head, or only the
+    /// content of the body.
+    pub fn standalone(mut self, standalone: bool) -> Self {
+        self.standalone = standalone;
+        self
+    }
+}
+
+impl Docx {
+    /// Converts the body to HTML.
+    ///
+    /// Paragraphs, runs and tables get the class of their style, see
+    /// [`Docx::html_style_sheet`], and direct formatting becomes tags such as
+    /// `<strong>` or a `style` attribute. Numbered paragraphs become nested
+    /// lists, merged cells get `colspan` and `rowspan`, and images are
+    /// embedded as data URIs. Footnotes and comments follow the content.
+    pub fn to_html(&self, options: &HtmlOptions) -> String {
+        let mut writer = HtmlWriter {
+            docx: self,
+            options,
+            headings: self.styles.create_heading_style_map(),
+            labels: ListLabelResolver::new(&self.numberings, &self.styles),
+            footnotes: vec![],
+            comments: vec![],
+            open_comments: vec![],
+        };
+        let mut body = String::new();
+        writer.blocks(&blocks(&self.document.children), &mut body);
+
+        // Notes may refer to further notes.
+        if !writer.footnotes.is_empty() {
+            body.push_str("<section class=\"footnotes\">\n<ol>\n");
+            let mut i = 0;
+            while i < writer.footnotes.len() {
+                let mut note = String::new();
+                writer.blocks(&blocks(writer.footnotes[i]), &mut note);
+                body.push_str(&format!(
+                    "<li id=\"footnote-{n}\">\n{note}<a href=\"#footnote-ref-{n}\">↩</a></li>\n",
+                    n = i + 1
+                ));
+                i += 1;
+            }
+            body.push_str("</ol>\n</section>\n");
+        }
+
+        if !writer.comments.is_empty() {
+            body.push_str("<aside class=\"comments\">\n");
+            for start in std::mem::take(&mut writer.comments) {
+                let comment = self
+                    .comments
+                    .comments
+                    .iter()
+                    .find(|c| c.id == start.id)
+                    .unwrap_or(&start.comment);
+                let mut content = String::new();
+                writer.blocks(&blocks(&comment.children), &mut content);
+                body.push_str(&format!(
+                    "<div class=\"comment\" id=\"comment-{}\">\n\
+                     <p class=\"comment-meta\"><span class=\"comment-author\">{}</span> <time>{}</time></p>\n\
+                     {}</div>\n",
+                    start.id,
+                    escape_html(&comment.author),
+                    escape_html(&comment.date),
+                    content
+                ));
+            }
+            body.push_str("</aside>\n");
+        }
+
+        if !options.standalone {
+            return body;
+        }
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<style>\n{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+            self.html_style_sheet(),
+            body
+        )
+    }
+
+    /// The CSS for the classes [`Docx::to_html`] writes: a rule per style
+    /// with the formatting of its `basedOn` chain, and the document defaults
+    /// on `body`.
+    pub fn html_style_sheet(&self) -> String {
+        let mut css = String::from(BASE_CSS);
+        let defaults = &self.styles.doc_defaults;
+        let mut body = Declarations::default();
+        body.paragraph(defaults.get_paragraph_property());
+        body.run(defaults.get_run_property());
+        if !body.0.is_empty() {
+            css.push_str(&body.rule("body"));
+        }
+        let default_style = self.styles.default_style(StyleType::Paragraph);
+        for style in &self.styles.styles {
+            let mut declarations = Declarations::default();
+            for s in style_chain(&self.styles, style).iter().rev() {
+                declarations.paragraph(&s.paragraph_property);
+                declarations.run(&s.run_property);
+            }
+            if declarations.0.is_empty() {
+                continue;
+            }
+            let class = format!(".{}", class_name(&style.style_id));
+            let selector = if default_style.is_some_and(|d| std::ptr::eq(d, style)) {
+                format!("p, li, {class}")
+            } else {
+                class
+            };
+            css.push_str(&declarations.rule(&selector));
+        }
+        css
+    }
+}
+
+const BASE_CSS: &str = "p, h1, h2, h3, h4, h5, h6 { white-space: pre-wrap; }
+table { border-collapse: collapse; }
+td { border: 1px solid #000; padding: 0 5.4pt; vertical-align: top; }
+ins { color: #2e7d32; }
+del { color: #c62828; }
+mark.comment { background-color: #fff2a8; }
+";
+
+/// The style and the styles it is based on, nearest first.
+pub(super) fn style_chain<'a>(styles: &'a Styles, style: &'a Style) -> Vec<&'a Style> {
+    let mut chain = vec![style];
+    let mut current = style;
+    while let Some(base) = &current.based_on {
+        match styles.find_style_by_id(base.get_val()) {
+            // Guards against styles based on each other.
+            Some(s) if chain.len() < 10 && !chain.iter().any(|c| std::ptr::eq(*c, s)) => {
+                chain.push(s);
+                current = s;
+            }
+            _ => break,
+        }
+    }
+    chain
+}
+
+/// A class name for a style id, which may contain any character.
+pub(super) fn class_name(style_id: &str) -> String {
+    let name: String = style_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("s{name}")
+    } else {
+        name
+    }
+}
+
+fn points(twips: impl Into<f64>) -> String {
+    format!("{}pt", twips.into() / 20.0)
+}
+
+/// CSS properties, where a later value replaces an earlier one.
+#[derive(Debug, Default)]
+struct Declarations(Vec<(&'static str, String)>);
+
+impl Declarations {
+    fn set(&mut self, property: &'static str, value: impl Into<String>) {
+        let value = value.into();
+        match self.0.iter_mut().find(|(p, _)| *p == property) {
+            Some((_, v)) => *v = value,
+            None => self.0.push((property, value)),
+        }
+    }
+
+    fn rule(&self, selector: &str) -> String {
+        format!("{} {{ {} }}\n", selector, self.inline())
+    }
+
+    fn inline(&self) -> String {
+        self.0
+            .iter()
+            .map(|(p, v)| format!("{p}: {v};"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn paragraph(&mut self, p: &ParagraphProperty) {
+        if let Some(alignment) = &p.alignment {
+            let align = match alignment.val.as_str() {
+                "center" => "center",
+                "right" | "end" => "right",
+                "both" | "distribute" => "justify",
+                _ => "left",
+            };
+            self.set("text-align", align);
+        }
+        if let Some(indent) = &p.indent {
+            if let Some(start) = indent.start {
+                self.set("margin-left", points(start));
+            }
+            if let Some(end) = indent.end {
+                self.set("margin-right", points(end));
+            }
+            match indent.special_indent {
+                Some(SpecialIndentType::FirstLine(v)) => self.set("text-indent", points(v)),
+                Some(SpecialIndentType::Hanging(v)) => self.set("text-indent", points(-v)),
+                None => {}
+            }
+        }
+        if let Some(spacing) = &p.line_spacing {
+            if let Some(before) = spacing.get_before() {
+                self.set("margin-top", points(before));
+            }
+            if let Some(after) = spacing.get_after() {
+                self.set("margin-bottom", points(after));
+            }
+            if let Some(line) = spacing.get_line() {
+                match spacing.get_line_rule() {
+                    Some(LineSpacingType::Exact) | Some(LineSpacingType::AtLeast) => {
+                        self.set("line-height", points(line))
+                    }
+                    _ => self.set("line-height", format!("{}", line as f64 / 240.0)),
+                }
+            }
+        }
+        if let Some(shading) = &p.shading {
+            if is_color(&shading.fill) {
+                self.set("background-color", format!("#{}", shading.fill));
+            }
+        }
+    }
+
+    fn run(&mut self, r: &RunProperty) {
+        if let Some(bold) = &r.bold {
+            self.set(
+                "font-weight",
+                if bold.get_val() { "bold" } else { "normal" },
+            );
+        }
+        if let Some(italic) = &r.italic {
+            self.set(
+                "font-style",
+                if italic.get_val() { "italic" } else { "normal" },
+            );
+        }
+        let underline = r.underline.as_ref().map(|u| u.get_val() != "none");
+        let strike = match (&r.strike, &r.dstrike) {
+            (None, None) => None,
+            (s, d) => Some(s.as_ref().is_some_and(|s| s.val) || d.as_ref().is_some_and(|d| d.val)),
+        };
+        if underline.is_some() || strike.is_some() {
+            let lines: Vec<&str> = [
+                (underline == Some(true), "underline"),
+                (strike == Some(true), "line-through"),
+            ]
+            .iter()
+            .filter(|(on, _)| *on)
+            .map(|(_, line)| *line)
+            .collect();
+            let lines = if lines.is_empty() {
+                "none".to_owned()
+            } else {
+                lines.join(" ")
+            };
+            self.set("text-decoration", lines);
+        }
+        if let Some(vert_align) = &r.vert_align {
+            let align = match vert_align.get_val() {
+                VertAlignType::SuperScript => "super",
+                VertAlignType::SubScript => "sub",
+                _ => "baseline",
+            };
+            self.set("vertical-align", align);
+        }
+        if let Some(sz) = &r.sz {
+            self.set("font-size", format!("{}pt", sz.get_val() as f64 / 2.0));
+        }
+        if let Some(color) = r.color.as_ref().filter(|c| is_color(c.get_val())) {
+            self.set("color", format!("#{}", color.get_val()));
+        }
+        if let Some(shading) = r.shading.as_ref().filter(|s| is_color(&s.fill)) {
+            self.set("background-color", format!("#{}", shading.fill));
+        }
+        if let Some(highlight) = r
+            .highlight
+            .as_ref()
+            .and_then(|h| highlight_rgb(h.get_val()))
+        {
+            self.set("background-color", format!("#{highlight}"));
+        }
+        if let Some(font) = r.fonts.as_ref().and_then(|f| f.get_ascii()) {
+            self.set("font-family", format!("'{}'", font.replace('\'', "")));
+        }
+        if let Some(caps) = &r.caps {
+            self.set(
+                "text-transform",
+                if caps.get_val() { "uppercase" } else { "none" },
+            );
+        }
+        if let Some(small_caps) = &r.small_caps {
+            let variant = if small_caps.get_val() {
+                "small-caps"
+            } else {
+                "normal"
+            };
+            self.set("font-variant", variant);
+        }
+    }
+}
+
+struct OpenList {
+    tag: &'static str,
+    item_open: bool,
+}
+
+fn close_lists(lists: &mut Vec<OpenList>, depth: usize, out: &mut String) {
+    while lists.len() > depth {
+        let Some(list) = lists.pop() else {
+            break;
+        };
+        if list.item_open {
+            out.push_str("</li>\n");
+        }
+        out.push_str(&format!("</{}>\n", list.tag));
+    }
+}
+
+struct HtmlWriter<'a> {
+    docx: &'a Docx,
+    options: &'a HtmlOptions,
+    headings: HashMap<String, usize>,
+    labels: ListLabelResolver<'a>,
+    footnotes: Vec<&'a [Paragraph]>,
+    comments: Vec<&'a CommentRangeStart>,
+    /// The ids of the comments whose range is open.
+    open_comments: Vec<usize>,
+}
+
+impl<'a> HtmlWriter<'a> {
+    fn blocks(&mut self, blocks: &[Block<'a>], out: &mut String) {
+        let mut lists = vec![];
+        for block in blocks {
+            match block {
+                Block::Paragraph(paragraph) => match self.labels.resolve(paragraph) {
+                    Some(label) => self.list_item(paragraph, &label, &mut lists, out),
+                    None => {
+                        close_lists(&mut lists, 0, out);
+                        self.paragraph(paragraph, out);
+                    }
+                },
+                Block::Table(table) => {
+                    close_lists(&mut lists, 0, out);
+                    self.table(table, out);
+                }
+            }
+        }
+        close_lists(&mut lists, 0, out);
+    }
+
+    fn paragraph(&mut self, paragraph: &'a Paragraph, out: &mut String) {
+        let style = paragraph.property.style.as_ref().map(|s| s.val.as_str());
+        let heading = style
+            .and_then(|s| self.headings.get(s).copied())
+            .or_else(|| {
+                paragraph
+                    .property
+                    .outline_lvl
+                    .as_ref()
+                    .filter(|l| l.v < 9)
+                    .map(|l| l.v + 1)
+            });
+        let tag = heading.map_or("p".to_owned(), |l| format!("h{}", l.clamp(1, 6)));
+        let mut text_boxes = vec![];
+        let content = self.paragraph_content(paragraph, &mut text_boxes);
+        out.push_str(&format!(
+            "<{tag}{}>{content}</{tag}>\n",
+            paragraph_attributes(paragraph)
+        ));
+        self.text_boxes(text_boxes, out);
+    }
+
+    fn list_item(
+        &mut self,
+        paragraph: &'a Paragraph,
+        label: &ListLabel,
+        lists: &mut Vec<OpenList>,
+        out: &mut String,
+    ) {
+        let tag = if label.format == "bullet" || label.format == "none" {
+            "ul"
+        } else {
+            "ol"
+        };
+        let depth = label.level + 1;
+        close_lists(lists, depth, out);
+        let open = lists.len();
+        if let Some(list) = lists.last_mut().filter(|_| open == depth) {
+            if list.tag != tag {
+                close_lists(lists, depth - 1, out);
+            } else if list.item_open {
+                out.push_str("</li>\n");
+                list.item_open = false;
+            }
+        }
+        while lists.len() < depth {
+            // Nested lists go inside an item of their parent.
+            if let Some(parent) = lists.last_mut() {
+                if !parent.item_open {
+                    out.push_str("<li>");
+                    parent.item_open = true;
+                }
+            }
+            out.push_str(&format!("<{tag}>\n"));
+            lists.push(OpenList {
+                tag,
+                item_open: false,
+            });
+        }
+        let value = match last_number(&label.text) {
+            Some(n) if tag == "ol" => format!(" value=\"{n}\""),
+            _ => String::new(),
+        };
+        let mut text_boxes = vec![];
+        let content = self.paragraph_content(paragraph, &mut text_boxes);
+        out.push_str(&format!(
+            "<li{value}{}>{content}",
+            paragraph_attributes(paragraph)
+        ));
+        if let Some(list) = lists.last_mut() {
+            list.item_open = true;
+        }
+        self.text_boxes(text_boxes, out);
+    }
+
+    fn text_boxes(&mut self, text_boxes: Vec<&'a [TextBoxContentChild]>, out: &mut String) {
+        for children in text_boxes {
+            out.push_str("<div class=\"text-box\">\n");
+            self.blocks(&blocks(children), out);
+            out.push_str("</div>\n");
+        }
+    }
+
+    fn paragraph_content(
+        &mut self,
+        paragraph: &'a Paragraph,
+        text_boxes: &mut Vec<&'a [TextBoxContentChild]>,
+    ) -> String {
+        let options = InlineOptions {
+            revisions: self.options.revisions,
+            hidden_text: false,
+        };
+        self.inline_html(&inlines(self.docx, paragraph, options), text_boxes)
+    }
+
+    fn inline_html(
+        &mut self,
+        inlines: &[Inline<'a>],
+        text_boxes: &mut Vec<&'a [TextBoxContentChild]>,
+    ) -> String {
+        let mut out = String::new();
+        for inline in inlines {
+            match inline {
+                Inline::Text { text, run, change } => {
+                    let mut html = run_html(&escape_html(text), run);
+                    match change {
+                        Change::Inserted { author, date } => {
+                            html = format!("<ins{}>{html}</ins>", revision_attributes(author, date))
+                        }
+                        Change::Deleted { author, date } => {
+                            html = format!("<del{}>{html}</del>", revision_attributes(author, date))
+                        }
+                        Change::None => {}
+                    }
+                    if !self.open_comments.is_empty() {
+                        let ids: Vec<String> =
+                            self.open_comments.iter().map(|id| id.to_string()).collect();
+                        html = format!(
+                            "<mark class=\"comment\" data-comments=\"{}\">{html}</mark>",
+                            ids.join(" ")
+                        );
+                    }
+                    out.push_str(&html);
+                }
+                Inline::Tab => out.push('\t'),
+                Inline::Break => out.push_str("<br>"),
+                Inline::Link { link, children } => {
+                    let content = self.inline_html(children, text_boxes);
+                    let target = link_target(self.docx, link);
+                    out.push_str(&format!(
+                        "<a href=\"{}\">{}</a>",
+                        escape_html(&target),
+                        content
+                    ));
+                }
+                Inline::FootnoteReference(reference) => {
+                    self.footnotes.push(&reference.content);
+                    out.push_str(&format!(
+                        "<sup class=\"footnote-ref\"><a href=\"#footnote-{n}\" id=\"footnote-ref-{n}\">{n}</a></sup>",
+                        n = self.footnotes.len()
+                    ));
+                }
+                Inline::Picture(pic) => {
+                    if let Some(img) = self.image(pic) {
+                        out.push_str(&img);
+                    }
+                }
+                Inline::TextBox(children) => text_boxes.push(children),
+                Inline::CommentStart(start) if self.options.comments => {
+                    if !self.comments.iter().any(|c| c.id == start.id) {
+                        self.comments.push(start);
+                    }
+                    self.open_comments.push(start.id);
+                }
+                Inline::CommentEnd(id) if self.options.comments => {
+                    self.open_comments.retain(|open| open != id);
+                    if let Some(n) = self.comments.iter().position(|c| c.id == *id) {
+                        out.push_str(&format!(
+                            "<sup class=\"comment-ref\"><a href=\"#comment-{id}\">[{}]</a></sup>",
+                            n + 1
+                        ));
+                    }
+                }
+                Inline::CommentStart(_) | Inline::CommentEnd(_) => {}
+            }
+        }
+        out
+    }
+
+    /// An `<img>` with the image as a data URI, preferring the PNG preview
+    /// the reader made of formats browsers may not show.
+    fn image(&self, pic: &Pic) -> Option<String> {
+        let preview = self
+            .docx
+            .images
+            .iter()
+            .find(|(id, ..)| *id == pic.id)
+            .map(|(_, _, _, Png(png))| png)
+            .filter(|png| !png.is_empty());
+        let (mime, data) = match preview {
+            Some(png) => ("png", png),
+            None if !pic.image.is_empty() => (image_extension(&pic.image), &pic.image),
+            None => return None,
+        };
+        // EMU per pixel at 96 dpi.
+        let (width, height) = (pic.size.0 / 9525, pic.size.1 / 9525);
+        Some(format!(
+            "<img src=\"data:image/{mime};base64,{}\" width=\"{width}\" height=\"{height}\" alt=\"\">",
+            base64::engine::general_purpose::STANDARD.encode(data)
+        ))
+    }
+
+    fn table(&mut self, table: &'a Table, out: &mut String) {
+        let class = table
+            .property
+            .get_style()
+            .map(|s| format!(" class=\"{}\"", class_name(s.get_val())))
+            .unwrap_or_default();
+        out.push_str(&format!("<table{class}>\n"));
+        for row in spanned_cells(table) {
+            out.push_str("<tr>\n");
+            for SpannedCell {
+                cell,
+                colspan,
+                rowspan,
+                ..
+            } in row
+            {
+                let mut attributes = String::new();
+                if colspan > 1 {
+                    attributes.push_str(&format!(" colspan=\"{colspan}\""));
+                }
+                if rowspan > 1 {
+                    attributes.push_str(&format!(" rowspan=\"{rowspan}\""));
+                }
+                out.push_str(&format!("<td{attributes}>\n"));
+                self.blocks(&blocks(&cell.children), out);
+                out.push_str("</td>\n");
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</table>\n");
+    }
+}
+
+/// The class of the paragraph style and direct formatting as `style`.
+fn paragraph_attributes(paragraph: &Paragraph) -> String {
+    let mut attributes = String::new();
+    if let Some(style) = &paragraph.property.style {
+        attributes.push_str(&format!(" class=\"{}\"", class_name(&style.val)));
+    }
+    let mut declarations = Declarations::default();
+    declarations.paragraph(&paragraph.property);
+    if !declarations.0.is_empty() {
+        attributes.push_str(&format!(
+            " style=\"{}\"",
+            escape_html(&declarations.inline())
+        ));
+    }
+    attributes
+}
+
+/// Wraps text in tags for the direct formatting of the run and a `<span>`
+/// with the class of its character style and the remaining formatting.
+fn run_html(text: &str, run: &Run) -> String {
+    let mut declarations = Declarations::default();
+    declarations.run(&run.run_property);
+    let mut tags = vec![];
+    let mut style = Declarations::default();
+    for (property, value) in declarations.0 {
+        match (property, value.as_str()) {
+            ("font-weight", "bold") => tags.push("strong"),
+            ("font-style", "italic") => tags.push("em"),
+            ("vertical-align", "super") => tags.push("sup"),
+            ("vertical-align", "sub") => tags.push("sub"),
+            ("text-decoration", lines) if lines != "none" => {
+                if lines.contains("underline") {
+                    tags.push("u");
+                }
+                if lines.contains("line-through") {
+                    tags.push("s");
+                }
+            }
+            _ => style.set(property, value),
+        }
+    }
+    let mut html = text.to_owned();
+    for tag in tags.iter().rev() {
+        html = format!("<{tag}>{html}</{tag}>");
+    }
+    let mut attributes = String::new();
+    if let Some(s) = &run.run_property.style {
+        attributes.push_str(&format!(" class=\"{}\"", class_name(&s.val)));
+    }
+    if !style.0.is_empty() {
+        attributes.push_str(&format!(" style=\"{}\"", escape_html(&style.inline())));
+    }
+    if attributes.is_empty() {
+        html
+    } else {
+        format!("<span{attributes}>{html}</span>")
+    }
+}
+
+fn revision_attributes(author: &str, date: &str) -> String {
+    let mut attributes = format!(" data-author=\"{}\"", escape_html(author));
+    if !date.is_empty() {
+        attributes.push_str(&format!(" datetime=\"{}\"", escape_html(date)));
+    }
+    attributes
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_to_html() {
+        let docx = Docx::new()
+            .add_style(
+                Style::new("Heading1", StyleType::Paragraph)
+                    .name("Heading 1")
+                    .bold(),
+            )
+            .add_abstract_numbering(
+                AbstractNumbering::new(1)
+                    .add_level(Level::new(
+                        0,
+                        Start::new(1),
+                        NumberFormat::new("decimal"),
+                        LevelText::new("%1."),
+                        LevelJc::new("left"),
+                    ))
+                    .add_level(Level::new(
+                        1,
+                        Start::new(1),
+                        NumberFormat::new("bullet"),
+                        LevelText::new("•"),
+                        LevelJc::new("left"),
+                    )),
+            )
+            .add_numbering(Numbering::new(1, 1))
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading1")
+                    .add_run(Run::new().add_text("Title")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .align(AlignmentType::Center)
+                    .add_run(Run::new().add_text("a < b").bold().color("FF0000"))
+                    .add_insert(Insert::new(Run::new().add_text(" new")).author("Ann"))
+                    .add_delete(
+                        Delete::new()
+                            .add_run(Run::new().add_delete_text(" old"))
+                            .author("Bob"),
+                    ),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("One"))
+                    .numbering(NumberingId::new(1), IndentLevel::new(0)),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Sub"))
+                    .numbering(NumberingId::new(1), IndentLevel::new(1)),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Two"))
+                    .numbering(NumberingId::new(1), IndentLevel::new(0)),
+            )
+            .add_table(Table::new(vec![
+                TableRow::new(vec![
+                    TableCell::new()
+                        .vertical_merge(VMergeType::Restart)
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("A"))),
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("B"))),
+                ]),
+                TableRow::new(vec![
+                    TableCell::new().vertical_merge(VMergeType::Continue),
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("C"))),
+                ]),
+            ]));
+        assert_eq!(
+            docx.to_html(&HtmlOptions::new().standalone(false)),
+            "<h1 class=\"Heading1\">Title</h1>\n\
+             <p style=\"text-align: center;\"><span style=\"color: #FF0000;\"><strong>a &lt; b</strong></span>\
+             <ins data-author=\"Ann\" datetime=\"1970-01-01T00:00:00Z\"> new</ins>\
+             <del data-author=\"Bob\" datetime=\"1970-01-01T00:00:00Z\"> old</del></p>\n\
+             <ol>\n<li value=\"1\">One<ul>\n<li>Sub</li>\n</ul>\n</li>\n<li value=\"2\">Two</li>\n</ol>\n\
+             <table>\n<tr>\n<td rowspan=\"2\">\n<p>A</p>\n</td>\n<td>\n<p>B</p>\n</td>\n</tr>\n\
+             <tr>\n<td>\n<p>C</p>\n</td>\n</tr>\n</table>\n"
+        );
+        let css = docx.html_style_sheet();
+        assert!(css.contains(".Heading1 { font-weight: bold; }\n"));
+    }
+
+    #[test]
+    fn test_to_html_with_comments_and_footnotes() {
+        let comment = Comment::new(1)
+            .author("Ann")
+            .date("2024-01-01T00:00:00Z")
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Check")));
+        let docx = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_comment_start(comment)
+                .add_run(Run::new().add_text("Text"))
+                .add_comment_end(1)
+                .add_run(
+                    Run::new().add_footnote_reference(
+                        Footnote::new()
+                            .add_content(Paragraph::new().add_run(Run::new().add_text("Note"))),
+                    ),
+                ),
+        );
+        let html = docx.to_html(&HtmlOptions::new());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(
+            "<p><mark class=\"comment\" data-comments=\"1\">Text</mark>\
+             <sup class=\"comment-ref\"><a href=\"#comment-1\">[1]</a></sup>\
+             <sup class=\"footnote-ref\"><a href=\"#footnote-1\" id=\"footnote-ref-1\">1</a></sup></p>\n"
+        ));
+        assert!(html.contains(
+            "<li id=\"footnote-1\">\n<p>Note</p>\n<a href=\"#footnote-ref-1\">↩</a></li>\n"
+        ));
+        assert!(html.contains(
+            "<div class=\"comment\" id=\"comment-1\">\n\
+             <p class=\"comment-meta\"><span class=\"comment-author\">Ann</span> <time>2024-01-01T00:00:00Z</time></p>\n\
+             <p>Check</p>\n</div>\n"
+        ));
+        assert!(!docx
+            .to_html(&HtmlOptions::new().comments(false).standalone(false))
+            .contains("mark"));
+    }
+
+    #[test]
+    fn test_to_html_with_hostile_formatting() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new().add_run(
+                    Run::new()
+                        .add_text("Text")
+                        .highlight("red;\" onmouseover=\"alert(1)")
+                        .shading(Shading::new().fill("000\"><script>alert(1)</script>")),
+                ),
+            )
+            .add_paragraph(
+                Paragraph::new().add_run(Run::new().add_text("Marked").highlight("yellow")),
+            );
+        let html = docx.to_html(&HtmlOptions::new().standalone(false));
+        assert_eq!(
+            html,
+            "<p>Text</p>\n<p><span style=\"background-color: #FFFF00;\">Marked</span></p>\n"
+        );
+    }
+}
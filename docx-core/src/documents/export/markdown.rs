@@ -0,0 +1,746 @@
+use std::collections::HashMap;
+
+use super::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownOptions {
+    image_dir: String,
+    revisions: RevisionView,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        Self {
+            image_dir: "images".to_owned(),
+            revisions: RevisionView::default(),
+        }
+    }
+}
+
+impl MarkdownOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The directory image paths are relative to, `images` by default.
+    pub fn image_dir(mut self, dir: impl Into<String>) -> Self {
+        self.image_dir = dir.into();
+        self
+    }
+
+    pub fn revisions(mut self, view: RevisionView) -> Self {
+        self.revisions = view;
+        self
+    }
+}
+
+/// A document converted to Markdown, with the images it links to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Markdown {
+    pub text: String,
+    pub images: Vec<MarkdownImage>,
+}
+
+/// An image to write next to the Markdown, at `path`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownImage {
+    pub path: String,
+    pub data: Vec<u8>,
+}
+
+impl Docx {
+    /// Converts the body to GitHub flavored Markdown.
+    ///
+    /// Headings come from heading styles and outline levels, code blocks from
+    /// paragraph styles named like `Code` or `HTMLPreformatted` and inline
+    /// code from monospace fonts. Emphasis follows the formatting in effect
+    /// after applying styles; headings leave out what their style gives.
+    /// Tables with merged cells are written as HTML.
+    pub fn to_markdown(&self, options: &MarkdownOptions) -> Markdown {
+        let mut writer = MarkdownWriter {
+            docx: self,
+            options,
+            headings: self.styles.create_heading_style_map(),
+            labels: ListLabelResolver::new(&self.numberings, &self.styles),
+            footnotes: vec![],
+            images: vec![],
+            image_paths: HashMap::new(),
+        };
+        let mut chunks = vec![];
+        writer.blocks(&blocks(&self.document.children), &mut chunks);
+
+        // Notes may refer to further notes.
+        let mut i = 0;
+        while i < writer.footnotes.len() {
+            let mut note = vec![];
+            writer.blocks(&blocks(writer.footnotes[i]), &mut note);
+            let note = join_chunks(note).replace('\n', "\n    ");
+            chunks.push(Chunk::Block(format!("[^{}]: {}", i + 1, note)));
+            i += 1;
+        }
+
+        let mut text = join_chunks(chunks);
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        Markdown {
+            text,
+            images: writer.images,
+        }
+    }
+}
+
+/// A piece of Markdown output. List items and code lines are joined with
+/// their neighbours, other blocks are separated by blank lines.
+enum Chunk {
+    Block(String),
+    ListItem(String),
+    Code(String),
+}
+
+fn join_chunks(chunks: Vec<Chunk>) -> String {
+    let mut text = String::new();
+    let mut previous: Option<Chunk> = None;
+    for chunk in chunks {
+        match (&previous, &chunk) {
+            (Some(Chunk::Code(_)), Chunk::Code(_)) => text.push('\n'),
+            (Some(Chunk::Code(_)), _) => text.push_str("\n```\n\n"),
+            (Some(Chunk::ListItem(_)), Chunk::ListItem(_)) => text.push('\n'),
+            (Some(_), _) => text.push_str("\n\n"),
+            (None, _) => {}
+        }
+        match &chunk {
+            Chunk::Code(code) => {
+                if !matches!(previous, Some(Chunk::Code(_))) {
+                    text.push_str("```\n");
+                }
+                text.push_str(code);
+            }
+            Chunk::Block(content) | Chunk::ListItem(content) => text.push_str(content),
+        }
+        previous = Some(chunk);
+    }
+    if matches!(previous, Some(Chunk::Code(_))) {
+        text.push_str("\n```");
+    }
+    text
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Syntax {
+    Markdown,
+    /// Inside HTML tables, where Markdown is not interpreted.
+    Html,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Format {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+    code: bool,
+}
+
+const MONOSPACE_FONTS: [&str; 7] = [
+    "courier",
+    "courier new",
+    "consolas",
+    "menlo",
+    "monaco",
+    "lucida console",
+    "source code pro",
+];
+
+impl Format {
+    /// The formatting of run properties resolved through the styles.
+    fn of(rp: &RunProperty) -> Self {
+        let code = rp
+            .style
+            .as_ref()
+            .is_some_and(|s| s.val.to_lowercase().contains("code"))
+            || rp
+                .fonts
+                .as_ref()
+                .and_then(|f| f.get_ascii())
+                .is_some_and(|f| MONOSPACE_FONTS.contains(&f.to_lowercase().as_str()));
+        Format {
+            bold: rp.bold.as_ref().is_some_and(|b| b.get_val()),
+            italic: rp.italic.as_ref().is_some_and(|i| i.get_val()),
+            strike: rp.strike.as_ref().is_some_and(|s| s.val)
+                || rp.dstrike.as_ref().is_some_and(|s| s.val),
+            code,
+        }
+    }
+
+    /// Leaves out the formatting `base` already gives, such as the bold of a
+    /// heading style.
+    fn without(self, base: Format) -> Self {
+        Format {
+            bold: self.bold && !base.bold,
+            italic: self.italic && !base.italic,
+            strike: self.strike && !base.strike,
+            code: self.code && !base.code,
+        }
+    }
+}
+
+struct MarkdownWriter<'a> {
+    docx: &'a Docx,
+    options: &'a MarkdownOptions,
+    headings: HashMap<String, usize>,
+    labels: ListLabelResolver<'a>,
+    footnotes: Vec<&'a [Paragraph]>,
+    images: Vec<MarkdownImage>,
+    /// Image paths by picture id, so each image is written once.
+    image_paths: HashMap<String, String>,
+}
+
+impl<'a> MarkdownWriter<'a> {
+    fn inline_options(&self) -> InlineOptions {
+        InlineOptions {
+            revisions: Some(self.options.revisions),
+            hidden_text: false,
+        }
+    }
+
+    fn blocks(&mut self, blocks: &[Block<'a>], chunks: &mut Vec<Chunk>) {
+        for block in blocks {
+            match block {
+                Block::Paragraph(paragraph) => self.paragraph(paragraph, chunks),
+                Block::Table(table) => {
+                    let table = if needs_html(table) {
+                        self.html_table(table)
+                    } else {
+                        self.gfm_table(table)
+                    };
+                    chunks.push(Chunk::Block(table));
+                }
+            }
+        }
+    }
+
+    fn paragraph(&mut self, paragraph: &'a Paragraph, chunks: &mut Vec<Chunk>) {
+        let label = self.labels.resolve(paragraph);
+        let inlines = inlines(self.docx, paragraph, self.inline_options());
+        let style = paragraph.property.style.as_ref().map(|s| s.val.as_str());
+        let mut text_boxes = vec![];
+
+        if style.is_some_and(is_code_style) {
+            chunks.push(Chunk::Code(plain_text(&inlines)));
+        } else {
+            let heading = style
+                .and_then(|s| self.headings.get(s).copied())
+                .or_else(|| {
+                    paragraph
+                        .property
+                        .outline_lvl
+                        .as_ref()
+                        .filter(|l| l.v < 9)
+                        .map(|l| l.v + 1)
+                });
+            // Headings are emphasized by their style already.
+            let base = match heading {
+                Some(_) => Format::of(
+                    &self
+                        .docx
+                        .resolve_paragraph_properties(paragraph)
+                        .run_property,
+                ),
+                None => Format::default(),
+            };
+            let content =
+                self.inline_text(paragraph, base, &inlines, Syntax::Markdown, &mut text_boxes);
+            if content.trim().is_empty() {
+                // Empty paragraphs only add space.
+            } else if let Some(level) = heading {
+                let content = content.replace("\\\n", " ");
+                chunks.push(Chunk::Block(format!(
+                    "{} {}",
+                    "#".repeat(level.clamp(1, 6)),
+                    content.trim()
+                )));
+            } else if let Some(label) = label {
+                let marker = if label.format == "bullet" || label.format == "none" {
+                    "-".to_owned()
+                } else {
+                    format!("{}.", last_number(&label.text).unwrap_or(1))
+                };
+                chunks.push(Chunk::ListItem(format!(
+                    "{}{} {}",
+                    "    ".repeat(label.level),
+                    marker,
+                    content.trim_start()
+                )));
+            } else {
+                chunks.push(Chunk::Block(escape_line_start(&content)));
+            }
+        }
+
+        for children in text_boxes {
+            self.blocks(&blocks(children), chunks);
+        }
+    }
+
+    fn inline_text(
+        &mut self,
+        paragraph: &'a Paragraph,
+        base: Format,
+        inlines: &[Inline<'a>],
+        syntax: Syntax,
+        text_boxes: &mut Vec<&'a [TextBoxContentChild]>,
+    ) -> String {
+        let mut out = String::new();
+        let mut spans: Vec<(String, Format)> = vec![];
+        for inline in inlines {
+            let markup = match inline {
+                Inline::Text { text, run, .. } => {
+                    let rp = self.docx.resolve_run_properties(paragraph, run);
+                    let format = Format::of(&rp).without(base);
+                    match spans.last_mut() {
+                        Some((last, f)) if *f == format => last.push_str(text),
+                        _ => spans.push((text.clone(), format)),
+                    }
+                    continue;
+                }
+                Inline::Tab => {
+                    spans.push(("\t".to_owned(), Format::default()));
+                    continue;
+                }
+                Inline::Break => match syntax {
+                    Syntax::Markdown => "\\\n".to_owned(),
+                    Syntax::Html => "<br>".to_owned(),
+                },
+                Inline::Link { link, children } => {
+                    let text = self.inline_text(paragraph, base, children, syntax, text_boxes);
+                    let target = link_target(self.docx, link);
+                    match syntax {
+                        Syntax::Markdown if target.contains([' ', '(', ')']) => {
+                            format!("[{text}](<{target}>)")
+                        }
+                        Syntax::Markdown => format!("[{text}]({target})"),
+                        Syntax::Html => {
+                            format!("<a href=\"{}\">{}</a>", escape_html(&target), text)
+                        }
+                    }
+                }
+                Inline::FootnoteReference(reference) => {
+                    self.footnotes.push(&reference.content);
+                    match syntax {
+                        Syntax::Markdown => format!("[^{}]", self.footnotes.len()),
+                        Syntax::Html => format!("<sup>{}</sup>", self.footnotes.len()),
+                    }
+                }
+                Inline::Picture(pic) => match self.image(pic) {
+                    Some(path) => match syntax {
+                        Syntax::Markdown => format!("![]({path})"),
+                        Syntax::Html => format!("<img src=\"{}\">", escape_html(&path)),
+                    },
+                    None => continue,
+                },
+                Inline::TextBox(children) => {
+                    text_boxes.push(children);
+                    continue;
+                }
+                Inline::CommentStart(_) | Inline::CommentEnd(_) => continue,
+            };
+            push_spans(&mut out, std::mem::take(&mut spans), syntax);
+            out.push_str(&markup);
+        }
+        push_spans(&mut out, spans, syntax);
+        out
+    }
+
+    /// Returns the path of the picture's image, adding the image to the
+    /// output the first time.
+    fn image(&mut self, pic: &Pic) -> Option<String> {
+        if let Some(path) = self.image_paths.get(&pic.id) {
+            return Some(path.clone());
+        }
+        let media = self.docx.images.iter().find(|(id, ..)| *id == pic.id);
+        let (name, data) = match media {
+            _ if !pic.image.is_empty() => (
+                format!("{}.{}", pic.id, image_extension(&pic.image)),
+                pic.image.clone(),
+            ),
+            Some((_, path, Image(data), _)) => (
+                path.rsplit('/').next().unwrap_or(path).to_owned(),
+                data.clone(),
+            ),
+            None => return None,
+        };
+        let path = if self.options.image_dir.is_empty() {
+            name
+        } else {
+            format!("{}/{}", self.options.image_dir.trim_end_matches('/'), name)
+        };
+        self.image_paths.insert(pic.id.clone(), path.clone());
+        self.images.push(MarkdownImage {
+            path: path.clone(),
+            data,
+        });
+        Some(path)
+    }
+
+    /// The content of a cell on one line.
+    fn cell_text(&mut self, cell: &'a TableCell, syntax: Syntax) -> String {
+        let mut lines = vec![];
+        let mut text_boxes = vec![];
+        for block in blocks(&cell.children) {
+            if let Block::Paragraph(paragraph) = block {
+                self.labels.resolve(paragraph);
+                let inlines = inlines(self.docx, paragraph, self.inline_options());
+                let text = self.inline_text(
+                    paragraph,
+                    Format::default(),
+                    &inlines,
+                    syntax,
+                    &mut text_boxes,
+                );
+                lines.push(text.replace("\\\n", "<br>"));
+            }
+        }
+        lines.join("<br>")
+    }
+
+    fn gfm_table(&mut self, table: &'a Table) -> String {
+        let mut rows: Vec<Vec<String>> = vec![];
+        for TableChild::TableRow(row) in &table.rows {
+            let mut cells = vec![];
+            for TableRowChild::TableCell(cell) in &row.cells {
+                let text = self.cell_text(cell, Syntax::Markdown);
+                cells.push(text.replace('|', "\\|").replace('\t', " "));
+            }
+            rows.push(cells);
+        }
+        let columns = rows.iter().map(|r| r.len()).max().unwrap_or(0).max(1);
+        let line = |cells: &[String]| {
+            let mut line = String::from("|");
+            for i in 0..columns {
+                line.push_str(&format!(" {} |", cells.get(i).map_or("", |c| c.trim())));
+            }
+            line
+        };
+        let mut lines = vec![];
+        let mut rows = rows.into_iter();
+        lines.push(line(&rows.next().unwrap_or_default()));
+        lines.push(format!("|{}", " --- |".repeat(columns)));
+        lines.extend(rows.map(|r| line(&r)));
+        lines.join("\n")
+    }
+
+    fn html_table(&mut self, table: &'a Table) -> String {
+        let mut html = String::from("<table>\n");
+        for row in spanned_cells(table) {
+            html.push_str("<tr>");
+            for SpannedCell {
+                cell,
+                colspan,
+                rowspan,
+                ..
+            } in row
+            {
+                let mut attributes = String::new();
+                if colspan > 1 {
+                    attributes.push_str(&format!(" colspan=\"{colspan}\""));
+                }
+                if rowspan > 1 {
+                    attributes.push_str(&format!(" rowspan=\"{rowspan}\""));
+                }
+                let text = self.cell_text(cell, Syntax::Html);
+                html.push_str(&format!("<td{attributes}>{text}</td>"));
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</table>");
+        html
+    }
+}
+
+fn needs_html(table: &Table) -> bool {
+    table.rows.iter().any(|TableChild::TableRow(row)| {
+        row.cells.iter().any(|TableRowChild::TableCell(cell)| {
+            cell.property.get_grid_span() > 1
+                || cell.property.get_vertical_merge().is_some()
+                || blocks(&cell.children)
+                    .iter()
+                    .any(|b| matches!(b, Block::Table(_)))
+        })
+    })
+}
+
+fn is_code_style(style: &str) -> bool {
+    let style = style.to_lowercase();
+    style.contains("code") || style.contains("preformatted")
+}
+
+fn plain_text(inlines: &[Inline]) -> String {
+    let mut text = String::new();
+    for inline in inlines {
+        match inline {
+            Inline::Text { text: t, .. } => text.push_str(t),
+            Inline::Tab => text.push('\t'),
+            Inline::Break => text.push('\n'),
+            Inline::Link { children, .. } => text.push_str(&plain_text(children)),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Writes runs of text, putting formatting markers around the text but not
+/// around its leading and trailing spaces, which Markdown would not accept.
+fn push_spans(out: &mut String, spans: Vec<(String, Format)>, syntax: Syntax) {
+    for (text, format) in spans {
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            out.push_str(&text);
+            continue;
+        }
+        let start = text.len() - text.trim_start().len();
+        let end = start + trimmed.len();
+        out.push_str(&text[..start]);
+        match syntax {
+            Syntax::Markdown if format.code => {
+                let fence = if trimmed.contains('`') { "``" } else { "`" };
+                out.push_str(&format!("{fence}{trimmed}{fence}"));
+            }
+            Syntax::Markdown => {
+                let markers = [
+                    (format.bold, "**"),
+                    (format.italic, "*"),
+                    (format.strike, "~~"),
+                ];
+                let open: String = markers.iter().filter(|m| m.0).map(|m| m.1).collect();
+                let close: String = markers.iter().rev().filter(|m| m.0).map(|m| m.1).collect();
+                out.push_str(&format!("{open}{}{close}", escape_markdown(trimmed)));
+            }
+            Syntax::Html => {
+                let tags = [
+                    (format.bold, "strong"),
+                    (format.italic, "em"),
+                    (format.strike, "del"),
+                    (format.code, "code"),
+                ];
+                for (_, tag) in tags.iter().filter(|t| t.0) {
+                    out.push_str(&format!("<{tag}>"));
+                }
+                out.push_str(&escape_html(trimmed));
+                for (_, tag) in tags.iter().rev().filter(|t| t.0) {
+                    out.push_str(&format!("</{tag}>"));
+                }
+            }
+        }
+        out.push_str(&text[end..]);
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '~') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Escapes text at the start of a paragraph that Markdown would read as a
+/// heading, quote or list.
+fn escape_line_start(text: &str) -> String {
+    let digits = text.len() - text.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let rest = &text[digits..];
+    if digits > 0 && (rest.starts_with(". ") || rest.starts_with(") ")) {
+        format!("{}\\{}", &text[..digits], rest)
+    } else if text.starts_with(['#', '-', '+', '=']) {
+        format!("\\{text}")
+    } else {
+        text.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_to_markdown() {
+        let docx = Docx::new()
+            .add_style(Style::new("Heading1", StyleType::Paragraph).name("Heading 1"))
+            .add_style(Style::new("Code", StyleType::Paragraph).name("Code"))
+            .add_abstract_numbering(AbstractNumbering::new(1).add_level(Level::new(
+                0,
+                Start::new(1),
+                NumberFormat::new("bullet"),
+                LevelText::new("•"),
+                LevelJc::new("left"),
+            )))
+            .add_numbering(Numbering::new(1, 1))
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading1")
+                    .add_run(Run::new().add_text("Title")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Some "))
+                    .add_run(Run::new().add_text("bold ").bold())
+                    .add_run(Run::new().add_text("and *stars*").italic())
+                    .add_hyperlink(
+                        Hyperlink::new("https://example.com", HyperlinkType::External)
+                            .add_run(Run::new().add_text(" link")),
+                    ),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Item"))
+                    .numbering(NumberingId::new(1), IndentLevel::new(0)),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(
+                        Run::new()
+                            .add_text("Next")
+                            .add_footnote_reference(Footnote::new().add_content(
+                                Paragraph::new().add_run(Run::new().add_text("Note")),
+                            )),
+                    )
+                    .numbering(NumberingId::new(1), IndentLevel::new(0)),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Code")
+                    .add_run(Run::new().add_text("let a = 1;")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Code")
+                    .add_run(Run::new().add_text("let b = 2;")),
+            )
+            .add_table(Table::new(vec![
+                TableRow::new(vec![
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("a|b"))),
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("c"))),
+                ]),
+                TableRow::new(vec![TableCell::new()
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text("1")))]),
+            ]));
+        assert_eq!(
+            docx.to_markdown(&MarkdownOptions::new()).text,
+            "# Title\n\n\
+             Some **bold** *and \\*stars\\**[ link](https://example.com)\n\n\
+             - Item\n\
+             - Next[^1]\n\n\
+             ```\nlet a = 1;\nlet b = 2;\n```\n\n\
+             | a\\|b | c |\n| --- | --- |\n| 1 |  |\n\n\
+             [^1]: Note\n"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_with_styled_runs() {
+        let docx = Docx::new()
+            .add_style(
+                Style::new("Heading1", StyleType::Paragraph)
+                    .name("Heading 1")
+                    .bold(),
+            )
+            .add_style(Style::new("Strong", StyleType::Paragraph).bold())
+            .add_style(Style::new("Emphasis", StyleType::Character).italic())
+            .add_style(
+                Style::new("Mono", StyleType::Character).fonts(RunFonts::new().ascii("Consolas")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading1")
+                    .add_run(Run::new().add_text("Title ").style("Emphasis"))
+                    .add_run(Run::new().add_text("plain")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("stressed").style("Emphasis"))
+                    .add_run(Run::new().add_text(" and "))
+                    .add_run(Run::new().add_text("x").style("Mono")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Strong")
+                    .add_run(Run::new().add_text("Bold paragraph")),
+            );
+        assert_eq!(
+            docx.to_markdown(&MarkdownOptions::new()).text,
+            "# *Title* plain\n\n*stressed* and `x`\n\n**Bold paragraph**\n"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_with_cjk_labels() {
+        let docx = Docx::new()
+            .add_abstract_numbering(AbstractNumbering::new(1).add_level(Level::new(
+                0,
+                Start::new(1),
+                NumberFormat::new("decimal"),
+                LevelText::new("第%1条"),
+                LevelJc::new("left"),
+            )))
+            .add_numbering(Numbering::new(1, 1))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("総則"))
+                    .numbering(NumberingId::new(1), IndentLevel::new(0)),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("定義"))
+                    .numbering(NumberingId::new(1), IndentLevel::new(0)),
+            );
+        assert_eq!(
+            docx.to_markdown(&MarkdownOptions::new()).text,
+            "1. 総則\n2. 定義\n"
+        );
+        assert!(docx
+            .to_html(&HtmlOptions::new())
+            .contains("<li value=\"2\">定義</li>"));
+        assert!(docx.pack_odt(std::io::Cursor::new(vec![])).is_ok());
+    }
+
+    #[test]
+    fn test_to_markdown_with_merged_cells_and_images() {
+        let pic = Pic::new_with_dimensions(vec![0x89, b'P', b'N', b'G'], 1, 1);
+        let id = pic.id.clone();
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_image(pic)))
+            .add_table(Table::new(vec![
+                TableRow::new(vec![TableCell::new().grid_span(2).add_paragraph(
+                    Paragraph::new().add_run(Run::new().add_text("a & b").bold()),
+                )]),
+                TableRow::new(vec![
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("c"))),
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("d"))),
+                ]),
+            ]));
+        let markdown = docx.to_markdown(&MarkdownOptions::new().image_dir("media"));
+        assert_eq!(
+            markdown.text,
+            format!(
+                "![](media/{id}.png)\n\n<table>\n\
+                 <tr><td colspan=\"2\"><strong>a &amp; b</strong></td></tr>\n\
+                 <tr><td>c</td><td>d</td></tr>\n</table>\n"
+            )
+        );
+        assert_eq!(
+            markdown.images,
+            vec![MarkdownImage {
+                path: format!("media/{id}.png"),
+                data: vec![0x89, b'P', b'N', b'G'],
+            }]
+        );
+    }
+}
@@ -0,0 +1,624 @@
+//! Renders documents into other formats.
+//!
+//! Every exporter walks the same reading order: containers such as sections,
+//! content controls and tables of contents are flattened into paragraphs and
+//! tables, and paragraph content is reduced to the text a reader sees, with
+//! tracked changes and hidden text resolved as the options ask.
+
+mod html;
+mod markdown;
+mod odt;
+mod rtf;
+mod symbols;
+mod text;
+
+pub use html::*;
+pub use markdown::*;
+pub(crate) use odt::*;
+pub use text::*;
+
+use crate::documents::*;
+use crate::escape::replace_escaped;
+use crate::types::*;
+
+/// Which side of tracked changes to show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RevisionView {
+    /// The document with all changes accepted.
+    #[default]
+    Final,
+    /// The document with all changes rejected.
+    Original,
+}
+
+/// A paragraph or table in reading order.
+pub(crate) enum Block<'a> {
+    Paragraph(&'a Paragraph),
+    Table(&'a Table),
+}
+
+/// Content of a container such as the body, a table cell or a header.
+pub(crate) trait BlockContent {
+    fn push_blocks<'a>(&'a self, blocks: &mut Vec<Block<'a>>);
+}
+
+pub(crate) fn blocks<C: BlockContent>(children: &[C]) -> Vec<Block<'_>> {
+    let mut blocks = vec![];
+    for child in children {
+        child.push_blocks(&mut blocks);
+    }
+    blocks
+}
+
+impl BlockContent for DocumentChild {
+    fn push_blocks<'a>(&'a self, blocks: &mut Vec<Block<'a>>) {
+        match self {
+            DocumentChild::Paragraph(p) => blocks.push(Block::Paragraph(p)),
+            DocumentChild::Table(t) => blocks.push(Block::Table(t)),
+            DocumentChild::StructuredDataTag(tag) => tag.push_blocks(blocks),
+            DocumentChild::TableOfContents(toc) => toc.push_blocks(blocks),
+            DocumentChild::Section(section) => {
+                for child in &section.children {
+                    child.push_blocks(blocks);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl BlockContent for SectionChild {
+    fn push_blocks<'a>(&'a self, blocks: &mut Vec<Block<'a>>) {
+        match self {
+            SectionChild::Paragraph(p) => blocks.push(Block::Paragraph(p)),
+            SectionChild::Table(t) => blocks.push(Block::Table(t)),
+            SectionChild::StructuredDataTag(tag) => tag.push_blocks(blocks),
+            SectionChild::TableOfContents(toc) => toc.push_blocks(blocks),
+            _ => {}
+        }
+    }
+}
+
+impl BlockContent for TableCellContent {
+    fn push_blocks<'a>(&'a self, blocks: &mut Vec<Block<'a>>) {
+        match self {
+            TableCellContent::Paragraph(p) => blocks.push(Block::Paragraph(p)),
+            TableCellContent::Table(t) => blocks.push(Block::Table(t)),
+            TableCellContent::StructuredDataTag(tag) => tag.push_blocks(blocks),
+            TableCellContent::TableOfContents(toc) => toc.push_blocks(blocks),
+        }
+    }
+}
+
+impl BlockContent for HeaderChild {
+    fn push_blocks<'a>(&'a self, blocks: &mut Vec<Block<'a>>) {
+        match self {
+            HeaderChild::Paragraph(p) => blocks.push(Block::Paragraph(p)),
+            HeaderChild::Table(t) => blocks.push(Block::Table(t)),
+            HeaderChild::StructuredDataTag(tag) => tag.push_blocks(blocks),
+        }
+    }
+}
+
+impl BlockContent for FooterChild {
+    fn push_blocks<'a>(&'a self, blocks: &mut Vec<Block<'a>>) {
+        match self {
+            FooterChild::Paragraph(p) => blocks.push(Block::Paragraph(p)),
+            FooterChild::Table(t) => blocks.push(Block::Table(t)),
+            FooterChild::StructuredDataTag(tag) => tag.push_blocks(blocks),
+        }
+    }
+}
+
+impl BlockContent for TextBoxContentChild {
+    fn push_blocks<'a>(&'a self, blocks: &mut Vec<Block<'a>>) {
+        match self {
+            TextBoxContentChild::Paragraph(p) => blocks.push(Block::Paragraph(p)),
+            TextBoxContentChild::Table(t) => blocks.push(Block::Table(t)),
+        }
+    }
+}
+
+impl BlockContent for CommentChild {
+    fn push_blocks<'a>(&'a self, blocks: &mut Vec<Block<'a>>) {
+        match self {
+            CommentChild::Paragraph(p) => blocks.push(Block::Paragraph(p)),
+            CommentChild::Table(t) => blocks.push(Block::Table(t)),
+        }
+    }
+}
+
+impl BlockContent for Paragraph {
+    fn push_blocks<'a>(&'a self, blocks: &mut Vec<Block<'a>>) {
+        blocks.push(Block::Paragraph(self));
+    }
+}
+
+impl BlockContent for StructuredDataTag {
+    fn push_blocks<'a>(&'a self, blocks: &mut Vec<Block<'a>>) {
+        for child in &self.children {
+            match child {
+                StructuredDataTagChild::Paragraph(p) => blocks.push(Block::Paragraph(p)),
+                StructuredDataTagChild::Table(t) => blocks.push(Block::Table(t)),
+                StructuredDataTagChild::StructuredDataTag(tag) => tag.push_blocks(blocks),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl BlockContent for TableOfContents {
+    fn push_blocks<'a>(&'a self, blocks: &mut Vec<Block<'a>>) {
+        for content in self.before_contents.iter().chain(&self.after_contents) {
+            match content {
+                TocContent::Paragraph(p) => blocks.push(Block::Paragraph(p)),
+                TocContent::Table(t) => blocks.push(Block::Table(t)),
+            }
+        }
+    }
+}
+
+/// The body content of one section and its properties.
+pub(crate) struct BodySection<'a> {
+    pub property: &'a SectionProperty,
+    pub blocks: Vec<Block<'a>>,
+}
+
+/// Splits the body at the sections a `w:sectPr` ends, the last one by the
+/// properties of the body itself.
+pub(crate) fn sections(document: &Document) -> Vec<BodySection<'_>> {
+    let mut sections = vec![];
+    let mut blocks = vec![];
+    for child in &document.children {
+        match child {
+            DocumentChild::Section(section) => {
+                for child in &section.children {
+                    child.push_blocks(&mut blocks);
+                }
+                sections.push(BodySection {
+                    property: &section.property,
+                    blocks: std::mem::take(&mut blocks),
+                });
+            }
+            DocumentChild::Paragraph(paragraph) => {
+                blocks.push(Block::Paragraph(paragraph));
+                if let Some(property) = &paragraph.property.section_property {
+                    sections.push(BodySection {
+                        property,
+                        blocks: std::mem::take(&mut blocks),
+                    });
+                }
+            }
+            _ => child.push_blocks(&mut blocks),
+        }
+    }
+    if !blocks.is_empty() || sections.is_empty() {
+        sections.push(BodySection {
+            property: &document.section_property,
+            blocks,
+        });
+    }
+    sections
+}
+
+/// The headers and footers of a section, where Word takes the ones a
+/// section leaves out from the section before it.
+#[derive(Default, Clone)]
+pub(crate) struct HeadersAndFooters<'a> {
+    pub header: Option<&'a Header>,
+    pub first_header: Option<&'a Header>,
+    pub even_header: Option<&'a Header>,
+    pub footer: Option<&'a Footer>,
+    pub first_footer: Option<&'a Footer>,
+    pub even_footer: Option<&'a Footer>,
+    pub title_pg: bool,
+}
+
+impl<'a> HeadersAndFooters<'a> {
+    pub(crate) fn update(self, property: &'a SectionProperty) -> Self {
+        Self {
+            header: property.header.as_ref().map(|(_, h)| h).or(self.header),
+            first_header: property
+                .first_header
+                .as_ref()
+                .map(|(_, h)| h)
+                .or(self.first_header),
+            even_header: property
+                .even_header
+                .as_ref()
+                .map(|(_, h)| h)
+                .or(self.even_header),
+            footer: property.footer.as_ref().map(|(_, f)| f).or(self.footer),
+            first_footer: property
+                .first_footer
+                .as_ref()
+                .map(|(_, f)| f)
+                .or(self.first_footer),
+            even_footer: property
+                .even_footer
+                .as_ref()
+                .map(|(_, f)| f)
+                .or(self.even_footer),
+            title_pg: property.title_pg,
+        }
+    }
+
+    pub(crate) fn has_header(&self) -> bool {
+        self.header.is_some() || self.first_header.is_some() || self.even_header.is_some()
+    }
+
+    pub(crate) fn has_footer(&self) -> bool {
+        self.footer.is_some() || self.first_footer.is_some() || self.even_footer.is_some()
+    }
+}
+
+/// Whether text was inserted or deleted with change tracking on, and by
+/// whom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Change<'a> {
+    None,
+    Inserted { author: &'a str, date: &'a str },
+    Deleted { author: &'a str, date: &'a str },
+}
+
+/// Paragraph content as a reader sees it.
+pub(crate) enum Inline<'a> {
+    Text {
+        text: String,
+        run: &'a Run,
+        change: Change<'a>,
+    },
+    Tab,
+    Break,
+    Link {
+        link: &'a Hyperlink,
+        children: Vec<Inline<'a>>,
+    },
+    FootnoteReference(&'a FootnoteReference),
+    Picture(&'a Pic),
+    TextBox(&'a [TextBoxContentChild]),
+    CommentStart(&'a CommentRangeStart),
+    CommentEnd(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct InlineOptions {
+    /// The side of tracked changes to keep, or `None` for both.
+    pub revisions: Option<RevisionView>,
+    pub hidden_text: bool,
+}
+
+/// The content of `paragraph`. Runs are hidden by `w:vanish` in effect
+/// after applying styles, not just by their direct formatting.
+pub(crate) fn inlines<'a>(
+    docx: &'a Docx,
+    paragraph: &'a Paragraph,
+    options: InlineOptions,
+) -> Vec<Inline<'a>> {
+    let mut inlines = vec![];
+    let mut collector = Collector {
+        docx,
+        paragraph,
+        options,
+        inlines: &mut inlines,
+    };
+    collector.paragraph_children(&paragraph.children);
+    inlines
+}
+
+struct Collector<'a, 'o> {
+    docx: &'a Docx,
+    paragraph: &'a Paragraph,
+    options: InlineOptions,
+    inlines: &'o mut Vec<Inline<'a>>,
+}
+
+impl<'a> Collector<'a, '_> {
+    fn paragraph_children(&mut self, children: &'a [ParagraphChild]) {
+        for child in children {
+            match child {
+                ParagraphChild::Run(run) => self.run(run, Change::None),
+                ParagraphChild::Insert(insert) => {
+                    let change = Change::Inserted {
+                        author: &insert.author,
+                        date: &insert.date,
+                    };
+                    for child in &insert.children {
+                        match child {
+                            InsertChild::Run(run) => self.run(run, change),
+                            InsertChild::Delete(delete) => self.delete(delete),
+                            InsertChild::CommentStart(start) => {
+                                self.inlines.push(Inline::CommentStart(start))
+                            }
+                            InsertChild::CommentEnd(end) => {
+                                self.inlines.push(Inline::CommentEnd(end.get_id()))
+                            }
+                        }
+                    }
+                }
+                ParagraphChild::Delete(delete) => self.delete(delete),
+                ParagraphChild::MoveFrom(moved) => {
+                    let change = Change::Deleted {
+                        author: &moved.author,
+                        date: &moved.date,
+                    };
+                    for child in &moved.children {
+                        if let MoveFromChild::Run(run) = child {
+                            self.run(run, change);
+                        }
+                    }
+                }
+                ParagraphChild::MoveTo(moved) => {
+                    let change = Change::Inserted {
+                        author: &moved.author,
+                        date: &moved.date,
+                    };
+                    for child in &moved.children {
+                        match child {
+                            MoveToChild::Run(run) => self.run(run, change),
+                            MoveToChild::Delete(delete) => self.delete(delete),
+                            _ => {}
+                        }
+                    }
+                }
+                ParagraphChild::Hyperlink(link) => {
+                    let mut children = vec![];
+                    Collector {
+                        docx: self.docx,
+                        paragraph: self.paragraph,
+                        options: self.options,
+                        inlines: &mut children,
+                    }
+                    .paragraph_children(&link.children);
+                    if !children.is_empty() {
+                        self.inlines.push(Inline::Link { link, children });
+                    }
+                }
+                ParagraphChild::StructuredDataTag(tag) => self.tag(tag),
+                ParagraphChild::CommentStart(start) => {
+                    self.inlines.push(Inline::CommentStart(start))
+                }
+                ParagraphChild::CommentEnd(end) => {
+                    self.inlines.push(Inline::CommentEnd(end.get_id()))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn tag(&mut self, tag: &'a StructuredDataTag) {
+        for child in &tag.children {
+            match child {
+                StructuredDataTagChild::Run(run) => self.run(run, Change::None),
+                StructuredDataTagChild::StructuredDataTag(tag) => self.tag(tag),
+                _ => {}
+            }
+        }
+    }
+
+    fn delete(&mut self, delete: &'a Delete) {
+        let change = Change::Deleted {
+            author: &delete.author,
+            date: &delete.date,
+        };
+        for child in &delete.children {
+            if let DeleteChild::Run(run) = child {
+                self.run(run, change);
+            }
+        }
+    }
+
+    fn run(&mut self, run: &'a Run, change: Change<'a>) {
+        let hidden = matches!(
+            (change, self.options.revisions),
+            (Change::Inserted { .. }, Some(RevisionView::Original))
+                | (Change::Deleted { .. }, Some(RevisionView::Final))
+        );
+        if hidden || (!self.options.hidden_text && self.is_vanished(run)) {
+            return;
+        }
+        for child in &run.children {
+            let text = match child {
+                RunChild::Text(t) => replace_escaped(&t.text),
+                RunChild::DeleteText(t) => replace_escaped(t.text()),
+                RunChild::Sym(sym) => match symbols::symbol_char(&sym.font, &sym.char) {
+                    Some(c) => c.to_string(),
+                    None => continue,
+                },
+                RunChild::Tab(_) | RunChild::PTab(_) => {
+                    self.inlines.push(Inline::Tab);
+                    continue;
+                }
+                RunChild::Break(_) | RunChild::CarriageReturn(_) => {
+                    self.inlines.push(Inline::Break);
+                    continue;
+                }
+                RunChild::FootnoteReference(reference) => {
+                    self.inlines.push(Inline::FootnoteReference(reference));
+                    continue;
+                }
+                RunChild::Drawing(drawing) => {
+                    match &drawing.data {
+                        Some(DrawingData::Pic(pic)) => self.inlines.push(Inline::Picture(pic)),
+                        Some(DrawingData::TextBox(text_box)) => {
+                            self.inlines.push(Inline::TextBox(&text_box.children))
+                        }
+                        None => {}
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            // Neighbouring text of one run is kept together.
+            if let Some(Inline::Text {
+                text: last, run: r, ..
+            }) = self.inlines.last_mut()
+            {
+                if std::ptr::eq(*r, run) {
+                    last.push_str(&text);
+                    continue;
+                }
+            }
+            self.inlines.push(Inline::Text { text, run, change });
+        }
+    }
+
+    fn is_vanished(&self, run: &Run) -> bool {
+        self.docx
+            .resolve_run_properties(self.paragraph, run)
+            .vanish
+            .is_some()
+    }
+}
+
+/// The cells of a table that are not covered by merges, with the number of
+/// grid columns and rows each one spans.
+pub(crate) struct SpannedCell<'a> {
+    pub cell: &'a TableCell,
+    /// The grid column the cell starts at.
+    pub column: usize,
+    pub colspan: usize,
+    pub rowspan: usize,
+}
+
+pub(crate) fn spanned_cells(table: &Table) -> Vec<Vec<SpannedCell<'_>>> {
+    // The grid column each cell starts at, to follow vertical merges.
+    let grid: Vec<Vec<(usize, &TableCell)>> = table
+        .rows
+        .iter()
+        .map(|TableChild::TableRow(row)| {
+            let mut column = 0;
+            row.cells
+                .iter()
+                .map(|TableRowChild::TableCell(cell)| {
+                    let start = column;
+                    column += cell.property.get_grid_span();
+                    (start, cell)
+                })
+                .collect()
+        })
+        .collect();
+    grid.iter()
+        .enumerate()
+        .map(|(r, row)| {
+            row.iter()
+                .filter_map(|(column, cell)| {
+                    let merge = cell.property.get_vertical_merge();
+                    if merge == Some(&VMergeType::Continue) {
+                        return None;
+                    }
+                    let rowspan = if merge == Some(&VMergeType::Restart) {
+                        1 + grid[r + 1..]
+                            .iter()
+                            .take_while(|row| {
+                                row.iter().any(|(c, cell)| {
+                                    c == column
+                                        && cell.property.get_vertical_merge()
+                                            == Some(&VMergeType::Continue)
+                                })
+                            })
+                            .count()
+                    } else {
+                        1
+                    };
+                    Some(SpannedCell {
+                        cell,
+                        column: *column,
+                        colspan: cell.property.get_grid_span(),
+                        rowspan,
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The address a hyperlink points to.
+pub(crate) fn link_target(docx: &Docx, link: &Hyperlink) -> String {
+    match &link.link {
+        HyperlinkData::Anchor { anchor } => format!("#{anchor}"),
+        HyperlinkData::External { path, .. } if !path.is_empty() => path.clone(),
+        HyperlinkData::External { rid, .. } => docx
+            .hyperlinks
+            .iter()
+            .find(|(id, ..)| id == rid)
+            .map(|(_, path, _)| path.clone())
+            .unwrap_or_default(),
+    }
+}
+
+/// The number at the end of a list label, e.g. `3` of `2.3.`.
+pub(crate) fn last_number(label: &str) -> Option<usize> {
+    let end = label.rfind(|c: char| c.is_ascii_digit())? + 1;
+    let start = label[..end]
+        .char_indices()
+        .rfind(|(_, c)| !c.is_ascii_digit())
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    label[start..end].parse().ok()
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub(crate) fn image_extension(data: &[u8]) -> &'static str {
+    if data.starts_with(&[0x89, b'P', b'N', b'G']) {
+        "png"
+    } else if data.starts_with(&[0xFF, 0xD8]) {
+        "jpeg"
+    } else if data.starts_with(b"GIF8") {
+        "gif"
+    } else if data.starts_with(b"BM") {
+        "bmp"
+    } else {
+        "bin"
+    }
+}
+
+pub(crate) fn is_color(value: &str) -> bool {
+    value.len() == 6 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// The hex RGB of a `w:highlight` value.
+pub(crate) fn highlight_rgb(value: &str) -> Option<&'static str> {
+    let color = match value {
+        "black" => "000000",
+        "blue" => "0000FF",
+        "cyan" => "00FFFF",
+        "green" => "00FF00",
+        "magenta" => "FF00FF",
+        "red" => "FF0000",
+        "yellow" => "FFFF00",
+        "white" => "FFFFFF",
+        "darkBlue" => "000080",
+        "darkCyan" => "008080",
+        "darkGreen" => "008000",
+        "darkMagenta" => "800080",
+        "darkRed" => "800000",
+        "darkYellow" => "808000",
+        "darkGray" => "808080",
+        "lightGray" => "C0C0C0",
+        _ => return None,
+    };
+    Some(color)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_last_number() {
+        assert_eq!(last_number("2.3."), Some(3));
+        assert_eq!(last_number("12"), Some(12));
+        assert_eq!(last_number("第1条"), Some(1));
+        assert_eq!(last_number("（十2）"), Some(2));
+        assert_eq!(last_number("•"), None);
+    }
+}
@@ -0,0 +1,1285 @@
+use std::collections::{BTreeSet, HashMap};
+use std::io::{Seek, Write};
+
+use super::html::class_name;
+use super::*;
+use crate::documents::image_collector::MediaRegistry;
+
+pub(crate) const ODT_MIMETYPE: &str = "application/vnd.oasis.opendocument.text";
+
+const NAMESPACES: &str = " xmlns:office=\"urn:oasis:names:tc:opendocument:xmlns:office:1.0\" \
+xmlns:style=\"urn:oasis:names:tc:opendocument:xmlns:style:1.0\" \
+xmlns:text=\"urn:oasis:names:tc:opendocument:xmlns:text:1.0\" \
+xmlns:table=\"urn:oasis:names:tc:opendocument:xmlns:table:1.0\" \
+xmlns:draw=\"urn:oasis:names:tc:opendocument:xmlns:drawing:1.0\" \
+xmlns:fo=\"urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0\" \
+xmlns:svg=\"urn:oasis:names:tc:opendocument:xmlns:svg-compatible:1.0\" \
+xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+xmlns:dc=\"http://purl.org/dc/elements/1.1/\" office:version=\"1.3\"";
+
+/// The parts of an OpenDocument Text package.
+pub(crate) struct OdtPackage {
+    pub(crate) manifest: String,
+    pub(crate) content: String,
+    pub(crate) styles: String,
+    /// Images by their path in the package.
+    pub(crate) pictures: Vec<(String, Vec<u8>)>,
+}
+
+impl Docx {
+    /// Writes the document as OpenDocument Text (`.odt`).
+    ///
+    /// Styles and document defaults become the common styles of
+    /// `styles.xml`, and every section a master page with its page layout,
+    /// headers and footers. Direct formatting becomes automatic styles,
+    /// numberings become list styles, and images are stored once under
+    /// `Pictures/`. Tracked changes are shown accepted.
+    pub fn pack_odt<W>(&self, writer: W) -> zip::result::ZipResult<()>
+    where
+        W: Write + Seek,
+    {
+        crate::zipper::zip_odt(writer, self.odt_package())
+    }
+
+    pub(crate) fn odt_package(&self) -> OdtPackage {
+        let mut media = MediaRegistry::default();
+        let sections = sections(&self.document);
+
+        let mut body = OdtWriter::new(self, "", &mut media);
+        let mut text = String::new();
+        for (index, section) in sections.iter().enumerate() {
+            body.master_page = Some(format!("Mp{}", index + 1));
+            body.blocks(&section.blocks, &mut text);
+        }
+        let content_styles = std::mem::take(&mut body.automatic.xml);
+        let mut lists = std::mem::take(&mut body.lists);
+
+        let mut masters = OdtWriter::new(self, "M", &mut media);
+        let mut layouts = String::new();
+        let mut master_pages = String::new();
+        let mut inherited = HeadersAndFooters::default();
+        for (index, section) in sections.iter().enumerate() {
+            inherited = inherited.update(section.property);
+            layouts.push_str(&page_layout(index + 1, section.property, &inherited));
+            masters.master_page(index + 1, &inherited, &mut master_pages);
+        }
+        let master_styles = std::mem::take(&mut masters.automatic.xml);
+        lists.append(&mut masters.lists);
+
+        let pictures: Vec<(String, Vec<u8>)> = media
+            .into_media()
+            .into_iter()
+            .map(|(id, bytes)| (picture_path(&id, &bytes), bytes))
+            .collect();
+
+        let content = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <office:document-content{NAMESPACES}>\
+             <office:automatic-styles>{content_styles}</office:automatic-styles>\
+             <office:body><office:text>{text}</office:text></office:body>\
+             </office:document-content>"
+        );
+        let styles = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <office:document-styles{NAMESPACES}>\
+             <office:styles>{}{}</office:styles>\
+             <office:automatic-styles>{layouts}{master_styles}</office:automatic-styles>\
+             <office:master-styles>{master_pages}</office:master-styles>\
+             </office:document-styles>",
+            self.odt_common_styles(),
+            self.odt_list_styles(&lists)
+        );
+        OdtPackage {
+            manifest: manifest(&pictures),
+            content,
+            styles,
+            pictures,
+        }
+    }
+
+    /// The document defaults and the paragraph and character styles.
+    fn odt_common_styles(&self) -> String {
+        let defaults = &self.styles.doc_defaults;
+        let mut paragraph = Properties::default();
+        paragraph.paragraph(defaults.get_paragraph_property());
+        let mut text = Properties::default();
+        text.text(defaults.get_run_property());
+        let mut xml = format!(
+            "<style:default-style style:family=\"paragraph\">{}{}</style:default-style>",
+            paragraph.element("style:paragraph-properties"),
+            text.element("style:text-properties")
+        );
+
+        let headings = self.styles.create_heading_style_map();
+        for style in &self.styles.styles {
+            let family = match style.style_type {
+                StyleType::Paragraph => "paragraph",
+                StyleType::Character => "text",
+                _ => continue,
+            };
+            let mut attributes = format!(
+                " style:name=\"{}\" style:display-name=\"{}\" style:family=\"{family}\"",
+                style_name(&style.style_id),
+                style.name.escaped()
+            );
+            if let Some(based_on) = &style.based_on {
+                attributes.push_str(&format!(
+                    " style:parent-style-name=\"{}\"",
+                    style_name(based_on.get_val())
+                ));
+            }
+            if let Some(next) = &style.next {
+                attributes.push_str(&format!(
+                    " style:next-style-name=\"{}\"",
+                    style_name(next.get_val())
+                ));
+            }
+            if let Some(level) = headings.get(&style.style_id) {
+                attributes.push_str(&format!(" style:default-outline-level=\"{level}\""));
+            }
+            let mut paragraph = Properties::default();
+            if style.style_type == StyleType::Paragraph {
+                paragraph.paragraph(&style.paragraph_property);
+            }
+            let mut text = Properties::default();
+            text.text(&style.run_property);
+            xml.push_str(&format!(
+                "<style:style{attributes}>{}{}</style:style>",
+                paragraph.element("style:paragraph-properties"),
+                text.element("style:text-properties")
+            ));
+        }
+        xml
+    }
+
+    /// A list style for each numbering in use.
+    fn odt_list_styles(&self, numbering_ids: &BTreeSet<usize>) -> String {
+        let lookup = NumberingLookup::new(&self.numberings, &self.styles);
+        let mut xml = String::new();
+        for id in numbering_ids {
+            let levels: String = lookup.levels(*id).into_iter().map(list_level).collect();
+            xml.push_str(&format!(
+                "<text:list-style style:name=\"{}\">{levels}</text:list-style>",
+                list_style_name(*id)
+            ));
+        }
+        xml
+    }
+}
+
+/// The page size and margins of a section. Word measures the top and
+/// bottom margins to the body and OpenDocument to the header and footer, so
+/// the difference becomes their minimum height.
+fn page_layout(number: usize, property: &SectionProperty, parts: &HeadersAndFooters) -> String {
+    let size = &property.page_size;
+    let margin = &property.page_margin;
+    let orientation = match size.orient {
+        Some(PageOrientationType::Landscape) => "landscape",
+        _ => "portrait",
+    };
+    let (top, bottom) = (margin.top.abs(), margin.bottom.abs());
+    let page_top = if parts.has_header() {
+        margin.header.min(top)
+    } else {
+        top
+    };
+    let page_bottom = if parts.has_footer() {
+        margin.footer.min(bottom)
+    } else {
+        bottom
+    };
+    let columns = if property.columns > 1 {
+        format!(
+            "<style:columns fo:column-count=\"{}\" fo:column-gap=\"{}\"/>",
+            property.columns,
+            points(property.space as f64)
+        )
+    } else {
+        String::new()
+    };
+    let header_style = if parts.has_header() {
+        format!(
+            "<style:header-footer-properties fo:min-height=\"{}\" fo:margin-bottom=\"0pt\" style:dynamic-spacing=\"true\"/>",
+            points((top - page_top) as f64)
+        )
+    } else {
+        String::new()
+    };
+    let footer_style = if parts.has_footer() {
+        format!(
+            "<style:header-footer-properties fo:min-height=\"{}\" fo:margin-top=\"0pt\" style:dynamic-spacing=\"true\"/>",
+            points((bottom - page_bottom) as f64)
+        )
+    } else {
+        String::new()
+    };
+    format!(
+        "<style:page-layout style:name=\"pm{number}\">\
+         <style:page-layout-properties fo:page-width=\"{}\" fo:page-height=\"{}\" style:print-orientation=\"{orientation}\" \
+         fo:margin-top=\"{}\" fo:margin-bottom=\"{}\" fo:margin-left=\"{}\" fo:margin-right=\"{}\">{columns}</style:page-layout-properties>\
+         <style:header-style>{header_style}</style:header-style>\
+         <style:footer-style>{footer_style}</style:footer-style>\
+         </style:page-layout>",
+        points(size.w as f64),
+        points(size.h as f64),
+        points(page_top as f64),
+        points(page_bottom as f64),
+        points(margin.left as f64),
+        points(margin.right as f64)
+    )
+}
+
+fn manifest(pictures: &[(String, Vec<u8>)]) -> String {
+    let mut xml = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <manifest:manifest xmlns:manifest=\"urn:oasis:names:tc:opendocument:xmlns:manifest:1.0\" manifest:version=\"1.3\">\
+         <manifest:file-entry manifest:full-path=\"/\" manifest:version=\"1.3\" manifest:media-type=\"{ODT_MIMETYPE}\"/>\
+         <manifest:file-entry manifest:full-path=\"content.xml\" manifest:media-type=\"text/xml\"/>\
+         <manifest:file-entry manifest:full-path=\"styles.xml\" manifest:media-type=\"text/xml\"/>"
+    );
+    for (path, bytes) in pictures {
+        let media_type = match image_extension(bytes) {
+            "bin" => "application/octet-stream".to_owned(),
+            extension => format!("image/{extension}"),
+        };
+        xml.push_str(&format!(
+            "<manifest:file-entry manifest:full-path=\"{path}\" manifest:media-type=\"{media_type}\"/>"
+        ));
+    }
+    xml.push_str("</manifest:manifest>");
+    xml
+}
+
+/// The path of an image, named by its id in the media registry.
+fn picture_path(id: &str, bytes: &[u8]) -> String {
+    format!("Pictures/{}.{}", id, image_extension(bytes))
+}
+
+/// A style name for a style id, which may contain any character.
+fn style_name(style_id: &str) -> String {
+    class_name(style_id)
+}
+
+fn list_style_name(numbering_id: usize) -> String {
+    format!("WWNum{numbering_id}")
+}
+
+fn points(twips: f64) -> String {
+    format!("{}pt", twips / 20.0)
+}
+
+/// A level of a list style, with the label and indentation of a `w:lvl`.
+fn list_level(level: &Level) -> String {
+    let mut margin = 0;
+    let mut text_indent = 0;
+    if let Some(indent) = &level.paragraph_property.indent {
+        margin = indent.start.unwrap_or_default();
+        text_indent = match indent.special_indent {
+            Some(SpecialIndentType::FirstLine(v)) => v,
+            Some(SpecialIndentType::Hanging(v)) => -v,
+            None => 0,
+        };
+    }
+    let followed_by = match level.suffix {
+        LevelSuffixType::Tab => format!(
+            "text:label-followed-by=\"listtab\" text:list-tab-stop-position=\"{}\"",
+            points(margin as f64)
+        ),
+        LevelSuffixType::Space => "text:label-followed-by=\"space\"".to_owned(),
+        LevelSuffixType::Nothing => "text:label-followed-by=\"nothing\"".to_owned(),
+    };
+    let properties = format!(
+        "<style:list-level-properties text:list-level-position-and-space-mode=\"label-alignment\">\
+         <style:list-level-label-alignment {followed_by} fo:margin-left=\"{}\" fo:text-indent=\"{}\"/>\
+         </style:list-level-properties>",
+        points(margin as f64),
+        points(text_indent as f64)
+    );
+    let number = level.level + 1;
+    if level.format.val == "bullet" {
+        let font = level
+            .run_property
+            .fonts
+            .as_ref()
+            .and_then(|f| f.get_ascii().map(|f| f.as_str()))
+            .unwrap_or_default();
+        let bullet = level
+            .text
+            .get_val()
+            .chars()
+            .next()
+            .and_then(|c| symbols::symbol_char(font, &format!("{:X}", c as u32)))
+            .unwrap_or('•');
+        return format!(
+            "<text:list-level-style-bullet text:level=\"{number}\" text:bullet-char=\"{}\">{properties}</text:list-level-style-bullet>",
+            escape_html(&bullet.to_string())
+        );
+    }
+    let format = match level.format.val.as_str() {
+        "lowerLetter" => "a",
+        "upperLetter" => "A",
+        "lowerRoman" => "i",
+        "upperRoman" => "I",
+        "none" => "",
+        _ => "1",
+    };
+    let (prefix, suffix, display_levels) = level_text_parts(level.text.get_val());
+    format!(
+        "<text:list-level-style-number text:level=\"{number}\" style:num-prefix=\"{}\" style:num-suffix=\"{}\" \
+         style:num-format=\"{format}\" text:display-levels=\"{display_levels}\" text:start-value=\"{}\">{properties}</text:list-level-style-number>",
+        escape_html(prefix),
+        escape_html(suffix),
+        level.start.get_val()
+    )
+}
+
+/// The text before the first and after the last placeholder of a
+/// `w:lvlText` such as `(%1.%2)`, and the number of levels it shows.
+fn level_text_parts(text: &str) -> (&str, &str, usize) {
+    let placeholders: Vec<usize> = text
+        .char_indices()
+        .filter(|(i, c)| {
+            *c == '%'
+                && text[i + 1..]
+                    .chars()
+                    .next()
+                    .is_some_and(|d| ('1'..='9').contains(&d))
+        })
+        .map(|(i, _)| i)
+        .collect();
+    match (placeholders.first(), placeholders.last()) {
+        (Some(first), Some(last)) => (&text[..*first], &text[last + 2..], placeholders.len()),
+        _ => (text, "", 1),
+    }
+}
+
+/// ODF formatting attributes, where a later value replaces an earlier one.
+#[derive(Debug, Default)]
+struct Properties(Vec<(&'static str, String)>);
+
+impl Properties {
+    fn set(&mut self, attribute: &'static str, value: impl Into<String>) {
+        let value = value.into();
+        match self.0.iter_mut().find(|(a, _)| *a == attribute) {
+            Some((_, v)) => *v = value,
+            None => self.0.push((attribute, value)),
+        }
+    }
+
+    fn element(&self, name: &str) -> String {
+        if self.0.is_empty() {
+            return String::new();
+        }
+        let attributes: String = self
+            .0
+            .iter()
+            .map(|(a, v)| format!(" {a}=\"{}\"", escape_html(v)))
+            .collect();
+        format!("<{name}{attributes}/>")
+    }
+
+    fn paragraph(&mut self, p: &ParagraphProperty) {
+        if let Some(alignment) = &p.alignment {
+            let align = match alignment.val.as_str() {
+                "center" => "center",
+                "right" | "end" => "end",
+                "both" | "distribute" => "justify",
+                _ => "start",
+            };
+            self.set("fo:text-align", align);
+        }
+        if let Some(indent) = &p.indent {
+            if let Some(start) = indent.start {
+                self.set("fo:margin-left", points(start as f64));
+            }
+            if let Some(end) = indent.end {
+                self.set("fo:margin-right", points(end as f64));
+            }
+            match indent.special_indent {
+                Some(SpecialIndentType::FirstLine(v)) => {
+                    self.set("fo:text-indent", points(v as f64))
+                }
+                Some(SpecialIndentType::Hanging(v)) => {
+                    self.set("fo:text-indent", points(-v as f64))
+                }
+                None => {}
+            }
+        }
+        if let Some(spacing) = &p.line_spacing {
+            if let Some(before) = spacing.get_before() {
+                self.set("fo:margin-top", points(before as f64));
+            }
+            if let Some(after) = spacing.get_after() {
+                self.set("fo:margin-bottom", points(after as f64));
+            }
+            if let Some(line) = spacing.get_line() {
+                match spacing.get_line_rule() {
+                    Some(LineSpacingType::Exact) => self.set("fo:line-height", points(line as f64)),
+                    Some(LineSpacingType::AtLeast) => {
+                        self.set("style:line-height-at-least", points(line as f64))
+                    }
+                    _ => self.set(
+                        "fo:line-height",
+                        format!("{}%", (line as f64 / 2.4).round()),
+                    ),
+                }
+            }
+        }
+        if let Some(shading) = p.shading.as_ref().filter(|s| is_color(&s.fill)) {
+            self.set("fo:background-color", format!("#{}", shading.fill));
+        }
+        if p.keep_next == Some(true) {
+            self.set("fo:keep-with-next", "always");
+        }
+        if p.keep_lines == Some(true) {
+            self.set("fo:keep-together", "always");
+        }
+        if p.page_break_before == Some(true) {
+            self.set("fo:break-before", "page");
+        }
+        if let Some(widow_control) = p.widow_control {
+            let lines = if widow_control { "2" } else { "0" };
+            self.set("fo:widows", lines);
+            self.set("fo:orphans", lines);
+        }
+        if p.bidi == Some(true) {
+            self.set("style:writing-mode", "rl-tb");
+        }
+    }
+
+    fn text(&mut self, r: &RunProperty) {
+        if let Some(bold) = &r.bold {
+            let weight = if bold.get_val() { "bold" } else { "normal" };
+            self.set("fo:font-weight", weight);
+            self.set("style:font-weight-asian", weight);
+            self.set("style:font-weight-complex", weight);
+        }
+        if let Some(italic) = &r.italic {
+            let style = if italic.get_val() { "italic" } else { "normal" };
+            self.set("fo:font-style", style);
+            self.set("style:font-style-asian", style);
+            self.set("style:font-style-complex", style);
+        }
+        if let Some(underline) = &r.underline {
+            match underline.get_val() {
+                "none" => self.set("style:text-underline-style", "none"),
+                val => {
+                    self.set("style:text-underline-style", "solid");
+                    self.set("style:text-underline-width", "auto");
+                    self.set("style:text-underline-color", "font-color");
+                    if val == "double" {
+                        self.set("style:text-underline-type", "double");
+                    }
+                }
+            }
+        }
+        if let Some(strike) = &r.strike {
+            let style = if strike.val { "solid" } else { "none" };
+            self.set("style:text-line-through-style", style);
+        }
+        if r.dstrike.as_ref().is_some_and(|d| d.val) {
+            self.set("style:text-line-through-style", "solid");
+            self.set("style:text-line-through-type", "double");
+        }
+        if let Some(vert_align) = &r.vert_align {
+            let position = match vert_align.get_val() {
+                VertAlignType::SuperScript => "super 58%",
+                VertAlignType::SubScript => "sub 58%",
+                _ => "0% 100%",
+            };
+            self.set("style:text-position", position);
+        }
+        if let Some(sz) = &r.sz {
+            let size = format!("{}pt", sz.get_val() as f64 / 2.0);
+            self.set("fo:font-size", size.clone());
+            self.set("style:font-size-asian", size);
+        }
+        if let Some(sz) = &r.sz_cs {
+            self.set(
+                "style:font-size-complex",
+                format!("{}pt", sz.get_val() as f64 / 2.0),
+            );
+        }
+        if let Some(color) = r.color.as_ref().filter(|c| is_color(c.get_val())) {
+            self.set("fo:color", format!("#{}", color.get_val()));
+        }
+        if let Some(shading) = r.shading.as_ref().filter(|s| is_color(&s.fill)) {
+            self.set("fo:background-color", format!("#{}", shading.fill));
+        }
+        if let Some(highlight) = r
+            .highlight
+            .as_ref()
+            .and_then(|h| highlight_rgb(h.get_val()))
+        {
+            self.set("fo:background-color", format!("#{highlight}"));
+        }
+        if let Some(fonts) = &r.fonts {
+            if let Some(font) = fonts.get_ascii().or(fonts.get_hi_ansi()) {
+                self.set("fo:font-family", quote_font(font));
+            }
+            if let Some(font) = fonts.get_east_asia() {
+                self.set("style:font-family-asian", quote_font(font));
+            }
+            if let Some(font) = fonts.get_cs() {
+                self.set("style:font-family-complex", quote_font(font));
+            }
+        }
+        if let Some(caps) = &r.caps {
+            self.set(
+                "fo:text-transform",
+                if caps.get_val() { "uppercase" } else { "none" },
+            );
+        }
+        if let Some(small_caps) = &r.small_caps {
+            let variant = if small_caps.get_val() {
+                "small-caps"
+            } else {
+                "normal"
+            };
+            self.set("fo:font-variant", variant);
+        }
+        if r.vanish.is_some() {
+            self.set("text:display", "none");
+        }
+    }
+}
+
+fn quote_font(font: &str) -> String {
+    if font.contains(' ') {
+        format!("'{}'", font.replace('\'', ""))
+    } else {
+        font.to_owned()
+    }
+}
+
+/// Text content with the runs of spaces and the leading space OpenDocument
+/// would otherwise collapse written as `<text:s/>`.
+fn odf_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut spaces = 0;
+    let mut after_text = false;
+    for c in text.chars() {
+        if c == ' ' {
+            spaces += 1;
+            continue;
+        }
+        push_spaces(&mut out, spaces, after_text);
+        spaces = 0;
+        after_text = true;
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' => {
+                out.push_str("<text:tab/>");
+                after_text = false;
+            }
+            '\n' => {
+                out.push_str("<text:line-break/>");
+                after_text = false;
+            }
+            c => out.push(c),
+        }
+    }
+    push_spaces(&mut out, spaces, after_text);
+    out
+}
+
+fn push_spaces(out: &mut String, mut count: usize, after_text: bool) {
+    if count > 0 && after_text {
+        out.push(' ');
+        count -= 1;
+    }
+    match count {
+        0 => {}
+        1 => out.push_str("<text:s/>"),
+        n => out.push_str(&format!("<text:s text:c=\"{n}\"/>")),
+    }
+}
+
+/// The automatic styles of one part, written once per distinct formatting.
+#[derive(Default)]
+struct AutomaticStyles {
+    xml: String,
+    names: HashMap<String, String>,
+    counts: HashMap<&'static str, usize>,
+}
+
+impl AutomaticStyles {
+    fn add(
+        &mut self,
+        prefix: &str,
+        family: &'static str,
+        attributes: &str,
+        children: &str,
+    ) -> String {
+        let key = format!("{family}{attributes}{children}");
+        if let Some(name) = self.names.get(&key) {
+            return name.clone();
+        }
+        let count = self.counts.entry(family).or_default();
+        *count += 1;
+        let letter = match family {
+            "paragraph" => "P",
+            "text" => "T",
+            "table" => "Table",
+            "table-column" => "Co",
+            "table-cell" => "Ce",
+            _ => "G",
+        };
+        let name = format!("{prefix}{letter}{count}");
+        self.xml.push_str(&format!(
+            "<style:style style:name=\"{name}\" style:family=\"{family}\"{attributes}>{children}</style:style>"
+        ));
+        self.names.insert(key, name.clone());
+        name
+    }
+}
+
+struct OpenList {
+    style: String,
+    item_open: bool,
+}
+
+fn close_lists(lists: &mut Vec<OpenList>, depth: usize, out: &mut String) {
+    while lists.len() > depth {
+        let Some(list) = lists.pop() else {
+            break;
+        };
+        if list.item_open {
+            out.push_str("</text:list-item>");
+        }
+        out.push_str("</text:list>");
+    }
+}
+
+const INLINE_OPTIONS: InlineOptions = InlineOptions {
+    revisions: Some(RevisionView::Final),
+    hidden_text: true,
+};
+
+struct OdtWriter<'a, 'm> {
+    docx: &'a Docx,
+    /// Keeps the names of automatic styles in `styles.xml` apart from the
+    /// ones in `content.xml`.
+    prefix: &'static str,
+    automatic: AutomaticStyles,
+    media: &'m mut MediaRegistry,
+    headings: HashMap<String, usize>,
+    labels: ListLabelResolver<'a>,
+    /// The numberings lists use, which need a list style.
+    lists: BTreeSet<usize>,
+    /// The master page of a new section, set on its first block.
+    master_page: Option<String>,
+    tables: usize,
+    pictures: usize,
+    notes: usize,
+    /// The comments whose annotation was written.
+    comments: Vec<usize>,
+}
+
+impl<'a, 'm> OdtWriter<'a, 'm> {
+    fn new(docx: &'a Docx, prefix: &'static str, media: &'m mut MediaRegistry) -> Self {
+        Self {
+            docx,
+            prefix,
+            automatic: AutomaticStyles::default(),
+            media,
+            headings: docx.styles.create_heading_style_map(),
+            labels: ListLabelResolver::new(&docx.numberings, &docx.styles),
+            lists: BTreeSet::new(),
+            master_page: None,
+            tables: 0,
+            pictures: 0,
+            notes: 0,
+            comments: vec![],
+        }
+    }
+
+    fn master_page(&mut self, number: usize, parts: &HeadersAndFooters<'a>, out: &mut String) {
+        out.push_str(&format!(
+            "<style:master-page style:name=\"Mp{number}\" style:page-layout-name=\"pm{number}\">"
+        ));
+        let even = self.docx.settings.even_and_odd_headers;
+        let regions = [
+            ("style:header", parts.header.map(|h| blocks(&h.children))),
+            (
+                "style:header-left",
+                parts
+                    .even_header
+                    .filter(|_| even)
+                    .map(|h| blocks(&h.children)),
+            ),
+            (
+                "style:header-first",
+                parts
+                    .first_header
+                    .filter(|_| parts.title_pg)
+                    .map(|h| blocks(&h.children)),
+            ),
+            ("style:footer", parts.footer.map(|f| blocks(&f.children))),
+            (
+                "style:footer-left",
+                parts
+                    .even_footer
+                    .filter(|_| even)
+                    .map(|f| blocks(&f.children)),
+            ),
+            (
+                "style:footer-first",
+                parts
+                    .first_footer
+                    .filter(|_| parts.title_pg)
+                    .map(|f| blocks(&f.children)),
+            ),
+        ];
+        for (element, content) in regions {
+            if let Some(content) = content {
+                out.push_str(&format!("<{element}>"));
+                self.blocks(&content, out);
+                out.push_str(&format!("</{element}>"));
+            }
+        }
+        out.push_str("</style:master-page>");
+    }
+
+    fn blocks(&mut self, blocks: &[Block<'a>], out: &mut String) {
+        let mut lists = vec![];
+        for block in blocks {
+            match block {
+                Block::Paragraph(paragraph) => match self.labels.resolve(paragraph) {
+                    Some(label) => self.list_item(paragraph, &label, &mut lists, out),
+                    None => {
+                        close_lists(&mut lists, 0, out);
+                        self.paragraph(paragraph, out);
+                    }
+                },
+                Block::Table(table) => {
+                    close_lists(&mut lists, 0, out);
+                    self.table(table, out);
+                }
+            }
+        }
+        close_lists(&mut lists, 0, out);
+    }
+
+    fn list_item(
+        &mut self,
+        paragraph: &'a Paragraph,
+        label: &ListLabel,
+        lists: &mut Vec<OpenList>,
+        out: &mut String,
+    ) {
+        self.lists.insert(label.numbering_id);
+        let style = list_style_name(label.numbering_id);
+        let depth = label.level + 1;
+        close_lists(lists, depth, out);
+        let open = lists.len();
+        if let Some(list) = lists.last_mut().filter(|_| open == depth) {
+            if list.style != style {
+                close_lists(lists, depth - 1, out);
+            } else if list.item_open {
+                out.push_str("</text:list-item>");
+                list.item_open = false;
+            }
+        }
+        let mut started = false;
+        while lists.len() < depth {
+            // Nested lists go inside an item of their parent.
+            if let Some(parent) = lists.last_mut() {
+                if !parent.item_open {
+                    out.push_str("<text:list-item>");
+                    parent.item_open = true;
+                }
+            }
+            out.push_str(&format!("<text:list text:style-name=\"{style}\">"));
+            lists.push(OpenList {
+                style: style.clone(),
+                item_open: false,
+            });
+            started = true;
+        }
+        // A list continued after other content starts at its current number.
+        let start = match last_number(&label.text) {
+            Some(n) if started && label.format != "bullet" => {
+                format!(" text:start-value=\"{n}\"")
+            }
+            _ => String::new(),
+        };
+        out.push_str(&format!("<text:list-item{start}>"));
+        if let Some(list) = lists.last_mut() {
+            list.item_open = true;
+        }
+        self.paragraph(paragraph, out);
+    }
+
+    fn paragraph(&mut self, paragraph: &'a Paragraph, out: &mut String) {
+        let style = self.paragraph_style(&paragraph.property);
+        let style = style
+            .map(|s| format!(" text:style-name=\"{s}\""))
+            .unwrap_or_default();
+        let heading = paragraph
+            .property
+            .style
+            .as_ref()
+            .and_then(|s| self.headings.get(&s.val).copied())
+            .or_else(|| {
+                paragraph
+                    .property
+                    .outline_lvl
+                    .as_ref()
+                    .filter(|l| l.v < 9)
+                    .map(|l| l.v + 1)
+            });
+        let mut text_boxes = vec![];
+        let content = self.inline_content(
+            &inlines(self.docx, paragraph, INLINE_OPTIONS),
+            &mut text_boxes,
+        );
+        match heading {
+            Some(level) => out.push_str(&format!(
+                "<text:h{style} text:outline-level=\"{level}\">{content}</text:h>"
+            )),
+            None => out.push_str(&format!("<text:p{style}>{content}</text:p>")),
+        }
+        for children in text_boxes {
+            self.blocks(&blocks(children), out);
+        }
+    }
+
+    /// The paragraph style, or an automatic style based on it with the
+    /// direct formatting of the paragraph.
+    fn paragraph_style(&mut self, property: &ParagraphProperty) -> Option<String> {
+        let parent = property
+            .style
+            .as_ref()
+            .map(|s| s.val.as_str())
+            .or_else(|| {
+                self.docx
+                    .styles
+                    .default_style(StyleType::Paragraph)
+                    .map(|s| s.style_id.as_str())
+            })
+            .map(style_name);
+        let mut properties = Properties::default();
+        properties.paragraph(property);
+        let master_page = self.master_page.take();
+        if properties.0.is_empty() && master_page.is_none() {
+            return parent;
+        }
+        let mut attributes = String::new();
+        if let Some(parent) = &parent {
+            attributes.push_str(&format!(" style:parent-style-name=\"{parent}\""));
+        }
+        if let Some(master_page) = &master_page {
+            attributes.push_str(&format!(" style:master-page-name=\"{master_page}\""));
+        }
+        Some(self.automatic.add(
+            self.prefix,
+            "paragraph",
+            &attributes,
+            &properties.element("style:paragraph-properties"),
+        ))
+    }
+
+    /// The character style of a run, or an automatic style based on it with
+    /// the direct formatting of the run.
+    fn text_style(&mut self, property: &RunProperty) -> Option<String> {
+        let parent = property.style.as_ref().map(|s| style_name(&s.val));
+        let mut properties = Properties::default();
+        properties.text(property);
+        if properties.0.is_empty() {
+            return parent;
+        }
+        let attributes = parent
+            .map(|p| format!(" style:parent-style-name=\"{p}\""))
+            .unwrap_or_default();
+        Some(self.automatic.add(
+            self.prefix,
+            "text",
+            &attributes,
+            &properties.element("style:text-properties"),
+        ))
+    }
+
+    fn inline_content(
+        &mut self,
+        inlines: &[Inline<'a>],
+        text_boxes: &mut Vec<&'a [TextBoxContentChild]>,
+    ) -> String {
+        let mut out = String::new();
+        for inline in inlines {
+            match inline {
+                Inline::Text { text, run, .. } => {
+                    let text = odf_text(text);
+                    match self.text_style(&run.run_property) {
+                        Some(style) => out.push_str(&format!(
+                            "<text:span text:style-name=\"{style}\">{text}</text:span>"
+                        )),
+                        None => out.push_str(&text),
+                    }
+                }
+                Inline::Tab => out.push_str("<text:tab/>"),
+                Inline::Break => out.push_str("<text:line-break/>"),
+                Inline::Link { link, children } => {
+                    let content = self.inline_content(children, text_boxes);
+                    out.push_str(&format!(
+                        "<text:a xlink:type=\"simple\" xlink:href=\"{}\">{content}</text:a>",
+                        escape_html(&link_target(self.docx, link))
+                    ));
+                }
+                Inline::FootnoteReference(reference) => {
+                    self.notes += 1;
+                    let id = format!("{}ftn{}", self.prefix, self.notes);
+                    let mut body = String::new();
+                    self.blocks(&blocks(&reference.content), &mut body);
+                    out.push_str(&format!(
+                        "<text:note text:id=\"{id}\" text:note-class=\"footnote\">\
+                         <text:note-citation>{}</text:note-citation>\
+                         <text:note-body>{body}</text:note-body></text:note>",
+                        self.notes
+                    ));
+                }
+                Inline::Picture(pic) => {
+                    if let Some(frame) = self.picture(pic) {
+                        out.push_str(&frame);
+                    }
+                }
+                Inline::TextBox(children) => text_boxes.push(children),
+                Inline::CommentStart(start) => {
+                    let comment = self
+                        .docx
+                        .comments
+                        .comments
+                        .iter()
+                        .find(|c| c.id == start.id)
+                        .unwrap_or(&start.comment);
+                    let mut body = String::new();
+                    self.blocks(&blocks(&comment.children), &mut body);
+                    if body.is_empty() {
+                        body.push_str("<text:p/>");
+                    }
+                    let date = if comment.date.is_empty() {
+                        String::new()
+                    } else {
+                        format!("<dc:date>{}</dc:date>", escape_html(&comment.date))
+                    };
+                    out.push_str(&format!(
+                        "<office:annotation office:name=\"comment{}\"><dc:creator>{}</dc:creator>{date}{body}</office:annotation>",
+                        start.id,
+                        escape_html(&comment.author)
+                    ));
+                    self.comments.push(start.id);
+                }
+                Inline::CommentEnd(id) => {
+                    if self.comments.contains(id) {
+                        out.push_str(&format!(
+                            "<office:annotation-end office:name=\"comment{id}\"/>"
+                        ));
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// A frame with the image, stored once per distinct content.
+    fn picture(&mut self, pic: &Pic) -> Option<String> {
+        let bytes = if pic.image.is_empty() {
+            self.docx
+                .images
+                .iter()
+                .find(|(id, ..)| *id == pic.id)
+                .map(|(_, _, Image(image), _)| image.clone())
+                .filter(|image| !image.is_empty())?
+        } else {
+            pic.image.clone()
+        };
+        let extension = image_extension(&bytes);
+        // Content the registry already has keeps its first id.
+        let index = self.media.register(&pic.id, bytes);
+        let path = format!("Pictures/{}.{extension}", self.media.media_id(index));
+        self.pictures += 1;
+        // EMU per point.
+        let (width, height) = (pic.size.0 as f64 / 12700.0, pic.size.1 as f64 / 12700.0);
+        let anchor = match pic.position_type {
+            DrawingPositionType::Inline => "text:anchor-type=\"as-char\"".to_owned(),
+            DrawingPositionType::Anchor => {
+                let offset = |position: &DrawingPosition| match position {
+                    DrawingPosition::Offset(emu) => *emu as f64 / 12700.0,
+                    DrawingPosition::Align(_) => 0.0,
+                };
+                format!(
+                    "text:anchor-type=\"paragraph\" svg:x=\"{}pt\" svg:y=\"{}pt\"",
+                    offset(&pic.position_h),
+                    offset(&pic.position_v)
+                )
+            }
+        };
+        Some(format!(
+            "<draw:frame draw:name=\"{}Image{}\" {anchor} svg:width=\"{width}pt\" svg:height=\"{height}pt\">\
+             <draw:image xlink:href=\"{path}\" xlink:type=\"simple\" xlink:show=\"embed\" xlink:actuate=\"onLoad\"/>\
+             </draw:frame>",
+            self.prefix, self.pictures
+        ))
+    }
+
+    fn table(&mut self, table: &'a Table, out: &mut String) {
+        self.tables += 1;
+        let width: usize = table.grid.iter().sum();
+        let mut attributes = String::new();
+        if let Some(master_page) = self.master_page.take() {
+            attributes.push_str(&format!(" style:master-page-name=\"{master_page}\""));
+        }
+        let width = if width > 0 {
+            format!(" style:width=\"{}\"", points(width as f64))
+        } else {
+            String::new()
+        };
+        let style = self.automatic.add(
+            self.prefix,
+            "table",
+            &attributes,
+            &format!("<style:table-properties{width} table:align=\"left\"/>"),
+        );
+        out.push_str(&format!(
+            "<table:table table:name=\"{}Table{}\" table:style-name=\"{style}\">",
+            self.prefix, self.tables
+        ));
+
+        let rows = spanned_cells(table);
+        let columns = rows
+            .iter()
+            .flat_map(|row| row.iter().map(|c| c.column + c.colspan))
+            .max()
+            .unwrap_or_default()
+            .max(table.grid.len());
+        for column in 0..columns {
+            match table.grid.get(column) {
+                Some(width) => {
+                    let style = self.automatic.add(
+                        self.prefix,
+                        "table-column",
+                        "",
+                        &format!(
+                            "<style:table-column-properties style:column-width=\"{}\"/>",
+                            points(*width as f64)
+                        ),
+                    );
+                    out.push_str(&format!(
+                        "<table:table-column table:style-name=\"{style}\"/>"
+                    ));
+                }
+                None => out.push_str("<table:table-column/>"),
+            }
+        }
+
+        let cell_style = table.property.has_borders().then(|| {
+            self.automatic.add(
+                self.prefix,
+                "table-cell",
+                "",
+                "<style:table-cell-properties fo:border=\"0.5pt solid #000000\" fo:padding-left=\"5.4pt\" fo:padding-right=\"5.4pt\"/>",
+            )
+        });
+        let cell_style = cell_style
+            .map(|s| format!(" table:style-name=\"{s}\""))
+            .unwrap_or_default();
+        // Rows still covered by a cell above, per column.
+        let mut covered_rows = vec![0; columns];
+        for row in rows {
+            let covered: Vec<bool> = covered_rows.iter().map(|r| *r > 0).collect();
+            for r in covered_rows.iter_mut() {
+                *r -= (*r > 0) as usize;
+            }
+            out.push_str("<table:table-row>");
+            let mut column = 0;
+            for SpannedCell {
+                cell,
+                column: start,
+                colspan,
+                rowspan,
+            } in row
+            {
+                fill_cells(&covered, column, start, out);
+                let mut spans = String::new();
+                if colspan > 1 {
+                    spans.push_str(&format!(" table:number-columns-spanned=\"{colspan}\""));
+                }
+                if rowspan > 1 {
+                    spans.push_str(&format!(" table:number-rows-spanned=\"{rowspan}\""));
+                    for r in covered_rows.iter_mut().skip(start).take(colspan) {
+                        *r = rowspan - 1;
+                    }
+                }
+                out.push_str(&format!(
+                    "<table:table-cell{cell_style}{spans} office:value-type=\"string\">"
+                ));
+                self.blocks(&blocks(&cell.children), out);
+                out.push_str("</table:table-cell>");
+                for _ in 1..colspan {
+                    out.push_str("<table:covered-table-cell/>");
+                }
+                column = start + colspan;
+            }
+            fill_cells(&covered, column, columns, out);
+            out.push_str("</table:table-row>");
+        }
+        out.push_str("</table:table>");
+    }
+}
+
+/// Fills the columns a row has no cell for, with covered cells where a cell
+/// above spans into them.
+fn fill_cells(covered: &[bool], from: usize, to: usize, out: &mut String) {
+    for column in from..to {
+        if covered.get(column).copied().unwrap_or_default() {
+            out.push_str("<table:covered-table-cell/>");
+        } else {
+            out.push_str("<table:table-cell/>");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::io::{Cursor, Read};
+
+    #[test]
+    fn test_odf_text() {
+        assert_eq!(odf_text("a  b"), "a <text:s/>b");
+        assert_eq!(odf_text(" x<"), "<text:s/>x&lt;");
+        assert_eq!(odf_text("a    "), "a <text:s text:c=\"3\"/>");
+        assert_eq!(level_text_parts("(%1.%2)"), ("(", ")", 2));
+        assert_eq!(level_text_parts("•"), ("•", "", 1));
+    }
+
+    #[test]
+    fn test_odt_package() {
+        let docx = Docx::new()
+            .add_style(
+                Style::new("Heading1", StyleType::Paragraph)
+                    .name("Heading 1")
+                    .bold(),
+            )
+            .add_abstract_numbering(AbstractNumbering::new(2).add_level(Level::new(
+                0,
+                Start::new(1),
+                NumberFormat::new("decimal"),
+                LevelText::new("%1."),
+                LevelJc::new("left"),
+            )))
+            .add_numbering(Numbering::new(2, 2))
+            .header(
+                Header::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Head"))),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading1")
+                    .add_run(Run::new().add_text("Title")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .align(AlignmentType::Center)
+                    .add_run(Run::new().add_text("Bold").bold())
+                    .add_run(
+                        Run::new()
+                            .add_footnote_reference(Footnote::new().add_content(
+                                Paragraph::new().add_run(Run::new().add_text("Note")),
+                            )),
+                    ),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("One"))
+                    .numbering(NumberingId::new(2), IndentLevel::new(0)),
+            )
+            .add_table(Table::new(vec![
+                TableRow::new(vec![
+                    TableCell::new()
+                        .vertical_merge(VMergeType::Restart)
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("A"))),
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("B"))),
+                ]),
+                TableRow::new(vec![
+                    TableCell::new().vertical_merge(VMergeType::Continue),
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("C"))),
+                ]),
+            ]));
+        let package = docx.odt_package();
+        assert!(package
+            .content
+            .contains("<text:h text:style-name=\"P1\" text:outline-level=\"1\">Title</text:h>"));
+        assert!(package.content.contains(
+            "<style:style style:name=\"P1\" style:family=\"paragraph\" style:parent-style-name=\"Heading1\" style:master-page-name=\"Mp1\"></style:style>"
+        ));
+        assert!(package.content.contains(
+            "<text:p text:style-name=\"P2\"><text:span text:style-name=\"T1\">Bold</text:span>\
+             <text:note text:id=\"ftn1\" text:note-class=\"footnote\"><text:note-citation>1</text:note-citation>\
+             <text:note-body><text:p>Note</text:p></text:note-body></text:note></text:p>"
+        ));
+        assert!(package.content.contains(
+            "<text:list text:style-name=\"WWNum2\"><text:list-item text:start-value=\"1\"><text:p>One</text:p></text:list-item></text:list>"
+        ));
+        assert!(package.content.contains(
+            "<table:table-row><table:table-cell table:style-name=\"Ce1\" table:number-rows-spanned=\"2\" office:value-type=\"string\"><text:p>A</text:p></table:table-cell>"
+        ));
+        assert!(package.content.contains(
+            "<table:table-row><table:covered-table-cell/><table:table-cell table:style-name=\"Ce1\" office:value-type=\"string\"><text:p>C</text:p></table:table-cell></table:table-row>"
+        ));
+        assert!(package.styles.contains(
+            "<style:style style:name=\"Heading1\" style:display-name=\"Heading 1\" style:family=\"paragraph\" style:default-outline-level=\"1\">"
+        ));
+        assert!(package.styles.contains(
+            "<text:list-level-style-number text:level=\"1\" style:num-prefix=\"\" style:num-suffix=\".\" style:num-format=\"1\""
+        ));
+        assert!(package.styles.contains(
+            "<style:master-page style:name=\"Mp1\" style:page-layout-name=\"pm1\"><style:header><text:p>Head</text:p></style:header></style:master-page>"
+        ));
+
+        let mut buf = Cursor::new(Vec::new());
+        docx.pack_odt(&mut buf).unwrap();
+        let mut archive = zip::ZipArchive::new(buf).unwrap();
+        assert_eq!(archive.by_index(0).unwrap().name(), "mimetype");
+        let mut mimetype = String::new();
+        archive
+            .by_name("mimetype")
+            .unwrap()
+            .read_to_string(&mut mimetype)
+            .unwrap();
+        assert_eq!(mimetype, ODT_MIMETYPE);
+        assert!(archive.by_name("META-INF/manifest.xml").is_ok());
+    }
+
+    #[test]
+    fn test_odt_pictures_are_stored_once() {
+        let image = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new().add_run(Run::new().add_image(Pic::new_with_dimensions(
+                    image.clone(),
+                    10,
+                    10,
+                ))),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_image(Pic::new_with_dimensions(image, 10, 10))),
+            );
+        let package = docx.odt_package();
+        assert_eq!(package.pictures.len(), 1);
+        let path = &package.pictures[0].0;
+        assert!(path.starts_with("Pictures/") && path.ends_with(".png"));
+        assert_eq!(package.content.matches(path.as_str()).count(), 2);
+        assert!(package.manifest.contains(&format!(
+            "<manifest:file-entry manifest:full-path=\"{path}\" manifest:media-type=\"image/png\"/>"
+        )));
+    }
+}
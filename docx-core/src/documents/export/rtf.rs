@@ -0,0 +1,881 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::html::style_chain;
+use super::*;
+
+impl Docx {
+    /// Converts the document to RTF.
+    ///
+    /// The font and color tables hold what the runs use, styles become the
+    /// style sheet and numberings the list table. Every section starts with
+    /// its page setup, headers and footers. Tables keep their merged cells,
+    /// footnotes are written in place and PNG and JPEG images as `\pict`.
+    /// Tracked changes are shown accepted.
+    pub fn to_rtf(&self) -> String {
+        let mut writer = RtfWriter::new(self);
+        // The default font is `\f0`.
+        let default_font = self
+            .styles
+            .doc_defaults
+            .get_run_property()
+            .fonts
+            .as_ref()
+            .and_then(|f| f.get_ascii().cloned())
+            .unwrap_or_else(|| "Times New Roman".to_owned());
+        writer.font(&default_font);
+
+        let mut body = String::new();
+        let sections = sections(&self.document);
+        let mut parts = HeadersAndFooters::default();
+        for (index, section) in sections.iter().enumerate() {
+            parts = parts.update(section.property);
+            writer.section(section.property, &parts, &mut body);
+            writer.blocks(&section.blocks, 0, &mut body);
+            if index + 1 < sections.len() {
+                body.push_str("\\sect\n");
+            }
+        }
+
+        let style_sheet = writer.style_sheet();
+        let list_table = writer.list_table();
+        let defaults = &self.styles.doc_defaults;
+        let default_character = writer.character_format(defaults.get_run_property());
+        let default_paragraph = writer.paragraph_format(defaults.get_paragraph_property());
+
+        let mut rtf = String::from("{\\rtf1\\ansi\\ansicpg1252\\deff0\\uc1\n{\\fonttbl");
+        for (index, font) in writer.fonts.iter().enumerate() {
+            rtf.push_str(&format!("{{\\f{index}\\fnil {};}}", escape_rtf(font)));
+        }
+        rtf.push_str("}\n{\\colortbl;");
+        for color in &writer.colors {
+            let channel = |i: usize| u8::from_str_radix(&color[i..i + 2], 16).unwrap_or_default();
+            rtf.push_str(&format!(
+                "\\red{}\\green{}\\blue{};",
+                channel(0),
+                channel(2),
+                channel(4)
+            ));
+        }
+        rtf.push_str("}\n");
+        rtf.push_str(&style_sheet);
+        rtf.push_str(&list_table);
+        rtf.push_str(&format!(
+            "{{\\*\\defchp{default_character}}}{{\\*\\defpap{default_paragraph}}}\n"
+        ));
+        if let Some(first) = sections.first() {
+            let size = &first.property.page_size;
+            let margin = &first.property.page_margin;
+            rtf.push_str(&format!(
+                "\\paperw{}\\paperh{}\\margl{}\\margr{}\\margt{}\\margb{}",
+                size.w, size.h, margin.left, margin.right, margin.top, margin.bottom
+            ));
+        }
+        if self.settings.even_and_odd_headers {
+            rtf.push_str("\\facingp");
+        }
+        rtf.push('\n');
+        rtf.push_str(&body);
+        rtf.push_str("}\n");
+        rtf
+    }
+}
+
+/// Escapes text for RTF, writing characters outside ASCII as `\u` with a
+/// `?` for readers that do not know it.
+fn escape_rtf(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '{' => out.push_str("\\{"),
+            '}' => out.push_str("\\}"),
+            '\t' => out.push_str("\\tab "),
+            '\n' => out.push_str("\\line "),
+            c if c.is_ascii() => out.push(c),
+            c => {
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    out.push_str(&format!("\\u{}?", *unit as i16));
+                }
+            }
+        }
+    }
+    out
+}
+
+/// The `\levelnfc` of a `w:numFmt`.
+fn level_number_format(format: &str) -> usize {
+    match format {
+        "upperRoman" => 1,
+        "lowerRoman" => 2,
+        "upperLetter" => 3,
+        "lowerLetter" => 4,
+        "ordinal" => 5,
+        "decimalZero" => 22,
+        "bullet" => 23,
+        "none" => 255,
+        _ => 0,
+    }
+}
+
+/// `\leveltext` and `\levelnumbers` of a `w:lvlText`: the text with its
+/// length first and `%1`..`%9` as level indexes, and the offsets of those.
+fn level_text(text: &str) -> (String, String) {
+    let mut units = vec![];
+    let mut numbers = String::new();
+    let mut chars = text.chars().peekable();
+    let mut length = 0;
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            if let Some(n) = chars.peek().and_then(|d| d.to_digit(10)).filter(|n| *n > 0) {
+                chars.next();
+                units.push(format!("\\'{:02x}", n - 1));
+                length += 1;
+                numbers.push_str(&format!("\\'{length:02x}"));
+                continue;
+            }
+        }
+        units.push(escape_rtf(&c.to_string()));
+        length += c.len_utf16();
+    }
+    (format!("\\'{length:02x}{}", units.concat()), numbers)
+}
+
+struct RtfWriter<'a> {
+    docx: &'a Docx,
+    fonts: Vec<String>,
+    /// Hex RGB, numbered from 1 as `\cf0` is the automatic color.
+    colors: Vec<String>,
+    /// Style ids and their numbers in the style sheet.
+    styles: HashMap<&'a str, usize>,
+    labels: ListLabelResolver<'a>,
+    lookup: NumberingLookup<'a>,
+    /// The numberings lists use, which go to the list table.
+    lists: BTreeSet<usize>,
+}
+
+impl<'a> RtfWriter<'a> {
+    fn new(docx: &'a Docx) -> Self {
+        // The default paragraph style is `\s0`.
+        let default_style = docx.styles.default_style(StyleType::Paragraph);
+        let mut styles = HashMap::new();
+        if let Some(style) = default_style {
+            styles.insert(style.style_id.as_str(), 0);
+        }
+        for style in &docx.styles.styles {
+            if matches!(
+                style.style_type,
+                StyleType::Paragraph | StyleType::Character
+            ) && !styles.contains_key(style.style_id.as_str())
+            {
+                let number = styles.len() + usize::from(default_style.is_none());
+                styles.insert(style.style_id.as_str(), number);
+            }
+        }
+        Self {
+            docx,
+            fonts: vec![],
+            colors: vec![],
+            styles,
+            labels: ListLabelResolver::new(&docx.numberings, &docx.styles),
+            lookup: NumberingLookup::new(&docx.numberings, &docx.styles),
+            lists: BTreeSet::new(),
+        }
+    }
+
+    fn font(&mut self, name: &str) -> usize {
+        match self.fonts.iter().position(|f| f == name) {
+            Some(index) => index,
+            None => {
+                self.fonts.push(name.to_owned());
+                self.fonts.len() - 1
+            }
+        }
+    }
+
+    fn color(&mut self, hex: &str) -> usize {
+        let hex = hex.to_uppercase();
+        match self.colors.iter().position(|c| *c == hex) {
+            Some(index) => index + 1,
+            None => {
+                self.colors.push(hex);
+                self.colors.len()
+            }
+        }
+    }
+
+    fn style_sheet(&mut self) -> String {
+        let mut sheet = String::from("{\\stylesheet");
+        let docx = self.docx;
+        let mut styles: Vec<(&Style, usize)> = docx
+            .styles
+            .styles
+            .iter()
+            .filter_map(|s| {
+                self.styles
+                    .get(s.style_id.as_str())
+                    .map(|number| (s, *number))
+            })
+            .collect();
+        styles.sort_by_key(|(_, number)| *number);
+        for (style, number) in styles {
+            let based_on = style
+                .based_on
+                .as_ref()
+                .and_then(|b| self.styles.get(b.get_val()))
+                .map(|n| format!("\\sbasedon{n}"))
+                .unwrap_or_default();
+            let character = self.character_format(&style.run_property);
+            let name = escape_rtf(&crate::escape::replace_escaped(style.name.escaped()));
+            if style.style_type == StyleType::Character {
+                sheet.push_str(&format!(
+                    "{{\\*\\cs{number}\\additive{character}{based_on} {name};}}"
+                ));
+            } else {
+                let paragraph = self.paragraph_format(&style.paragraph_property);
+                let next = style
+                    .next
+                    .as_ref()
+                    .and_then(|n| self.styles.get(n.get_val()))
+                    .map(|n| format!("\\snext{n}"))
+                    .unwrap_or_default();
+                sheet.push_str(&format!(
+                    "{{\\s{number}{paragraph}{character}{based_on}{next} {name};}}"
+                ));
+            }
+        }
+        sheet.push_str("}\n");
+        sheet
+    }
+
+    /// A list per numbering in use, with its overrides applied, and an
+    /// override entry that paragraphs refer to by the numbering id.
+    fn list_table(&mut self) -> String {
+        if self.lists.is_empty() {
+            return String::new();
+        }
+        let mut table = String::from("{\\*\\listtable");
+        let mut overrides = String::from("{\\*\\listoverridetable");
+        for id in std::mem::take(&mut self.lists) {
+            table.push_str("{\\list\\listtemplateid");
+            table.push_str(&id.to_string());
+            for level in self.lookup.levels(id) {
+                let format = level_number_format(&level.format.val);
+                let follow = match level.suffix {
+                    LevelSuffixType::Tab => 0,
+                    LevelSuffixType::Space => 1,
+                    LevelSuffixType::Nothing => 2,
+                };
+                let (text, numbers) = if format == 23 {
+                    let bullet = level
+                        .text
+                        .get_val()
+                        .chars()
+                        .next()
+                        .map(|c| c.to_string())
+                        .unwrap_or_default();
+                    level_text(&bullet)
+                } else {
+                    level_text(level.text.get_val())
+                };
+                let indent = self.paragraph_format(&level.paragraph_property);
+                let character = self.character_format(&level.run_property);
+                table.push_str(&format!(
+                    "{{\\listlevel\\levelnfc{format}\\levelnfcn{format}\\leveljc0\\leveljcn0\\levelfollow{follow}\\levelstartat{}\
+                     {{\\leveltext{text};}}{{\\levelnumbers{numbers};}}{character}{indent}}}",
+                    level.start.get_val()
+                ));
+            }
+            table.push_str(&format!("{{\\listname ;}}\\listid{id}}}"));
+            overrides.push_str(&format!(
+                "{{\\listoverride\\listid{id}\\listoverridecount0\\ls{id}}}"
+            ));
+        }
+        format!("{table}}}\n{overrides}}}\n")
+    }
+
+    fn section(
+        &mut self,
+        property: &SectionProperty,
+        parts: &HeadersAndFooters<'a>,
+        out: &mut String,
+    ) {
+        let size = &property.page_size;
+        let margin = &property.page_margin;
+        out.push_str(&format!(
+            "\\sectd\\sbkpage\\pgwsxn{}\\pghsxn{}\\marglsxn{}\\margrsxn{}\\margtsxn{}\\margbsxn{}\\headery{}\\footery{}",
+            size.w,
+            size.h,
+            margin.left,
+            margin.right,
+            margin.top,
+            margin.bottom,
+            margin.header,
+            margin.footer
+        ));
+        if size.orient == Some(PageOrientationType::Landscape) {
+            out.push_str("\\lndscpsxn");
+        }
+        if property.columns > 1 {
+            out.push_str(&format!(
+                "\\cols{}\\colsx{}",
+                property.columns, property.space
+            ));
+        }
+        if parts.title_pg {
+            out.push_str("\\titlepg");
+        }
+        out.push('\n');
+        let even = self.docx.settings.even_and_odd_headers;
+        let (header, footer) = if even {
+            ("headerr", "footerr")
+        } else {
+            ("header", "footer")
+        };
+        let regions = [
+            (header, parts.header.map(|h| blocks(&h.children))),
+            (
+                "headerl",
+                parts
+                    .even_header
+                    .filter(|_| even)
+                    .map(|h| blocks(&h.children)),
+            ),
+            (
+                "headerf",
+                parts
+                    .first_header
+                    .filter(|_| parts.title_pg)
+                    .map(|h| blocks(&h.children)),
+            ),
+            (footer, parts.footer.map(|f| blocks(&f.children))),
+            (
+                "footerl",
+                parts
+                    .even_footer
+                    .filter(|_| even)
+                    .map(|f| blocks(&f.children)),
+            ),
+            (
+                "footerf",
+                parts
+                    .first_footer
+                    .filter(|_| parts.title_pg)
+                    .map(|f| blocks(&f.children)),
+            ),
+        ];
+        for (destination, content) in regions {
+            if let Some(content) = content {
+                out.push_str(&format!("{{\\{destination}"));
+                self.blocks(&content, 0, out);
+                out.push_str("}\n");
+            }
+        }
+    }
+
+    /// Writes blocks, in a table `depth` levels deep.
+    fn blocks(&mut self, blocks: &[Block<'a>], depth: usize, out: &mut String) {
+        for block in blocks {
+            match block {
+                Block::Paragraph(paragraph) => self.paragraph(paragraph, depth, out),
+                Block::Table(table) => self.table(table, depth + 1, out),
+            }
+        }
+    }
+
+    fn paragraph(&mut self, paragraph: &'a Paragraph, depth: usize, out: &mut String) {
+        out.push_str("\\pard\\plain");
+        if depth > 0 {
+            out.push_str("\\intbl");
+            if depth > 1 {
+                out.push_str(&format!("\\itap{depth}"));
+            }
+        }
+        let docx = self.docx;
+        let style = match &paragraph.property.style {
+            Some(s) => docx.styles.find_style_by_id(&s.val),
+            None => docx.styles.default_style(StyleType::Paragraph),
+        };
+        if let Some(style) = style {
+            if let Some(number) = self.styles.get(style.style_id.as_str()) {
+                out.push_str(&format!("\\s{number}"));
+            }
+            // Readers do not apply the style sheet, so the formatting of the
+            // style is written as well.
+            for s in style_chain(&docx.styles, style).iter().rev() {
+                let paragraph = self.paragraph_format(&s.paragraph_property);
+                let character = self.character_format(&s.run_property);
+                out.push_str(&paragraph);
+                out.push_str(&character);
+            }
+        }
+        let mut label_text = String::new();
+        if let Some(label) = self.labels.resolve(paragraph) {
+            self.lists.insert(label.numbering_id);
+            if let Some(level) = self.lookup.level_of(paragraph) {
+                let indent = self.paragraph_format(&level.definition.paragraph_property);
+                out.push_str(&indent);
+            }
+            out.push_str(&format!("\\ls{}\\ilvl{}", label.numbering_id, label.level));
+            let separator = match label.suffix {
+                LevelSuffixType::Tab => "\\tab",
+                LevelSuffixType::Space => " ",
+                LevelSuffixType::Nothing => "",
+            };
+            label_text = format!(
+                "{{\\listtext\\pard\\plain {}{separator}}}",
+                escape_rtf(&label.text)
+            );
+        }
+        let direct = self.paragraph_format(&paragraph.property);
+        out.push_str(&direct);
+        out.push(' ');
+        out.push_str(&label_text);
+        let mut text_boxes = vec![];
+        let options = InlineOptions {
+            revisions: Some(RevisionView::Final),
+            hidden_text: true,
+        };
+        let content = self.inline_content(&inlines(self.docx, paragraph, options), &mut text_boxes);
+        out.push_str(&content);
+        out.push_str("\\par\n");
+        for children in text_boxes {
+            self.blocks(&blocks(children), depth, out);
+        }
+    }
+
+    fn inline_content(
+        &mut self,
+        inlines: &[Inline<'a>],
+        text_boxes: &mut Vec<&'a [TextBoxContentChild]>,
+    ) -> String {
+        let mut out = String::new();
+        for inline in inlines {
+            match inline {
+                Inline::Text { text, run, .. } => {
+                    let mut format = String::new();
+                    let docx = self.docx;
+                    if let Some(style) = run
+                        .run_property
+                        .style
+                        .as_ref()
+                        .and_then(|s| docx.styles.find_style_by_id(&s.val))
+                    {
+                        if let Some(number) = self.styles.get(style.style_id.as_str()) {
+                            format.push_str(&format!("\\cs{number}"));
+                        }
+                        for s in style_chain(&docx.styles, style).iter().rev() {
+                            let character = self.character_format(&s.run_property);
+                            format.push_str(&character);
+                        }
+                    }
+                    let direct = self.character_format(&run.run_property);
+                    format.push_str(&direct);
+                    if format.is_empty() {
+                        out.push_str(&escape_rtf(text));
+                    } else {
+                        out.push_str(&format!("{{{format} {}}}", escape_rtf(text)));
+                    }
+                }
+                Inline::Tab => out.push_str("\\tab "),
+                Inline::Break => out.push_str("\\line "),
+                Inline::Link { link, children } => {
+                    let content = self.inline_content(children, text_boxes);
+                    let target = link_target(self.docx, link);
+                    let instruction = match target.strip_prefix('#') {
+                        Some(anchor) => format!("HYPERLINK \\\\l \"{}\"", escape_rtf(anchor)),
+                        None => format!("HYPERLINK \"{}\"", escape_rtf(&target)),
+                    };
+                    out.push_str(&format!(
+                        "{{\\field{{\\*\\fldinst {instruction}}}{{\\fldrslt {content}}}}}"
+                    ));
+                }
+                Inline::FootnoteReference(reference) => {
+                    let mut note = String::new();
+                    self.blocks(&blocks(&reference.content), 0, &mut note);
+                    let note = note.strip_suffix("\\par\n").unwrap_or(&note);
+                    out.push_str(&format!(
+                        "{{\\super\\chftn}}{{\\footnote{{\\super\\chftn}}{note}}}"
+                    ));
+                }
+                Inline::Picture(pic) => {
+                    if let Some(pict) = self.picture(pic) {
+                        out.push_str(&pict);
+                    }
+                }
+                Inline::TextBox(children) => text_boxes.push(children),
+                Inline::CommentStart(_) | Inline::CommentEnd(_) => {}
+            }
+        }
+        out
+    }
+
+    /// A `\pict` of a PNG or JPEG image, or of the PNG preview the reader
+    /// made of other formats.
+    fn picture(&self, pic: &Pic) -> Option<String> {
+        let stored = self.docx.images.iter().find(|(id, ..)| *id == pic.id);
+        let image = if pic.image.is_empty() {
+            stored.map(|(_, _, Image(image), _)| image)?
+        } else {
+            &pic.image
+        };
+        let (blip, data) = match image_extension(image) {
+            "png" => ("\\pngblip", image),
+            "jpeg" => ("\\jpegblip", image),
+            _ => (
+                "\\pngblip",
+                stored
+                    .map(|(_, _, _, Png(png))| png)
+                    .filter(|png| !png.is_empty())?,
+            ),
+        };
+        let hex: String = data.iter().map(|b| format!("{b:02x}")).collect();
+        let lines: Vec<&str> = hex
+            .as_bytes()
+            .chunks(128)
+            .map(|line| std::str::from_utf8(line).unwrap_or_default())
+            .collect();
+        // EMU per pixel at 96 dpi and per twip.
+        Some(format!(
+            "{{\\pict{blip}\\picw{}\\pich{}\\picwgoal{}\\pichgoal{}\n{}}}",
+            pic.size.0 / 9525,
+            pic.size.1 / 9525,
+            pic.size.0 / 635,
+            pic.size.1 / 635,
+            lines.join("\n")
+        ))
+    }
+
+    fn table(&mut self, table: &'a Table, depth: usize, out: &mut String) {
+        let rows: Vec<Vec<(&TableCell, usize)>> = table
+            .rows
+            .iter()
+            .map(|TableChild::TableRow(row)| {
+                row.cells
+                    .iter()
+                    .map(|TableRowChild::TableCell(cell)| (cell, cell.property.get_grid_span()))
+                    .collect()
+            })
+            .collect();
+        let columns = rows
+            .iter()
+            .map(|row| row.iter().map(|(_, span)| span).sum::<usize>())
+            .max()
+            .unwrap_or_default()
+            .max(table.grid.len());
+        // Columns without a grid width share the width of a default page.
+        let default_width = 9000 / columns.max(1);
+        let mut boundaries = vec![];
+        let mut right = 0;
+        for column in 0..columns {
+            right += table.grid.get(column).copied().unwrap_or(default_width);
+            boundaries.push(right);
+        }
+        let borders = if table.property.has_borders() {
+            "\\clbrdrt\\brdrs\\brdrw10\\clbrdrl\\brdrs\\brdrw10\\clbrdrb\\brdrs\\brdrw10\\clbrdrr\\brdrs\\brdrw10"
+        } else {
+            ""
+        };
+        let (cell_end, row_end) = if depth > 1 {
+            ("\\nestcell\n", "\\nestrow")
+        } else {
+            ("\\cell\n", "\\row")
+        };
+        for row in rows {
+            let mut definition = String::from("\\trowd\\trgaph108\\trleft0");
+            let mut column = 0;
+            for (cell, span) in &row {
+                match cell.property.get_vertical_merge() {
+                    Some(VMergeType::Restart) => definition.push_str("\\clvmgf"),
+                    Some(VMergeType::Continue) => definition.push_str("\\clvmrg"),
+                    _ => {}
+                }
+                definition.push_str(borders);
+                column = (column + span).min(columns);
+                let right = column
+                    .checked_sub(1)
+                    .and_then(|c| boundaries.get(c))
+                    .copied()
+                    .unwrap_or_default();
+                definition.push_str(&format!("\\cellx{right}"));
+            }
+            if depth == 1 {
+                out.push_str(&definition);
+                out.push('\n');
+            }
+            for (cell, _) in row {
+                let start = out.len();
+                self.blocks(&blocks(&cell.children), depth, out);
+                // The last paragraph of a cell ends it instead of `\par`.
+                if out[start..].ends_with("\\par\n") {
+                    out.truncate(out.len() - "\\par\n".len());
+                } else {
+                    out.push_str("\\pard\\plain\\intbl");
+                    if depth > 1 {
+                        out.push_str(&format!("\\itap{depth}"));
+                    }
+                    out.push(' ');
+                }
+                out.push_str(cell_end);
+            }
+            if depth == 1 {
+                out.push_str(&format!("{definition}{row_end}\n"));
+            } else {
+                out.push_str(&format!(
+                    "{{\\*\\nesttableprops{definition}{row_end}}}{{\\nonesttables\\par}}\n"
+                ));
+            }
+        }
+    }
+
+    fn paragraph_format(&mut self, p: &ParagraphProperty) -> String {
+        let mut format = String::new();
+        if let Some(alignment) = &p.alignment {
+            format.push_str(match alignment.val.as_str() {
+                "center" => "\\qc",
+                "right" | "end" => "\\qr",
+                "both" | "distribute" => "\\qj",
+                _ => "\\ql",
+            });
+        }
+        if let Some(indent) = &p.indent {
+            if let Some(start) = indent.start {
+                format.push_str(&format!("\\li{start}"));
+            }
+            if let Some(end) = indent.end {
+                format.push_str(&format!("\\ri{end}"));
+            }
+            match indent.special_indent {
+                Some(SpecialIndentType::FirstLine(v)) => format.push_str(&format!("\\fi{v}")),
+                Some(SpecialIndentType::Hanging(v)) => format.push_str(&format!("\\fi{}", -v)),
+                None => {}
+            }
+        }
+        if let Some(spacing) = &p.line_spacing {
+            if let Some(before) = spacing.get_before() {
+                format.push_str(&format!("\\sb{before}"));
+            }
+            if let Some(after) = spacing.get_after() {
+                format.push_str(&format!("\\sa{after}"));
+            }
+            if let Some(line) = spacing.get_line() {
+                match spacing.get_line_rule() {
+                    Some(LineSpacingType::Exact) => {
+                        format.push_str(&format!("\\sl-{line}\\slmult0"))
+                    }
+                    Some(LineSpacingType::AtLeast) => {
+                        format.push_str(&format!("\\sl{line}\\slmult0"))
+                    }
+                    _ => format.push_str(&format!("\\sl{line}\\slmult1")),
+                }
+            }
+        }
+        if let Some(shading) = p.shading.as_ref().filter(|s| is_color(&s.fill)) {
+            let color = self.color(&shading.fill);
+            format.push_str(&format!("\\cbpat{color}"));
+        }
+        if p.keep_next == Some(true) {
+            format.push_str("\\keepn");
+        }
+        if p.keep_lines == Some(true) {
+            format.push_str("\\keep");
+        }
+        if p.page_break_before == Some(true) {
+            format.push_str("\\pagebb");
+        }
+        match p.widow_control {
+            Some(true) => format.push_str("\\widctlpar"),
+            Some(false) => format.push_str("\\nowidctlpar"),
+            None => {}
+        }
+        if p.bidi == Some(true) {
+            format.push_str("\\rtlpar");
+        }
+        if let Some(level) = p.outline_lvl.as_ref().filter(|l| l.v < 9) {
+            format.push_str(&format!("\\outlinelevel{}", level.v));
+        }
+        format
+    }
+
+    fn character_format(&mut self, r: &RunProperty) -> String {
+        let mut format = String::new();
+        if let Some(bold) = &r.bold {
+            format.push_str(if bold.get_val() { "\\b" } else { "\\b0" });
+        }
+        if let Some(italic) = &r.italic {
+            format.push_str(if italic.get_val() { "\\i" } else { "\\i0" });
+        }
+        if let Some(underline) = &r.underline {
+            format.push_str(match underline.get_val() {
+                "none" => "\\ulnone",
+                "double" => "\\uldb",
+                "dotted" => "\\uld",
+                "dash" => "\\uldash",
+                "wave" => "\\ulwave",
+                "words" => "\\ulw",
+                "thick" => "\\ulth",
+                _ => "\\ul",
+            });
+        }
+        if let Some(strike) = &r.strike {
+            format.push_str(if strike.val { "\\strike" } else { "\\strike0" });
+        }
+        if let Some(dstrike) = &r.dstrike {
+            format.push_str(if dstrike.val {
+                "\\striked1"
+            } else {
+                "\\striked0"
+            });
+        }
+        if let Some(vert_align) = &r.vert_align {
+            format.push_str(match vert_align.get_val() {
+                VertAlignType::SuperScript => "\\super",
+                VertAlignType::SubScript => "\\sub",
+                _ => "\\nosupersub",
+            });
+        }
+        if let Some(sz) = &r.sz {
+            format.push_str(&format!("\\fs{}", sz.get_val()));
+        }
+        if let Some(color) = r.color.as_ref().filter(|c| is_color(c.get_val())) {
+            let index = self.color(color.get_val());
+            format.push_str(&format!("\\cf{index}"));
+        }
+        if let Some(shading) = r.shading.as_ref().filter(|s| is_color(&s.fill)) {
+            let index = self.color(&shading.fill);
+            format.push_str(&format!("\\chcbpat{index}"));
+        }
+        if let Some(highlight) = r
+            .highlight
+            .as_ref()
+            .and_then(|h| highlight_rgb(h.get_val()))
+        {
+            let index = self.color(highlight);
+            format.push_str(&format!("\\highlight{index}"));
+        }
+        if let Some(fonts) = &r.fonts {
+            if let Some(font) = fonts.get_ascii().or(fonts.get_hi_ansi()) {
+                let index = self.font(font);
+                format.push_str(&format!("\\f{index}"));
+            }
+            if let Some(font) = fonts.get_east_asia() {
+                let index = self.font(font);
+                format.push_str(&format!("\\af{index}"));
+            }
+        }
+        if let Some(caps) = &r.caps {
+            format.push_str(if caps.get_val() { "\\caps" } else { "\\caps0" });
+        }
+        if let Some(small_caps) = &r.small_caps {
+            format.push_str(if small_caps.get_val() {
+                "\\scaps"
+            } else {
+                "\\scaps0"
+            });
+        }
+        if r.vanish.is_some() {
+            format.push_str("\\v");
+        }
+        format
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_escape_rtf() {
+        assert_eq!(escape_rtf("a{b}\\c"), "a\\{b\\}\\\\c");
+        assert_eq!(escape_rtf("é😀"), "\\u233?\\u-10179?\\u-8704?");
+        assert_eq!(
+            level_text("%1.%2)"),
+            ("\\'04\\'00.\\'01)".to_owned(), "\\'01\\'03".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_to_rtf() {
+        let docx = Docx::new()
+            .add_style(
+                Style::new("Heading1", StyleType::Paragraph)
+                    .name("Heading 1")
+                    .bold(),
+            )
+            .add_abstract_numbering(AbstractNumbering::new(2).add_level(Level::new(
+                0,
+                Start::new(1),
+                NumberFormat::new("decimal"),
+                LevelText::new("%1."),
+                LevelJc::new("left"),
+            )))
+            .add_numbering(Numbering::new(2, 2))
+            .header(
+                Header::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Head"))),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading1")
+                    .add_run(Run::new().add_text("Title")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .align(AlignmentType::Center)
+                    .add_run(
+                        Run::new()
+                            .add_text("Red")
+                            .color("FF0000")
+                            .fonts(RunFonts::new().ascii("Arial")),
+                    )
+                    .add_run(
+                        Run::new()
+                            .add_footnote_reference(Footnote::new().add_content(
+                                Paragraph::new().add_run(Run::new().add_text("Note")),
+                            )),
+                    ),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("One"))
+                    .numbering(NumberingId::new(2), IndentLevel::new(0)),
+            )
+            .add_table(Table::new(vec![
+                TableRow::new(vec![
+                    TableCell::new()
+                        .vertical_merge(VMergeType::Restart)
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("A"))),
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("B"))),
+                ]),
+                TableRow::new(vec![
+                    TableCell::new().vertical_merge(VMergeType::Continue),
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("C"))),
+                ]),
+            ]));
+        let rtf = docx.to_rtf();
+        assert!(rtf.starts_with("{\\rtf1\\ansi"));
+        assert!(rtf.ends_with("}\n"));
+        assert!(rtf.contains("{\\fonttbl{\\f0\\fnil Times New Roman;}{\\f1\\fnil Arial;}}\n"));
+        assert!(rtf.contains("{\\colortbl;\\red255\\green0\\blue0;}\n"));
+        assert!(rtf.contains("{\\s1\\b Heading 1;}"));
+        assert!(rtf.contains("{\\header\\pard\\plain Head\\par\n}\n"));
+        assert!(rtf.contains("\\pard\\plain\\s1\\b Title\\par\n"));
+        assert!(rtf.contains(
+            "\\pard\\plain\\qc {\\cf1\\f1 Red}{\\super\\chftn}{\\footnote{\\super\\chftn}\\pard\\plain Note}\\par\n"
+        ));
+        assert!(rtf.contains(
+            "{\\listlevel\\levelnfc0\\levelnfcn0\\leveljc0\\leveljcn0\\levelfollow0\\levelstartat1{\\leveltext\\'02\\'00.;}{\\levelnumbers\\'01;}"
+        ));
+        assert!(rtf.contains("{\\listoverride\\listid2\\listoverridecount0\\ls2}"));
+        assert!(rtf.contains("\\ls2\\ilvl0 {\\listtext\\pard\\plain 1.\\tab}One\\par\n"));
+        assert!(rtf.contains(
+            "\\trowd\\trgaph108\\trleft0\\clvmgf\\clbrdrt\\brdrs\\brdrw10\\clbrdrl\\brdrs\\brdrw10\\clbrdrb\\brdrs\\brdrw10\\clbrdrr\\brdrs\\brdrw10\\cellx4500"
+        ));
+        assert!(rtf.contains("\\pard\\plain\\intbl A\\cell\n"));
+        assert!(rtf.contains("\\pard\\plain\\intbl \\cell\n\\pard\\plain\\intbl C\\cell\n"));
+    }
+}
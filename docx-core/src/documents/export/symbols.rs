@@ -0,0 +1,116 @@
+//! Maps `w:sym` characters of symbol fonts to Unicode.
+//!
+//! Symbol fonts put their glyphs at ASCII positions, which Word stores in the
+//! private use area from `F000`. The common glyphs of Symbol and Wingdings
+//! have Unicode equivalents; others are dropped.
+
+const SYMBOL: [(u8, char); 30] = [
+    (0x22, '∀'),
+    (0x24, '∃'),
+    (0x27, '∋'),
+    (0xA2, '′'),
+    (0xA3, '≤'),
+    (0xA5, '∞'),
+    (0xAC, '←'),
+    (0xAD, '↑'),
+    (0xAE, '→'),
+    (0xAF, '↓'),
+    (0xB0, '°'),
+    (0xB1, '±'),
+    (0xB2, '″'),
+    (0xB3, '≥'),
+    (0xB4, '×'),
+    (0xB6, '∂'),
+    (0xB7, '•'),
+    (0xB8, '÷'),
+    (0xB9, '≠'),
+    (0xBB, '≈'),
+    (0xBC, '…'),
+    (0xC6, '∅'),
+    (0xCE, '∈'),
+    (0xD2, '®'),
+    (0xD3, '©'),
+    (0xD4, '™'),
+    (0xD5, '∏'),
+    (0xD6, '√'),
+    (0xE5, '∑'),
+    (0xF2, '∫'),
+];
+
+/// Greek letters of the Symbol font for `A` to `Z`, then `a` to `z`.
+const SYMBOL_GREEK: [&str; 2] = ["ΑΒΧΔΕΦΓΗΙϑΚΛΜΝΟΠΘΡΣΤΥςΩΞΨΖ", "αβχδεφγηιϕκλμνοπθρστυϖωξψζ"];
+
+const WINGDINGS: [(u8, char); 18] = [
+    (0x28, '☎'),
+    (0x2A, '✉'),
+    (0x46, '☞'),
+    (0x4A, '☺'),
+    (0x4C, '☹'),
+    (0x6C, '●'),
+    (0x6E, '■'),
+    (0x71, '❑'),
+    (0x75, '◆'),
+    (0x9F, '•'),
+    (0xA7, '▪'),
+    (0xA8, '◻'),
+    (0xD8, '➢'),
+    (0xE8, '➔'),
+    (0xFB, '✘'),
+    (0xFC, '✔'),
+    (0xFD, '☒'),
+    (0xFE, '☑'),
+];
+
+/// The character a `w:sym` with the given font and hex code shows.
+pub(crate) fn symbol_char(font: &str, code: &str) -> Option<char> {
+    let code = u32::from_str_radix(code, 16).ok()?;
+    let code = if (0xF000..=0xF0FF).contains(&code) {
+        code - 0xF000
+    } else {
+        code
+    };
+    let font = font.to_lowercase();
+    let byte = u8::try_from(code).ok();
+    if font == "symbol" {
+        if let Some(byte) = byte {
+            if let Some(c) = greek(byte) {
+                return Some(c);
+            }
+            if let Some((_, c)) = SYMBOL.iter().find(|(b, _)| *b == byte) {
+                return Some(*c);
+            }
+        }
+    } else if font.starts_with("wingdings") {
+        return byte.and_then(|byte| WINGDINGS.iter().find(|(b, _)| *b == byte).map(|(_, c)| *c));
+    }
+    char::from_u32(code).filter(|c| !c.is_control() && !is_private_use(*c))
+}
+
+fn greek(byte: u8) -> Option<char> {
+    match byte {
+        b'A'..=b'Z' => SYMBOL_GREEK[0].chars().nth((byte - b'A') as usize),
+        b'a'..=b'z' => SYMBOL_GREEK[1].chars().nth((byte - b'a') as usize),
+        _ => None,
+    }
+}
+
+fn is_private_use(c: char) -> bool {
+    ('\u{E000}'..='\u{F8FF}').contains(&c)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_symbol_char() {
+        assert_eq!(symbol_char("Symbol", "F061"), Some('α'));
+        assert_eq!(symbol_char("Symbol", "F0B4"), Some('×'));
+        assert_eq!(symbol_char("Wingdings", "F0FC"), Some('✔'));
+        assert_eq!(symbol_char("Wingdings", "F021"), None);
+        assert_eq!(symbol_char("Arial", "2022"), Some('•'));
+    }
+}
@@ -0,0 +1,346 @@
+use super::*;
+
+/// How tables are laid out in plain text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextTableLayout {
+    /// A line per row with cells separated by tabs.
+    #[default]
+    Tabs,
+    /// Rows and cells drawn as a grid of `+`, `-` and `|`.
+    Grid,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextOptions {
+    table_layout: TextTableLayout,
+    revisions: RevisionView,
+    hidden_text: bool,
+    list_labels: bool,
+    footnotes: bool,
+    headers_and_footers: bool,
+}
+
+impl Default for TextOptions {
+    fn default() -> Self {
+        Self {
+            table_layout: TextTableLayout::default(),
+            revisions: RevisionView::default(),
+            hidden_text: false,
+            list_labels: true,
+            footnotes: true,
+            headers_and_footers: true,
+        }
+    }
+}
+
+impl TextOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn table_layout(mut self, layout: TextTableLayout) -> Self {
+        self.table_layout = layout;
+        self
+    }
+
+    pub fn revisions(mut self, view: RevisionView) -> Self {
+        self.revisions = view;
+        self
+    }
+
+    pub fn hidden_text(mut self, include: bool) -> Self {
+        self.hidden_text = include;
+        self
+    }
+
+    pub fn list_labels(mut self, include: bool) -> Self {
+        self.list_labels = include;
+        self
+    }
+
+    /// Marks footnote references as `[1]` and appends the notes.
+    pub fn footnotes(mut self, include: bool) -> Self {
+        self.footnotes = include;
+        self
+    }
+
+    pub fn headers_and_footers(mut self, include: bool) -> Self {
+        self.headers_and_footers = include;
+        self
+    }
+}
+
+impl Docx {
+    /// Extracts the text of the document with a line per paragraph, e.g. for
+    /// search indexing. Headers come first and footers and footnotes last.
+    ///
+    /// Footnotes are only known for documents built in code: `read_docx`
+    /// does not read footnote references, so a read document has none.
+    pub fn to_text(&self, options: &TextOptions) -> String {
+        let mut writer = TextWriter {
+            docx: self,
+            options,
+            labels: ListLabelResolver::new(&self.numberings, &self.styles),
+            footnotes: vec![],
+        };
+        let mut out = String::new();
+        if options.headers_and_footers {
+            for (_, header) in self.document.headers() {
+                writer.labels = ListLabelResolver::new(&self.numberings, &self.styles);
+                writer.blocks(&blocks(&header.children), &mut out);
+            }
+        }
+        writer.labels = ListLabelResolver::new(&self.numberings, &self.styles);
+        writer.blocks(&blocks(&self.document.children), &mut out);
+        if options.headers_and_footers {
+            for (_, footer) in self.document.footers() {
+                writer.labels = ListLabelResolver::new(&self.numberings, &self.styles);
+                writer.blocks(&blocks(&footer.children), &mut out);
+            }
+        }
+
+        // Notes may refer to further notes.
+        let mut i = 0;
+        while i < writer.footnotes.len() {
+            let mut note = String::new();
+            writer.blocks(&blocks(writer.footnotes[i]), &mut note);
+            out.push_str(&format!("[{}] {}", i + 1, note));
+            i += 1;
+        }
+        out
+    }
+}
+
+struct TextWriter<'a> {
+    docx: &'a Docx,
+    options: &'a TextOptions,
+    labels: ListLabelResolver<'a>,
+    footnotes: Vec<&'a [Paragraph]>,
+}
+
+impl<'a> TextWriter<'a> {
+    fn blocks(&mut self, blocks: &[Block<'a>], out: &mut String) {
+        for block in blocks {
+            match block {
+                Block::Paragraph(paragraph) => self.paragraph(paragraph, out),
+                Block::Table(table) => self.table(table, out),
+            }
+        }
+    }
+
+    fn paragraph(&mut self, paragraph: &'a Paragraph, out: &mut String) {
+        // Counters advance even when labels are not shown.
+        let label = self.labels.resolve(paragraph);
+        let options = InlineOptions {
+            revisions: Some(self.options.revisions),
+            hidden_text: self.options.hidden_text,
+        };
+        let inlines = inlines(self.docx, paragraph, options);
+        // A paragraph whose mark and content are hidden takes no line.
+        if inlines.is_empty() && !self.options.hidden_text && self.is_vanished(paragraph) {
+            return;
+        }
+        if let Some(label) = label.filter(|_| self.options.list_labels) {
+            out.push_str(&label.text);
+            out.push_str(match label.suffix {
+                LevelSuffixType::Tab => "\t",
+                LevelSuffixType::Space => " ",
+                LevelSuffixType::Nothing => "",
+            });
+        }
+        let mut text_boxes = vec![];
+        self.inlines(&inlines, out, &mut text_boxes);
+        out.push('\n');
+        for children in text_boxes {
+            self.blocks(&blocks(children), out);
+        }
+    }
+
+    fn is_vanished(&self, paragraph: &Paragraph) -> bool {
+        self.docx
+            .resolve_paragraph_properties(paragraph)
+            .run_property
+            .vanish
+            .is_some()
+    }
+
+    fn inlines(
+        &mut self,
+        inlines: &[Inline<'a>],
+        out: &mut String,
+        text_boxes: &mut Vec<&'a [TextBoxContentChild]>,
+    ) {
+        for inline in inlines {
+            match inline {
+                Inline::Text { text, .. } => out.push_str(text),
+                Inline::Tab => out.push('\t'),
+                Inline::Break => out.push('\n'),
+                Inline::Link { children, .. } => self.inlines(children, out, text_boxes),
+                Inline::FootnoteReference(reference) if self.options.footnotes => {
+                    self.footnotes.push(&reference.content);
+                    out.push_str(&format!("[{}]", self.footnotes.len()));
+                }
+                Inline::TextBox(children) => text_boxes.push(children),
+                Inline::FootnoteReference(_)
+                | Inline::Picture(_)
+                | Inline::CommentStart(_)
+                | Inline::CommentEnd(_) => {}
+            }
+        }
+    }
+
+    fn table(&mut self, table: &'a Table, out: &mut String) {
+        let mut rows: Vec<Vec<String>> = vec![];
+        for TableChild::TableRow(row) in &table.rows {
+            let mut cells = vec![];
+            for TableRowChild::TableCell(cell) in &row.cells {
+                let mut text = String::new();
+                self.blocks(&blocks(&cell.children), &mut text);
+                cells.push(text.trim_end_matches('\n').to_owned());
+            }
+            rows.push(cells);
+        }
+        match self.options.table_layout {
+            TextTableLayout::Tabs => {
+                for cells in rows {
+                    let cells: Vec<String> =
+                        cells.iter().map(|c| c.replace(['\n', '\t'], " ")).collect();
+                    out.push_str(&cells.join("\t"));
+                    out.push('\n');
+                }
+            }
+            TextTableLayout::Grid => draw_grid(&rows, out),
+        }
+    }
+}
+
+fn draw_grid(rows: &[Vec<String>], out: &mut String) {
+    let columns = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let mut widths = vec![0; columns];
+    for cells in rows {
+        for (i, cell) in cells.iter().enumerate() {
+            for line in cell.lines() {
+                widths[i] = widths[i].max(line.replace('\t', " ").chars().count());
+            }
+        }
+    }
+    let border: String = widths
+        .iter()
+        .map(|w| format!("+{}", "-".repeat(w + 2)))
+        .collect::<String>()
+        + "+\n";
+    out.push_str(&border);
+    for cells in rows {
+        let lines: Vec<Vec<String>> = (0..columns)
+            .map(|i| {
+                cells
+                    .get(i)
+                    .map(|c| c.lines().map(|l| l.replace('\t', " ")).collect())
+                    .unwrap_or_default()
+            })
+            .collect();
+        let height = lines.iter().map(|l| l.len()).max().unwrap_or(0).max(1);
+        for n in 0..height {
+            for (i, cell) in lines.iter().enumerate() {
+                let line = cell.get(n).map_or("", |l| l.as_str());
+                let padding = widths[i] - line.chars().count();
+                out.push_str(&format!("| {}{} ", line, " ".repeat(padding)));
+            }
+            out.push_str("|\n");
+        }
+        out.push_str(&border);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_to_text() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Tom & Jerry"))
+                    .add_run(Run::new().add_text(" hidden").vanish())
+                    .add_delete(Delete::new().add_run(Run::new().add_delete_text(" old")))
+                    .add_insert(Insert::new(Run::new().add_text(" new")))
+                    .add_run(Run::new().add_sym(Sym::new("Wingdings", "F0FC"))),
+            )
+            .add_paragraph(
+                Paragraph::new().add_run(
+                    Run::new().add_text("Note").add_footnote_reference(
+                        Footnote::new()
+                            .add_content(Paragraph::new().add_run(Run::new().add_text("Source"))),
+                    ),
+                ),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![
+                TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("a"))),
+                TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("bc"))),
+            ])]));
+        assert_eq!(
+            docx.to_text(&TextOptions::new()),
+            "Tom & Jerry new✔\nNote[1]\na\tbc\n[1] Source\n"
+        );
+        assert_eq!(
+            docx.to_text(
+                &TextOptions::new()
+                    .revisions(RevisionView::Original)
+                    .hidden_text(true)
+                    .footnotes(false)
+                    .table_layout(TextTableLayout::Grid)
+            ),
+            "Tom & Jerry hidden old✔\nNote\n+---+----+\n| a | bc |\n+---+----+\n"
+        );
+    }
+
+    #[test]
+    fn test_to_text_with_list_labels() {
+        let docx = Docx::new()
+            .add_abstract_numbering(AbstractNumbering::new(1).add_level(Level::new(
+                0,
+                Start::new(1),
+                NumberFormat::new("decimal"),
+                LevelText::new("%1."),
+                LevelJc::new("left"),
+            )))
+            .add_numbering(Numbering::new(1, 1))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("One"))
+                    .numbering(NumberingId::new(1), IndentLevel::new(0)),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Two"))
+                    .numbering(NumberingId::new(1), IndentLevel::new(0)),
+            );
+        assert_eq!(docx.to_text(&TextOptions::new()), "1.\tOne\n2.\tTwo\n");
+    }
+
+    #[test]
+    fn test_to_text_with_hidden_styles() {
+        let docx = Docx::new()
+            .add_style(Style::new("Hid", StyleType::Character).vanish())
+            .add_style(Style::new("HidPara", StyleType::Paragraph).vanish())
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("shown"))
+                    .add_run(Run::new().add_text(" secret").style("Hid")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .style("HidPara")
+                    .add_run(Run::new().add_text("hidden paragraph")),
+            );
+        assert_eq!(docx.to_text(&TextOptions::new()), "shown\n");
+        assert_eq!(
+            docx.to_text(&TextOptions::new().hidden_text(true)),
+            "shown secret\nhidden paragraph\n"
+        );
+    }
+}
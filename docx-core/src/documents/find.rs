@@ -0,0 +1,948 @@
+//! Finds text in a document and replaces or restyles it.
+//!
+//! Word splits the text of a paragraph into runs freely, so text is matched
+//! against the whole paragraph and may span several runs. Matches never cross
+//! paragraphs.
+
+use std::collections::HashSet;
+
+use super::paragraph_text::*;
+use crate::documents::*;
+use crate::escape::{escaped_offset, unescape_text};
+use crate::types::*;
+
+/// What to search for: literal text or a regular expression.
+#[derive(Debug, Clone)]
+pub enum SearchPattern {
+    Literal(String),
+    Regex(regex::Regex),
+}
+
+impl From<&str> for SearchPattern {
+    fn from(text: &str) -> Self {
+        SearchPattern::Literal(text.to_owned())
+    }
+}
+
+impl From<String> for SearchPattern {
+    fn from(text: String) -> Self {
+        SearchPattern::Literal(text)
+    }
+}
+
+impl From<regex::Regex> for SearchPattern {
+    fn from(regex: regex::Regex) -> Self {
+        SearchPattern::Regex(regex)
+    }
+}
+
+/// A match in the text of a paragraph.
+struct Hit {
+    start: usize,
+    end: usize,
+    groups: Vec<Option<String>>,
+    /// The replacement text with `$1`-style references expanded.
+    expanded: String,
+}
+
+impl SearchPattern {
+    pub(crate) fn is_match(&self, text: &str) -> bool {
+        !self.search(text, None).is_empty()
+    }
+
+    /// Empty matches are skipped since there is nothing to replace.
+    fn search(&self, text: &str, template: Option<&str>) -> Vec<Hit> {
+        match self {
+            SearchPattern::Literal(literal) if literal.is_empty() => vec![],
+            SearchPattern::Literal(literal) => text
+                .match_indices(literal.as_str())
+                .map(|(start, m)| Hit {
+                    start,
+                    end: start + m.len(),
+                    groups: vec![Some(m.to_owned())],
+                    expanded: template.unwrap_or_default().to_owned(),
+                })
+                .collect(),
+            SearchPattern::Regex(regex) => regex
+                .captures_iter(text)
+                .filter_map(|captures| {
+                    let m = captures.get(0).filter(|m| !m.is_empty())?;
+                    let mut expanded = String::new();
+                    if let Some(template) = template {
+                        captures.expand(template, &mut expanded);
+                    }
+                    Some(Hit {
+                        start: m.start(),
+                        end: m.end(),
+                        groups: captures
+                            .iter()
+                            .map(|g| g.map(|g| g.as_str().to_owned()))
+                            .collect(),
+                        expanded,
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The part of the package a match is in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentPart {
+    Body,
+    /// A header, by relationship id.
+    Header(String),
+    /// A footer, by relationship id.
+    Footer(String),
+    /// A footnote, by id.
+    Footnote(usize),
+    /// A comment, by id.
+    Comment(usize),
+}
+
+/// A position in the text of a paragraph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextPosition {
+    /// The index of the run among the runs holding paragraph text, i.e. the
+    /// runs of the paragraph, its hyperlinks, content controls and
+    /// insertions in document order.
+    pub run: usize,
+    /// The byte offset in the unescaped text of the run.
+    pub offset: usize,
+}
+
+/// Where text was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextMatch {
+    pub part: DocumentPart,
+    /// The indices leading to the paragraph from the top of the part. Table
+    /// cells add the row and cell index, and the contents of a table of
+    /// contents are counted as one list.
+    pub paragraph: Vec<usize>,
+    pub start: TextPosition,
+    /// The position just after the match.
+    pub end: TextPosition,
+    pub text: String,
+}
+
+/// Text found by [`Docx::replace_all`], given to [`Replacement::With`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FoundText {
+    pub text: String,
+    /// The capture groups of a regular expression, the whole match first.
+    pub groups: Vec<Option<String>>,
+    /// The formatting of the run the match starts in.
+    pub run_property: RunProperty,
+}
+
+/// Changes the formatting of found text.
+pub type FormatFn = Box<dyn Fn(RunProperty) -> RunProperty>;
+
+/// Builds the content found text is replaced with.
+pub type ContentFn = Box<dyn Fn(&FoundText) -> Vec<ParagraphChild>>;
+
+/// What found text is replaced with.
+pub enum Replacement {
+    /// Text in the formatting of the run the match starts in. With a regular
+    /// expression, `$1` or `${name}` insert capture groups.
+    Text(String),
+    /// Keeps the text and changes its formatting.
+    Format(FormatFn),
+    /// Links the text to the address it spells, e.g. a URL.
+    Hyperlink,
+    /// Content built from the found text.
+    With(ContentFn),
+}
+
+impl Replacement {
+    pub fn text(text: impl Into<String>) -> Self {
+        Replacement::Text(text.into())
+    }
+
+    pub fn format(format: impl Fn(RunProperty) -> RunProperty + 'static) -> Self {
+        Replacement::Format(Box::new(format))
+    }
+
+    pub fn hyperlink() -> Self {
+        Replacement::Hyperlink
+    }
+
+    pub fn with(build: impl Fn(&FoundText) -> Vec<ParagraphChild> + 'static) -> Self {
+        Replacement::With(Box::new(build))
+    }
+}
+
+impl From<&str> for Replacement {
+    fn from(text: &str) -> Self {
+        Replacement::text(text)
+    }
+}
+
+impl From<String> for Replacement {
+    fn from(text: String) -> Self {
+        Replacement::Text(text)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReplaceOptions {
+    track_changes: Option<(String, String)>,
+}
+
+impl ReplaceOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Records replacements as tracked deletions and insertions. Formatting
+    /// changes are not tracked.
+    pub fn track_changes(mut self, author: impl Into<String>, date: impl Into<String>) -> Self {
+        self.track_changes = Some((author.into(), date.into()));
+        self
+    }
+}
+
+impl Docx {
+    /// Finds text in the body, headers, footers, footnotes and comments, in
+    /// that order.
+    pub fn find(&self, pattern: impl Into<SearchPattern>) -> Vec<TextMatch> {
+        let pattern = pattern.into();
+        let mut finder = Finder {
+            pattern: &pattern,
+            part: DocumentPart::Body,
+            path: vec![],
+            matches: vec![],
+            notes: vec![],
+            seen_footnotes: HashSet::new(),
+            seen_comments: HashSet::new(),
+        };
+        finder.find_in_blocks(self.document.children.iter().map(BlockNode::from));
+        for (rid, header) in self.document.headers() {
+            finder.part = DocumentPart::Header(rid.clone());
+            finder.find_in_blocks(header.children.iter().map(BlockNode::from));
+        }
+        for (rid, footer) in self.document.footers() {
+            finder.part = DocumentPart::Footer(rid.clone());
+            finder.find_in_blocks(footer.children.iter().map(BlockNode::from));
+        }
+        finder.find_in_notes();
+
+        // Footnotes and comments not referred to from the tree.
+        for footnote in &self.footnotes.footnotes {
+            finder.note_footnote(footnote.id, &footnote.content);
+        }
+        for comment in &self.comments.comments {
+            finder.note_comment(&comment.id, comment);
+        }
+        finder.find_in_notes();
+        finder.matches
+    }
+
+    /// Replaces all matches in the body, headers, footers, footnotes and
+    /// comments.
+    pub fn replace_all(
+        self,
+        pattern: impl Into<SearchPattern>,
+        replacement: impl Into<Replacement>,
+    ) -> Self {
+        self.replace_all_with_options(pattern, replacement, ReplaceOptions::new())
+    }
+
+    pub fn replace_all_with_options(
+        mut self,
+        pattern: impl Into<SearchPattern>,
+        replacement: impl Into<Replacement>,
+        options: ReplaceOptions,
+    ) -> Self {
+        let mut replacer = Replacer {
+            pattern: &pattern.into(),
+            replacement: &replacement.into(),
+            options: &options,
+        };
+        self.visit_mut(&mut replacer);
+        for comment in &mut self.comments.comments {
+            visit_comment_mut(comment, &mut replacer);
+        }
+        for footnote in &mut self.footnotes.footnotes {
+            visit_footnote_mut(footnote, &mut replacer);
+        }
+        self
+    }
+}
+
+/// Content of a container such as the body or a table cell.
+pub(crate) enum BlockNode<'a> {
+    Paragraph(&'a Paragraph),
+    Table(&'a Table),
+    StructuredDataTag(&'a StructuredDataTag),
+    TableOfContents(&'a TableOfContents),
+    Section(&'a Section),
+    CommentStart(&'a CommentRangeStart),
+    CommentEnd(&'a CommentRangeEnd),
+    Other,
+}
+
+impl<'a> From<&'a DocumentChild> for BlockNode<'a> {
+    fn from(child: &'a DocumentChild) -> Self {
+        match child {
+            DocumentChild::Paragraph(p) => BlockNode::Paragraph(p),
+            DocumentChild::Table(t) => BlockNode::Table(t),
+            DocumentChild::StructuredDataTag(t) => BlockNode::StructuredDataTag(t),
+            DocumentChild::TableOfContents(t) => BlockNode::TableOfContents(t),
+            DocumentChild::Section(s) => BlockNode::Section(s),
+            DocumentChild::CommentStart(c) => BlockNode::CommentStart(c),
+            DocumentChild::CommentEnd(c) => BlockNode::CommentEnd(c),
+            _ => BlockNode::Other,
+        }
+    }
+}
+
+impl<'a> From<&'a SectionChild> for BlockNode<'a> {
+    fn from(child: &'a SectionChild) -> Self {
+        match child {
+            SectionChild::Paragraph(p) => BlockNode::Paragraph(p),
+            SectionChild::Table(t) => BlockNode::Table(t),
+            SectionChild::StructuredDataTag(t) => BlockNode::StructuredDataTag(t),
+            SectionChild::TableOfContents(t) => BlockNode::TableOfContents(t),
+            SectionChild::CommentStart(c) => BlockNode::CommentStart(c),
+            SectionChild::CommentEnd(c) => BlockNode::CommentEnd(c),
+            _ => BlockNode::Other,
+        }
+    }
+}
+
+impl<'a> From<&'a TableCellContent> for BlockNode<'a> {
+    fn from(child: &'a TableCellContent) -> Self {
+        match child {
+            TableCellContent::Paragraph(p) => BlockNode::Paragraph(p),
+            TableCellContent::Table(t) => BlockNode::Table(t),
+            TableCellContent::StructuredDataTag(t) => BlockNode::StructuredDataTag(t),
+            TableCellContent::TableOfContents(t) => BlockNode::TableOfContents(t),
+        }
+    }
+}
+
+impl<'a> From<&'a StructuredDataTagChild> for BlockNode<'a> {
+    fn from(child: &'a StructuredDataTagChild) -> Self {
+        match child {
+            StructuredDataTagChild::Paragraph(p) => BlockNode::Paragraph(p),
+            StructuredDataTagChild::Table(t) => BlockNode::Table(t),
+            StructuredDataTagChild::StructuredDataTag(t) => BlockNode::StructuredDataTag(t),
+            StructuredDataTagChild::CommentStart(c) => BlockNode::CommentStart(c),
+            StructuredDataTagChild::CommentEnd(c) => BlockNode::CommentEnd(c),
+            _ => BlockNode::Other,
+        }
+    }
+}
+
+impl<'a> From<&'a TocContent> for BlockNode<'a> {
+    fn from(child: &'a TocContent) -> Self {
+        match child {
+            TocContent::Paragraph(p) => BlockNode::Paragraph(p),
+            TocContent::Table(t) => BlockNode::Table(t),
+        }
+    }
+}
+
+impl<'a> From<&'a HeaderChild> for BlockNode<'a> {
+    fn from(child: &'a HeaderChild) -> Self {
+        match child {
+            HeaderChild::Paragraph(p) => BlockNode::Paragraph(p),
+            HeaderChild::Table(t) => BlockNode::Table(t),
+            HeaderChild::StructuredDataTag(t) => BlockNode::StructuredDataTag(t),
+        }
+    }
+}
+
+impl<'a> From<&'a FooterChild> for BlockNode<'a> {
+    fn from(child: &'a FooterChild) -> Self {
+        match child {
+            FooterChild::Paragraph(p) => BlockNode::Paragraph(p),
+            FooterChild::Table(t) => BlockNode::Table(t),
+            FooterChild::StructuredDataTag(t) => BlockNode::StructuredDataTag(t),
+        }
+    }
+}
+
+impl<'a> From<&'a CommentChild> for BlockNode<'a> {
+    fn from(child: &'a CommentChild) -> Self {
+        match child {
+            CommentChild::Paragraph(p) => BlockNode::Paragraph(p),
+            CommentChild::Table(t) => BlockNode::Table(t),
+        }
+    }
+}
+
+/// Footnotes and comments are searched after the part referring to them.
+enum Note<'a> {
+    Footnote(&'a [Paragraph]),
+    Comment(&'a [CommentChild]),
+}
+
+struct Finder<'a, 'p> {
+    pattern: &'p SearchPattern,
+    part: DocumentPart,
+    path: Vec<usize>,
+    matches: Vec<TextMatch>,
+    notes: Vec<(DocumentPart, Note<'a>)>,
+    seen_footnotes: HashSet<usize>,
+    seen_comments: HashSet<usize>,
+}
+
+impl<'a> Finder<'a, '_> {
+    fn find_in_blocks(&mut self, nodes: impl Iterator<Item = BlockNode<'a>>) {
+        for (i, node) in nodes.enumerate() {
+            self.path.push(i);
+            match node {
+                BlockNode::Paragraph(paragraph) => self.find_in_paragraph(paragraph),
+                BlockNode::Table(table) => self.find_in_table(table),
+                BlockNode::StructuredDataTag(tag) => {
+                    self.find_in_blocks(tag.children.iter().map(BlockNode::from))
+                }
+                BlockNode::TableOfContents(toc) => self.find_in_blocks(
+                    toc.before_contents
+                        .iter()
+                        .chain(&toc.after_contents)
+                        .map(BlockNode::from),
+                ),
+                BlockNode::Section(section) => {
+                    self.find_in_blocks(section.children.iter().map(BlockNode::from))
+                }
+                BlockNode::CommentStart(start) => self.note_comment(&start.id, &start.comment),
+                BlockNode::CommentEnd(_) | BlockNode::Other => {}
+            }
+            self.path.pop();
+        }
+    }
+
+    fn find_in_table(&mut self, table: &'a Table) {
+        for (r, TableChild::TableRow(row)) in table.rows.iter().enumerate() {
+            for (c, TableRowChild::TableCell(cell)) in row.cells.iter().enumerate() {
+                self.path.extend([r, c]);
+                self.find_in_blocks(cell.children.iter().map(BlockNode::from));
+                self.path.truncate(self.path.len() - 2);
+            }
+        }
+    }
+
+    fn find_in_paragraph(&mut self, paragraph: &'a Paragraph) {
+        for child in &paragraph.children {
+            if let ParagraphChild::CommentStart(start) = child {
+                self.note_comment(&start.id, &start.comment);
+            }
+        }
+        let mut runs = vec![];
+        collect_runs(&paragraph.children, &mut runs);
+        for run in &runs {
+            for child in &run.children {
+                match child {
+                    RunChild::FootnoteReference(r) => self.note_footnote(r.id, &r.content),
+                    RunChild::CommentStart(start) => self.note_comment(&start.id, &start.comment),
+                    _ => {}
+                }
+            }
+        }
+
+        let (text, segments) = text_segments(&runs);
+        for hit in self.pattern.search(&text, None) {
+            self.matches.push(TextMatch {
+                part: self.part.clone(),
+                paragraph: self.path.clone(),
+                start: position(&segments, hit.start, false),
+                end: position(&segments, hit.end, true),
+                text: text[hit.start..hit.end].to_owned(),
+            });
+        }
+    }
+
+    fn note_footnote(&mut self, id: usize, content: &'a [Paragraph]) {
+        if !content.is_empty() && self.seen_footnotes.insert(id) {
+            self.notes
+                .push((DocumentPart::Footnote(id), Note::Footnote(content)));
+        }
+    }
+
+    fn note_comment(&mut self, id: &usize, comment: &'a Comment) {
+        if !comment.children.is_empty() && self.seen_comments.insert(*id) {
+            self.notes
+                .push((DocumentPart::Comment(*id), Note::Comment(&comment.children)));
+        }
+    }
+
+    fn find_in_notes(&mut self) {
+        // Notes may refer to further notes.
+        while !self.notes.is_empty() {
+            for (part, note) in std::mem::take(&mut self.notes) {
+                self.part = part;
+                match note {
+                    Note::Footnote(content) => {
+                        self.find_in_blocks(content.iter().map(BlockNode::Paragraph))
+                    }
+                    Note::Comment(children) => {
+                        self.find_in_blocks(children.iter().map(BlockNode::from))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Converts an offset in the paragraph text to a position in a run. The end
+/// of a match belongs to the run the match ends in.
+fn position(segments: &[Segment], offset: usize, end: bool) -> TextPosition {
+    let segment = segments
+        .iter()
+        .find(|s| {
+            if end {
+                s.start < offset && offset <= s.start + s.len
+            } else {
+                s.start <= offset && offset < s.start + s.len
+            }
+        })
+        .expect("matches should be within text nodes");
+    // The text nodes of a run are next to each other in the paragraph text.
+    let run_start = segments
+        .iter()
+        .find(|s| s.run == segment.run)
+        .map_or(segment.start, |s| s.start);
+    TextPosition {
+        run: segment.run,
+        offset: offset - run_start,
+    }
+}
+
+struct Replacer<'p> {
+    pattern: &'p SearchPattern,
+    replacement: &'p Replacement,
+    options: &'p ReplaceOptions,
+}
+
+impl Replacer<'_> {
+    fn replace_in_paragraph(&self, paragraph: &mut Paragraph) {
+        let mut runs = vec![];
+        collect_runs(&paragraph.children, &mut runs);
+        let (text, segments) = text_segments(&runs);
+        let template = match self.replacement {
+            Replacement::Text(template) => Some(template.as_str()),
+            _ => None,
+        };
+        let hits = self.pattern.search(&text, template);
+        if hits.is_empty() {
+            return;
+        }
+
+        let contents = hits
+            .iter()
+            .map(|hit| {
+                let segment = segments
+                    .iter()
+                    .find(|s| s.start <= hit.start && hit.start < s.start + s.len)
+                    .expect("matches should start in a text node");
+                let found = FoundText {
+                    text: text[hit.start..hit.end].to_owned(),
+                    groups: hit.groups.clone(),
+                    run_property: runs[segment.run].run_property.clone(),
+                };
+                let content = self.content(hit, found);
+                match &self.options.track_changes {
+                    Some((author, date)) => track(content, author, date),
+                    None => content,
+                }
+            })
+            .collect();
+
+        let mut edit = ParagraphEdit {
+            hits: &hits,
+            contents,
+            replacement: self.replacement,
+            options: self.options,
+            offset: 0,
+        };
+        expand_runs(&mut paragraph.children, &mut |run| edit.split_run(run));
+    }
+
+    fn content(&self, hit: &Hit, found: FoundText) -> Vec<ParagraphChild> {
+        let run = |text: Text, run_property: RunProperty| Run {
+            run_property,
+            children: vec![RunChild::Text(text)],
+        };
+        match self.replacement {
+            Replacement::Text(_) if hit.expanded.is_empty() => vec![],
+            Replacement::Text(_) => vec![ParagraphChild::Run(Box::new(run(
+                Text::new(&hit.expanded),
+                found.run_property,
+            )))],
+            Replacement::Format(_) => vec![],
+            Replacement::Hyperlink => vec![ParagraphChild::Hyperlink(
+                Hyperlink::new(&found.text, HyperlinkType::External)
+                    .add_run(run(Text::new(&found.text), found.run_property)),
+            )],
+            Replacement::With(build) => build(&found),
+        }
+    }
+}
+
+impl DocumentTreeVisitorMut for Replacer<'_> {
+    fn visit_paragraph(&mut self, paragraph: &mut Paragraph) {
+        self.replace_in_paragraph(paragraph);
+    }
+}
+
+/// Wraps the runs of replacement content in insertions.
+fn track(content: Vec<ParagraphChild>, author: &str, date: &str) -> Vec<ParagraphChild> {
+    content
+        .into_iter()
+        .map(|child| match child {
+            ParagraphChild::Run(run) => {
+                ParagraphChild::Insert(Insert::new(*run).author(author).date(date))
+            }
+            ParagraphChild::Hyperlink(mut link) => {
+                link.children = track(link.children, author, date);
+                ParagraphChild::Hyperlink(link)
+            }
+            child => child,
+        })
+        .collect()
+}
+
+/// A part of a run's content, split at match boundaries.
+enum Piece {
+    Kept(RunChild),
+    Matched(usize, RunChild),
+    /// Where the replacement of a match goes, just after its end.
+    Replaced(usize),
+}
+
+/// Replaces the matches in the runs of one paragraph, run by run.
+struct ParagraphEdit<'a> {
+    hits: &'a [Hit],
+    contents: Vec<Vec<ParagraphChild>>,
+    replacement: &'a Replacement,
+    options: &'a ReplaceOptions,
+    /// The offset in the paragraph text the next run starts at.
+    offset: usize,
+}
+
+impl ParagraphEdit<'_> {
+    fn split_run(&mut self, run: Run) -> Vec<ParagraphChild> {
+        if run.children.is_empty() {
+            return vec![ParagraphChild::Run(Box::new(run))];
+        }
+        let Run {
+            run_property,
+            children,
+        } = run;
+
+        let mut pieces = vec![];
+        for child in children {
+            match child {
+                RunChild::Text(t) if !t.text.is_empty() => {
+                    // Offsets are in the unescaped text, and cut the stored
+                    // text between entities.
+                    let start = self.offset;
+                    let end = start + unescape_text(&t.text).len();
+                    let piece = |from: usize, to: usize| {
+                        let from = escaped_offset(&t.text, from - start);
+                        let to = escaped_offset(&t.text, to - start);
+                        RunChild::Text(Text {
+                            text: t.text[from..to].to_owned(),
+                            preserve_space: t.preserve_space,
+                        })
+                    };
+                    let mut pos = start;
+                    while pos < end {
+                        match self.hits.iter().position(|h| h.start <= pos && pos < h.end) {
+                            Some(i) => {
+                                let to = self.hits[i].end.min(end);
+                                pieces.push(Piece::Matched(i, piece(pos, to)));
+                                if self.hits[i].end == to {
+                                    pieces.push(Piece::Replaced(i));
+                                }
+                                pos = to;
+                            }
+                            None => {
+                                let to = self
+                                    .hits
+                                    .iter()
+                                    .map(|h| h.start)
+                                    .filter(|&s| s > pos)
+                                    .min()
+                                    .map_or(end, |s| s.min(end));
+                                pieces.push(Piece::Kept(piece(pos, to)));
+                                pos = to;
+                            }
+                        }
+                    }
+                    self.offset = end;
+                }
+                child => {
+                    let offset = self.offset;
+                    match self
+                        .hits
+                        .iter()
+                        .position(|h| h.start < offset && offset < h.end)
+                    {
+                        Some(i) => pieces.push(Piece::Matched(i, child)),
+                        None => pieces.push(Piece::Kept(child)),
+                    }
+                }
+            }
+        }
+
+        let mut replaced = vec![];
+        let mut pending = vec![];
+        let mut pending_hit = None;
+        for piece in pieces {
+            match piece {
+                Piece::Kept(child) => {
+                    if pending_hit.is_some() {
+                        self.flush(&run_property, &mut pending, pending_hit, &mut replaced);
+                    }
+                    pending_hit = None;
+                    pending.push(child);
+                }
+                Piece::Matched(i, child) => {
+                    if pending_hit != Some(i) {
+                        self.flush(&run_property, &mut pending, pending_hit, &mut replaced);
+                    }
+                    pending_hit = Some(i);
+                    pending.push(child);
+                }
+                Piece::Replaced(i) => {
+                    self.flush(&run_property, &mut pending, pending_hit, &mut replaced);
+                    replaced.append(&mut self.contents[i]);
+                }
+            }
+        }
+        self.flush(&run_property, &mut pending, pending_hit, &mut replaced);
+        replaced
+    }
+
+    /// Turns pieces of a run into content: kept pieces stay as they are and
+    /// matched ones are restyled, deleted or dropped.
+    fn flush(
+        &self,
+        run_property: &RunProperty,
+        pending: &mut Vec<RunChild>,
+        hit: Option<usize>,
+        replaced: &mut Vec<ParagraphChild>,
+    ) {
+        if pending.is_empty() {
+            return;
+        }
+        let children = std::mem::take(pending);
+        let run = |run_property: RunProperty, children| {
+            Box::new(Run {
+                run_property,
+                children,
+            })
+        };
+        match (hit, self.replacement, &self.options.track_changes) {
+            (None, _, _) => replaced.push(ParagraphChild::Run(run(run_property.clone(), children))),
+            (Some(_), Replacement::Format(format), _) => replaced.push(ParagraphChild::Run(run(
+                format(run_property.clone()),
+                children,
+            ))),
+            (Some(_), _, Some((author, date))) => {
+                let children = children
+                    .into_iter()
+                    .map(|child| match child {
+                        RunChild::Text(t) => {
+                            RunChild::DeleteText(DeleteText::without_escape(t.text))
+                        }
+                        child => child,
+                    })
+                    .collect();
+                replaced.push(ParagraphChild::Delete(
+                    Delete::new()
+                        .add_run(*run(run_property.clone(), children))
+                        .author(author)
+                        .date(date),
+                ));
+            }
+            (Some(_), _, None) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn paragraph_text(paragraph: &Paragraph) -> String {
+        let mut runs = vec![];
+        collect_runs(&paragraph.children, &mut runs);
+        text_segments(&runs).0
+    }
+
+    fn first_paragraph(docx: &Docx) -> &Paragraph {
+        match &docx.document.children[0] {
+            DocumentChild::Paragraph(p) => p,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_find_across_runs() {
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("intro")))
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()
+                .add_paragraph(
+                    Paragraph::new()
+                        .add_run(Run::new().add_text("Hello Wo"))
+                        .add_run(Run::new().add_text("rld, world")),
+                )])]));
+        let matches = docx.find(regex::Regex::new("(?i)world").unwrap());
+        assert_eq!(
+            matches,
+            vec![
+                TextMatch {
+                    part: DocumentPart::Body,
+                    paragraph: vec![1, 0, 0, 0],
+                    start: TextPosition { run: 0, offset: 6 },
+                    end: TextPosition { run: 1, offset: 3 },
+                    text: "World".to_owned(),
+                },
+                TextMatch {
+                    part: DocumentPart::Body,
+                    paragraph: vec![1, 0, 0, 0],
+                    start: TextPosition { run: 1, offset: 5 },
+                    end: TextPosition { run: 1, offset: 10 },
+                    text: "world".to_owned(),
+                },
+            ]
+        );
+        assert!(docx.find("").is_empty());
+    }
+
+    #[test]
+    fn test_find_in_footnotes_and_headers() {
+        let docx = Docx::new()
+            .header(
+                Header::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("a cat"))),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_footnote_reference(
+                Footnote::new().add_content(Paragraph::new().add_run(Run::new().add_text("cat"))),
+            )));
+        let parts: Vec<_> = docx.find("cat").into_iter().map(|m| m.part).collect();
+        assert_eq!(parts.len(), 2);
+        assert!(matches!(parts[0], DocumentPart::Header(_)));
+        assert!(matches!(parts[1], DocumentPart::Footnote(_)));
+    }
+
+    #[test]
+    fn test_replace_text_with_groups() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("due 2024-0").bold())
+                    .add_run(Run::new().add_text("3-01 now")),
+            )
+            .replace_all(regex::Regex::new(r"(\d+)-(\d+)-(\d+)").unwrap(), "$3/$2/$1");
+        let paragraph = first_paragraph(&docx);
+        assert_eq!(paragraph_text(paragraph), "due 01/03/2024 now");
+        let mut runs = vec![];
+        collect_runs(&paragraph.children, &mut runs);
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[1].run_property, RunProperty::new().bold());
+    }
+
+    #[test]
+    fn test_replace_with_format_and_hyperlink() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("see https://exa"))
+                    .add_run(Run::new().add_text("mple.com please")),
+            )
+            .replace_all("see", Replacement::format(|rp| rp.highlight("yellow")))
+            .replace_all(
+                regex::Regex::new(r"https?://\S+").unwrap(),
+                Replacement::hyperlink(),
+            );
+        let paragraph = first_paragraph(&docx);
+        assert_eq!(paragraph_text(paragraph), "see https://example.com please");
+        match &paragraph.children[0] {
+            ParagraphChild::Run(run) => {
+                assert_eq!(run.run_property, RunProperty::new().highlight("yellow"))
+            }
+            _ => unreachable!(),
+        }
+        let link = paragraph
+            .children
+            .iter()
+            .find_map(|c| match c {
+                ParagraphChild::Hyperlink(link) => Some(link),
+                _ => None,
+            })
+            .unwrap();
+        assert!(matches!(
+            &link.link,
+            HyperlinkData::External { path, .. } if path == "https://example.com"
+        ));
+    }
+
+    #[test]
+    fn test_replace_with_tracked_changes() {
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("old text")))
+            .replace_all_with_options(
+                "old",
+                "new",
+                ReplaceOptions::new().track_changes("editor", "2024-03-01T00:00:00Z"),
+            );
+        let paragraph = first_paragraph(&docx);
+        assert_eq!(paragraph_text(paragraph), "new text");
+        assert!(matches!(
+            &paragraph.children[0],
+            ParagraphChild::Delete(delete) if delete.author == "editor"
+        ));
+        assert!(matches!(
+            &paragraph.children[1],
+            ParagraphChild::Insert(insert) if insert.date == "2024-03-01T00:00:00Z"
+        ));
+    }
+
+    #[test]
+    fn test_find_and_replace_escaped_characters() {
+        let docx = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("AT&T <"))
+                .add_run(Run::new().add_text("b> Tom & Jerry")),
+        );
+        assert_eq!(
+            docx.find("AT&T"),
+            vec![TextMatch {
+                part: DocumentPart::Body,
+                paragraph: vec![0],
+                start: TextPosition { run: 0, offset: 0 },
+                end: TextPosition { run: 0, offset: 4 },
+                text: "AT&T".to_owned(),
+            }]
+        );
+        assert_eq!(docx.find("<b>")[0].end, TextPosition { run: 1, offset: 2 });
+        assert!(docx.find("&amp;").is_empty());
+        assert!(docx.find("amp").is_empty());
+
+        let docx = docx
+            .replace_all("amp", "x")
+            .replace_all(
+                regex::Regex::new("m").unwrap(),
+                Replacement::format(|p| p.bold()),
+            )
+            .replace_all("<b>", ">&<");
+        assert_eq!(
+            paragraph_text(first_paragraph(&docx)),
+            "AT&T >&< Tom & Jerry"
+        );
+
+        let mut buf = std::io::Cursor::new(vec![]);
+        docx.build().pack(&mut buf).unwrap();
+        let read = crate::reader::read_docx(buf.get_ref()).expect("should read docx");
+        assert_eq!(read.find("Tom & Jerry").len(), 1);
+        assert_eq!(read.find(">&<").len(), 1);
+    }
+}
@@ -1,4 +1,4 @@
-use super::Font;
+use super::FontDeclaration;
 use crate::documents::BuildXML;
 use crate::types::FontPitchType;
 use crate::xml_builder::*;
@@ -8,12 +8,22 @@ use serde::Serialize;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct FontTable {}
+pub struct FontTable {
+    /// Fonts read from a package. Empty tables are written with Times New
+    /// Roman, Symbol and Arial.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fonts: Vec<FontDeclaration>,
+}
 
 impl FontTable {
     pub fn new() -> FontTable {
         Default::default()
     }
+
+    pub fn add_font(mut self, font: FontDeclaration) -> Self {
+        self.fonts.push(font);
+        self
+    }
 }
 
 impl BuildXML for FontTable {
@@ -22,14 +32,20 @@ impl BuildXML for FontTable {
         stream: crate::xml::writer::EventWriter<W>,
     ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
         let b = XMLBuilder::from(stream);
-        let times = Font::new("Times New Roman", "00", "roman", FontPitchType::Variable);
-        let symbol = Font::new("Symbol", "02", "roman", FontPitchType::Variable);
-        let arial = Font::new("Arial", "00", "swiss", FontPitchType::Variable);
+        let defaults;
+        let fonts = if self.fonts.is_empty() {
+            defaults = [
+                FontDeclaration::new("Times New Roman", "00", "roman", FontPitchType::Variable),
+                FontDeclaration::new("Symbol", "02", "roman", FontPitchType::Variable),
+                FontDeclaration::new("Arial", "00", "swiss", FontPitchType::Variable),
+            ];
+            &defaults[..]
+        } else {
+            &self.fonts[..]
+        };
         b.declaration(Some(true))?
             .open_fonts()?
-            .add_child(&times)?
-            .add_child(&symbol)?
-            .add_child(&arial)?
+            .add_children(fonts)?
             .close()?
             .into_inner()
     }
@@ -0,0 +1,775 @@
+//! Resolves the formatting Word actually applies to a paragraph or run.
+//!
+//! Properties are layered in the order described in 17.7.2 (Style Hierarchy):
+//! document defaults, the table style with its conditional formatting, the
+//! numbering level, the paragraph style, the character style and finally the
+//! direct formatting. Each style contributes its whole `w:basedOn` chain.
+//!
+//! Toggle properties (17.7.3) such as bold or italic do not simply override:
+//! every style type that turns one on flips the value inherited from the
+//! layers below it, so a bold character style applied inside a bold
+//! paragraph style renders as not bold. Direct formatting is absolute.
+
+use super::list_label::{NumberingLookup, MAX_STYLE_DEPTH};
+use crate::documents::*;
+use crate::types::*;
+
+/// The cell a paragraph sits in, used to pick a table style's conditional
+/// formatting (`w:tblStylePr`). `row` counts the rows of the table and
+/// `column` the cells of the row, from zero.
+///
+/// The conditions that apply follow the table's `w:tblLook`, or Word's look
+/// for new tables when it has none: the header row and the first column are
+/// emphasized and rows are banded. A paragraph `w:cnfStyle` takes precedence
+/// over the position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TableCellPosition {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl TableCellPosition {
+    pub fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+}
+
+impl Docx {
+    /// Returns the paragraph properties in effect for `paragraph` after
+    /// applying document defaults, styles and numbering.
+    ///
+    /// `run_property` of the result holds the paragraph mark formatting.
+    pub fn resolve_paragraph_properties(&self, paragraph: &Paragraph) -> ParagraphProperty {
+        FormattingResolver::new(self, None).paragraph(paragraph)
+    }
+
+    /// Returns the run properties in effect for `run` inside `paragraph`.
+    pub fn resolve_run_properties(&self, paragraph: &Paragraph, run: &Run) -> RunProperty {
+        FormattingResolver::new(self, None).run(paragraph, run)
+    }
+
+    /// Like [`Docx::resolve_paragraph_properties`] for a paragraph in the
+    /// cell of `table` at `position`.
+    pub fn resolve_paragraph_properties_in_table(
+        &self,
+        table: &Table,
+        position: TableCellPosition,
+        paragraph: &Paragraph,
+    ) -> ParagraphProperty {
+        FormattingResolver::new(self, Some((table, position))).paragraph(paragraph)
+    }
+
+    /// Like [`Docx::resolve_run_properties`] for a run in the cell of `table`
+    /// at `position`.
+    pub fn resolve_run_properties_in_table(
+        &self,
+        table: &Table,
+        position: TableCellPosition,
+        paragraph: &Paragraph,
+        run: &Run,
+    ) -> RunProperty {
+        FormattingResolver::new(self, Some((table, position))).run(paragraph, run)
+    }
+}
+
+struct FormattingResolver<'a> {
+    styles: &'a Styles,
+    numberings: NumberingLookup<'a>,
+    table: Option<(&'a Table, TableCellPosition)>,
+}
+
+impl<'a> FormattingResolver<'a> {
+    fn new(docx: &'a Docx, table: Option<(&'a Table, TableCellPosition)>) -> Self {
+        Self {
+            styles: &docx.styles,
+            numberings: NumberingLookup::new(&docx.numberings, &docx.styles),
+            table,
+        }
+    }
+
+    fn paragraph(&self, paragraph: &Paragraph) -> ParagraphProperty {
+        let mut resolved = self.styles.doc_defaults.get_paragraph_property().clone();
+        for p in self.table_paragraph_properties(paragraph) {
+            overlay_paragraph(&mut resolved, p);
+        }
+
+        // Numbering set on the paragraph itself overrides its style, while
+        // numbering inherited from the style is overridden by it.
+        let numbering = self
+            .numberings
+            .level_of(paragraph)
+            .map(|l| &l.definition.paragraph_property);
+        let direct_numbering = paragraph.property.numbering_property.is_some();
+        if !direct_numbering {
+            if let Some(p) = numbering {
+                overlay_paragraph(&mut resolved, p);
+            }
+        }
+        let paragraph_style = self.paragraph_style(paragraph);
+        for style in &paragraph_style {
+            overlay_paragraph(&mut resolved, &style.paragraph_property);
+        }
+        if direct_numbering {
+            if let Some(p) = numbering {
+                overlay_paragraph(&mut resolved, p);
+            }
+        }
+        overlay_paragraph(&mut resolved, &paragraph.property);
+
+        if let Some(style) = paragraph_style.last() {
+            resolved.style = Some(ParagraphStyle::new(Some(&style.style_id)));
+        }
+        resolved.run_property = self.cascade_runs(paragraph, &[], &paragraph.property.run_property);
+        resolved
+    }
+
+    fn run(&self, paragraph: &Paragraph, run: &Run) -> RunProperty {
+        let character_style = self.character_style(&run.run_property);
+        let mut resolved = self.cascade_runs(
+            paragraph,
+            &character_style
+                .iter()
+                .map(|s| &s.run_property)
+                .collect::<Vec<_>>(),
+            &run.run_property,
+        );
+        if let Some(style) = character_style.last() {
+            resolved.style = Some(RunStyle::new(&style.style_id));
+        }
+        resolved
+    }
+
+    fn cascade_runs(
+        &self,
+        paragraph: &Paragraph,
+        character_style: &[&RunProperty],
+        direct: &RunProperty,
+    ) -> RunProperty {
+        let mut resolved = self.styles.doc_defaults.get_run_property().clone();
+        let style_types = [
+            self.table_run_properties(paragraph),
+            self.paragraph_style(paragraph)
+                .iter()
+                .map(|s| &s.run_property)
+                .collect(),
+            character_style.to_vec(),
+        ];
+        for chain in style_types {
+            let mut style_type = RunProperty::new();
+            for r in chain {
+                overlay_run(&mut style_type, r);
+            }
+            apply_style_type(&mut resolved, &style_type);
+        }
+        overlay_run(&mut resolved, direct);
+        resolved.del = direct.del.clone();
+        resolved.ins = direct.ins.clone();
+        resolved.run_property_change = direct.run_property_change.clone();
+        resolved
+    }
+
+    fn paragraph_style(&self, paragraph: &Paragraph) -> Vec<&'a Style> {
+        let style = paragraph
+            .property
+            .style
+            .as_ref()
+            .and_then(|s| self.styles.find_style_by_id(&s.val))
+            .or_else(|| self.styles.default_style(StyleType::Paragraph));
+        style.map(|s| self.chain(s)).unwrap_or_default()
+    }
+
+    // A paragraph style used as `w:rStyle` stands for its linked character
+    // style.
+    fn character_style(&self, run_property: &RunProperty) -> Vec<&'a Style> {
+        let style = match run_property
+            .style
+            .as_ref()
+            .and_then(|s| self.styles.find_style_by_id(&s.val))
+        {
+            Some(s) if s.style_type == StyleType::Character => Some(s),
+            Some(s) => s
+                .link
+                .as_ref()
+                .and_then(|l| self.styles.find_style_by_id(l.get_val()))
+                .filter(|l| l.style_type == StyleType::Character),
+            None => self.styles.default_style(StyleType::Character),
+        };
+        style.map(|s| self.chain(s)).unwrap_or_default()
+    }
+
+    fn table_style(&self) -> Vec<&'a Style> {
+        let Some((table, _)) = self.table else {
+            return vec![];
+        };
+        let style = table
+            .property
+            .get_style()
+            .and_then(|s| self.styles.find_style_by_id(s.get_val()))
+            .or_else(|| self.styles.default_style(StyleType::Table));
+        style.map(|s| self.chain(s)).unwrap_or_default()
+    }
+
+    // Returns `style` and the styles it is based on, the base first.
+    fn chain(&self, style: &'a Style) -> Vec<&'a Style> {
+        let mut chain = vec![style];
+        let mut current = style;
+        // basedOn chains are short; the bound guards against cycles.
+        while chain.len() < MAX_STYLE_DEPTH {
+            let Some(base) = current
+                .based_on
+                .as_ref()
+                .and_then(|b| self.styles.find_style_by_id(b.get_val()))
+            else {
+                break;
+            };
+            chain.push(base);
+            current = base;
+        }
+        chain.reverse();
+        chain
+    }
+
+    fn table_paragraph_properties(&self, paragraph: &Paragraph) -> Vec<&'a ParagraphProperty> {
+        self.table_layers(paragraph)
+            .into_iter()
+            .map(|(ppr, _)| ppr)
+            .collect()
+    }
+
+    fn table_run_properties(&self, paragraph: &Paragraph) -> Vec<&'a RunProperty> {
+        self.table_layers(paragraph)
+            .into_iter()
+            .map(|(_, rpr)| rpr)
+            .collect()
+    }
+
+    // The table style's own properties followed by the conditional
+    // formatting that applies to the cell, lowest priority first.
+    fn table_layers(&self, paragraph: &Paragraph) -> Vec<(&'a ParagraphProperty, &'a RunProperty)> {
+        let Some((table, position)) = self.table else {
+            return vec![];
+        };
+        let chain = self.table_style();
+        let mut layers = vec![];
+        for style in &chain {
+            layers.push((&style.paragraph_property, &style.run_property));
+        }
+        let conditions = paragraph
+            .property
+            .cnf_style
+            .as_ref()
+            .map(|c| conditions_of_cnf_style(&c.val))
+            .unwrap_or_else(|| conditions_of_position(table, position));
+        for condition in std::iter::once(TableStyleOverrideType::WholeTable).chain(conditions) {
+            for style in &chain {
+                for o in style
+                    .table_style_overrides
+                    .iter()
+                    .filter(|o| o.override_type == condition)
+                {
+                    layers.push((&o.paragraph_property, &o.run_property));
+                }
+            }
+        }
+        layers
+    }
+}
+
+// Conditional formatting in the order it is applied.
+const CONDITIONS: [TableStyleOverrideType; 12] = [
+    TableStyleOverrideType::Band1Vert,
+    TableStyleOverrideType::Band2Vert,
+    TableStyleOverrideType::Band1Horz,
+    TableStyleOverrideType::Band2Horz,
+    TableStyleOverrideType::FirstCol,
+    TableStyleOverrideType::LastCol,
+    TableStyleOverrideType::FirstRow,
+    TableStyleOverrideType::LastRow,
+    TableStyleOverrideType::NwCell,
+    TableStyleOverrideType::NeCell,
+    TableStyleOverrideType::SwCell,
+    TableStyleOverrideType::SeCell,
+];
+
+// `w:cnfStyle` lists first row, last row, first column, last column, odd and
+// even vertical bands, odd and even horizontal bands, then the corner cells
+// nw, ne, sw and se.
+fn conditions_of_cnf_style(val: &str) -> Vec<TableStyleOverrideType> {
+    use TableStyleOverrideType::*;
+    let order = [
+        FirstRow, LastRow, FirstCol, LastCol, Band1Vert, Band2Vert, Band1Horz, Band2Horz, NwCell,
+        NeCell, SwCell, SeCell,
+    ];
+    let set: Vec<_> = order
+        .into_iter()
+        .zip(val.chars())
+        .filter(|(_, c)| *c == '1')
+        .map(|(t, _)| t)
+        .collect();
+    CONDITIONS.into_iter().filter(|c| set.contains(c)).collect()
+}
+
+fn conditions_of_position(
+    table: &Table,
+    position: TableCellPosition,
+) -> Vec<TableStyleOverrideType> {
+    use TableStyleOverrideType::*;
+    let look = table.property.get_look().copied().unwrap_or_default();
+    let columns = table
+        .rows
+        .get(position.row)
+        .map_or(0, |TableChild::TableRow(row)| row.cells.len());
+    let first_row = look.get_first_row() && position.row == 0;
+    let last_row = look.get_last_row() && position.row + 1 == table.rows.len();
+    let first_col = look.get_first_column() && position.column == 0;
+    let last_col = look.get_last_column() && position.column + 1 == columns;
+    let mut set = vec![];
+    // The header row and first column are not part of the banding.
+    if !look.get_no_h_band() && !first_row {
+        let band = position.row - usize::from(look.get_first_row());
+        set.push(if band % 2 == 0 { Band1Horz } else { Band2Horz });
+    }
+    if !look.get_no_v_band() && !first_col {
+        let band = position.column - usize::from(look.get_first_column());
+        set.push(if band % 2 == 0 { Band1Vert } else { Band2Vert });
+    }
+    for (on, condition) in [
+        (first_col, FirstCol),
+        (last_col, LastCol),
+        (first_row, FirstRow),
+        (last_row, LastRow),
+        (first_row && first_col, NwCell),
+        (first_row && last_col, NeCell),
+        (last_row && first_col, SwCell),
+        (last_row && last_col, SeCell),
+    ] {
+        if on {
+            set.push(condition);
+        }
+    }
+    CONDITIONS.into_iter().filter(|c| set.contains(c)).collect()
+}
+
+macro_rules! overlay_fields {
+    ($base:ident, $top:ident, $($field:ident),* $(,)?) => {
+        $(
+            if $top.$field.is_some() {
+                $base.$field = $top.$field.clone();
+            }
+        )*
+    };
+}
+
+fn overlay_paragraph(base: &mut ParagraphProperty, top: &ParagraphProperty) {
+    overlay_fields!(
+        base,
+        top,
+        style,
+        numbering_property,
+        alignment,
+        keep_next,
+        keep_lines,
+        bidi,
+        page_break_before,
+        widow_control,
+        outline_lvl,
+        section_property,
+        paragraph_property_change,
+        borders,
+        frame_property,
+        text_alignment,
+        adjust_right_ind,
+        snap_to_grid,
+        shading,
+        contextual_spacing,
+        mirror_indents,
+        suppress_auto_hyphens,
+        suppress_line_numbers,
+        suppress_overlap,
+        word_wrap,
+        kinsoku,
+        overflow_punct,
+        top_line_punct,
+        auto_space_de,
+        auto_space_dn,
+        text_direction,
+        textbox_tight_wrap,
+        cnf_style,
+        div_id,
+    );
+    if let Some(top) = &top.indent {
+        let indent = base
+            .indent
+            .get_or_insert_with(|| Indent::new(None, None, None, None));
+        overlay_fields!(
+            indent,
+            top,
+            start,
+            end,
+            special_indent,
+            start_chars,
+            hanging_chars,
+            first_line_chars,
+        );
+    }
+    if let Some(top) = &top.line_spacing {
+        base.line_spacing
+            .get_or_insert_with(Default::default)
+            .overlay(top);
+    }
+    // Tab stops accumulate; a `clear` stop removes an inherited one.
+    for tab in &top.tabs {
+        base.tabs.retain(|t| t.pos != tab.pos);
+        if tab.val != Some(TabValueType::Clear) {
+            base.tabs.push(tab.clone());
+        }
+    }
+    base.tabs.sort_by_key(|t| t.pos);
+}
+
+fn overlay_run(base: &mut RunProperty, top: &RunProperty) {
+    overlay_fields!(
+        base,
+        top,
+        style,
+        sz,
+        sz_cs,
+        color,
+        highlight,
+        vert_align,
+        underline,
+        spec_vanish,
+        character_spacing,
+        fit_text,
+        stretch,
+        text_border,
+        dstrike,
+        positional_tab,
+        shading,
+        no_proof,
+        snap_to_grid,
+        web_hidden,
+        kern,
+        position,
+        effect,
+        rtl,
+        complex_script,
+        emphasis_mark,
+        lang,
+        east_asian_layout,
+        o_math,
+        glow,
+        text_shadow,
+        reflection,
+        text_outline,
+        text_fill,
+        ligatures,
+        number_form,
+        number_spacing,
+        stylistic_sets,
+        contextual_alternatives,
+    );
+    if let Some(top) = &top.fonts {
+        base.fonts.get_or_insert_with(Default::default).overlay(top);
+    }
+    for toggle in TOGGLES {
+        if let Some(v) = (toggle.get)(top) {
+            (toggle.set)(base, v);
+        }
+    }
+}
+
+// Non-toggle properties override; a toggle property turned on by the style
+// type flips the value below it.
+fn apply_style_type(base: &mut RunProperty, style_type: &RunProperty) {
+    let inherited: Vec<_> = TOGGLES.iter().map(|t| (t.get)(base)).collect();
+    overlay_run(base, style_type);
+    for (toggle, inherited) in TOGGLES.iter().zip(inherited) {
+        match ((toggle.get)(style_type), inherited) {
+            (Some(true), inherited) => (toggle.set)(base, !inherited.unwrap_or(false)),
+            (_, Some(inherited)) => (toggle.set)(base, inherited),
+            _ => {}
+        }
+    }
+}
+
+struct Toggle {
+    get: fn(&RunProperty) -> Option<bool>,
+    set: fn(&mut RunProperty, bool),
+}
+
+macro_rules! toggle {
+    ($field:ident, $ty:ident) => {
+        Toggle {
+            get: |r| r.$field.as_ref().map(|v| v.get_val()),
+            set: |r, v| r.$field = Some(if v { $ty::new() } else { $ty::new().disable() }),
+        }
+    };
+}
+
+// 17.7.3 Toggle (Style) Properties
+const TOGGLES: [Toggle; 12] = [
+    toggle!(bold, Bold),
+    toggle!(bold_cs, BoldCs),
+    toggle!(italic, Italic),
+    toggle!(italic_cs, ItalicCs),
+    toggle!(caps, Caps),
+    toggle!(small_caps, SmallCaps),
+    toggle!(strike, Strike),
+    toggle!(emboss, Emboss),
+    toggle!(imprint, Imprint),
+    toggle!(outline, Outline),
+    toggle!(shadow, Shadow),
+    // `w:vanish` has no off state in this crate.
+    Toggle {
+        get: |r| r.vanish.as_ref().map(|_| true),
+        set: |r, v| r.vanish = v.then(Vanish::new),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn bold(r: &RunProperty) -> Option<bool> {
+        r.bold.as_ref().map(|b| b.get_val())
+    }
+
+    #[test]
+    fn test_resolve_paragraph_properties_through_styles() {
+        let docx = Docx::new()
+            .default_line_spacing(LineSpacing::new().line(240))
+            .add_style(
+                Style::new("Base", StyleType::Paragraph)
+                    .indent(Some(720), None, None, None)
+                    .align(AlignmentType::Center),
+            )
+            .add_style(
+                Style::new("Derived", StyleType::Paragraph)
+                    .based_on("Base")
+                    .line_spacing(LineSpacing::new().after(120)),
+            );
+        let paragraph = Paragraph::new()
+            .style("Derived")
+            .indent(None, None, Some(360), None);
+
+        let resolved = docx.resolve_paragraph_properties(&paragraph);
+
+        assert_eq!(
+            resolved.alignment,
+            Some(Justification::new(AlignmentType::Center.to_string()))
+        );
+        let indent = resolved.indent.unwrap();
+        assert_eq!(indent.start, Some(720));
+        assert_eq!(indent.end, Some(360));
+        let spacing = resolved.line_spacing.unwrap();
+        assert_eq!(spacing.get_line(), Some(240));
+        assert_eq!(spacing.get_after(), Some(120));
+        assert_eq!(resolved.style.unwrap().val, "Derived");
+    }
+
+    #[test]
+    fn test_direct_numbering_overrides_style_indent() {
+        let docx = Docx::new()
+            .add_style(Style::new("ListParagraph", StyleType::Paragraph).indent(
+                Some(720),
+                None,
+                None,
+                None,
+            ))
+            .add_abstract_numbering(
+                AbstractNumbering::new(2).add_level(
+                    Level::new(
+                        0,
+                        Start::new(1),
+                        NumberFormat::new("decimal"),
+                        LevelText::new("%1."),
+                        LevelJc::new("left"),
+                    )
+                    .indent(
+                        Some(1080),
+                        Some(SpecialIndentType::Hanging(360)),
+                        None,
+                        None,
+                    ),
+                ),
+            )
+            .add_numbering(Numbering::new(2, 2));
+        let paragraph = Paragraph::new()
+            .style("ListParagraph")
+            .numbering(NumberingId::new(2), IndentLevel::new(0));
+
+        let indent = docx
+            .resolve_paragraph_properties(&paragraph)
+            .indent
+            .unwrap();
+
+        assert_eq!(indent.start, Some(1080));
+        assert_eq!(indent.special_indent, Some(SpecialIndentType::Hanging(360)));
+    }
+
+    #[test]
+    fn test_resolve_run_properties_with_linked_and_default_styles() {
+        let docx = Docx::new()
+            .default_size(20)
+            .add_style(
+                Style::new("DefaultFont", StyleType::Character)
+                    .as_default()
+                    .color("111111"),
+            )
+            .add_style(
+                Style::new("Heading1", StyleType::Paragraph)
+                    .size(32)
+                    .link("Heading1Char"),
+            )
+            .add_style(
+                Style::new("Heading1Char", StyleType::Character)
+                    .color("FF0000")
+                    .link("Heading1"),
+            );
+        let paragraph = Paragraph::new();
+
+        let plain = docx.resolve_run_properties(&paragraph, &Run::new());
+        assert_eq!(plain.sz, Some(Sz::new(20)));
+        assert_eq!(plain.color, Some(Color::new("111111")));
+
+        let linked = docx.resolve_run_properties(&paragraph, &Run::new().style("Heading1"));
+        assert_eq!(linked.sz, Some(Sz::new(20)));
+        assert_eq!(linked.color, Some(Color::new("FF0000")));
+        assert_eq!(linked.style, Some(RunStyle::new("Heading1Char")));
+
+        let heading = Paragraph::new().style("Heading1");
+        let in_heading = docx.resolve_run_properties(&heading, &Run::new().color("00FF00"));
+        assert_eq!(in_heading.sz, Some(Sz::new(32)));
+        assert_eq!(in_heading.color, Some(Color::new("00FF00")));
+    }
+
+    #[test]
+    fn test_toggle_properties() {
+        let docx = Docx::new()
+            .add_style(Style::new("Strong", StyleType::Paragraph).bold())
+            .add_style(Style::new("Emphasis", StyleType::Character).bold())
+            .add_style(Style::new("Plain", StyleType::Character).based_on("Emphasis"))
+            .add_style(Style {
+                run_property: RunProperty::new().disable_bold(),
+                ..Style::new("NotBold", StyleType::Character).based_on("Emphasis")
+            });
+        let strong = Paragraph::new().style("Strong");
+        let normal = Paragraph::new();
+
+        let resolve = |p: &Paragraph, r: Run| bold(&docx.resolve_run_properties(p, &r));
+
+        assert_eq!(resolve(&strong, Run::new()), Some(true));
+        // Each style type turning bold on flips the inherited value.
+        assert_eq!(resolve(&strong, Run::new().style("Emphasis")), Some(false));
+        assert_eq!(resolve(&strong, Run::new().style("Plain")), Some(false));
+        assert_eq!(resolve(&strong, Run::new().style("NotBold")), Some(true));
+        assert_eq!(resolve(&normal, Run::new().style("Emphasis")), Some(true));
+        // Direct formatting is absolute.
+        assert_eq!(
+            resolve(&strong, Run::new().style("Emphasis").bold()),
+            Some(true)
+        );
+        assert_eq!(resolve(&strong, Run::new().disable_bold()), Some(false));
+    }
+
+    #[test]
+    fn test_table_style_conditional_formatting() {
+        let table_style = Style::new("Grid", StyleType::Table)
+            .color("000000")
+            .add_table_style_override(
+                TableStyleOverride::new(TableStyleOverrideType::FirstRow)
+                    .run_property(RunProperty::new().bold().color("FFFFFF")),
+            )
+            .add_table_style_override(
+                TableStyleOverride::new(TableStyleOverrideType::Band1Horz)
+                    .run_property(RunProperty::new().color("FF0000")),
+            );
+        let docx = Docx::new().add_style(table_style);
+        let table = Table::new(vec![]).style("Grid");
+        let paragraph = Paragraph::new();
+        let run = Run::new();
+
+        let header = docx.resolve_run_properties_in_table(
+            &table,
+            TableCellPosition::new(0, 1),
+            &paragraph,
+            &run,
+        );
+        assert_eq!(bold(&header), Some(true));
+        assert_eq!(header.color, Some(Color::new("FFFFFF")));
+
+        let odd = docx.resolve_run_properties_in_table(
+            &table,
+            TableCellPosition::new(1, 1),
+            &paragraph,
+            &run,
+        );
+        assert_eq!(odd.color, Some(Color::new("FF0000")));
+
+        let even = docx.resolve_run_properties_in_table(
+            &table,
+            TableCellPosition::new(2, 1),
+            &paragraph,
+            &run,
+        );
+        assert_eq!(even.color, Some(Color::new("000000")));
+
+        // `w:cnfStyle` wins over the cell position.
+        let marked = Paragraph::new().cnf_style(CnfStyle::new("100000000000"));
+        let marked = docx.resolve_run_properties_in_table(
+            &table,
+            TableCellPosition::new(2, 1),
+            &marked,
+            &run,
+        );
+        assert_eq!(marked.color, Some(Color::new("FFFFFF")));
+    }
+
+    #[test]
+    fn test_table_style_conditional_formatting_follows_table_look() {
+        let color = |t: TableStyleOverrideType, c: &str| {
+            TableStyleOverride::new(t).run_property(RunProperty::new().color(c))
+        };
+        let table_style = Style::new("Grid", StyleType::Table)
+            .color("000000")
+            .add_table_style_override(color(TableStyleOverrideType::FirstRow, "111111"))
+            .add_table_style_override(color(TableStyleOverrideType::Band1Horz, "222222"))
+            .add_table_style_override(color(TableStyleOverrideType::Band2Vert, "333333"))
+            .add_table_style_override(color(TableStyleOverrideType::LastRow, "444444"))
+            .add_table_style_override(color(TableStyleOverrideType::LastCol, "555555"))
+            .add_table_style_override(color(TableStyleOverrideType::SeCell, "666666"));
+        let docx = Docx::new().add_style(table_style);
+        let row = || TableRow::new(vec![TableCell::new(); 4]);
+        let table = Table::new(vec![row(), row(), row()]).style("Grid").look(
+            TableLook::new()
+                .first_row(false)
+                .last_row(true)
+                .last_column(true)
+                .no_h_band(true)
+                .no_v_band(false),
+        );
+        let paragraph = Paragraph::new();
+        let color_at = |row, column| {
+            docx.resolve_run_properties_in_table(
+                &table,
+                TableCellPosition::new(row, column),
+                &paragraph,
+                &Run::new(),
+            )
+            .color
+        };
+
+        // No header row and no row banding; columns are banded after the
+        // first one.
+        assert_eq!(color_at(0, 0), Some(Color::new("000000")));
+        assert_eq!(color_at(1, 1), Some(Color::new("000000")));
+        assert_eq!(color_at(1, 2), Some(Color::new("333333")));
+        assert_eq!(color_at(0, 3), Some(Color::new("555555")));
+        assert_eq!(color_at(2, 0), Some(Color::new("444444")));
+        assert_eq!(color_at(2, 3), Some(Color::new("666666")));
+    }
+}
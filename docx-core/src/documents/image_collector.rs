@@ -6,9 +6,12 @@
 
 use std::collections::{HashMap, HashSet};
 
-use super::document_tree::{visit_document, visit_footer, visit_header, DocumentTreeVisitor};
+use super::document_tree::{
+    visit_document_part, visit_footer_part, visit_header_part, DocumentTreeVisitorMut,
+};
 use crate::{
-    Document, Footer, Footnote, FootnoteReference, Header, ImageIdAndBuf, ImageIdAndPath, Pic,
+    Document, Footer, Footnote, FootnoteReference, Header, ImageIdAndBuf, ImageIdAndPath,
+    Numberings, Pic, SectionProperty,
 };
 
 /// Stores physical media once for the entire OPC package.
@@ -33,7 +36,7 @@ impl MediaRegistry {
     /// picture while keeping the package-visible media ID encapsulated here.
     /// Reused picture IDs are checked before calculating a content fingerprint;
     /// byte equality still protects callers that reuse an ID for new content.
-    fn register(&mut self, preferred_id: &str, bytes: Vec<u8>) -> usize {
+    pub(crate) fn register(&mut self, preferred_id: &str, bytes: Vec<u8>) -> usize {
         if let Some(index) = self.media_by_id.get(preferred_id).copied() {
             if self.media[index].1 == bytes {
                 return index;
@@ -66,7 +69,7 @@ impl MediaRegistry {
     }
 
     /// Returns the package-visible ID assigned to registered media.
-    fn media_id(&self, index: usize) -> &str {
+    pub(crate) fn media_id(&self, index: usize) -> &str {
         &self.media[index].0
     }
 
@@ -181,18 +184,14 @@ impl<'a> PackagePartCollector<'a> {
     }
 }
 
-impl DocumentTreeVisitor for PackagePartCollector<'_> {
-    fn visit_picture(&mut self, picture: &mut Pic) {
-        let preferred_relationship_id = self.relationship_id(&picture.id);
-        let media_index = self.registry.register(
-            &preferred_relationship_id,
-            std::mem::take(&mut picture.image),
-        );
+impl PackagePartCollector<'_> {
+    /// Registers image bytes and points `id` at the part-local relationship.
+    fn register_image(&mut self, id: &mut String, image: Vec<u8>) {
+        let preferred_relationship_id = self.relationship_id(id);
+        let media_index = self.registry.register(&preferred_relationship_id, image);
 
         if let Some(relationship_index) = self.relationships_by_media.get(&media_index) {
-            picture
-                .id
-                .clone_from(&self.relationships[*relationship_index].0);
+            id.clone_from(&self.relationships[*relationship_index].0);
             return;
         }
 
@@ -202,12 +201,19 @@ impl DocumentTreeVisitor for PackagePartCollector<'_> {
         self.relationship_ids.insert(relationship_id.clone());
         self.relationships_by_media
             .insert(media_index, relationship_index);
-        picture.id.clone_from(&relationship_id);
+        id.clone_from(&relationship_id);
         self.relationships.push((relationship_id, target));
     }
+}
 
-    fn visit_footnote_reference(&mut self, reference: &FootnoteReference) {
-        self.footnotes.push(reference.into());
+impl DocumentTreeVisitorMut for PackagePartCollector<'_> {
+    fn visit_picture(&mut self, picture: &mut Pic) {
+        let image = std::mem::take(&mut picture.image);
+        self.register_image(&mut picture.id, image);
+    }
+
+    fn visit_footnote_reference(&mut self, reference: &mut FootnoteReference) {
+        self.footnotes.push((&*reference).into());
     }
 }
 
@@ -217,7 +223,7 @@ pub(crate) fn collect_document_part(
     registry: &mut MediaRegistry,
 ) -> CollectedPart {
     let mut collector = PackagePartCollector::new(registry, None);
-    visit_document(document, &mut collector);
+    visit_document_part(document, &mut collector);
     collector.finish()
 }
 
@@ -233,14 +239,14 @@ pub(crate) fn collect_document_footnotes(document: &mut Document) -> Vec<Footnot
         footnotes: Vec<Footnote>,
     }
 
-    impl DocumentTreeVisitor for FootnoteCollector {
-        fn visit_footnote_reference(&mut self, reference: &FootnoteReference) {
-            self.footnotes.push(reference.into());
+    impl DocumentTreeVisitorMut for FootnoteCollector {
+        fn visit_footnote_reference(&mut self, reference: &mut FootnoteReference) {
+            self.footnotes.push((&*reference).into());
         }
     }
 
     let mut collector = FootnoteCollector::default();
-    visit_document(document, &mut collector);
+    visit_document_part(document, &mut collector);
     collector.footnotes
 }
 
@@ -250,7 +256,7 @@ pub(crate) fn collect_header_part(
     registry: &mut MediaRegistry,
 ) -> CollectedPart {
     let mut collector = PackagePartCollector::new(registry, Some("header"));
-    visit_header(header, &mut collector);
+    visit_header_part(header, &mut collector);
     collector.finish()
 }
 
@@ -260,10 +266,100 @@ pub(crate) fn collect_footer_part(
     registry: &mut MediaRegistry,
 ) -> CollectedPart {
     let mut collector = PackagePartCollector::new(registry, Some("footer"));
-    visit_footer(footer, &mut collector);
+    visit_footer_part(footer, &mut collector);
+    collector.finish()
+}
+
+/// Collects picture bullet images from the numbering part.
+pub(crate) fn collect_numbering_part(
+    numberings: &mut Numberings,
+    registry: &mut MediaRegistry,
+) -> CollectedPart {
+    let mut collector = PackagePartCollector::new(registry, Some("numbering"));
+    for bullet in &mut numberings.num_pic_bullets {
+        let image = std::mem::take(&mut bullet.image);
+        collector.register_image(&mut bullet.pic_id, image);
+    }
     collector.finish()
 }
 
+/// Puts the image bytes the reader keeps per part, see
+/// [`crate::Docx::header_footer_images`], back into the pictures of the
+/// headers and footers of `property`. Their relationship ids are scoped to
+/// the part, so they must not be looked up among the document's images.
+pub(crate) fn fill_header_footer_images(
+    property: &mut SectionProperty,
+    images: &HashMap<String, HashMap<String, Vec<u8>>>,
+) {
+    for (rid, header) in property.headers_mut() {
+        visit_header_part(header, &mut ImageFiller(images.get(rid.as_str())));
+    }
+    for (rid, footer) in property.footers_mut() {
+        visit_footer_part(footer, &mut ImageFiller(images.get(rid.as_str())));
+    }
+}
+
+/// Like [`fill_header_footer_images`] for the headers and footers of every
+/// section of `document`.
+pub(crate) fn fill_document_header_footer_images(
+    document: &mut Document,
+    images: &HashMap<String, HashMap<String, Vec<u8>>>,
+) {
+    for (rid, header) in document.headers_mut() {
+        visit_header_part(header, &mut ImageFiller(images.get(rid.as_str())));
+    }
+    for (rid, footer) in document.footers_mut() {
+        visit_footer_part(footer, &mut ImageFiller(images.get(rid.as_str())));
+    }
+}
+
+/// Fills pictures the reader left empty from the images of their part.
+struct ImageFiller<'a>(Option<&'a HashMap<String, Vec<u8>>>);
+
+impl DocumentTreeVisitorMut for ImageFiller<'_> {
+    fn visit_picture(&mut self, picture: &mut Pic) {
+        if !picture.image.is_empty() {
+            return;
+        }
+        if let Some(image) = self.0.and_then(|images| images.get(&picture.id)) {
+            picture.image = image.clone();
+        }
+    }
+}
+
+/// Packs `docx` and returns the media bytes the picture of each
+/// `word/header{n}.xml` points at through the relationships of its part.
+#[cfg(test)]
+pub(crate) fn packed_header_images(docx: crate::Docx) -> Vec<Vec<u8>> {
+    use std::io::Read;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf).unwrap();
+    let mut archive = zip::ZipArchive::new(buf).unwrap();
+    let mut read = |name: &str| {
+        let mut data = vec![];
+        archive.by_name(name).ok()?.read_to_end(&mut data).ok()?;
+        Some(data)
+    };
+    let attribute = |xml: &str, name: &str| {
+        let from = xml.find(name).unwrap() + name.len();
+        xml[from..from + xml[from..].find('"').unwrap()].to_owned()
+    };
+    let mut images = vec![];
+    for n in 1.. {
+        let Some(header) = read(&format!("word/header{n}.xml")) else {
+            break;
+        };
+        let embed = attribute(&String::from_utf8(header).unwrap(), "r:embed=\"");
+        let rels = read(&format!("word/_rels/header{n}.xml.rels")).unwrap();
+        let rels = String::from_utf8(rels).unwrap();
+        let relationship = &rels[rels.find(&format!("Id=\"{embed}\"")).unwrap()..];
+        let target = attribute(relationship, "Target=\"");
+        images.push(read(&format!("word/{target}")).unwrap());
+    }
+    images
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -363,4 +459,25 @@ mod tests {
         assert_eq!(part.relationships[1].0, "shared");
         assert_eq!(part.relationships[2].0, "shared_3");
     }
+
+    #[test]
+    fn numbering_pictures_share_media_with_the_document() {
+        let bytes = vec![3, 1, 4, 1, 5];
+        let mut document =
+            Document::new().add_paragraph(crate::Paragraph::new().add_run(
+                crate::Run::new().add_image(Pic::new_with_dimensions(bytes.clone(), 1, 1)),
+            ));
+        let mut numberings = Numberings::new()
+            .add_num_pic_bullet(crate::NumPicBullet::new(0, bytes).pic_id("rIdImage1"));
+        let mut registry = MediaRegistry::default();
+
+        collect_document_part(&mut document, &mut registry);
+        let part = collect_numbering_part(&mut numberings, &mut registry);
+
+        assert_eq!(registry.media.len(), 1);
+        assert_eq!(part.relationships.len(), 1);
+        assert_eq!(part.relationships[0].0, "numberingrIdImage1");
+        assert_eq!(numberings.num_pic_bullets[0].pic_id, "numberingrIdImage1");
+        assert!(numberings.num_pic_bullets[0].image.is_empty());
+    }
 }
@@ -0,0 +1,299 @@
+//! The inline CSS and presentational attributes web editors write, read
+//! into run and paragraph formatting.
+
+use super::dom::Element;
+use crate::documents::*;
+use crate::types::*;
+
+/// The run formatting of the elements around some text. `None` leaves a
+/// property to the style.
+#[derive(Debug, Clone, Default)]
+pub(super) struct Format {
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub underline: Option<bool>,
+    pub strike: Option<bool>,
+    pub vert_align: Option<VertAlignType>,
+    /// Hex RGB.
+    pub color: Option<String>,
+    /// Half-points.
+    pub size: Option<usize>,
+    pub font: Option<String>,
+    /// A highlight color name.
+    pub highlight: Option<String>,
+    /// Hex RGB.
+    pub shading: Option<String>,
+    pub code: bool,
+    pub style: Option<String>,
+    pub link: Option<String>,
+}
+
+impl Format {
+    pub fn apply(&mut self, declarations: &[(String, String)]) {
+        for (property, value) in declarations {
+            let value = value.as_str();
+            match property.as_str() {
+                "font-weight" => {
+                    self.bold = match value {
+                        "bold" | "bolder" => Some(true),
+                        "normal" | "lighter" => Some(false),
+                        _ => value.parse::<u32>().ok().map(|w| w >= 600),
+                    }
+                }
+                "font-style" => self.italic = Some(value != "normal"),
+                "text-decoration" | "text-decoration-line" => {
+                    self.underline = Some(value.contains("underline"));
+                    self.strike = Some(value.contains("line-through"));
+                }
+                "vertical-align" => {
+                    self.vert_align = match value {
+                        "super" => Some(VertAlignType::SuperScript),
+                        "sub" => Some(VertAlignType::SubScript),
+                        _ => Some(VertAlignType::Baseline),
+                    }
+                }
+                "color" => self.color = css_color(value).or(self.color.take()),
+                "background-color" | "background" => {
+                    if let Some(color) = css_color(value) {
+                        self.shading = Some(color);
+                    }
+                }
+                "font-size" => self.size = css_font_size(value).or(self.size),
+                "font-family" => self.font = css_font_family(value).or(self.font.take()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Reads the attributes of a `<font>` element.
+    pub fn apply_font_element(&mut self, element: &Element) {
+        if let Some(color) = element.attribute("color").and_then(css_color) {
+            self.color = Some(color);
+        }
+        if let Some(face) = element.attribute("face").and_then(css_font_family) {
+            self.font = Some(face);
+        }
+        // The seven sizes of HTML, in half-points.
+        let size = element
+            .attribute("size")
+            .and_then(|s| s.trim().parse::<usize>().ok());
+        if let Some(size) = size.filter(|s| (1..=7).contains(s)) {
+            self.size = Some([15, 20, 24, 27, 36, 48, 72][size - 1]);
+        }
+    }
+
+    pub fn run(&self) -> Run {
+        let mut run = Run::new();
+        match self.bold {
+            Some(true) => run = run.bold(),
+            Some(false) => run = run.disable_bold(),
+            None => {}
+        }
+        match self.italic {
+            Some(true) => run = run.italic(),
+            Some(false) => run = run.disable_italic(),
+            None => {}
+        }
+        if self.underline == Some(true) {
+            run = run.underline("single");
+        }
+        if self.strike == Some(true) {
+            run = run.strike();
+        }
+        if let Some(vert_align) = self.vert_align {
+            run.run_property = run.run_property.vert_align(vert_align);
+        }
+        if let Some(color) = &self.color {
+            run = run.color(color);
+        }
+        if let Some(size) = self.size {
+            run = run.size(size);
+        }
+        if let Some(font) = &self.font {
+            run = run.fonts(
+                RunFonts::new()
+                    .ascii(font)
+                    .hi_ansi(font)
+                    .east_asia(font)
+                    .cs(font),
+            );
+        }
+        if let Some(fill) = &self.shading {
+            run = run.shading(
+                Shading::new()
+                    .shd_type(ShdType::Clear)
+                    .color("auto")
+                    .fill(fill),
+            );
+        }
+        if let Some(highlight) = &self.highlight {
+            run = run.highlight(highlight);
+        }
+        run
+    }
+}
+
+/// Splits a `style` attribute into lowercase properties and their values.
+pub(super) fn parse_css(style: &str) -> Vec<(String, String)> {
+    style
+        .split(';')
+        .filter_map(|declaration| {
+            let (property, value) = declaration.split_once(':')?;
+            let value = value.trim();
+            let value = value
+                .strip_suffix("!important")
+                .map_or(value, |v| v.trim_end());
+            Some((property.trim().to_ascii_lowercase(), value.to_owned()))
+        })
+        .collect()
+}
+
+/// The alignment of a block from its CSS or `align` attribute.
+pub(super) fn block_alignment(
+    element: &Element,
+    declarations: &[(String, String)],
+) -> Option<AlignmentType> {
+    let value = declarations
+        .iter()
+        .rev()
+        .find(|(p, _)| p == "text-align")
+        .map(|(_, v)| v.as_str())
+        .or_else(|| element.attribute("align"))?;
+    match value.trim().to_ascii_lowercase().as_str() {
+        "left" | "start" => Some(AlignmentType::Left),
+        "center" => Some(AlignmentType::Center),
+        "right" | "end" => Some(AlignmentType::Right),
+        "justify" => Some(AlignmentType::Both),
+        _ => None,
+    }
+}
+
+/// A CSS color as hex RGB.
+fn css_color(value: &str) -> Option<String> {
+    let value = value.trim().to_ascii_lowercase();
+    if let Some(hex) = value.strip_prefix('#') {
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        return match hex.len() {
+            3 => Some(
+                hex.chars()
+                    .flat_map(|c| [c, c])
+                    .collect::<String>()
+                    .to_uppercase(),
+            ),
+            6 => Some(hex.to_uppercase()),
+            _ => None,
+        };
+    }
+    if let Some(args) = value
+        .strip_prefix("rgb(")
+        .or_else(|| value.strip_prefix("rgba("))
+    {
+        let channels: Vec<u8> = args
+            .trim_end_matches(')')
+            .split([',', ' ', '/'])
+            .filter(|c| !c.is_empty())
+            .take(3)
+            .filter_map(|c| match c.strip_suffix('%') {
+                Some(percent) => percent
+                    .parse::<f64>()
+                    .ok()
+                    .map(|p| (p.clamp(0.0, 100.0) * 2.55).round() as u8),
+                None => c
+                    .parse::<f64>()
+                    .ok()
+                    .map(|v| v.clamp(0.0, 255.0).round() as u8),
+            })
+            .collect();
+        return match channels[..] {
+            [r, g, b] => Some(format!("{r:02X}{g:02X}{b:02X}")),
+            _ => None,
+        };
+    }
+    let hex = match value.as_str() {
+        "black" => "000000",
+        "white" => "FFFFFF",
+        "red" => "FF0000",
+        "green" => "008000",
+        "blue" => "0000FF",
+        "yellow" => "FFFF00",
+        "gray" | "grey" => "808080",
+        "silver" => "C0C0C0",
+        "maroon" => "800000",
+        "purple" => "800080",
+        "fuchsia" | "magenta" => "FF00FF",
+        "lime" => "00FF00",
+        "olive" => "808000",
+        "navy" => "000080",
+        "teal" => "008080",
+        "aqua" | "cyan" => "00FFFF",
+        "orange" => "FFA500",
+        _ => return None,
+    };
+    Some(hex.to_owned())
+}
+
+/// A CSS font size in half-points.
+fn css_font_size(value: &str) -> Option<usize> {
+    let value = value.trim().to_ascii_lowercase();
+    let keyword = match value.as_str() {
+        "xx-small" => Some(14.0),
+        "x-small" => Some(15.0),
+        "small" => Some(20.0),
+        "medium" => Some(24.0),
+        "large" => Some(27.0),
+        "x-large" => Some(36.0),
+        "xx-large" => Some(48.0),
+        _ => None,
+    };
+    let number = |suffix: &str| -> Option<f64> { value.strip_suffix(suffix)?.trim().parse().ok() };
+    // Relative sizes are taken against the 12pt default.
+    let half_points = keyword
+        .or_else(|| number("pt").map(|v| v * 2.0))
+        .or_else(|| number("px").map(|v| v * 1.5))
+        .or_else(|| number("rem").map(|v| v * 24.0))
+        .or_else(|| number("em").map(|v| v * 24.0))
+        .or_else(|| number("%").map(|v| v * 0.24))?;
+    Some(half_points.round() as usize).filter(|s| *s > 0)
+}
+
+/// The first font of a CSS font family list, unless it is generic.
+fn css_font_family(value: &str) -> Option<String> {
+    let first = value.split(',').next()?.trim();
+    let font = first.trim_matches(|c| c == '"' || c == '\'').trim();
+    match font {
+        "" | "serif" | "sans-serif" | "monospace" | "cursive" | "fantasy" | "system-ui"
+        | "inherit" | "initial" => None,
+        _ => Some(font.to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_css_values() {
+        assert_eq!(css_color("#abc"), Some("AABBCC".to_owned()));
+        assert_eq!(css_color("rgb(255, 0, 16)"), Some("FF0010".to_owned()));
+        assert_eq!(css_color("Red"), Some("FF0000".to_owned()));
+        assert_eq!(css_color("currentColor"), None);
+        assert_eq!(css_font_size("16px"), Some(24));
+        assert_eq!(css_font_size("10.5pt"), Some(21));
+        assert_eq!(
+            css_font_family("\"Times New Roman\", serif"),
+            Some("Times New Roman".to_owned())
+        );
+        assert_eq!(
+            parse_css("color: red; font-weight:700 !important"),
+            vec![
+                ("color".to_owned(), "red".to_owned()),
+                ("font-weight".to_owned(), "700".to_owned())
+            ]
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//! A forgiving HTML parser for the content of web editors. It builds a tree
+//! the way browsers do for well-formed markup, and closes the paragraphs,
+//! list items and cells that HTML lets authors leave open.
+
+use crate::documents::decode_entity;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(super) struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    fn new(name: &str, attributes: Vec<(String, String)>) -> Self {
+        Self {
+            name: name.to_owned(),
+            attributes,
+            children: vec![],
+        }
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if let Some(Node::Text(last)) = self.children.last_mut() {
+            last.push_str(text);
+        } else {
+            self.children.push(Node::Text(text.to_owned()));
+        }
+    }
+}
+
+const VOID: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Elements whose content is not markup and is not shown.
+const RAW: &[&str] = &["script", "style", "title", "textarea", "template"];
+
+/// Elements that start a block and so end an open paragraph.
+const BLOCKS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "div",
+    "dl",
+    "figure",
+    "footer",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+
+pub(super) const INLINE: &[&str] = &[
+    "a", "abbr", "b", "bdi", "bdo", "big", "cite", "code", "del", "dfn", "em", "font", "i", "ins",
+    "kbd", "label", "mark", "q", "s", "samp", "small", "span", "strike", "strong", "sub", "sup",
+    "tt", "u", "var",
+];
+
+pub(super) fn parse_html(html: &str) -> Vec<Node> {
+    let mut builder = TreeBuilder {
+        stack: vec![Element::default()],
+    };
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            builder.text(&decode_entities(rest));
+            break;
+        };
+        if lt > 0 {
+            builder.text(&decode_entities(&rest[..lt]));
+            rest = &rest[lt..];
+        }
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some(end_tag) = rest
+            .strip_prefix("</")
+            .filter(|t| t.starts_with(|c: char| c.is_ascii_alphabetic()))
+        {
+            let name = tag_name(end_tag);
+            builder.end(&name);
+            rest = end_tag.find('>').map_or("", |end| &end_tag[end + 1..]);
+        } else if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let (name, attributes, self_closing, after) = start_tag(&rest[1..]);
+            rest = after;
+            if RAW.contains(&name.as_str()) {
+                rest = skip_raw(rest, &name);
+            } else {
+                builder.start(&name, attributes, self_closing);
+            }
+        } else {
+            builder.text("<");
+            rest = &rest[1..];
+        }
+    }
+    builder.finish()
+}
+
+fn tag_name(s: &str) -> String {
+    s.chars()
+        .take_while(|c| !c.is_whitespace() && *c != '>' && *c != '/')
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+/// Reads a start tag after its `<`, returning its name, attributes, whether
+/// it ends with `/>`, and the input after it.
+fn start_tag(s: &str) -> (String, Vec<(String, String)>, bool, &str) {
+    let name = tag_name(s);
+    let mut rest = &s[name.len()..];
+    let mut attributes = vec![];
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            return (name, attributes, false, rest);
+        }
+        if let Some(after) = rest.strip_prefix("/>") {
+            return (name, attributes, true, after);
+        }
+        if let Some(after) = rest.strip_prefix('>') {
+            return (name, attributes, false, after);
+        }
+        if let Some(after) = rest.strip_prefix('/') {
+            rest = after;
+            continue;
+        }
+        let end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/')
+            .unwrap_or(rest.len())
+            .max(1);
+        let attribute = rest[..end].to_ascii_lowercase();
+        rest = rest[end..].trim_start();
+        let mut value = String::new();
+        if let Some(after) = rest.strip_prefix('=') {
+            rest = after.trim_start();
+            let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'');
+            let (raw, after) = match quote {
+                Some(q) => match rest[1..].find(q) {
+                    Some(close) => (&rest[1..close + 1], &rest[close + 2..]),
+                    None => (&rest[1..], ""),
+                },
+                None => {
+                    let end = rest
+                        .find(|c: char| c.is_whitespace() || c == '>')
+                        .unwrap_or(rest.len());
+                    (&rest[..end], &rest[end..])
+                }
+            };
+            value = decode_entities(raw);
+            rest = after;
+        }
+        attributes.push((attribute, value));
+    }
+}
+
+/// Skips the content of an element such as `<script>` up to its end tag.
+fn skip_raw<'a>(s: &'a str, name: &str) -> &'a str {
+    let end_tag = format!("</{name}");
+    let lower = s.to_ascii_lowercase();
+    match lower.find(&end_tag) {
+        Some(start) => {
+            let after = &s[start..];
+            after.find('>').map_or("", |end| &after[end + 1..])
+        }
+        None => "",
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|end| *end > 0 && *end <= 32)
+            .and_then(|end| decode_entity(&rest[1..end + 1]).map(|c| (c, end + 2)));
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+struct TreeBuilder {
+    /// The open elements, the root first.
+    stack: Vec<Element>,
+}
+
+impl TreeBuilder {
+    fn current(&mut self) -> &mut Element {
+        self.stack.last_mut().expect("the root stays open")
+    }
+
+    fn text(&mut self, text: &str) {
+        self.current().push_text(text);
+    }
+
+    fn start(&mut self, name: &str, attributes: Vec<(String, String)>, self_closing: bool) {
+        self.close_implied(name);
+        let element = Element::new(name, attributes);
+        if self_closing || VOID.contains(&name) {
+            self.current().children.push(Node::Element(element));
+        } else {
+            self.stack.push(element);
+        }
+    }
+
+    fn end(&mut self, name: &str) {
+        if name == "br" {
+            self.start(name, vec![], true);
+            return;
+        }
+        if let Some(index) = self.stack.iter().skip(1).rposition(|e| e.name == name) {
+            self.close_to(index + 1);
+        }
+    }
+
+    /// Closes the element at the index and the ones inside it.
+    fn close_to(&mut self, index: usize) {
+        while self.stack.len() > index.max(1) {
+            let element = self.stack.pop().expect("checked by the loop");
+            self.current().children.push(Node::Element(element));
+        }
+    }
+
+    /// The index of the nearest open element named one of `names`, looking
+    /// no further out than an element named one of `scope`.
+    fn find_open(&self, names: &[&str], scope: &[&str]) -> Option<usize> {
+        for (index, element) in self.stack.iter().enumerate().skip(1).rev() {
+            if names.contains(&element.name.as_str()) {
+                return Some(index);
+            }
+            if scope.contains(&element.name.as_str()) {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// Closes the elements a start tag ends without their end tags.
+    fn close_implied(&mut self, name: &str) {
+        let open = match name {
+            "li" => self.find_open(&["li"], &["ul", "ol"]),
+            "dt" | "dd" => self.find_open(&["dt", "dd"], &["dl"]),
+            "td" | "th" => self.find_open(&["td", "th"], &["tr", "table"]),
+            "tr" => self.find_open(&["tr"], &["thead", "tbody", "tfoot", "table"]),
+            "thead" | "tbody" | "tfoot" => self.find_open(&["thead", "tbody", "tfoot"], &["table"]),
+            _ => None,
+        };
+        if let Some(index) = open {
+            self.close_to(index);
+            return;
+        }
+        if BLOCKS.contains(&name) || matches!(name, "li" | "dt" | "dd") {
+            // Paragraphs hold inline content only.
+            let paragraph = self
+                .stack
+                .iter()
+                .enumerate()
+                .skip(1)
+                .rev()
+                .find(|(_, e)| !INLINE.contains(&e.name.as_str()));
+            if let Some((index, _)) = paragraph.filter(|(_, e)| e.name == "p") {
+                self.close_to(index);
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<Node> {
+        self.close_to(1);
+        self.stack
+            .pop()
+            .map(|root| root.children)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn element(name: &str, children: Vec<Node>) -> Node {
+        Node::Element(Element::new(name, vec![]).children(children))
+    }
+
+    impl Element {
+        fn children(mut self, children: Vec<Node>) -> Self {
+            self.children = children;
+            self
+        }
+    }
+
+    fn text(t: &str) -> Node {
+        Node::Text(t.to_owned())
+    }
+
+    #[test]
+    fn test_parse_html_closes_implied_elements() {
+        let nodes = parse_html(
+            "<!DOCTYPE html><style>p{}</style><p>a &amp; b<p>c<ul><li>d<li>e</ul><table><tr><td>f<td>g</table>",
+        );
+        assert_eq!(
+            nodes,
+            vec![
+                element("p", vec![text("a & b")]),
+                element("p", vec![text("c")]),
+                element(
+                    "ul",
+                    vec![
+                        element("li", vec![text("d")]),
+                        element("li", vec![text("e")])
+                    ]
+                ),
+                element(
+                    "table",
+                    vec![element(
+                        "tr",
+                        vec![
+                            element("td", vec![text("f")]),
+                            element("td", vec![text("g")])
+                        ]
+                    )]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_html_attributes() {
+        let nodes = parse_html("<a href=\"x?a=1&amp;b=2\" title='t' hidden data-x=y>l</a><br/>");
+        let Node::Element(a) = &nodes[0] else {
+            unreachable!()
+        };
+        assert_eq!(a.attribute("href"), Some("x?a=1&b=2"));
+        assert_eq!(a.attribute("title"), Some("t"));
+        assert_eq!(a.attribute("hidden"), Some(""));
+        assert_eq!(a.attribute("data-x"), Some("y"));
+        assert_eq!(nodes[1], element("br", vec![]));
+    }
+}
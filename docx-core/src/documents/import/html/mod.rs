@@ -0,0 +1,662 @@
+mod css;
+mod dom;
+
+use std::collections::BTreeSet;
+
+use self::css::*;
+use self::dom::*;
+use super::*;
+
+#[derive(Default)]
+pub struct HtmlImportOptions {
+    image_loader: Option<ImageLoader>,
+}
+
+impl HtmlImportOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Loads images whose source is not a `data:` URI. Images that cannot be
+    /// loaded show their alt text.
+    pub fn image_loader(mut self, loader: impl Fn(&str) -> Option<Vec<u8>> + 'static) -> Self {
+        self.image_loader = Some(Box::new(loader));
+        self
+    }
+}
+
+const CODE_STYLE: &str = "SourceCode";
+const CODE_CHAR_STYLE: &str = "SourceCodeChar";
+
+/// Makes a document of HTML such as web editors produce: paragraphs,
+/// headings, lists, tables, links, images, quotes and preformatted text,
+/// with the formatting of tags and of inline CSS.
+pub fn read_html(html: &str, options: &HtmlImportOptions) -> Docx {
+    Docx::new().add_html(html, options)
+}
+
+impl Docx {
+    /// Appends the content of HTML to the body.
+    pub fn add_html(self, html: &str, options: &HtmlImportOptions) -> Docx {
+        let index = self.document.children.len();
+        self.insert_html(index, html, options)
+    }
+
+    /// Inserts the content of HTML into the body before the child at
+    /// `index`, or at the end when it is past the last child.
+    ///
+    /// Each list gets a new numbering and the default styles the content
+    /// uses are added unless the document defines them. Elements whose
+    /// `class` is the id of a style of the document get that style.
+    pub fn insert_html(mut self, index: usize, html: &str, options: &HtmlImportOptions) -> Docx {
+        let nodes = parse_html(html);
+        let mut converter = Converter {
+            options,
+            docx: &mut self,
+            styles: BTreeSet::new(),
+            pieces: vec![],
+            numbering: None,
+            pre_start: false,
+        };
+        let mut contents = vec![];
+        converter.nodes(
+            &nodes,
+            &Context::default(),
+            &Format::default(),
+            &mut contents,
+        );
+        converter.flush(&Context::default(), &mut contents);
+        let styles = converter.styles;
+        add_missing_styles(&mut self, &styles, (CODE_STYLE, CODE_CHAR_STYLE));
+
+        let mut index = index.min(self.document.children.len());
+        for content in contents {
+            let (has_numbering, child) = match content {
+                Content::Paragraph(p) => (p.has_numbering, DocumentChild::Paragraph(p)),
+                Content::Table(t) => (t.has_numbering, DocumentChild::Table(t)),
+            };
+            if has_numbering {
+                self.document.has_numbering = true;
+                self.document_rels.has_numberings = true;
+            }
+            self.document.children.insert(index, child);
+            index += 1;
+        }
+        self
+    }
+}
+
+/// Where a block sits.
+#[derive(Debug, Clone, Default)]
+struct Context {
+    /// The level of the list item the block continues.
+    list_level: Option<usize>,
+    style: Option<String>,
+    alignment: Option<AlignmentType>,
+    pre: bool,
+}
+
+enum Piece {
+    Text(String, Format),
+    Break(Format),
+    Picture(Box<Pic>, Format),
+}
+
+impl Piece {
+    fn format(&self) -> &Format {
+        match self {
+            Piece::Text(_, format) | Piece::Break(format) | Piece::Picture(_, format) => format,
+        }
+    }
+}
+
+struct Converter<'a> {
+    options: &'a HtmlImportOptions,
+    docx: &'a mut Docx,
+    /// The ids of the styles used.
+    styles: BTreeSet<String>,
+    /// The inline content of the paragraph being built.
+    pieces: Vec<Piece>,
+    /// The numbering and level of the list item whose first paragraph is
+    /// being built.
+    numbering: Option<(usize, usize)>,
+    /// Whether the text is the first of a `<pre>`, whose leading newline is
+    /// dropped.
+    pre_start: bool,
+}
+
+impl Converter<'_> {
+    fn style(&mut self, id: &str) -> String {
+        self.styles.insert(id.to_owned());
+        id.to_owned()
+    }
+
+    /// The first class of the element that names a style of the given type.
+    fn class_style(&self, element: &Element, style_type: StyleType) -> Option<String> {
+        element
+            .attribute("class")?
+            .split_whitespace()
+            .find(|class| {
+                self.docx
+                    .styles
+                    .find_style_by_id(class)
+                    .is_some_and(|s| s.style_type == style_type)
+            })
+            .map(|class| class.to_owned())
+    }
+
+    fn nodes(
+        &mut self,
+        nodes: &[Node],
+        context: &Context,
+        format: &Format,
+        out: &mut Vec<Content>,
+    ) {
+        for node in nodes {
+            match node {
+                Node::Text(text) => self.text(text, context, format, out),
+                Node::Element(element) => self.element(element, context, format, out),
+            }
+        }
+    }
+
+    fn text(&mut self, text: &str, context: &Context, format: &Format, out: &mut Vec<Content>) {
+        if context.pre {
+            let text = if std::mem::take(&mut self.pre_start) {
+                text.strip_prefix('\n').unwrap_or(text)
+            } else {
+                text
+            };
+            for (i, line) in text.split('\n').enumerate() {
+                if i > 0 {
+                    self.paragraph(context, out);
+                }
+                if !line.is_empty() {
+                    self.pieces
+                        .push(Piece::Text(line.to_owned(), format.clone()));
+                }
+            }
+            return;
+        }
+        let mut collapsed = String::with_capacity(text.len());
+        let mut space = match self.pieces.last() {
+            Some(Piece::Text(last, _)) => last.ends_with(' '),
+            // Spaces at the start of a line are not shown.
+            Some(Piece::Break(_)) | None => true,
+            Some(Piece::Picture(..)) => false,
+        };
+        for c in text.chars() {
+            if c.is_ascii_whitespace() {
+                if !space {
+                    collapsed.push(' ');
+                    space = true;
+                }
+            } else {
+                collapsed.push(c);
+                space = false;
+            }
+        }
+        if !collapsed.is_empty() {
+            self.pieces.push(Piece::Text(collapsed, format.clone()));
+        }
+    }
+
+    fn element(
+        &mut self,
+        element: &Element,
+        context: &Context,
+        format: &Format,
+        out: &mut Vec<Content>,
+    ) {
+        let declarations = element
+            .attribute("style")
+            .map(parse_css)
+            .unwrap_or_default();
+        let mut format = format.clone();
+        format.apply(&declarations);
+        let name = element.name.as_str();
+        match name {
+            "br" => self.pieces.push(Piece::Break(format)),
+            "img" => self.image(element, format),
+            "ul" | "ol" => self.list(element, context, &format, out),
+            "table" => self.table(element, context, &format, out),
+            "hr" => {
+                self.end_label(context, out);
+                let mut p = Paragraph::new();
+                p.property = p.property.set_border(
+                    ParagraphBorder::new(ParagraphBorderPosition::Bottom)
+                        .size(6)
+                        .space(1),
+                );
+                out.push(Content::Paragraph(Box::new(p)));
+            }
+            "head" | "select" | "button" | "input" | "noscript" | "iframe" | "object" | "svg" => {}
+            _ if INLINE.contains(&name) => {
+                match name {
+                    "b" | "strong" => format.bold = Some(true),
+                    "i" | "em" | "cite" | "dfn" | "var" => format.italic = Some(true),
+                    "u" | "ins" => format.underline = Some(true),
+                    "s" | "strike" | "del" => format.strike = Some(true),
+                    "sup" => format.vert_align = Some(VertAlignType::SuperScript),
+                    "sub" => format.vert_align = Some(VertAlignType::SubScript),
+                    "code" | "kbd" | "samp" | "tt" => format.code = true,
+                    "mark" => format.highlight = Some("yellow".to_owned()),
+                    "font" => format.apply_font_element(element),
+                    "a" => {
+                        // Word cannot nest links.
+                        if let Some(href) =
+                            element.attribute("href").filter(|_| format.link.is_none())
+                        {
+                            format.link = Some(href.to_owned());
+                        }
+                    }
+                    _ => {}
+                }
+                if let Some(style) = self.class_style(element, StyleType::Character) {
+                    format.style = Some(style);
+                }
+                self.nodes(&element.children, context, &format, out);
+            }
+            _ => {
+                // Everything else is a block, even unknown elements.
+                self.flush(context, out);
+                let mut context = context.clone();
+                if let Some(alignment) = block_alignment(element, &declarations) {
+                    context.alignment = Some(alignment);
+                }
+                match name {
+                    "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                        context.style = Some(self.style(&format!("Heading{}", &name[1..])));
+                    }
+                    "blockquote" => context.style = Some(self.style("Quote")),
+                    "pre" => {
+                        context.style = Some(self.style(CODE_STYLE));
+                        context.pre = true;
+                        self.pre_start = true;
+                    }
+                    "th" => format.bold = Some(true),
+                    _ => {}
+                }
+                if let Some(style) = self.class_style(element, StyleType::Paragraph) {
+                    context.style = Some(style);
+                }
+                self.nodes(&element.children, &context, &format, out);
+                self.flush(&context, out);
+                self.pre_start = false;
+            }
+        }
+    }
+
+    fn image(&mut self, element: &Element, format: Format) {
+        let src = element.attribute("src").unwrap_or_default();
+        let bytes = data_uri(src).or_else(|| {
+            self.options
+                .image_loader
+                .as_ref()
+                .and_then(|load| load(src))
+        });
+        match bytes.as_deref().and_then(picture) {
+            Some(mut pic) => {
+                let px = |name| {
+                    element
+                        .attribute(name)
+                        .and_then(|v| v.trim().trim_end_matches("px").parse::<f64>().ok())
+                        .filter(|v| *v > 0.0)
+                };
+                let (w, h) = (pic.size.0 as f64, pic.size.1 as f64);
+                // EMU per pixel at 96 dpi.
+                let size = match (px("width"), px("height")) {
+                    (Some(width), Some(height)) => Some((width * 9525.0, height * 9525.0)),
+                    (Some(width), None) => Some((width * 9525.0, h * width * 9525.0 / w)),
+                    (None, Some(height)) => Some((w * height * 9525.0 / h, height * 9525.0)),
+                    (None, None) => None,
+                };
+                if let Some((w, h)) = size {
+                    pic = pic.size(w.round() as u32, h.round() as u32);
+                }
+                self.pieces.push(Piece::Picture(Box::new(pic), format));
+            }
+            None => {
+                if let Some(alt) = element.attribute("alt").filter(|a| !a.is_empty()) {
+                    self.pieces.push(Piece::Text(alt.to_owned(), format));
+                }
+            }
+        }
+    }
+
+    fn list(
+        &mut self,
+        element: &Element,
+        context: &Context,
+        format: &Format,
+        out: &mut Vec<Content>,
+    ) {
+        self.end_label(context, out);
+        let level = context.list_level.map_or(0, |l| (l + 1).min(8));
+        let start = element
+            .attribute("start")
+            .and_then(|s| s.trim().parse().ok())
+            .unwrap_or(1);
+        let id = add_list_numbering(self.docx, element.name == "ol", start);
+        let item_context = Context {
+            list_level: Some(level),
+            ..context.clone()
+        };
+        for child in &element.children {
+            let Node::Element(item) = child else {
+                continue;
+            };
+            if item.name != "li" {
+                // Lists directly in lists belong to the item before them.
+                self.element(item, &item_context, format, out);
+                continue;
+            }
+            let mut format = format.clone();
+            format.apply(&item.attribute("style").map(parse_css).unwrap_or_default());
+            self.numbering = Some((id, level));
+            self.nodes(&item.children, &item_context, &format, out);
+            self.end_label(&item_context, out);
+        }
+    }
+
+    fn table(
+        &mut self,
+        element: &Element,
+        context: &Context,
+        format: &Format,
+        out: &mut Vec<Content>,
+    ) {
+        self.end_label(context, out);
+        let mut html_rows = vec![];
+        for child in &element.children {
+            match child {
+                Node::Element(row) if row.name == "tr" => html_rows.push(row),
+                Node::Element(group)
+                    if matches!(group.name.as_str(), "thead" | "tbody" | "tfoot") =>
+                {
+                    for child in &group.children {
+                        if let Node::Element(row) = child {
+                            if row.name == "tr" {
+                                html_rows.push(row);
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // The cells merged from rows above: rows left to cover and columns.
+        let mut merged: Vec<Option<(usize, usize)>> = vec![];
+        let mut rows = vec![];
+        for html_row in html_rows {
+            let mut cells = vec![];
+            let mut column = 0;
+            for child in &html_row.children {
+                let Node::Element(html_cell) = child else {
+                    continue;
+                };
+                if !matches!(html_cell.name.as_str(), "td" | "th") {
+                    continue;
+                }
+                continue_merges(&mut merged, &mut column, &mut cells);
+                let span = |name| {
+                    html_cell
+                        .attribute(name)
+                        .and_then(|v| v.trim().parse::<usize>().ok())
+                        .unwrap_or(1)
+                        .clamp(1, 1000)
+                };
+                let (colspan, rowspan) = (span("colspan"), span("rowspan"));
+
+                let mut contents = vec![];
+                let cell_context = Context::default();
+                self.element(html_cell, &cell_context, format, &mut contents);
+                let mut cell = TableCell::new();
+                if contents.is_empty() {
+                    cell = cell.add_paragraph(Paragraph::new());
+                }
+                for content in contents {
+                    cell = match content {
+                        Content::Paragraph(p) => cell.add_paragraph(*p),
+                        // A cell ends with a paragraph.
+                        Content::Table(t) => cell.add_table(*t).add_paragraph(Paragraph::new()),
+                    };
+                }
+                if colspan > 1 {
+                    cell = cell.grid_span(colspan);
+                }
+                if rowspan > 1 {
+                    cell = cell.vertical_merge(VMergeType::Restart);
+                    if merged.len() < column + 1 {
+                        merged.resize(column + 1, None);
+                    }
+                    merged[column] = Some((rowspan - 1, colspan));
+                }
+                cells.push(cell);
+                column += colspan;
+            }
+            continue_merges(&mut merged, &mut column, &mut cells);
+            if !cells.is_empty() {
+                rows.push(TableRow::new(cells));
+            }
+        }
+        if rows.is_empty() {
+            return;
+        }
+        let table = Table::new(rows)
+            .style(self.style("TableGrid"))
+            .width(5000, WidthType::Pct);
+        out.push(Content::Table(Box::new(table)));
+    }
+
+    fn run(&mut self, format: &Format) -> Run {
+        let mut run = format.run();
+        if format.code {
+            run = run.style(&self.style(CODE_CHAR_STYLE));
+        } else if let Some(style) = &format.style {
+            run = run.style(style);
+        } else if format.link.is_some() {
+            run = run.style(&self.style("Hyperlink"));
+        }
+        run
+    }
+
+    /// Ends the paragraph being built, unless it is empty.
+    fn flush(&mut self, context: &Context, out: &mut Vec<Content>) {
+        // Spaces at the end of a line are not shown.
+        while let Some(Piece::Text(text, _)) = self.pieces.last_mut() {
+            if !context.pre {
+                let trimmed = text.trim_end_matches(' ').len();
+                text.truncate(trimmed);
+            }
+            if !text.is_empty() {
+                break;
+            }
+            self.pieces.pop();
+        }
+        if !self.pieces.is_empty() {
+            self.paragraph(context, out);
+        }
+    }
+
+    /// Ends the paragraph being built, and makes one for the label of a list
+    /// item that has none yet, before content that cannot carry it.
+    fn end_label(&mut self, context: &Context, out: &mut Vec<Content>) {
+        self.flush(context, out);
+        if self.numbering.is_some() {
+            self.paragraph(context, out);
+        }
+    }
+
+    /// Ends the paragraph being built, even when it is empty.
+    fn paragraph(&mut self, context: &Context, out: &mut Vec<Content>) {
+        let mut p = Paragraph::new();
+        if let Some(style) = &context.style {
+            p = p.style(style);
+        }
+        if let Some(alignment) = context.alignment {
+            p = p.align(alignment);
+        }
+        // The first paragraph of a list item carries the label; the others
+        // are indented under it.
+        if let Some((id, level)) = self.numbering.take() {
+            p = p.numbering(NumberingId::new(id), IndentLevel::new(level));
+        } else if let Some(level) = context.list_level {
+            p = p.indent(Some(list_indent(level)), None, None, None);
+        }
+
+        let mut link: Option<(String, Hyperlink)> = None;
+        for piece in std::mem::take(&mut self.pieces) {
+            let href = piece.format().link.clone();
+            let run = match piece {
+                Piece::Text(text, format) => self.run(&format).add_text(text),
+                Piece::Break(format) => self.run(&format).add_break(BreakType::TextWrapping),
+                Piece::Picture(pic, format) => self.run(&format).add_image(*pic),
+            };
+            if link.as_ref().map(|(h, _)| h) != href.as_ref() {
+                if let Some((_, l)) = link.take() {
+                    p = p.add_hyperlink(l);
+                }
+                link = href.map(|href| {
+                    let l = match href.strip_prefix('#') {
+                        Some(anchor) => Hyperlink::new(anchor, HyperlinkType::Anchor),
+                        None => Hyperlink::new(&href, HyperlinkType::External),
+                    };
+                    (href, l)
+                });
+            }
+            match link.take() {
+                Some((href, l)) => link = Some((href, l.add_run(run))),
+                None => p = p.add_run(run),
+            }
+        }
+        if let Some((_, l)) = link {
+            p = p.add_hyperlink(l);
+        }
+        out.push(Content::Paragraph(Box::new(p)));
+    }
+}
+
+/// Adds the cells that continue cells merged from the rows above, from the
+/// given column on.
+fn continue_merges(
+    merged: &mut [Option<(usize, usize)>],
+    column: &mut usize,
+    cells: &mut Vec<TableCell>,
+) {
+    while let Some(Some((rows_left, span))) = merged.get(*column).copied() {
+        let mut cell = TableCell::new()
+            .vertical_merge(VMergeType::Continue)
+            .add_paragraph(Paragraph::new());
+        if span > 1 {
+            cell = cell.grid_span(span);
+        }
+        cells.push(cell);
+        merged[*column] = if rows_left > 1 {
+            Some((rows_left - 1, span))
+        } else {
+            None
+        };
+        *column += span;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_html() {
+        let html = "<h1>Title</h1>\n<p style=\"text-align: center\">Some <b>bold</b>,\n  <span style=\"color: red; font-size: 14pt; font-family: 'Arial', sans-serif\">red</span> \
+            and <a href=\"https://example.com\">a <i>link</i></a>.<br>Next</p>\
+            <ul><li>one<ol start=\"3\"><li>nested</li></ol></li><li><p>two</p><p>more</p></li></ul>\
+            <blockquote>quoted</blockquote><pre>\nlet x;\n  y\n</pre>";
+        let docx = read_html(html, &HtmlImportOptions::new());
+        let md = docx.to_markdown(&MarkdownOptions::new());
+        assert_eq!(
+            md.text,
+            "# Title\n\nSome **bold**, red and [a *link*](https://example.com).\\\nNext\n\n- one\n    1. nested\n- two\n\nmore\n\n*quoted*\n\n```\nlet x;\n  y\n```\n"
+        );
+        let labels: Vec<String> = docx.list_labels().into_iter().map(|l| l.text).collect();
+        assert_eq!(labels, vec!["•", "c.", "•"]);
+        let DocumentChild::Paragraph(p) = &docx.document.children[1] else {
+            unreachable!()
+        };
+        assert_eq!(
+            p.property.alignment,
+            Some(Justification::new(AlignmentType::Center.to_string()))
+        );
+        let ParagraphChild::Run(red) = &p.children[3] else {
+            unreachable!()
+        };
+        assert_eq!(red.run_property.color, Some(Color::new("FF0000")));
+        assert_eq!(red.run_property.sz, Some(Sz::new(28)));
+        assert_eq!(
+            red.run_property
+                .fonts
+                .as_ref()
+                .and_then(|f| f.get_ascii().cloned()),
+            Some("Arial".to_owned())
+        );
+        for id in ["Heading1", "Quote", "SourceCode", "Hyperlink"] {
+            assert!(docx.styles.find_style_by_id(id).is_some(), "{id}");
+        }
+    }
+
+    #[test]
+    fn test_read_html_table_spans() {
+        let html = "<table><tr><th colspan=2>a</th><td rowspan=2>b</td></tr><tr><td>c<td>d</tr><tr><td>e<td>f<td>g</table>";
+        let docx = read_html(html, &HtmlImportOptions::new());
+        let DocumentChild::Table(table) = &docx.document.children[0] else {
+            unreachable!()
+        };
+        let cells = spanned_cells(table);
+        let spans: Vec<Vec<(usize, usize)>> = cells
+            .iter()
+            .map(|row| row.iter().map(|c| (c.colspan, c.rowspan)).collect())
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                vec![(2, 1), (1, 2)],
+                vec![(1, 1), (1, 1)],
+                vec![(1, 1), (1, 1), (1, 1)]
+            ]
+        );
+        let TableChild::TableRow(row) = &table.rows[1];
+        assert_eq!(row.cells.len(), 3);
+    }
+
+    #[test]
+    fn test_insert_html_fragment() {
+        let png = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+        let docx = Docx::new()
+            .add_style(Style::new("Note", StyleType::Paragraph).name("Note"))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("first")))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("last")))
+            .insert_html(
+                1,
+                &format!("<div class=\"Note\">a <img src=\"data:image/png;base64,{png}\" width=\"20\"></div>"),
+                &HtmlImportOptions::new(),
+            );
+        assert_eq!(docx.document.children.len(), 3);
+        let DocumentChild::Paragraph(p) = &docx.document.children[1] else {
+            unreachable!()
+        };
+        assert_eq!(p.property.style, Some(ParagraphStyle::new(Some("Note"))));
+        let ParagraphChild::Run(run) = &p.children[1] else {
+            unreachable!()
+        };
+        let RunChild::Drawing(drawing) = &run.children[0] else {
+            unreachable!()
+        };
+        let Some(DrawingData::Pic(pic)) = &drawing.data else {
+            unreachable!()
+        };
+        assert_eq!(pic.size, (20 * 9525, 20 * 9525));
+    }
+}
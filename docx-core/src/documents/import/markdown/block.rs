@@ -0,0 +1,619 @@
+//! Splits Markdown into blocks: the container structure of CommonMark and
+//! the tables and footnotes of GitHub Flavored Markdown.
+
+use std::collections::HashMap;
+
+use crate::types::AlignmentType;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum MdBlock {
+    Heading(usize, String),
+    Paragraph(String),
+    Code(String),
+    Quote(Vec<MdBlock>),
+    List(MdList),
+    Table(MdTable),
+    Rule,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct MdList {
+    pub ordered: bool,
+    pub start: usize,
+    pub items: Vec<MdItem>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct MdItem {
+    /// Whether the box of a task list item is checked.
+    pub task: Option<bool>,
+    pub blocks: Vec<MdBlock>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct MdTable {
+    pub aligns: Vec<Option<AlignmentType>>,
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Link reference definitions and footnote definitions, which may appear
+/// anywhere in the document.
+#[derive(Debug, Default)]
+pub(super) struct References {
+    pub links: HashMap<String, String>,
+    pub footnotes: HashMap<String, Vec<MdBlock>>,
+}
+
+/// Labels match case-insensitively with runs of whitespace collapsed.
+pub(super) fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+pub(super) fn parse_blocks(markdown: &str, references: &mut References) -> Vec<MdBlock> {
+    let lines: Vec<String> = markdown.lines().map(expand_tabs).collect();
+    Parser { references }.blocks(&lines)
+}
+
+/// Replaces tabs by spaces up to the next multiple of four columns.
+fn expand_tabs(line: &str) -> String {
+    let mut out = String::new();
+    let mut column = 0;
+    for c in line.chars() {
+        if c == '\t' {
+            let n = 4 - column % 4;
+            out.push_str(&" ".repeat(n));
+            column += n;
+        } else {
+            out.push(c);
+            column += 1;
+        }
+    }
+    out
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// The line without its first `n` columns of indentation.
+fn dedent(line: &str, n: usize) -> &str {
+    &line[indent(line).min(n)..]
+}
+
+struct Fence {
+    indent: usize,
+    marker: char,
+    len: usize,
+}
+
+fn fence_start(line: &str) -> Option<Fence> {
+    let indent = indent(line);
+    let rest = &line[indent..];
+    let marker = rest.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = rest.chars().take_while(|c| *c == marker).count();
+    if indent > 3 || len < 3 || (marker == '`' && rest[len..].contains('`')) {
+        return None;
+    }
+    Some(Fence {
+        indent,
+        marker,
+        len,
+    })
+}
+
+fn fence_end(line: &str, fence: &Fence) -> bool {
+    let rest = line.trim_start_matches(' ');
+    indent(line) < 4
+        && rest.chars().take_while(|c| *c == fence.marker).count() >= fence.len
+        && rest.trim_start_matches(fence.marker).trim().is_empty()
+}
+
+fn atx_heading(line: &str) -> Option<(usize, String)> {
+    if indent(line) > 3 {
+        return None;
+    }
+    let rest = line.trim_start();
+    let level = rest.chars().take_while(|c| *c == '#').count();
+    let content = &rest[level..];
+    if !(1..=6).contains(&level) || !(content.is_empty() || content.starts_with(' ')) {
+        return None;
+    }
+    let content = content.trim();
+    // A closing sequence of `#` must follow a space.
+    let stripped = content.trim_end_matches('#');
+    let content = if stripped.is_empty() {
+        stripped
+    } else if stripped.ends_with(' ') {
+        stripped.trim_end()
+    } else {
+        content
+    };
+    Some((level, content.to_owned()))
+}
+
+fn is_rule(line: &str) -> bool {
+    if indent(line) > 3 {
+        return false;
+    }
+    let chars: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+    chars.len() >= 3 && matches!(chars[0], '*' | '-' | '_') && chars.iter().all(|c| *c == chars[0])
+}
+
+fn setext_level(line: &str) -> Option<usize> {
+    let rest = line.trim();
+    if indent(line) > 3 || rest.is_empty() {
+        return None;
+    }
+    if rest.chars().all(|c| c == '=') {
+        Some(1)
+    } else if rest.chars().all(|c| c == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+struct Marker {
+    ordered: bool,
+    /// The bullet character, or the delimiter after the number.
+    symbol: char,
+    start: usize,
+    /// The column the content of the item starts at.
+    content: usize,
+    empty: bool,
+}
+
+fn list_marker(line: &str) -> Option<Marker> {
+    let offset = indent(line);
+    if offset > 3 {
+        return None;
+    }
+    let rest = &line[offset..];
+    let (ordered, symbol, start, width) = match rest.chars().next()? {
+        c @ ('-' | '+' | '*') => (false, c, 1, 1),
+        _ => {
+            let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+            let symbol = rest[digits..].chars().next()?;
+            if !(1..=9).contains(&digits) || !(symbol == '.' || symbol == ')') {
+                return None;
+            }
+            (true, symbol, rest[..digits].parse().ok()?, digits + 1)
+        }
+    };
+    let after = &rest[width..];
+    if !(after.is_empty() || after.starts_with(' ')) {
+        return None;
+    }
+    let spaces = indent(after);
+    let empty = after.trim().is_empty();
+    let gap = if empty || spaces > 4 { 1 } else { spaces };
+    Some(Marker {
+        ordered,
+        symbol,
+        start,
+        content: offset + width + gap,
+        empty,
+    })
+}
+
+fn footnote_definition(line: &str) -> Option<(String, &str)> {
+    let rest = line.trim_start().strip_prefix("[^")?;
+    let (label, text) = rest.split_once("]:")?;
+    if indent(line) > 3 || label.is_empty() || label.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((normalize_label(label), text.trim_start()))
+}
+
+fn link_definition(line: &str) -> Option<(String, String)> {
+    let rest = line.trim_start().strip_prefix('[')?;
+    let (label, destination) = rest.split_once("]:")?;
+    if indent(line) > 3 || label.trim().is_empty() || label.starts_with('^') {
+        return None;
+    }
+    let destination = destination.split_whitespace().next()?;
+    let destination = destination
+        .strip_prefix('<')
+        .and_then(|d| d.strip_suffix('>'))
+        .unwrap_or(destination);
+    Some((normalize_label(label), destination.to_owned()))
+}
+
+/// Splits a table row into cells, keeping `\|` inside cells.
+fn table_cells(line: &str) -> Vec<String> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = if line.ends_with('|') && !line.ends_with("\\|") {
+        &line[..line.len() - 1]
+    } else {
+        line
+    };
+    let mut cells = vec![];
+    let mut cell = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_owned()),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_owned());
+    cells
+}
+
+fn table_delimiters(line: &str) -> Option<Vec<Option<AlignmentType>>> {
+    if !line.contains('-') || indent(line) > 3 {
+        return None;
+    }
+    table_cells(line)
+        .iter()
+        .map(|cell| {
+            let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+            if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+                return None;
+            }
+            Some(match (cell.starts_with(':'), cell.ends_with(':')) {
+                (true, true) => Some(AlignmentType::Center),
+                (false, true) => Some(AlignmentType::Right),
+                (true, false) => Some(AlignmentType::Left),
+                (false, false) => None,
+            })
+        })
+        .collect()
+}
+
+/// Whether the line starts a block that ends a paragraph.
+fn interrupts_paragraph(line: &str) -> bool {
+    fence_start(line).is_some()
+        || atx_heading(line).is_some()
+        || is_rule(line)
+        || (indent(line) < 4 && line.trim_start().starts_with('>'))
+        || list_marker(line).is_some_and(|m| !m.empty && (!m.ordered || m.start == 1))
+}
+
+struct Parser<'r> {
+    references: &'r mut References,
+}
+
+impl Parser<'_> {
+    fn blocks(&mut self, lines: &[String]) -> Vec<MdBlock> {
+        let mut blocks = vec![];
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i].as_str();
+            if is_blank(line) {
+                i += 1;
+            } else if indent(line) >= 4 {
+                i = self.indented_code(lines, i, &mut blocks);
+            } else if let Some(fence) = fence_start(line) {
+                i = self.fenced_code(lines, i, fence, &mut blocks);
+            } else if let Some((level, text)) = atx_heading(line) {
+                blocks.push(MdBlock::Heading(level, text));
+                i += 1;
+            } else if is_rule(line) {
+                blocks.push(MdBlock::Rule);
+                i += 1;
+            } else if line.trim_start().starts_with('>') {
+                i = self.quote(lines, i, &mut blocks);
+            } else if let Some(marker) = list_marker(line) {
+                i = self.list(lines, i, marker, &mut blocks);
+            } else if let Some((label, text)) = footnote_definition(line) {
+                i = self.footnote(lines, i, label, text);
+            } else if let Some((label, destination)) = link_definition(line) {
+                self.references.links.entry(label).or_insert(destination);
+                i += 1;
+            } else if let Some(aligns) = lines
+                .get(i + 1)
+                .filter(|_| line.contains('|'))
+                .and_then(|l| table_delimiters(l))
+                .filter(|a| a.len() == table_cells(line).len())
+            {
+                i = self.table(lines, i, aligns, &mut blocks);
+            } else {
+                i = self.paragraph(lines, i, &mut blocks);
+            }
+        }
+        blocks
+    }
+
+    fn indented_code(
+        &mut self,
+        lines: &[String],
+        mut i: usize,
+        blocks: &mut Vec<MdBlock>,
+    ) -> usize {
+        let mut code = vec![];
+        while i < lines.len() && (is_blank(&lines[i]) || indent(&lines[i]) >= 4) {
+            code.push(lines[i].get(4..).unwrap_or(""));
+            i += 1;
+        }
+        while code.last().is_some_and(|l| l.trim().is_empty()) {
+            code.pop();
+        }
+        blocks.push(MdBlock::Code(code.join("\n")));
+        i
+    }
+
+    fn fenced_code(
+        &mut self,
+        lines: &[String],
+        mut i: usize,
+        fence: Fence,
+        blocks: &mut Vec<MdBlock>,
+    ) -> usize {
+        let mut code = vec![];
+        i += 1;
+        while i < lines.len() && !fence_end(&lines[i], &fence) {
+            code.push(dedent(&lines[i], fence.indent));
+            i += 1;
+        }
+        blocks.push(MdBlock::Code(code.join("\n")));
+        i + 1
+    }
+
+    fn quote(&mut self, lines: &[String], mut i: usize, blocks: &mut Vec<MdBlock>) -> usize {
+        let mut content: Vec<String> = vec![];
+        while i < lines.len() && !is_blank(&lines[i]) {
+            let line = &lines[i];
+            let rest = line.trim_start();
+            if indent(line) < 4 && rest.starts_with('>') {
+                let rest = &rest[1..];
+                content.push(rest.strip_prefix(' ').unwrap_or(rest).to_owned());
+            } else if content.last().is_some_and(|l| !is_blank(l)) && !interrupts_paragraph(line) {
+                // A lazy continuation of a quoted paragraph.
+                content.push(line.to_owned());
+            } else {
+                break;
+            }
+            i += 1;
+        }
+        blocks.push(MdBlock::Quote(self.blocks(&content)));
+        i
+    }
+
+    fn list(
+        &mut self,
+        lines: &[String],
+        mut i: usize,
+        first: Marker,
+        blocks: &mut Vec<MdBlock>,
+    ) -> usize {
+        let mut list = MdList {
+            ordered: first.ordered,
+            start: first.start,
+            items: vec![],
+        };
+        let mut marker = Some(first);
+        while let Some(m) = marker.take() {
+            let mut content = vec![lines[i].get(m.content..).unwrap_or("").to_owned()];
+            i += 1;
+            while i < lines.len() {
+                let line = &lines[i];
+                if is_blank(line) {
+                    content.push(String::new());
+                } else if indent(line) >= m.content {
+                    content.push(line[m.content..].to_owned());
+                } else if content.last().is_some_and(|l| !is_blank(l))
+                    && !interrupts_paragraph(line)
+                    && list_marker(line).is_none()
+                {
+                    content.push(line.trim_start().to_owned());
+                } else {
+                    break;
+                }
+                i += 1;
+            }
+            // Blank lines at the end belong between the items.
+            while content.last().is_some_and(|l| is_blank(l)) {
+                content.pop();
+            }
+            let mut task = None;
+            if let Some(first) = content.first_mut() {
+                for (prefix, checked) in [("[ ] ", false), ("[x] ", true), ("[X] ", true)] {
+                    if let Some(rest) = first.strip_prefix(prefix) {
+                        task = Some(checked);
+                        *first = rest.to_owned();
+                        break;
+                    }
+                }
+            }
+            list.items.push(MdItem {
+                task,
+                blocks: self.blocks(&content),
+            });
+
+            let mut next = i;
+            while next < lines.len() && is_blank(&lines[next]) {
+                next += 1;
+            }
+            if next < lines.len() && !is_rule(&lines[next]) {
+                if let Some(n) = list_marker(&lines[next]) {
+                    if n.ordered == m.ordered && n.symbol == m.symbol {
+                        i = next;
+                        marker = Some(n);
+                    }
+                }
+            }
+        }
+        blocks.push(MdBlock::List(list));
+        i
+    }
+
+    fn footnote(&mut self, lines: &[String], mut i: usize, label: String, text: &str) -> usize {
+        let mut content = vec![text.to_owned()];
+        i += 1;
+        while i < lines.len() {
+            let line = &lines[i];
+            if is_blank(line) {
+                content.push(String::new());
+            } else if indent(line) >= 4 {
+                content.push(line[4..].to_owned());
+            } else if content.last().is_some_and(|l| !is_blank(l)) && !interrupts_paragraph(line) {
+                content.push(line.trim_start().to_owned());
+            } else {
+                break;
+            }
+            i += 1;
+        }
+        let blocks = self.blocks(&content);
+        self.references.footnotes.entry(label).or_insert(blocks);
+        i
+    }
+
+    fn table(
+        &mut self,
+        lines: &[String],
+        mut i: usize,
+        aligns: Vec<Option<AlignmentType>>,
+        blocks: &mut Vec<MdBlock>,
+    ) -> usize {
+        let header = table_cells(&lines[i]);
+        let columns = header.len();
+        let mut rows = vec![];
+        i += 2;
+        while i < lines.len() && !is_blank(&lines[i]) && !interrupts_paragraph(&lines[i]) {
+            let mut cells = table_cells(&lines[i]);
+            cells.resize(columns, String::new());
+            rows.push(cells);
+            i += 1;
+        }
+        blocks.push(MdBlock::Table(MdTable {
+            aligns,
+            header,
+            rows,
+        }));
+        i
+    }
+
+    fn paragraph(&mut self, lines: &[String], mut i: usize, blocks: &mut Vec<MdBlock>) -> usize {
+        let mut text = vec![lines[i].trim_start()];
+        i += 1;
+        while i < lines.len() {
+            let line = lines[i].as_str();
+            if let Some(level) = setext_level(line) {
+                blocks.push(MdBlock::Heading(level, text.join("\n")));
+                return i + 1;
+            }
+            if is_blank(line) || interrupts_paragraph(line) {
+                break;
+            }
+            // Keeps trailing spaces, which mark hard line breaks.
+            text.push(line.trim_start());
+            i += 1;
+        }
+        let text = text.join("\n");
+        blocks.push(MdBlock::Paragraph(text.trim_end().to_owned()));
+        i
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn parse(markdown: &str) -> Vec<MdBlock> {
+        parse_blocks(markdown, &mut References::default())
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let markdown = "# Title #\n\nSome *text*\ncontinued\n\n> quoted\nlazy\n\n```rust\nfn main() {}\n```\n\n---\n\nSub\n===\n";
+        assert_eq!(
+            parse(markdown),
+            vec![
+                MdBlock::Heading(1, "Title".to_owned()),
+                MdBlock::Paragraph("Some *text*\ncontinued".to_owned()),
+                MdBlock::Quote(vec![MdBlock::Paragraph("quoted\nlazy".to_owned())]),
+                MdBlock::Code("fn main() {}".to_owned()),
+                MdBlock::Rule,
+                MdBlock::Heading(1, "Sub".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_lists() {
+        let markdown = "1. one\n2. two\n   - [x] done\n   - open\n\n     more\n3) other list\n";
+        assert_eq!(
+            parse(markdown),
+            vec![
+                MdBlock::List(MdList {
+                    ordered: true,
+                    start: 1,
+                    items: vec![
+                        MdItem {
+                            task: None,
+                            blocks: vec![MdBlock::Paragraph("one".to_owned())],
+                        },
+                        MdItem {
+                            task: None,
+                            blocks: vec![
+                                MdBlock::Paragraph("two".to_owned()),
+                                MdBlock::List(MdList {
+                                    ordered: false,
+                                    start: 1,
+                                    items: vec![
+                                        MdItem {
+                                            task: Some(true),
+                                            blocks: vec![MdBlock::Paragraph("done".to_owned())],
+                                        },
+                                        MdItem {
+                                            task: None,
+                                            blocks: vec![
+                                                MdBlock::Paragraph("open".to_owned()),
+                                                MdBlock::Paragraph("more".to_owned()),
+                                            ],
+                                        },
+                                    ],
+                                }),
+                            ],
+                        },
+                    ],
+                }),
+                MdBlock::List(MdList {
+                    ordered: true,
+                    start: 3,
+                    items: vec![MdItem {
+                        task: None,
+                        blocks: vec![MdBlock::Paragraph("other list".to_owned())],
+                    }],
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_tables_and_definitions() {
+        let mut references = References::default();
+        let markdown = "| a | b \\| c |\n|:--|--:|\n| 1 |\n\n[^n]: Note\n    text\n\n[Home]: <https://example.com> \"Title\"\n";
+        assert_eq!(
+            parse_blocks(markdown, &mut references),
+            vec![MdBlock::Table(MdTable {
+                aligns: vec![Some(AlignmentType::Left), Some(AlignmentType::Right)],
+                header: vec!["a".to_owned(), "b | c".to_owned()],
+                rows: vec![vec!["1".to_owned(), String::new()]],
+            })]
+        );
+        assert_eq!(
+            references.footnotes["n"],
+            vec![MdBlock::Paragraph("Note\ntext".to_owned())]
+        );
+        assert_eq!(references.links["home"], "https://example.com");
+    }
+}
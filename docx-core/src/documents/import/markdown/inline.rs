@@ -0,0 +1,632 @@
+//! Parses the inline content of Markdown blocks: emphasis after the delimiter
+//! rules of CommonMark, code spans, links, images, autolinks, GFM
+//! strikethrough and footnote references.
+
+use super::block::{normalize_label, References};
+use crate::documents::decode_entity;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum MdInline {
+    Text(String),
+    Code(String),
+    Emphasis(Vec<MdInline>),
+    Strong(Vec<MdInline>),
+    Strike(Vec<MdInline>),
+    Link {
+        url: String,
+        children: Vec<MdInline>,
+    },
+    Image {
+        url: String,
+        alt: String,
+    },
+    FootnoteReference(String),
+    Break,
+}
+
+pub(super) fn parse_inlines(text: &str, references: &References) -> Vec<MdInline> {
+    let mut parser = InlineParser {
+        chars: text.chars().collect(),
+        pos: 0,
+        tokens: vec![],
+        references,
+    };
+    parser.parse();
+    resolve_emphasis(parser.tokens)
+}
+
+/// The text of inlines without formatting, e.g. for the alternative text of
+/// an image.
+pub(super) fn plain_text(inlines: &[MdInline]) -> String {
+    let mut text = String::new();
+    for inline in inlines {
+        match inline {
+            MdInline::Text(t) | MdInline::Code(t) => text.push_str(t),
+            MdInline::Emphasis(children)
+            | MdInline::Strong(children)
+            | MdInline::Strike(children)
+            | MdInline::Link { children, .. } => text.push_str(&plain_text(children)),
+            MdInline::Image { alt, .. } => text.push_str(alt),
+            MdInline::Break => text.push(' '),
+            MdInline::FootnoteReference(_) => {}
+        }
+    }
+    text
+}
+
+enum Token {
+    Text(String),
+    Inline(MdInline),
+    Delimiter(Delimiter),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Delimiter {
+    c: char,
+    n: usize,
+    open: bool,
+    close: bool,
+}
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || (!c.is_alphanumeric() && !c.is_whitespace() && !c.is_ascii())
+}
+
+struct InlineParser<'r> {
+    chars: Vec<char>,
+    pos: usize,
+    tokens: Vec<Token>,
+    references: &'r References,
+}
+
+impl InlineParser<'_> {
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn text(&mut self, text: &str) {
+        if let Some(Token::Text(last)) = self.tokens.last_mut() {
+            last.push_str(text);
+        } else {
+            self.tokens.push(Token::Text(text.to_owned()));
+        }
+    }
+
+    fn parse(&mut self) {
+        while let Some(c) = self.peek(0) {
+            match c {
+                '\\' => self.escape(),
+                '`' => self.code_span(),
+                '*' | '_' | '~' => self.delimiter(c),
+                '!' if self.peek(1) == Some('[') => {
+                    self.pos += 1;
+                    if !self.link(true) {
+                        self.text("!");
+                    }
+                }
+                '[' => {
+                    if !self.footnote_reference() && !self.link(false) {
+                        self.text("[");
+                        self.pos += 1;
+                    }
+                }
+                '<' => self.angle_bracket(),
+                '&' => self.entity(),
+                '\n' => self.line_break(),
+                'h' | 'w' if self.autolink_allowed() && self.extended_autolink() => {}
+                _ => {
+                    self.text(&c.to_string());
+                    self.pos += 1;
+                }
+            }
+        }
+    }
+
+    fn escape(&mut self) {
+        match self.peek(1) {
+            Some('\n') => {
+                self.tokens.push(Token::Inline(MdInline::Break));
+                self.pos += 2;
+            }
+            Some(c) if c.is_ascii_punctuation() => {
+                self.text(&c.to_string());
+                self.pos += 2;
+            }
+            _ => {
+                self.text("\\");
+                self.pos += 1;
+            }
+        }
+    }
+
+    fn run_length(&self, from: usize, c: char) -> usize {
+        self.chars[from..].iter().take_while(|x| **x == c).count()
+    }
+
+    fn code_span(&mut self) {
+        let n = self.run_length(self.pos, '`');
+        let start = self.pos + n;
+        let mut i = start;
+        while i < self.chars.len() {
+            if self.chars[i] == '`' {
+                let m = self.run_length(i, '`');
+                if m == n {
+                    let code: String = self.chars[start..i]
+                        .iter()
+                        .map(|c| if *c == '\n' { ' ' } else { *c })
+                        .collect();
+                    let code = match code.strip_prefix(' ').and_then(|c| c.strip_suffix(' ')) {
+                        Some(inner) if !inner.trim().is_empty() => inner.to_owned(),
+                        _ => code,
+                    };
+                    self.tokens.push(Token::Inline(MdInline::Code(code)));
+                    self.pos = i + m;
+                    return;
+                }
+                i += m;
+            } else {
+                i += 1;
+            }
+        }
+        self.text(&"`".repeat(n));
+        self.pos = start;
+    }
+
+    fn delimiter(&mut self, c: char) {
+        let n = self.run_length(self.pos, c);
+        let before = self.pos.checked_sub(1).map_or(' ', |i| self.chars[i]);
+        let after = self.chars.get(self.pos + n).copied().unwrap_or(' ');
+        self.pos += n;
+        if c == '~' && n > 2 {
+            self.text(&"~".repeat(n));
+            return;
+        }
+        let left = !after.is_whitespace()
+            && (!is_punctuation(after) || before.is_whitespace() || is_punctuation(before));
+        let right = !before.is_whitespace()
+            && (!is_punctuation(before) || after.is_whitespace() || is_punctuation(after));
+        let (open, close) = if c == '_' {
+            (
+                left && (!right || is_punctuation(before)),
+                right && (!left || is_punctuation(after)),
+            )
+        } else {
+            (left, right)
+        };
+        self.tokens
+            .push(Token::Delimiter(Delimiter { c, n, open, close }));
+    }
+
+    fn footnote_reference(&mut self) -> bool {
+        if self.peek(1) != Some('^') {
+            return false;
+        }
+        let Some(close) = self.closing_bracket(self.pos) else {
+            return false;
+        };
+        let label: String = self.chars[self.pos + 2..close].iter().collect();
+        let label = normalize_label(&label);
+        if !self.references.footnotes.contains_key(&label) {
+            return false;
+        }
+        self.tokens
+            .push(Token::Inline(MdInline::FootnoteReference(label)));
+        self.pos = close + 1;
+        true
+    }
+
+    /// The `]` matching the `[` at `open`.
+    fn closing_bracket(&self, open: usize) -> Option<usize> {
+        let mut depth = 0;
+        let mut i = open;
+        while i < self.chars.len() {
+            match self.chars[i] {
+                '\\' => i += 1,
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i);
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Parses a link or image whose text starts at the current `[`.
+    fn link(&mut self, image: bool) -> bool {
+        let Some(close) = self.closing_bracket(self.pos) else {
+            return false;
+        };
+        let inner: String = self.chars[self.pos + 1..close].iter().collect();
+        let target = match self.chars.get(close + 1) {
+            Some('(') => self.destination(close + 1),
+            Some('[') => self.closing_bracket(close + 1).and_then(|end| {
+                let label: String = self.chars[close + 2..end].iter().collect();
+                let label = if label.is_empty() { &inner } else { &label };
+                self.reference(label).map(|url| (url, end + 1))
+            }),
+            _ => None,
+        }
+        .or_else(|| self.reference(&inner).map(|url| (url, close + 1)));
+        let Some((url, end)) = target else {
+            return false;
+        };
+        let children = parse_inlines(&inner, self.references);
+        self.tokens.push(Token::Inline(if image {
+            MdInline::Image {
+                url,
+                alt: plain_text(&children),
+            }
+        } else {
+            MdInline::Link { url, children }
+        }));
+        self.pos = end;
+        true
+    }
+
+    fn reference(&self, label: &str) -> Option<String> {
+        self.references.links.get(&normalize_label(label)).cloned()
+    }
+
+    /// Parses `(destination "title")` at `open` and returns the destination
+    /// and the position after the `)`.
+    fn destination(&self, open: usize) -> Option<(String, usize)> {
+        let chars = &self.chars;
+        let skip_spaces = |mut i: usize| {
+            while chars.get(i).is_some_and(|c| c.is_whitespace()) {
+                i += 1;
+            }
+            i
+        };
+        let mut i = skip_spaces(open + 1);
+        let mut url = String::new();
+        if chars.get(i) == Some(&'<') {
+            i += 1;
+            while *chars.get(i)? != '>' {
+                url.push(chars[i]);
+                i += 1;
+            }
+            i += 1;
+        } else {
+            let mut depth = 0;
+            while let Some(&c) = chars.get(i) {
+                match c {
+                    '\\' if chars.get(i + 1).is_some_and(|c| c.is_ascii_punctuation()) => {
+                        url.push(chars[i + 1]);
+                        i += 2;
+                        continue;
+                    }
+                    '(' => depth += 1,
+                    ')' if depth == 0 => break,
+                    ')' => depth -= 1,
+                    c if c.is_whitespace() => break,
+                    _ => {}
+                }
+                url.push(c);
+                i += 1;
+            }
+        }
+        i = skip_spaces(i);
+        if let Some(&quote) = chars.get(i).filter(|c| matches!(c, '"' | '\'' | '(')) {
+            let end = if quote == '(' { ')' } else { quote };
+            i += 1;
+            while *chars.get(i)? != end {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i = skip_spaces(i + 1);
+        }
+        (chars.get(i) == Some(&')')).then_some((url, i + 1))
+    }
+
+    /// Autolinks such as `<https://example.com>` and `<br>` tags; other
+    /// HTML is kept as text.
+    fn angle_bracket(&mut self) {
+        let rest: String = self.chars[self.pos + 1..]
+            .iter()
+            .take_while(|c| **c != '>' && **c != '<' && !c.is_whitespace())
+            .collect();
+        let end = self.pos + 1 + rest.chars().count();
+        if self.chars.get(end) == Some(&'>') {
+            let scheme = rest.split_once(':').map(|(s, _)| s);
+            let url = if scheme.is_some_and(|s| {
+                (2..=32).contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphanumeric())
+            }) {
+                Some(rest.clone())
+            } else if rest.contains('@') && !rest.contains(':') {
+                Some(format!("mailto:{rest}"))
+            } else {
+                None
+            };
+            if let Some(url) = url {
+                self.tokens.push(Token::Inline(MdInline::Link {
+                    url,
+                    children: vec![MdInline::Text(rest)],
+                }));
+                self.pos = end + 1;
+                return;
+            }
+        }
+        let tag: String = self.chars[self.pos..]
+            .iter()
+            .take(6)
+            .collect::<String>()
+            .to_lowercase();
+        for br in ["<br>", "<br/>", "<br />"] {
+            if tag.starts_with(br) {
+                self.tokens.push(Token::Inline(MdInline::Break));
+                self.pos += br.len();
+                return;
+            }
+        }
+        self.text("<");
+        self.pos += 1;
+    }
+
+    fn entity(&mut self) {
+        let name: String = self.chars[self.pos + 1..]
+            .iter()
+            .take(10)
+            .take_while(|c| **c != ';')
+            .collect();
+        let decoded = if self.chars.get(self.pos + 1 + name.chars().count()) == Some(&';') {
+            decode_entity(&name)
+        } else {
+            None
+        };
+        match decoded {
+            Some(c) => {
+                self.text(&c.to_string());
+                self.pos += name.chars().count() + 2;
+            }
+            None => {
+                self.text("&");
+                self.pos += 1;
+            }
+        }
+    }
+
+    /// A newline after two spaces is a hard break, otherwise a space.
+    fn line_break(&mut self) {
+        let mut hard = false;
+        if let Some(Token::Text(last)) = self.tokens.last_mut() {
+            hard = last.ends_with("  ");
+            let trimmed = last.trim_end_matches(' ').len();
+            last.truncate(trimmed);
+        }
+        if hard {
+            self.tokens.push(Token::Inline(MdInline::Break));
+        } else {
+            self.text(" ");
+        }
+        self.pos += 1;
+        while self.peek(0) == Some(' ') {
+            self.pos += 1;
+        }
+    }
+
+    fn autolink_allowed(&self) -> bool {
+        self.pos == 0
+            || matches!(self.chars[self.pos - 1], '(' | '*' | '_' | '~')
+            || self.chars[self.pos - 1].is_whitespace()
+    }
+
+    /// GFM links to bare `http://`, `https://` and `www.` addresses.
+    fn extended_autolink(&mut self) -> bool {
+        let rest: String = self.chars[self.pos..]
+            .iter()
+            .take_while(|c| !c.is_whitespace() && **c != '<')
+            .collect();
+        let prefix = ["https://", "http://", "www."]
+            .into_iter()
+            .find(|p| rest.starts_with(p));
+        let Some(prefix) = prefix else {
+            return false;
+        };
+        let mut text = rest.as_str();
+        loop {
+            let trimmed =
+                text.trim_end_matches(['?', '!', '.', ',', ':', '*', '_', '~', '\'', '"']);
+            let trimmed = if trimmed.ends_with(')')
+                && trimmed.matches(')').count() > trimmed.matches('(').count()
+            {
+                &trimmed[..trimmed.len() - 1]
+            } else {
+                trimmed
+            };
+            if trimmed == text {
+                break;
+            }
+            text = trimmed;
+        }
+        if text.len() <= prefix.len() {
+            return false;
+        }
+        let url = if prefix == "www." {
+            format!("http://{text}")
+        } else {
+            text.to_owned()
+        };
+        self.tokens.push(Token::Inline(MdInline::Link {
+            url,
+            children: vec![MdInline::Text(text.to_owned())],
+        }));
+        self.pos += text.chars().count();
+        true
+    }
+}
+
+/// Matches emphasis delimiters from the innermost closer outwards, as the
+/// "process emphasis" procedure of CommonMark does.
+fn resolve_emphasis(mut tokens: Vec<Token>) -> Vec<MdInline> {
+    let mut c = 0;
+    while c < tokens.len() {
+        let closer = match &tokens[c] {
+            Token::Delimiter(d) if d.close => *d,
+            _ => {
+                c += 1;
+                continue;
+            }
+        };
+        let opener = (0..c).rev().find(|&o| match &tokens[o] {
+            Token::Delimiter(d) if d.open && d.c == closer.c => {
+                if closer.c == '~' {
+                    d.n == closer.n
+                } else {
+                    // The rule of three.
+                    !((d.close || closer.open)
+                        && (d.n + closer.n) % 3 == 0
+                        && !(d.n % 3 == 0 && closer.n % 3 == 0))
+                }
+            }
+            _ => false,
+        });
+        let Some(o) = opener else {
+            c += 1;
+            continue;
+        };
+        let Token::Delimiter(open) = tokens[o] else {
+            unreachable!()
+        };
+        let used = if closer.c == '~' {
+            closer.n
+        } else if open.n >= 2 && closer.n >= 2 {
+            2
+        } else {
+            1
+        };
+        let children = to_inlines(tokens.drain(o + 1..c).collect());
+        let node = match (closer.c, used) {
+            ('~', _) => MdInline::Strike(children),
+            (_, 2) => MdInline::Strong(children),
+            _ => MdInline::Emphasis(children),
+        };
+        tokens.insert(o + 1, Token::Inline(node));
+        // The closer now follows the new node.
+        let mut closer_index = o + 2;
+        if let Token::Delimiter(d) = &mut tokens[closer_index] {
+            d.n -= used;
+            if d.n == 0 {
+                tokens.remove(closer_index);
+            }
+        }
+        if let Token::Delimiter(d) = &mut tokens[o] {
+            d.n -= used;
+            if d.n == 0 {
+                tokens.remove(o);
+                closer_index -= 1;
+            }
+        }
+        c = closer_index;
+    }
+    to_inlines(tokens)
+}
+
+/// Unmatched delimiters become text.
+fn to_inlines(tokens: Vec<Token>) -> Vec<MdInline> {
+    let mut inlines: Vec<MdInline> = vec![];
+    for token in tokens {
+        let text = match token {
+            Token::Text(text) => text,
+            Token::Delimiter(d) => d.c.to_string().repeat(d.n),
+            Token::Inline(inline) => {
+                inlines.push(inline);
+                continue;
+            }
+        };
+        if let Some(MdInline::Text(last)) = inlines.last_mut() {
+            last.push_str(&text);
+        } else {
+            inlines.push(MdInline::Text(text));
+        }
+    }
+    inlines
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn text(t: &str) -> MdInline {
+        MdInline::Text(t.to_owned())
+    }
+
+    #[test]
+    fn test_parse_emphasis() {
+        let references = References::default();
+        assert_eq!(
+            parse_inlines("a *b **c** d* ~~e~~ snake_case_name ***f***", &references),
+            vec![
+                text("a "),
+                MdInline::Emphasis(vec![
+                    text("b "),
+                    MdInline::Strong(vec![text("c")]),
+                    text(" d"),
+                ]),
+                text(" "),
+                MdInline::Strike(vec![text("e")]),
+                text(" snake_case_name "),
+                MdInline::Emphasis(vec![MdInline::Strong(vec![text("f")])]),
+            ]
+        );
+        assert_eq!(
+            parse_inlines("`a*b*` \\*x\\* 1 &lt; 2\\\nend", &references),
+            vec![
+                MdInline::Code("a*b*".to_owned()),
+                text(" *x* 1 < 2"),
+                MdInline::Break,
+                text("end"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_links() {
+        let mut references = References::default();
+        references
+            .links
+            .insert("home".to_owned(), "https://example.com".to_owned());
+        references.footnotes.insert("1".to_owned(), vec![]);
+        assert_eq!(
+            parse_inlines(
+                "[a *b*](/x \"t\") ![alt](i.png) [Home] <me@example.com> www.example.com. [^1] [no]",
+                &references
+            ),
+            vec![
+                MdInline::Link {
+                    url: "/x".to_owned(),
+                    children: vec![text("a "), MdInline::Emphasis(vec![text("b")])],
+                },
+                text(" "),
+                MdInline::Image {
+                    url: "i.png".to_owned(),
+                    alt: "alt".to_owned(),
+                },
+                text(" "),
+                MdInline::Link {
+                    url: "https://example.com".to_owned(),
+                    children: vec![text("Home")],
+                },
+                text(" "),
+                MdInline::Link {
+                    url: "mailto:me@example.com".to_owned(),
+                    children: vec![text("me@example.com")],
+                },
+                text(" "),
+                MdInline::Link {
+                    url: "http://www.example.com".to_owned(),
+                    children: vec![text("www.example.com")],
+                },
+                text(". "),
+                MdInline::FootnoteReference("1".to_owned()),
+                text(" [no]"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,506 @@
+mod block;
+mod inline;
+
+use std::collections::{BTreeSet, HashMap};
+
+use self::block::*;
+use self::inline::*;
+use super::*;
+
+/// Loads the bytes of an image from its source in the Markdown.
+pub type ImageLoader = Box<dyn Fn(&str) -> Option<Vec<u8>>>;
+
+/// The parts of Markdown whose style can be chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarkdownElement {
+    /// A heading of level 1 to 6, `Heading1` to `Heading6` by default.
+    Heading(usize),
+    /// Paragraph style of fenced and indented code, `SourceCode` by default.
+    CodeBlock,
+    /// Character style of code spans, `SourceCodeChar` by default.
+    InlineCode,
+    /// Paragraph style of block quotes, `Quote` by default.
+    BlockQuote,
+    /// Character style of links, `Hyperlink` by default.
+    Link,
+    /// Style of tables, `TableGrid` by default.
+    Table,
+    /// Paragraph style of footnotes, `FootnoteText` by default.
+    Footnote,
+}
+
+#[derive(Default)]
+pub struct MarkdownImportOptions {
+    styles: HashMap<MarkdownElement, String>,
+    image_loader: Option<ImageLoader>,
+}
+
+impl MarkdownImportOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Uses the style with the given id for an element, e.g. one defined by
+    /// a template. Styles other than the defaults are not created.
+    pub fn style(mut self, element: MarkdownElement, style_id: impl Into<String>) -> Self {
+        self.styles.insert(element, style_id.into());
+        self
+    }
+
+    /// Loads images whose source is not a `data:` URI, e.g. from files next
+    /// to the Markdown. Images that cannot be loaded show their alt text.
+    pub fn image_loader(mut self, loader: impl Fn(&str) -> Option<Vec<u8>> + 'static) -> Self {
+        self.image_loader = Some(Box::new(loader));
+        self
+    }
+
+    fn style_id(&self, element: MarkdownElement) -> String {
+        if let Some(id) = self.styles.get(&element) {
+            return id.clone();
+        }
+        match element {
+            MarkdownElement::Heading(level) => format!("Heading{}", level.clamp(1, 6)),
+            MarkdownElement::CodeBlock => "SourceCode".to_owned(),
+            MarkdownElement::InlineCode => "SourceCodeChar".to_owned(),
+            MarkdownElement::BlockQuote => "Quote".to_owned(),
+            MarkdownElement::Link => "Hyperlink".to_owned(),
+            MarkdownElement::Table => "TableGrid".to_owned(),
+            MarkdownElement::Footnote => "FootnoteText".to_owned(),
+        }
+    }
+}
+
+/// Makes a document of CommonMark with the GitHub extensions for tables,
+/// task lists, strikethrough, autolinks and footnotes.
+pub fn read_markdown(markdown: &str, options: &MarkdownImportOptions) -> Docx {
+    Docx::new().add_markdown(markdown, options)
+}
+
+impl Docx {
+    /// Appends the content of Markdown to the body. Each list gets a new
+    /// numbering, and the default styles the content uses are added unless
+    /// the document defines them.
+    pub fn add_markdown(mut self, markdown: &str, options: &MarkdownImportOptions) -> Docx {
+        let mut references = References::default();
+        let blocks = parse_blocks(markdown, &mut references);
+        let mut converter = Converter {
+            options,
+            references: &references,
+            docx: &mut self,
+            styles: BTreeSet::new(),
+            notes: vec![],
+        };
+        let mut contents = vec![];
+        converter.blocks(&blocks, Context::default(), &mut contents);
+        let styles = converter.styles;
+
+        let code = (
+            options.style_id(MarkdownElement::CodeBlock),
+            options.style_id(MarkdownElement::InlineCode),
+        );
+        let defaults = MarkdownImportOptions::new();
+        let default_code = (
+            defaults.style_id(MarkdownElement::CodeBlock),
+            defaults.style_id(MarkdownElement::InlineCode),
+        );
+        // Code styles are only made under their default ids.
+        let code = (
+            if code.0 == default_code.0 {
+                code.0.as_str()
+            } else {
+                ""
+            },
+            if code.1 == default_code.1 {
+                code.1.as_str()
+            } else {
+                ""
+            },
+        );
+        add_missing_styles(&mut self, &styles, code);
+
+        for content in contents {
+            self = match content {
+                Content::Paragraph(p) => self.add_paragraph(*p),
+                Content::Table(t) => self.add_table(*t),
+            };
+        }
+        self
+    }
+}
+
+/// Where a block sits.
+#[derive(Debug, Clone, Copy, Default)]
+struct Context {
+    /// The level of the list item the block continues.
+    list_level: Option<usize>,
+    quote: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Format {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+    code: bool,
+    link: bool,
+}
+
+enum Piece {
+    Run(Box<Run>),
+    Link(Hyperlink),
+}
+
+impl Piece {
+    fn run(run: Run) -> Self {
+        Piece::Run(Box::new(run))
+    }
+}
+
+struct Converter<'a> {
+    options: &'a MarkdownImportOptions,
+    references: &'a References,
+    docx: &'a mut Docx,
+    /// The ids of the styles used.
+    styles: BTreeSet<String>,
+    /// The footnotes being converted, against notes that refer to themselves.
+    notes: Vec<String>,
+}
+
+impl Converter<'_> {
+    fn style(&mut self, element: MarkdownElement) -> String {
+        let id = self.options.style_id(element);
+        self.styles.insert(id.clone());
+        id
+    }
+
+    fn blocks(&mut self, blocks: &[MdBlock], context: Context, out: &mut Vec<Content>) {
+        for block in blocks {
+            match block {
+                MdBlock::Heading(level, text) => {
+                    let style = self.style(MarkdownElement::Heading(*level));
+                    let p = self.inlines(Paragraph::new().style(&style), text, Format::default());
+                    out.push(Content::Paragraph(Box::new(p)));
+                }
+                MdBlock::Paragraph(text) => {
+                    let p = self.block_paragraph(context);
+                    let p = self.inlines(p, text, Format::default());
+                    out.push(Content::Paragraph(Box::new(p)));
+                }
+                MdBlock::Code(code) => {
+                    let style = self.style(MarkdownElement::CodeBlock);
+                    for line in code.split('\n') {
+                        let mut p = Paragraph::new().style(&style);
+                        if let Some(level) = context.list_level {
+                            p = p.indent(Some(list_indent(level)), None, None, None);
+                        }
+                        if !line.is_empty() {
+                            p = p.add_run(Run::new().add_text(line));
+                        }
+                        out.push(Content::Paragraph(Box::new(p)));
+                    }
+                }
+                MdBlock::Quote(blocks) => {
+                    let context = Context {
+                        quote: true,
+                        ..context
+                    };
+                    self.blocks(blocks, context, out);
+                }
+                MdBlock::List(list) => self.list(list, context, out),
+                MdBlock::Table(table) => {
+                    let table = self.table(table);
+                    out.push(Content::Table(Box::new(table)));
+                }
+                MdBlock::Rule => {
+                    let mut p = Paragraph::new();
+                    p.property = p.property.set_border(
+                        ParagraphBorder::new(ParagraphBorderPosition::Bottom)
+                            .size(6)
+                            .space(1),
+                    );
+                    out.push(Content::Paragraph(Box::new(p)));
+                }
+            }
+        }
+    }
+
+    /// An empty paragraph for text in the given context.
+    fn block_paragraph(&mut self, context: Context) -> Paragraph {
+        let mut p = Paragraph::new();
+        if context.quote {
+            p = p.style(&self.style(MarkdownElement::BlockQuote));
+        }
+        if let Some(level) = context.list_level {
+            p = p.indent(Some(list_indent(level)), None, None, None);
+        }
+        p
+    }
+
+    fn list(&mut self, list: &MdList, context: Context, out: &mut Vec<Content>) {
+        let level = context.list_level.map_or(0, |l| (l + 1).min(8));
+        let id = add_list_numbering(self.docx, list.ordered, list.start);
+        for item in &list.items {
+            let mut p = Paragraph::new();
+            if let Some(checked) = item.task {
+                p = p.add_run(Run::new().add_text(if checked { "☒ " } else { "☐ " }));
+            }
+            // The first paragraph carries the label; other blocks follow it
+            // indented.
+            let mut rest = &item.blocks[..];
+            if let Some(MdBlock::Paragraph(text)) = rest.first() {
+                p = self.inlines(p, text, Format::default());
+                rest = &rest[1..];
+            }
+            p = p.numbering(NumberingId::new(id), IndentLevel::new(level));
+            out.push(Content::Paragraph(Box::new(p)));
+            let context = Context {
+                list_level: Some(level),
+                ..context
+            };
+            self.blocks(rest, context, out);
+        }
+    }
+
+    fn table(&mut self, table: &MdTable) -> Table {
+        let mut rows = vec![];
+        let header = Format {
+            bold: true,
+            ..Format::default()
+        };
+        for (cells, format) in std::iter::once((&table.header, header))
+            .chain(table.rows.iter().map(|r| (r, Format::default())))
+        {
+            let cells = cells
+                .iter()
+                .enumerate()
+                .map(|(i, text)| {
+                    let mut p = Paragraph::new();
+                    if let Some(Some(align)) = table.aligns.get(i) {
+                        p = p.align(*align);
+                    }
+                    TableCell::new().add_paragraph(self.inlines(p, text, format))
+                })
+                .collect();
+            rows.push(TableRow::new(cells));
+        }
+        Table::new(rows)
+            .style(self.style(MarkdownElement::Table))
+            .width(5000, WidthType::Pct)
+    }
+
+    fn inlines(&mut self, mut p: Paragraph, text: &str, format: Format) -> Paragraph {
+        let inlines = parse_inlines(text, self.references);
+        let mut pieces = vec![];
+        self.pieces(&inlines, format, &mut pieces);
+        for piece in pieces {
+            p = match piece {
+                Piece::Run(run) => p.add_run(*run),
+                Piece::Link(link) => p.add_hyperlink(link),
+            };
+        }
+        p
+    }
+
+    fn run(&mut self, format: Format) -> Run {
+        let mut run = Run::new();
+        if format.bold {
+            run = run.bold();
+        }
+        if format.italic {
+            run = run.italic();
+        }
+        if format.strike {
+            run = run.strike();
+        }
+        if format.code {
+            run = run.style(&self.style(MarkdownElement::InlineCode));
+        } else if format.link {
+            run = run.style(&self.style(MarkdownElement::Link));
+        }
+        run
+    }
+
+    fn pieces(&mut self, inlines: &[MdInline], format: Format, out: &mut Vec<Piece>) {
+        for inline in inlines {
+            match inline {
+                MdInline::Text(text) => out.push(Piece::run(self.run(format).add_text(text))),
+                MdInline::Code(code) => {
+                    let format = Format {
+                        code: true,
+                        ..format
+                    };
+                    out.push(Piece::run(self.run(format).add_text(code)));
+                }
+                MdInline::Emphasis(children) => {
+                    let format = Format {
+                        italic: true,
+                        ..format
+                    };
+                    self.pieces(children, format, out);
+                }
+                MdInline::Strong(children) => {
+                    let format = Format {
+                        bold: true,
+                        ..format
+                    };
+                    self.pieces(children, format, out);
+                }
+                MdInline::Strike(children) => {
+                    let format = Format {
+                        strike: true,
+                        ..format
+                    };
+                    self.pieces(children, format, out);
+                }
+                // Word cannot nest links.
+                MdInline::Link { children, .. } if format.link => {
+                    self.pieces(children, format, out)
+                }
+                MdInline::Link { url, children } => {
+                    let mut link = match url.strip_prefix('#') {
+                        Some(anchor) => Hyperlink::new(anchor, HyperlinkType::Anchor),
+                        None => Hyperlink::new(url, HyperlinkType::External),
+                    };
+                    let mut runs = vec![];
+                    let format = Format {
+                        link: true,
+                        ..format
+                    };
+                    self.pieces(children, format, &mut runs);
+                    for run in runs {
+                        if let Piece::Run(run) = run {
+                            link = link.add_run(*run);
+                        }
+                    }
+                    out.push(Piece::Link(link));
+                }
+                MdInline::Image { url, alt } => {
+                    let bytes = data_uri(url).or_else(|| {
+                        self.options
+                            .image_loader
+                            .as_ref()
+                            .and_then(|load| load(url))
+                    });
+                    match bytes.as_deref().and_then(picture) {
+                        Some(pic) => out.push(Piece::run(Run::new().add_image(pic))),
+                        None if !alt.is_empty() => {
+                            out.push(Piece::run(self.run(format).add_text(alt)))
+                        }
+                        None => {}
+                    }
+                }
+                MdInline::Break => {
+                    out.push(Piece::run(
+                        self.run(format).add_break(BreakType::TextWrapping),
+                    ));
+                }
+                MdInline::FootnoteReference(label) => {
+                    if let Some(footnote) = self.footnote(label) {
+                        out.push(Piece::run(Run::new().add_footnote_reference(footnote)));
+                    }
+                }
+            }
+        }
+    }
+
+    fn footnote(&mut self, label: &str) -> Option<Footnote> {
+        if self.notes.iter().any(|n| n == label) {
+            return None;
+        }
+        let references = self.references;
+        let blocks = references.footnotes.get(label)?;
+        self.notes.push(label.to_owned());
+        let mut contents = vec![];
+        self.blocks(blocks, Context::default(), &mut contents);
+        self.notes.pop();
+
+        let style = self.style(MarkdownElement::Footnote);
+        let mut footnote = Footnote::new();
+        // Notes hold paragraphs only, so tables give their cell paragraphs.
+        let mut paragraphs = vec![];
+        for content in contents {
+            match content {
+                Content::Paragraph(p) => paragraphs.push(*p),
+                Content::Table(t) => {
+                    for TableChild::TableRow(row) in t.rows {
+                        for TableRowChild::TableCell(cell) in row.cells {
+                            for child in cell.children {
+                                if let TableCellContent::Paragraph(p) = child {
+                                    paragraphs.push(*p);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        for p in paragraphs {
+            let p = if p.property.style.is_none() {
+                p.style(&style)
+            } else {
+                p
+            };
+            footnote.add_content(p);
+        }
+        Some(footnote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_markdown() {
+        let markdown = "# Title\n\nSome **bold** and `code` with [a link](https://example.com).[^1]\n\n- one\n  1. nested\n- [x] two\n\n> quoted\n\n```\nlet x;\n```\n\n| a | b |\n|---|--:|\n| 1 | 2 |\n\n[^1]: A note.\n";
+        let docx = read_markdown(markdown, &MarkdownImportOptions::new());
+        let md = docx.to_markdown(&MarkdownOptions::new());
+        assert_eq!(
+            md.text,
+            "# Title\n\nSome **bold** and `code` with [a link](https://example.com).[^1]\n\n- one\n    1. nested\n- ☒ two\n\n*quoted*\n\n```\nlet x;\n```\n\n| **a** | **b** |\n| --- | --- |\n| 1 | 2 |\n\n[^1]: A note.\n"
+        );
+        for id in [
+            "Heading1",
+            "SourceCode",
+            "SourceCodeChar",
+            "Quote",
+            "Hyperlink",
+            "TableGrid",
+        ] {
+            assert!(docx.styles.find_style_by_id(id).is_some(), "{id}");
+        }
+        assert_eq!(docx.numberings.abstract_nums.len(), 2);
+    }
+
+    #[test]
+    fn test_add_markdown_with_style_mapping() {
+        let options = MarkdownImportOptions::new()
+            .style(MarkdownElement::Heading(1), "Title")
+            .style(MarkdownElement::CodeBlock, "MyCode");
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Intro")))
+            .add_markdown("# Report\n\n    code\n", &options);
+        assert_eq!(docx.document.children.len(), 3);
+        assert!(docx.styles.find_style_by_id("Title").is_some());
+        assert!(docx.styles.find_style_by_id("MyCode").is_none());
+        match &docx.document.children[2] {
+            DocumentChild::Paragraph(p) => {
+                assert_eq!(p.property.style, Some(ParagraphStyle::new(Some("MyCode"))))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_read_markdown_images() {
+        let png = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+        let markdown = format!("![dot](data:image/png;base64,{png}) ![missing](missing.png)");
+        let docx = read_markdown(&markdown, &MarkdownImportOptions::new());
+        let md = docx.to_markdown(&MarkdownOptions::new());
+        assert_eq!(md.images.len(), 1);
+        assert!(md.text.ends_with(" missing\n"));
+    }
+}
@@ -0,0 +1,231 @@
+//! Builds documents from other formats.
+//!
+//! Importers add paragraphs and tables to an existing `Docx`, so the same
+//! content can make a new document or be appended to a template. List
+//! definitions get ids after the ones in use, and the built-in styles the
+//! content refers to are added when missing.
+
+mod html;
+mod markdown;
+
+pub use html::*;
+pub use markdown::*;
+
+use base64::Engine;
+
+use crate::documents::*;
+use crate::types::*;
+
+/// The widest a picture is made, six inches in EMU.
+const MAX_PICTURE_WIDTH: u32 = 5_486_400;
+
+/// Adds a list definition whose levels all count the same way and returns
+/// the id of its numbering. Every list gets its own numbering so that each
+/// one starts from `start`.
+pub(crate) fn add_list_numbering(docx: &mut Docx, ordered: bool, start: usize) -> usize {
+    // The default numbering written with every package uses id 1.
+    let abstract_id = docx
+        .numberings
+        .abstract_nums
+        .iter()
+        .map(|a| a.id)
+        .chain(Some(1))
+        .max()
+        .unwrap_or(1)
+        + 1;
+    let id = docx
+        .numberings
+        .numberings
+        .iter()
+        .map(|n| n.id)
+        .chain(Some(1))
+        .max()
+        .unwrap_or(1)
+        + 1;
+    let mut numbering = AbstractNumbering::new(abstract_id);
+    for level in 0..9 {
+        let (format, text) = if ordered {
+            let format = ["decimal", "lowerLetter", "lowerRoman"][level % 3];
+            (format, format!("%{}.", level + 1))
+        } else {
+            ("bullet", ["•", "◦", "▪"][level % 3].to_owned())
+        };
+        numbering = numbering.add_level(
+            Level::new(
+                level,
+                Start::new(start),
+                NumberFormat::new(format),
+                LevelText::new(text),
+                LevelJc::new("left"),
+            )
+            .indent(
+                Some(list_indent(level)),
+                Some(SpecialIndentType::Hanging(360)),
+                None,
+                None,
+            ),
+        );
+    }
+    docx.numberings.abstract_nums.push(numbering);
+    docx.numberings
+        .numberings
+        .push(Numbering::new(id, abstract_id));
+    id
+}
+
+/// Converted blocks, before they are added to a document.
+pub(crate) enum Content {
+    Paragraph(Box<Paragraph>),
+    Table(Box<Table>),
+}
+
+/// Decodes the name of a character reference, without `&` and `;`.
+pub(crate) fn decode_entity(name: &str) -> Option<char> {
+    if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code).filter(|c| *c != '\0');
+    }
+    Some(match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        "hellip" => '…',
+        "mdash" => '—',
+        "ndash" => '–',
+        "laquo" => '«',
+        "raquo" => '»',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "bull" => '•',
+        "middot" => '·',
+        "euro" => '€',
+        _ => return None,
+    })
+}
+
+/// The left indent of list paragraphs at the given level, in twips.
+pub(crate) fn list_indent(level: usize) -> i32 {
+    720 * (level as i32 + 1)
+}
+
+/// Decodes the payload of a `data:` URI.
+pub(crate) fn data_uri(uri: &str) -> Option<Vec<u8>> {
+    let rest = uri.strip_prefix("data:")?;
+    let (meta, data) = rest.split_once(',')?;
+    if meta.ends_with(";base64") {
+        let data: String = data.chars().filter(|c| !c.is_whitespace()).collect();
+        base64::engine::general_purpose::STANDARD.decode(data).ok()
+    } else {
+        Some(data.as_bytes().to_vec())
+    }
+}
+
+/// Makes a picture of the given image bytes, or `None` when they cannot be
+/// read. Pictures wider than the text of a default page are scaled down.
+pub(crate) fn picture(bytes: &[u8]) -> Option<Pic> {
+    let pic = new_pic(bytes)?;
+    let (w, h) = pic.size;
+    if w > MAX_PICTURE_WIDTH {
+        let h = (h as u64 * MAX_PICTURE_WIDTH as u64 / w as u64) as u32;
+        return Some(pic.size(MAX_PICTURE_WIDTH, h));
+    }
+    Some(pic)
+}
+
+#[cfg(feature = "image")]
+fn new_pic(bytes: &[u8]) -> Option<Pic> {
+    ::image::load_from_memory(bytes).ok()?;
+    Some(Pic::new(bytes))
+}
+
+/// Without the `image` feature only PNG can be embedded, sized from its
+/// header.
+#[cfg(not(feature = "image"))]
+fn new_pic(bytes: &[u8]) -> Option<Pic> {
+    if !bytes.starts_with(&[137, 80, 78, 71, 13, 10, 26, 10]) || bytes.len() < 24 {
+        return None;
+    }
+    let w = u32::from_be_bytes(bytes[16..20].try_into().ok()?);
+    let h = u32::from_be_bytes(bytes[20..24].try_into().ok()?);
+    Some(Pic::new_with_dimensions(bytes.to_vec(), w, h))
+}
+
+/// The paragraph style for preformatted text, for ids that are not built in.
+pub(crate) fn code_style(style_id: &str) -> Style {
+    Style::new(style_id, StyleType::Paragraph)
+        .name("Source Code")
+        .based_on("Normal")
+        .line_spacing(LineSpacing::new().before(0).after(0))
+        .fonts(code_fonts())
+        .size(20)
+}
+
+/// The character style for code within a paragraph.
+pub(crate) fn code_char_style(style_id: &str) -> Style {
+    Style::new(style_id, StyleType::Character)
+        .name("Source Code Char")
+        .based_on("DefaultParagraphFont")
+        .fonts(code_fonts())
+}
+
+fn code_fonts() -> RunFonts {
+    RunFonts::new()
+        .ascii("Courier New")
+        .hi_ansi("Courier New")
+        .cs("Courier New")
+}
+
+/// Adds the styles with the given ids that are not defined yet: Word's
+/// built-in ones, and the code styles under the ids `code` asks for.
+pub(crate) fn add_missing_styles(
+    docx: &mut Docx,
+    ids: &std::collections::BTreeSet<String>,
+    code: (&str, &str),
+) {
+    docx.styles
+        .add_missing_builtin_styles(ids.iter().map(|s| s.as_str()));
+    if ids.contains(code.0) && docx.styles.find_style_by_id(code.0).is_none() {
+        docx.styles.styles.push(code_style(code.0));
+    }
+    if ids.contains(code.1) && docx.styles.find_style_by_id(code.1).is_none() {
+        docx.styles.styles.push(code_char_style(code.1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_data_uri() {
+        assert_eq!(data_uri("data:image/png;base64,AAEC"), Some(vec![0, 1, 2]));
+        assert_eq!(data_uri("data:text/plain,hi"), Some(b"hi".to_vec()));
+        assert_eq!(data_uri("image.png"), None);
+    }
+
+    #[test]
+    fn test_add_list_numbering() {
+        let mut docx = Docx::new();
+        assert_eq!(add_list_numbering(&mut docx, true, 3), 2);
+        assert_eq!(add_list_numbering(&mut docx, false, 1), 3);
+        assert_eq!(docx.numberings.abstract_nums[1].id, 3);
+        assert_eq!(
+            docx.numberings.abstract_nums[0].levels[0].start,
+            Start::new(3)
+        );
+    }
+}
@@ -0,0 +1,515 @@
+//! Computes the list labels ("1.", "1.2.a)", "•") Word renders for numbered
+//! paragraphs.
+//!
+//! Numbering state lives on the abstract numbering definition, so paragraphs
+//! that use different `w:num` instances of one `w:abstractNum` share a counter
+//! unless an instance overrides the start value. Labels therefore depend on
+//! every numbered paragraph before them and must be resolved in document
+//! order.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use serde::Serialize;
+
+use super::number_text::format_number;
+use super::numberings::create_default_numbering;
+use crate::documents::*;
+use crate::types::*;
+
+const MAX_LEVELS: usize = 9;
+
+/// The label of one numbered paragraph.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListLabel {
+    pub paragraph_id: String,
+    pub numbering_id: usize,
+    pub level: usize,
+    /// `lvlText` with every `%n` placeholder replaced by its counter.
+    pub text: String,
+    /// The `numFmt` of the paragraph's level, e.g. `decimal` or `bullet`.
+    pub format: String,
+    /// What Word puts between the label and the paragraph text.
+    pub suffix: LevelSuffixType,
+}
+
+/// Resolves list labels for paragraphs visited in document order.
+///
+/// Use [`Docx::list_labels`] for the whole body. The resolver is exposed for
+/// callers that walk the document themselves and need a label as they go;
+/// every numbered paragraph must be passed to [`ListLabelResolver::resolve`]
+/// exactly once and in order.
+pub struct ListLabelResolver<'a> {
+    lookup: NumberingLookup<'a>,
+    // Last value rendered per abstract numbering and level.
+    counters: HashMap<usize, [Option<usize>; MAX_LEVELS]>,
+    seen_numberings: HashSet<usize>,
+}
+
+impl<'a> ListLabelResolver<'a> {
+    pub fn new(numberings: &'a Numberings, styles: &'a Styles) -> Self {
+        Self {
+            lookup: NumberingLookup::new(numberings, styles),
+            counters: HashMap::new(),
+            seen_numberings: HashSet::new(),
+        }
+    }
+
+    /// Advances the list counters for `paragraph` and returns its label, or
+    /// `None` when the paragraph is not numbered.
+    pub fn resolve(&mut self, paragraph: &Paragraph) -> Option<ListLabel> {
+        let NumberingLevel {
+            numbering_id,
+            level,
+            numbering,
+            abstract_num,
+            definition,
+        } = self.lookup.level_of(paragraph)?;
+        let abstract_id = abstract_num.id;
+
+        let counters = self
+            .counters
+            .entry(abstract_id)
+            .or_insert([None; MAX_LEVELS]);
+
+        // A start override takes effect the first time its instance is used.
+        if self.seen_numberings.insert(numbering_id) {
+            for o in &numbering.level_overrides {
+                if o.override_start.is_some() && o.level < MAX_LEVELS {
+                    counters[o.level] = None;
+                }
+            }
+        }
+
+        let start = level_start(numbering, abstract_num, level);
+        counters[level] = Some(counters[level].map_or(start, |v| v + 1));
+
+        for (deeper, counter) in counters.iter_mut().enumerate().skip(level + 1) {
+            let restart_after = level_definition(numbering, abstract_num, deeper)
+                .and_then(|l| l.level_restart.as_ref())
+                .map_or(deeper, |r| r.get_val() as usize);
+            // `w:lvlRestart` is 1-based; 0 means the level never restarts.
+            if restart_after != 0 && level < restart_after {
+                *counter = None;
+            }
+        }
+
+        let text = if definition.format.val == "bullet" {
+            definition.text.get_val().to_owned()
+        } else {
+            let counters = *counters;
+            render_level_text(definition.text.get_val(), |n| {
+                let value = counters[n].unwrap_or_else(|| level_start(numbering, abstract_num, n));
+                let format = if definition.is_lgl.is_some() {
+                    "decimal"
+                } else {
+                    level_definition(numbering, abstract_num, n)
+                        .map_or("decimal", |l| l.format.val.as_str())
+                };
+                format_number(value, format)
+            })
+        };
+
+        Some(ListLabel {
+            paragraph_id: paragraph.id.clone(),
+            numbering_id,
+            level,
+            text,
+            format: definition.format.val.clone(),
+            suffix: definition.suffix.clone(),
+        })
+    }
+}
+
+/// The numbering level a paragraph uses, with the definitions it came from.
+pub(crate) struct NumberingLevel<'a> {
+    pub(crate) numbering_id: usize,
+    pub(crate) level: usize,
+    pub(crate) numbering: &'a Numbering,
+    pub(crate) abstract_num: &'a AbstractNumbering,
+    pub(crate) definition: &'a Level,
+}
+
+/// Finds the numbering definition of a paragraph through its direct
+/// `w:numPr`, its paragraph style and `w:numStyleLink`.
+pub(crate) struct NumberingLookup<'a> {
+    numberings: &'a Numberings,
+    styles: &'a Styles,
+}
+
+impl<'a> NumberingLookup<'a> {
+    pub(crate) fn new(numberings: &'a Numberings, styles: &'a Styles) -> Self {
+        Self { numberings, styles }
+    }
+
+    pub(crate) fn level_of(&self, paragraph: &Paragraph) -> Option<NumberingLevel<'a>> {
+        let (numbering_id, level) = self.numbering_of(paragraph)?;
+        let numbering = self.find_numbering(numbering_id)?;
+        let abstract_num = self.find_abstract(numbering.abstract_num_id)?;
+        let definition = level_definition(numbering, abstract_num, level)?;
+        Some(NumberingLevel {
+            numbering_id,
+            level,
+            numbering,
+            abstract_num,
+            definition,
+        })
+    }
+
+    // The paragraph's own `w:numPr` wins over one inherited from its style.
+    fn numbering_of(&self, paragraph: &Paragraph) -> Option<(usize, usize)> {
+        let own = paragraph.property.numbering_property.as_ref();
+        let style_id = paragraph
+            .property
+            .style
+            .as_ref()
+            .map(|s| s.val.as_str())
+            .or_else(|| {
+                self.styles
+                    .default_style(StyleType::Paragraph)
+                    .map(|s| s.style_id.as_str())
+            });
+        let from_style = style_id.and_then(|id| self.style_numbering(id));
+
+        let id = own
+            .and_then(|n| n.id.as_ref())
+            .or_else(|| from_style.and_then(|n| n.id.as_ref()))?
+            .id;
+        // numId 0 removes numbering inherited from a style.
+        if id == 0 {
+            return None;
+        }
+        let level = own
+            .and_then(|n| n.level.as_ref())
+            .or_else(|| from_style.and_then(|n| n.level.as_ref()))
+            .map(|l| l.val)
+            .or_else(|| style_id.and_then(|s| self.level_linked_to_style(id, s)))
+            .unwrap_or_default();
+        (level < MAX_LEVELS).then_some((id, level))
+    }
+
+    fn style_numbering(&self, style_id: &str) -> Option<&'a NumberingProperty> {
+        let mut style_id = style_id.to_owned();
+        // basedOn chains are short; the bound guards against cycles.
+        for _ in 0..MAX_STYLE_DEPTH {
+            let style = self.styles.find_style_by_id(&style_id)?;
+            if let Some(n) = style.paragraph_property.numbering_property.as_ref() {
+                return Some(n);
+            }
+            style_id = style.based_on.as_ref()?.get_val().to_owned();
+        }
+        None
+    }
+
+    /// The level definitions of a numbering, with its overrides applied.
+    pub(crate) fn levels(&self, numbering_id: usize) -> Vec<&'a Level> {
+        let Some(numbering) = self.find_numbering(numbering_id) else {
+            return vec![];
+        };
+        let Some(abstract_num) = self.find_abstract(numbering.abstract_num_id) else {
+            return vec![];
+        };
+        (0..MAX_LEVELS)
+            .filter_map(|level| level_definition(numbering, abstract_num, level))
+            .collect()
+    }
+
+    // A level with `w:pStyle` is used by paragraphs of that style.
+    fn level_linked_to_style(&self, numbering_id: usize, style_id: &str) -> Option<usize> {
+        let numbering = self.find_numbering(numbering_id)?;
+        let abstract_num = self.find_abstract(numbering.abstract_num_id)?;
+        abstract_num
+            .levels
+            .iter()
+            .find(|l| l.pstyle.as_ref().is_some_and(|p| p.val == style_id))
+            .map(|l| l.level)
+    }
+
+    fn find_numbering(&self, id: usize) -> Option<&'a Numbering> {
+        self.numberings
+            .numberings
+            .iter()
+            .find(|n| n.id == id)
+            // Numbering 1 is always written by `Numberings`.
+            .or_else(|| {
+                static DEFAULT: OnceLock<Numbering> = OnceLock::new();
+                let default = DEFAULT.get_or_init(|| Numbering::new(1, 1));
+                (id == default.id).then_some(default)
+            })
+    }
+
+    // Follows `w:numStyleLink` to the definition that carries the levels.
+    fn find_abstract(&self, id: usize) -> Option<&'a AbstractNumbering> {
+        let abstract_num = self.find_abstract_by_id(id)?;
+        if let Some(link) = &abstract_num.num_style_link {
+            let linked = self
+                .style_numbering(link)
+                .and_then(|n| n.id.as_ref())
+                .and_then(|id| self.find_numbering(id.id))
+                .and_then(|n| self.find_abstract_by_id(n.abstract_num_id));
+            if let Some(linked) = linked {
+                return Some(linked);
+            }
+        }
+        Some(abstract_num)
+    }
+
+    fn find_abstract_by_id(&self, id: usize) -> Option<&'a AbstractNumbering> {
+        self.numberings
+            .abstract_nums
+            .iter()
+            .find(|a| a.id == id)
+            // Abstract numbering 1 is always written by `Numberings`.
+            .or_else(|| {
+                static DEFAULT: OnceLock<AbstractNumbering> = OnceLock::new();
+                let default = DEFAULT.get_or_init(create_default_numbering);
+                (id == default.id).then_some(default)
+            })
+    }
+}
+
+pub(crate) const MAX_STYLE_DEPTH: usize = 16;
+
+fn level_definition<'a>(
+    numbering: &'a Numbering,
+    abstract_num: &'a AbstractNumbering,
+    level: usize,
+) -> Option<&'a Level> {
+    numbering
+        .level_overrides
+        .iter()
+        .find(|o| o.level == level)
+        .and_then(|o| o.override_level.as_ref())
+        .or_else(|| abstract_num.levels.iter().find(|l| l.level == level))
+}
+
+fn level_start(numbering: &Numbering, abstract_num: &AbstractNumbering, level: usize) -> usize {
+    numbering
+        .level_overrides
+        .iter()
+        .find(|o| o.level == level)
+        .and_then(|o| o.override_start)
+        .or_else(|| level_definition(numbering, abstract_num, level).map(|l| l.start.get_val()))
+        .unwrap_or(1)
+}
+
+// Replaces `%1`..`%9` with the rendered counter of levels 0..8.
+fn render_level_text(text: &str, mut value: impl FnMut(usize) -> String) -> String {
+    let mut rendered = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            if let Some(n) = chars.peek().and_then(|d| d.to_digit(10)) {
+                if (1..=MAX_LEVELS as u32).contains(&n) {
+                    chars.next();
+                    rendered.push_str(&value(n as usize - 1));
+                    continue;
+                }
+            }
+        }
+        rendered.push(c);
+    }
+    rendered
+}
+
+/// Collects the body paragraphs in reading order, including those nested in
+/// tables, sections, content controls and table of contents wrappers.
+pub(crate) fn paragraphs_in_order(document: &Document) -> Vec<&Paragraph> {
+    fn push_table<'a>(table: &'a Table, out: &mut Vec<&'a Paragraph>) {
+        for TableChild::TableRow(row) in &table.rows {
+            for TableRowChild::TableCell(cell) in &row.cells {
+                for content in &cell.children {
+                    match content {
+                        TableCellContent::Paragraph(p) => out.push(p),
+                        TableCellContent::Table(t) => push_table(t, out),
+                        TableCellContent::StructuredDataTag(tag) => push_tag(tag, out),
+                        TableCellContent::TableOfContents(toc) => push_toc(toc, out),
+                    }
+                }
+            }
+        }
+    }
+
+    fn push_tag<'a>(tag: &'a StructuredDataTag, out: &mut Vec<&'a Paragraph>) {
+        for child in &tag.children {
+            match child {
+                StructuredDataTagChild::Paragraph(p) => out.push(p),
+                StructuredDataTagChild::Table(t) => push_table(t, out),
+                StructuredDataTagChild::StructuredDataTag(tag) => push_tag(tag, out),
+                _ => {}
+            }
+        }
+    }
+
+    fn push_toc<'a>(toc: &'a TableOfContents, out: &mut Vec<&'a Paragraph>) {
+        for content in toc.before_contents.iter().chain(&toc.after_contents) {
+            match content {
+                TocContent::Paragraph(p) => out.push(p),
+                TocContent::Table(t) => push_table(t, out),
+            }
+        }
+    }
+
+    fn push_child<'a>(child: &'a DocumentChild, out: &mut Vec<&'a Paragraph>) {
+        match child {
+            DocumentChild::Paragraph(p) => out.push(p),
+            DocumentChild::Table(t) => push_table(t, out),
+            DocumentChild::StructuredDataTag(tag) => push_tag(tag, out),
+            DocumentChild::TableOfContents(toc) => push_toc(toc, out),
+            DocumentChild::Section(section) => {
+                for child in &section.children {
+                    match child {
+                        SectionChild::Paragraph(p) => out.push(p),
+                        SectionChild::Table(t) => push_table(t, out),
+                        SectionChild::StructuredDataTag(tag) => push_tag(tag, out),
+                        SectionChild::TableOfContents(toc) => push_toc(toc, out),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut out = vec![];
+    for child in &document.children {
+        push_child(child, &mut out);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn level(i: usize, format: &str, text: &str) -> Level {
+        Level::new(
+            i,
+            Start::new(1),
+            NumberFormat::new(format),
+            LevelText::new(text),
+            LevelJc::new("left"),
+        )
+    }
+
+    fn item(num: usize, lvl: usize) -> Paragraph {
+        Paragraph::new().numbering(NumberingId::new(num), IndentLevel::new(lvl))
+    }
+
+    fn labels(docx: &Docx) -> Vec<String> {
+        docx.list_labels().into_iter().map(|l| l.text).collect()
+    }
+
+    #[test]
+    fn test_multilevel_labels() {
+        let docx = Docx::new()
+            .add_abstract_numbering(
+                AbstractNumbering::new(2)
+                    .add_level(level(0, "decimal", "%1."))
+                    .add_level(level(1, "decimal", "%1.%2."))
+                    .add_level(level(2, "lowerLetter", "%3)")),
+            )
+            .add_numbering(Numbering::new(2, 2))
+            .add_paragraph(item(2, 0))
+            .add_paragraph(item(2, 1))
+            .add_paragraph(item(2, 2))
+            .add_paragraph(item(2, 2))
+            .add_paragraph(Paragraph::new())
+            .add_paragraph(item(2, 1))
+            .add_paragraph(item(2, 2))
+            .add_paragraph(item(2, 0));
+        assert_eq!(
+            labels(&docx),
+            vec!["1.", "1.1.", "a)", "b)", "1.2.", "a)", "2."]
+        );
+    }
+
+    #[test]
+    fn test_level_restart_and_is_lgl() {
+        let mut no_restart = level(1, "upperRoman", "%2");
+        no_restart.level_restart = Some(LevelRestart::new(0u32));
+        let mut legal = level(2, "lowerRoman", "%1.%2.%3");
+        legal.is_lgl = Some(IsLgl::new());
+        let docx = Docx::new()
+            .add_abstract_numbering(
+                AbstractNumbering::new(2)
+                    .add_level(level(0, "upperLetter", "%1"))
+                    .add_level(no_restart)
+                    .add_level(legal),
+            )
+            .add_numbering(Numbering::new(2, 2))
+            .add_paragraph(item(2, 0))
+            .add_paragraph(item(2, 1))
+            .add_paragraph(item(2, 2))
+            .add_paragraph(item(2, 0))
+            .add_paragraph(item(2, 1));
+        assert_eq!(labels(&docx), vec!["A", "I", "1.1.1", "B", "II"]);
+    }
+
+    #[test]
+    fn test_overrides_and_shared_abstract_numbering() {
+        let docx = Docx::new()
+            .add_abstract_numbering(AbstractNumbering::new(2).add_level(level(
+                0,
+                "japaneseCounting",
+                "%1、",
+            )))
+            .add_numbering(Numbering::new(2, 2))
+            .add_numbering(Numbering::new(3, 2))
+            .add_numbering(
+                Numbering::new(4, 2).add_override(LevelOverride::new(0).start(10).level(level(
+                    0,
+                    "chineseCounting",
+                    "第%1章",
+                ))),
+            )
+            .add_paragraph(item(2, 0))
+            .add_paragraph(item(3, 0))
+            .add_paragraph(item(4, 0))
+            .add_paragraph(item(4, 0));
+        assert_eq!(labels(&docx), vec!["一、", "二、", "第十章", "第十一章"]);
+    }
+
+    #[test]
+    fn test_bullets_and_style_numbering() {
+        let docx = Docx::new()
+            .add_style(
+                Style::new("ListHeading", StyleType::Paragraph)
+                    .numbering(NumberingId::new(2), IndentLevel::new(0)),
+            )
+            .add_style(Style::new("Child", StyleType::Paragraph).based_on("ListHeading"))
+            .add_abstract_numbering(
+                AbstractNumbering::new(2)
+                    .add_level(level(0, "ordinalText", "%1:"))
+                    .add_level(level(1, "bullet", "•")),
+            )
+            .add_numbering(Numbering::new(2, 2))
+            .add_paragraph(Paragraph::new().style("ListHeading"))
+            .add_paragraph(item(2, 1))
+            .add_paragraph(Paragraph::new().style("Child"))
+            .add_paragraph(
+                Paragraph::new()
+                    .style("ListHeading")
+                    .numbering(NumberingId::new(0), IndentLevel::new(0)),
+            );
+        let labels = docx.list_labels();
+        assert_eq!(
+            labels.iter().map(|l| l.text.as_str()).collect::<Vec<_>>(),
+            vec!["First:", "•", "Second:"]
+        );
+        assert_eq!(labels[1].format, "bullet");
+    }
+
+    #[test]
+    fn test_default_numbering_and_tables() {
+        let table = Table::new(vec![TableRow::new(vec![
+            TableCell::new().add_paragraph(item(1, 0))
+        ])]);
+        let docx = Docx::new().add_paragraph(item(1, 0)).add_table(table);
+        assert_eq!(labels(&docx), vec!["1.", "2."]);
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use crate::MailMergeError;
+
+/// Records to merge into a document, e.g. one per addressee.
+///
+/// Field names are matched without regard to case, as Word does.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MergeData {
+    pub(crate) records: Vec<HashMap<String, String>>,
+}
+
+impl MergeData {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn add_record<K, V>(mut self, record: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.records.push(
+            record
+                .into_iter()
+                .map(|(k, v)| (k.into().to_lowercase(), v.into()))
+                .collect(),
+        );
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Reads records from CSV text whose first line holds the field names.
+    ///
+    /// Fields may be quoted with `"`, with `""` standing for a quote inside a
+    /// quoted field. Quoted fields may span lines.
+    pub fn from_csv(csv: &str) -> Result<Self, MailMergeError> {
+        let mut rows = parse_csv(csv)?.into_iter();
+        let Some(header) = rows.next() else {
+            return Ok(Self::new());
+        };
+        let mut data = Self::new();
+        for row in rows {
+            data = data.add_record(header.iter().cloned().zip(row));
+        }
+        Ok(data)
+    }
+
+    /// Reads records from a JSON object or an array of objects. Values that
+    /// are not strings are merged as written in JSON and `null` as empty text.
+    pub fn from_json(value: &serde_json::Value) -> Result<Self, MailMergeError> {
+        let objects = match value {
+            serde_json::Value::Array(values) => values.iter().collect(),
+            value => vec![value],
+        };
+        let mut data = Self::new();
+        for object in objects {
+            let serde_json::Value::Object(object) = object else {
+                return Err(MailMergeError::JsonShapeError);
+            };
+            data = data.add_record(object.iter().map(|(k, v)| {
+                let v = match v {
+                    serde_json::Value::String(s) => s.clone(),
+                    serde_json::Value::Null => String::new(),
+                    v => v.to_string(),
+                };
+                (k.as_str(), v)
+            }));
+        }
+        Ok(data)
+    }
+}
+
+fn parse_csv(csv: &str) -> Result<Vec<Vec<String>>, MailMergeError> {
+    let csv = csv.strip_prefix('\u{feff}').unwrap_or(csv);
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut quote_line = 0;
+    let mut chars = csv.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if field.is_empty() => {
+                quoted = true;
+                quote_line = line;
+            }
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !quoted && chars.peek() == Some(&'\n') => {}
+            '\n' if !quoted => {
+                line += 1;
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+    if quoted {
+        return Err(MailMergeError::CsvError(quote_line));
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    // Blank lines, e.g. at the end of the file, are not records.
+    rows.retain(|r| !(r.len() == 1 && r[0].is_empty()));
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_csv() {
+        let data = MergeData::from_csv("Name,Note\r\nAda,\"says \"\"hi\"\",\nbye\"\r\n\r\nBob,\n")
+            .unwrap();
+        assert_eq!(
+            data,
+            MergeData::new()
+                .add_record([("name", "Ada"), ("note", "says \"hi\",\nbye")])
+                .add_record([("name", "Bob"), ("note", "")])
+        );
+        assert!(matches!(
+            MergeData::from_csv("a\n\"b"),
+            Err(MailMergeError::CsvError(2))
+        ));
+    }
+
+    #[test]
+    fn test_from_json() {
+        let json = serde_json::json!([{ "Name": "Ada", "Age": 36, "Title": null }]);
+        assert_eq!(
+            MergeData::from_json(&json).unwrap(),
+            MergeData::new().add_record([("name", "Ada"), ("age", "36"), ("title", "")])
+        );
+        assert!(MergeData::from_json(&serde_json::json!([1])).is_err());
+    }
+}
@@ -0,0 +1,349 @@
+//! Field instruction parsing and the `\*`, `\#` and `\@` formatting switches.
+
+/// A word of a field instruction. Quoted words keep their spaces and lose
+/// their quotes.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Token {
+    pub text: String,
+    pub quoted: bool,
+}
+
+pub(super) fn tokenize(instruction: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut chars = instruction.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let mut text = String::new();
+        if c == '"' {
+            chars.next();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' if matches!(chars.peek(), Some('"') | Some('\\')) => {
+                        text.extend(chars.next());
+                    }
+                    '"' => break,
+                    c => text.push(c),
+                }
+            }
+            tokens.push(Token { text, quoted: true });
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() || (c == '"' && !text.is_empty()) {
+                    break;
+                }
+                text.push(c);
+                chars.next();
+            }
+            tokens.push(Token {
+                text,
+                quoted: false,
+            });
+        }
+    }
+    tokens
+}
+
+/// Quotes `text` for use as one word of an instruction, unless it is inserted
+/// inside quotes already.
+pub(super) fn quote(instruction: &str, text: &str) -> String {
+    let mut quoted = false;
+    let mut escaped = false;
+    for c in instruction.chars() {
+        match c {
+            '\\' if quoted => escaped = !escaped,
+            '"' if !escaped => quoted = !quoted,
+            _ => escaped = false,
+        }
+    }
+    let text = text.replace('\\', "\\\\").replace('"', "\\\"");
+    if quoted {
+        text
+    } else {
+        format!("\"{text}\"")
+    }
+}
+
+/// Applies a `\*` switch. Unknown formats such as `MERGEFORMAT` leave the
+/// text as it is.
+pub(super) fn format_text(text: &str, format: &str) -> String {
+    match format.to_lowercase().as_str() {
+        "upper" => text.to_uppercase(),
+        "lower" => text.to_lowercase(),
+        "firstcap" => capitalize(text),
+        "caps" => text
+            .split(' ')
+            .map(capitalize)
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => text.to_owned(),
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Applies a `\#` numeric picture such as `#,##0.00` or `$#,##0;($#,##0)`.
+/// Returns `None` when `value` is not a number.
+pub(super) fn format_number(value: &str, picture: &str) -> Option<String> {
+    let value: f64 = value.trim().replace(',', "").parse().ok()?;
+    let sections: Vec<&str> = picture.split(';').collect();
+    let (section, sign) = match sections.as_slice() {
+        [_, negative, ..] if value < 0.0 => (*negative, ""),
+        [_, _, zero, ..] if value == 0.0 => (*zero, ""),
+        [positive, ..] if value < 0.0 => (*positive, "-"),
+        [positive, ..] => (*positive, ""),
+        [] => unreachable!("split returns at least one section"),
+    };
+
+    let is_digit = |c: char| matches!(c, '0' | '#' | 'x');
+    let Some(first) = section.find(is_digit) else {
+        return Some(literal(section));
+    };
+    let last = section.rfind(is_digit).unwrap_or(first);
+    let (prefix, rest) = section.split_at(first);
+    let (pattern, suffix) = rest.split_at(last - first + 1);
+
+    let (integer, fraction) = pattern.split_once('.').unwrap_or((pattern, ""));
+    let min_integer = integer.chars().filter(|&c| c == '0').count();
+    let decimals = fraction.chars().filter(|&c| is_digit(c)).count();
+    let min_decimals = fraction.chars().filter(|&c| c == '0').count();
+
+    // Word rounds halves away from zero.
+    let scale = 10f64.powi(decimals as i32);
+    let formatted = format!("{:.*}", decimals, (value.abs() * scale).round() / scale);
+    let (mut int_digits, frac_digits) = match formatted.split_once('.') {
+        Some((i, f)) => (i.to_owned(), f.trim_end_matches('0').to_owned()),
+        None => (formatted, String::new()),
+    };
+    if int_digits == "0" && min_integer == 0 {
+        int_digits.clear();
+    }
+    while int_digits.len() < min_integer {
+        int_digits.insert(0, '0');
+    }
+    if integer.contains(',') {
+        int_digits = group_thousands(&int_digits);
+    }
+    let mut frac_digits = frac_digits;
+    while frac_digits.len() < min_decimals {
+        frac_digits.push('0');
+    }
+
+    let mut number = format!("{}{}{}", sign, literal(prefix), int_digits);
+    if !frac_digits.is_empty() {
+        number.push('.');
+        number.push_str(&frac_digits);
+    }
+    number.push_str(&literal(suffix));
+    Some(number)
+}
+
+fn group_thousands(digits: &str) -> String {
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+/// Strips the single quotes Word uses around literal text in pictures.
+fn literal(text: &str) -> String {
+    text.replace('\'', "")
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Applies a `\@` date picture such as `d MMMM yyyy` or `HH:mm`. Returns
+/// `None` when `value` is not an ISO 8601 date, e.g. `2024-03-01` or
+/// `2024-03-01T09:30:00`.
+pub(super) fn format_date(value: &str, picture: &str) -> Option<String> {
+    let value = value.trim();
+    let (date, time) = match value.split_once(['T', ' ']) {
+        Some((date, time)) => (date, time),
+        None => (value, ""),
+    };
+    let mut date = date.split(['-', '/']).map(|p| p.parse::<u32>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+    if date.next().is_some()
+        || year == 0
+        || !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+    {
+        return None;
+    }
+    let mut time = time
+        .split(':')
+        .map(|p| p.get(..2).unwrap_or(p).parse::<u32>().unwrap_or(0));
+    let hour = time.next().unwrap_or(0);
+    let minute = time.next().unwrap_or(0);
+    let second = time.next().unwrap_or(0);
+
+    let mut formatted = String::new();
+    let mut chars = picture.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            formatted.extend(chars.by_ref().take_while(|&c| c != '\''));
+            continue;
+        }
+        let rest: String = std::iter::once(c).chain(chars.clone()).collect();
+        if let Some(am_pm) = ["AM/PM", "am/pm"].iter().find(|p| rest.starts_with(**p)) {
+            let upper = am_pm.starts_with('A');
+            formatted.push_str(match (hour < 12, upper) {
+                (true, true) => "AM",
+                (false, true) => "PM",
+                (true, false) => "am",
+                (false, false) => "pm",
+            });
+            for _ in 1..am_pm.len() {
+                chars.next();
+            }
+            continue;
+        }
+        if !matches!(c, 'y' | 'Y' | 'M' | 'd' | 'D' | 'H' | 'h' | 'm' | 's') {
+            formatted.push(c);
+            continue;
+        }
+        let mut count = 1;
+        while chars.peek() == Some(&c) {
+            chars.next();
+            count += 1;
+        }
+        match (c, count) {
+            ('y' | 'Y', 1..=2) => formatted.push_str(&format!("{:02}", year % 100)),
+            ('y' | 'Y', _) => formatted.push_str(&format!("{year:04}")),
+            ('M', 1) => formatted.push_str(&month.to_string()),
+            ('M', 2) => formatted.push_str(&format!("{month:02}")),
+            ('M', 3) => formatted.push_str(&MONTHS[month as usize - 1][..3]),
+            ('M', _) => formatted.push_str(MONTHS[month as usize - 1]),
+            ('d' | 'D', 1) => formatted.push_str(&day.to_string()),
+            ('d' | 'D', 2) => formatted.push_str(&format!("{day:02}")),
+            ('d' | 'D', 3) => formatted.push_str(&WEEKDAYS[weekday(year, month, day)][..3]),
+            ('d' | 'D', _) => formatted.push_str(WEEKDAYS[weekday(year, month, day)]),
+            ('H', 1) => formatted.push_str(&hour.to_string()),
+            ('H', _) => formatted.push_str(&format!("{hour:02}")),
+            ('h', n) => {
+                let hour = (hour + 11) % 12 + 1;
+                match n {
+                    1 => formatted.push_str(&hour.to_string()),
+                    _ => formatted.push_str(&format!("{hour:02}")),
+                }
+            }
+            ('m', 1) => formatted.push_str(&minute.to_string()),
+            ('m', _) => formatted.push_str(&format!("{minute:02}")),
+            ('s', 1) => formatted.push_str(&second.to_string()),
+            (_, _) => formatted.push_str(&format!("{second:02}")),
+        }
+    }
+    Some(formatted)
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Day of the week, 0 being Sunday. `year` is at least 1.
+fn weekday(year: u32, month: u32, day: u32) -> usize {
+    const OFFSETS: [u32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = if month < 3 { year - 1 } else { year };
+    ((year + year / 4 - year / 100 + year / 400 + OFFSETS[month as usize - 1] + day) % 7) as usize
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_tokenize() {
+        let texts: Vec<(String, bool)> =
+            tokenize(r#" MERGEFIELD  "First Name" \b "Dear \"you\" " \* Upper"#)
+                .into_iter()
+                .map(|t| (t.text, t.quoted))
+                .collect();
+        assert_eq!(
+            texts,
+            vec![
+                ("MERGEFIELD".to_owned(), false),
+                ("First Name".to_owned(), true),
+                ("\\b".to_owned(), false),
+                ("Dear \"you\" ".to_owned(), true),
+                ("\\*".to_owned(), false),
+                ("Upper".to_owned(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number("1234.5", "#,##0.00").unwrap(), "1,234.50");
+        assert_eq!(format_number("-1234.5", "$#,##0").unwrap(), "-$1,235");
+        assert_eq!(format_number("-3", "0;(0)").unwrap(), "(3)");
+        assert_eq!(format_number("0.5", "#.##").unwrap(), ".5");
+        assert_eq!(format_number("7", "000 'pcs'").unwrap(), "007 pcs");
+        assert_eq!(format_number("n/a", "0"), None);
+    }
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(
+            format_date("2024-03-01", "dddd, d MMMM yyyy").unwrap(),
+            "Friday, 1 March 2024"
+        );
+        assert_eq!(
+            format_date("2024-03-01T21:05:09", "yy/MM/dd h:mm AM/PM 'at' ss").unwrap(),
+            "24/03/01 9:05 PM at 09"
+        );
+        assert_eq!(format_date("next week", "yyyy"), None);
+        assert_eq!(format_date("2024-02-31", "yyyy"), None);
+        assert_eq!(format_date("2023-02-29", "yyyy"), None);
+        assert_eq!(format_date("2024-04-31", "yyyy"), None);
+        assert_eq!(format_date("0000-01-15", "dddd"), None);
+        assert_eq!(
+            format_date("2024-02-29", "ddd d MMM").unwrap(),
+            "Thu 29 Feb"
+        );
+        assert_eq!(format_date("1900-02-29", "yyyy"), None);
+        assert_eq!(format_date("2000-02-29", "dddd").unwrap(), "Tuesday");
+    }
+}
@@ -0,0 +1,650 @@
+//! Mail merge over `MERGEFIELD` fields.
+//!
+//! The reader keeps field instructions as plain strings, so fields are
+//! recognized from their field characters and instruction text. `MERGEFIELD`,
+//! `IF`, `NEXTIF` and `NEXT` fields are replaced with their result; other
+//! fields are kept as they are. Fields have to begin and end among the runs
+//! of one paragraph, hyperlink, content control, insertion or move to be
+//! merged.
+
+mod data;
+mod format;
+
+pub use data::*;
+
+use std::collections::HashMap;
+
+use self::format::*;
+use super::document_tree::{
+    visit_document_part, visit_footer_part, visit_header_part, DocumentTreeVisitorMut,
+};
+use crate::documents::*;
+use crate::types::*;
+
+impl Docx {
+    /// Merges every record into a copy of this document and joins the copies
+    /// into one document, each starting in a new section on a new page.
+    /// Returns a copy of this document when there are no records.
+    pub fn mail_merge(&self, data: &MergeData) -> Docx {
+        let mut documents = self.mail_merge_each(data).into_iter();
+        let Some(first) = documents.next() else {
+            return self.clone();
+        };
+        documents.fold(first, |merged, document| {
+            merged.append_document(
+                document,
+                AppendDocumentOptions::new().section_break(SectionType::NextPage),
+            )
+        })
+    }
+
+    /// Merges the records into copies of this document, one per record
+    /// unless `NEXT` or `NEXTIF` fields move on to further records within a
+    /// copy.
+    pub fn mail_merge_each(&self, data: &MergeData) -> Vec<Docx> {
+        let mut documents = vec![];
+        let mut current = 0;
+        while current < data.records.len() {
+            let mut docx = self.clone();
+            // Headers and footers show the record the copy starts with.
+            let mut merger = Merger {
+                records: &data.records,
+                current,
+            };
+            for (_, header) in docx.document.headers_mut() {
+                visit_header_part(header, &mut merger);
+            }
+            for (_, footer) in docx.document.footers_mut() {
+                visit_footer_part(footer, &mut merger);
+            }
+            merger.current = current;
+            visit_document_part(&mut docx.document, &mut merger);
+            current = merger.current + 1;
+            documents.push(docx);
+        }
+        documents
+    }
+}
+
+struct Merger<'a> {
+    records: &'a [HashMap<String, String>],
+    current: usize,
+}
+
+impl DocumentTreeVisitorMut for Merger<'_> {
+    fn visit_paragraph(&mut self, paragraph: &mut Paragraph) {
+        self.merge_runs(&mut paragraph.children);
+    }
+}
+
+/// Children of an element that holds runs, such as a paragraph or a
+/// hyperlink.
+trait RunContent: Sized {
+    fn into_run(self) -> Result<Box<Run>, Self>;
+
+    fn from_run(run: Box<Run>) -> Self;
+
+    fn as_run_mut(&mut self) -> Option<&mut Run>;
+
+    /// Merges the fields among the runs of an element nested in this one.
+    fn merge_nested(&mut self, _merger: &mut Merger) {}
+}
+
+macro_rules! run_content {
+    ($child:ident $(, |$this:ident, $merger:ident| $nested:expr)?) => {
+        impl RunContent for $child {
+            fn into_run(self) -> Result<Box<Run>, Self> {
+                match self {
+                    $child::Run(run) => Ok(run),
+                    child => Err(child),
+                }
+            }
+
+            fn from_run(run: Box<Run>) -> Self {
+                $child::Run(run)
+            }
+
+            fn as_run_mut(&mut self) -> Option<&mut Run> {
+                match self {
+                    $child::Run(run) => Some(run),
+                    _ => None,
+                }
+            }
+
+            $(
+                fn merge_nested(&mut self, $merger: &mut Merger) {
+                    let $this = self;
+                    $nested
+                }
+            )?
+        }
+    };
+}
+
+run_content!(ParagraphChild, |child, merger| match child {
+    ParagraphChild::Hyperlink(link) => merger.merge_runs(&mut link.children),
+    ParagraphChild::StructuredDataTag(tag) => merger.merge_runs(&mut tag.children),
+    ParagraphChild::Insert(insert) => merger.merge_runs(&mut insert.children),
+    ParagraphChild::MoveTo(moved) => merger.merge_runs(&mut moved.children),
+    _ => {}
+});
+// Paragraphs in block-level content controls are visited on their own.
+run_content!(StructuredDataTagChild, |child, merger| {
+    if let StructuredDataTagChild::StructuredDataTag(tag) = child {
+        merger.merge_runs(&mut tag.children);
+    }
+});
+run_content!(InsertChild);
+run_content!(MoveToChild);
+
+/// Run content, one run child at a time. Run children carry the index of the
+/// run they came from, so that they can be put back together.
+enum Item<C> {
+    Run(usize, Box<Run>),
+    Other(C),
+}
+
+impl<C> Item<C> {
+    fn run(origin: usize, run_property: &RunProperty, child: RunChild) -> Item<C> {
+        Item::Run(
+            origin,
+            Box::new(Run {
+                run_property: run_property.clone(),
+                children: vec![child],
+            }),
+        )
+    }
+}
+
+/// A field whose end has not been reached yet.
+struct Field<C> {
+    instruction: String,
+    /// Whether the instruction has parts that are not plain text, such as
+    /// an `InstrText::TOC`, so the field is not ours to merge.
+    is_foreign: bool,
+    is_separated: bool,
+    /// Text of the result Word stored with the field.
+    result: String,
+    run_property: RunProperty,
+    /// Everything from the field's begin character, to keep it as it is.
+    items: Vec<Item<C>>,
+}
+
+impl Merger<'_> {
+    /// Merges the fields among runs, and in the elements nested among them.
+    fn merge_runs<C: RunContent>(&mut self, children: &mut Vec<C>) {
+        let has_fields = children.iter_mut().any(|c| {
+            c.as_run_mut().is_some_and(|run| {
+                run.children
+                    .iter()
+                    .any(|c| matches!(c, RunChild::FieldChar(_)))
+            })
+        });
+        if !has_fields {
+            for child in children {
+                child.merge_nested(self);
+            }
+            return;
+        }
+        let mut output = vec![];
+        let mut fields: Vec<Field<C>> = vec![];
+        let mut next_origin = children.len();
+        for (origin, child) in std::mem::take(children).into_iter().enumerate() {
+            let run = match child.into_run() {
+                Ok(run) => run,
+                Err(mut child) => {
+                    child.merge_nested(self);
+                    push_item(&mut fields, &mut output, Item::Other(child));
+                    continue;
+                }
+            };
+            let Run {
+                run_property,
+                children,
+            } = *run;
+            for child in children {
+                match &child {
+                    RunChild::FieldChar(c) if c.field_char_type == FieldCharType::Begin => {
+                        fields.push(Field {
+                            instruction: String::new(),
+                            is_foreign: false,
+                            is_separated: false,
+                            result: String::new(),
+                            run_property: run_property.clone(),
+                            items: vec![],
+                        });
+                    }
+                    RunChild::FieldChar(c) if c.field_char_type == FieldCharType::Separate => {
+                        if let Some(field) = fields.last_mut() {
+                            field.is_separated = true;
+                        }
+                    }
+                    RunChild::InstrTextString(s) => {
+                        if let Some(field) = fields.last_mut().filter(|f| !f.is_separated) {
+                            field.instruction.push_str(s);
+                        }
+                    }
+                    RunChild::InstrText(i) => {
+                        if let Some(field) = fields.last_mut().filter(|f| !f.is_separated) {
+                            match i.as_ref() {
+                                InstrText::Unsupported(s) => field.instruction.push_str(s),
+                                _ => field.is_foreign = true,
+                            }
+                        }
+                    }
+                    RunChild::Text(t) => {
+                        if let Some(field) = fields.last_mut().filter(|f| f.is_separated) {
+                            field.result.push_str(&t.text);
+                        }
+                    }
+                    _ => {}
+                }
+                let is_end = matches!(&child, RunChild::FieldChar(c) if c.field_char_type == FieldCharType::End);
+                push_item(
+                    &mut fields,
+                    &mut output,
+                    Item::run(origin, &run_property, child),
+                );
+                if !is_end {
+                    continue;
+                }
+                let Some(field) = fields.pop() else {
+                    continue;
+                };
+                let value = if field.is_foreign {
+                    None
+                } else {
+                    self.evaluate(&field.instruction)
+                };
+                match fields.last_mut() {
+                    // A field inside an instruction, e.g. the MERGEFIELD in
+                    // `IF { MERGEFIELD Title } = "Dr" ...`, contributes its
+                    // result to the instruction.
+                    Some(parent) if !parent.is_separated => {
+                        let text = value.as_ref().unwrap_or(&field.result);
+                        let text = quote(&parent.instruction, text);
+                        parent.instruction.push_str(&text);
+                        if value.is_some() {
+                            parent.items.push(Item::run(
+                                next_origin,
+                                &field.run_property,
+                                RunChild::InstrTextString(text),
+                            ));
+                            next_origin += 1;
+                        } else {
+                            parent.items.extend(field.items);
+                        }
+                    }
+                    parent => {
+                        let items = match parent {
+                            Some(parent) => &mut parent.items,
+                            None => &mut output,
+                        };
+                        match value {
+                            Some(value) => {
+                                push_text(items, next_origin, &field.run_property, &value);
+                                next_origin += 1;
+                            }
+                            None => items.extend(field.items),
+                        }
+                    }
+                }
+            }
+        }
+        // Fields continuing past these runs are kept as they are.
+        for field in fields {
+            output.extend(field.items);
+        }
+
+        let mut last_origin = None;
+        for item in output {
+            match item {
+                Item::Run(origin, run) => {
+                    if last_origin == Some(origin) {
+                        if let Some(last) = children.last_mut().and_then(C::as_run_mut) {
+                            last.children.extend(run.children);
+                            continue;
+                        }
+                    }
+                    last_origin = Some(origin);
+                    children.push(C::from_run(run));
+                }
+                Item::Other(child) => {
+                    last_origin = None;
+                    children.push(child);
+                }
+            }
+        }
+    }
+
+    /// Returns the result of a field, or `None` for fields that are not
+    /// merged.
+    fn evaluate(&mut self, instruction: &str) -> Option<String> {
+        let tokens = tokenize(instruction);
+        let (name, args) = tokens.split_first()?;
+        match name.text.to_uppercase().as_str() {
+            "MERGEFIELD" => Some(self.merge_field(args)),
+            "IF" => {
+                let text = if compare(args) {
+                    args.get(3)
+                } else {
+                    args.get(4)
+                };
+                Some(text.map(|t| t.text.clone()).unwrap_or_default())
+            }
+            "NEXTIF" => {
+                if compare(args) {
+                    self.current += 1;
+                }
+                Some(String::new())
+            }
+            "NEXT" => {
+                self.current += 1;
+                Some(String::new())
+            }
+            _ => None,
+        }
+    }
+
+    fn merge_field(&self, args: &[Token]) -> String {
+        let Some((name, switches)) = args.split_first() else {
+            return String::new();
+        };
+        let mut value = self
+            .records
+            .get(self.current)
+            .and_then(|r| r.get(&name.text.to_lowercase()))
+            .cloned()
+            .unwrap_or_default();
+        let (mut before, mut after) = (None, None);
+        let mut switches = switches.iter();
+        while let Some(switch) = switches.next() {
+            let switch = switch.text.as_str();
+            if !switch.starts_with('\\') || switch.len() < 2 {
+                continue;
+            }
+            let (flag, rest) = switch.split_at(2);
+            let arg = if rest.is_empty() {
+                switches.next().map(|a| a.text.as_str()).unwrap_or_default()
+            } else {
+                rest
+            };
+            match flag {
+                "\\*" => value = format_text(&value, arg),
+                "\\#" => value = format_number(&value, arg).unwrap_or(value),
+                "\\@" => value = format_date(&value, arg).unwrap_or(value),
+                "\\b" => before = Some(arg),
+                "\\f" => after = Some(arg),
+                _ => {}
+            }
+        }
+        if value.is_empty() {
+            return value;
+        }
+        format!(
+            "{}{}{}",
+            before.unwrap_or_default(),
+            value,
+            after.unwrap_or_default()
+        )
+    }
+}
+
+/// Adds an item to the innermost open field, or to the output.
+fn push_item<C>(fields: &mut [Field<C>], output: &mut Vec<Item<C>>, item: Item<C>) {
+    match fields.last_mut() {
+        Some(field) => field.items.push(item),
+        None => output.push(item),
+    }
+}
+
+/// Adds a field result, turning line breaks into `w:br`.
+fn push_text<C>(items: &mut Vec<Item<C>>, origin: usize, run_property: &RunProperty, text: &str) {
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            items.push(Item::run(
+                origin,
+                run_property,
+                RunChild::Break(Break::new(BreakType::TextWrapping)),
+            ));
+        }
+        if !line.is_empty() {
+            items.push(Item::run(
+                origin,
+                run_property,
+                RunChild::Text(Text::new(line)),
+            ));
+        }
+    }
+}
+
+/// Evaluates the `left operator right` condition of `IF` and `NEXTIF`.
+fn compare(args: &[Token]) -> bool {
+    let [left, operator, right, ..] = args else {
+        return false;
+    };
+    let (left, right) = (left.text.trim(), &right.text);
+    let wildcard = right.contains(['*', '?']);
+    let ordering = match (left.parse::<f64>(), right.trim().parse::<f64>()) {
+        (Ok(l), Ok(r)) => l.partial_cmp(&r),
+        _ => Some(left.cmp(right.as_str())),
+    };
+    match operator.text.as_str() {
+        "=" if wildcard => matches_wildcard(left, right),
+        "<>" if wildcard => !matches_wildcard(left, right),
+        "=" => ordering == Some(std::cmp::Ordering::Equal),
+        "<>" => ordering != Some(std::cmp::Ordering::Equal),
+        "<" => ordering == Some(std::cmp::Ordering::Less),
+        "<=" => matches!(ordering, Some(o) if o.is_le()),
+        ">" => ordering == Some(std::cmp::Ordering::Greater),
+        ">=" => matches!(ordering, Some(o) if o.is_ge()),
+        _ => false,
+    }
+}
+
+/// Matches `text` against a pattern where `*` stands for any text and `?`
+/// for any character.
+fn matches_wildcard(text: &str, pattern: &str) -> bool {
+    let text: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    // matches[j]: whether the text so far matches pattern[..j].
+    let mut matches = vec![false; pattern.len() + 1];
+    matches[0] = true;
+    for j in 0..pattern.len() {
+        matches[j + 1] = matches[j] && pattern[j] == '*';
+    }
+    for c in text {
+        let mut next = vec![false; pattern.len() + 1];
+        for j in 0..pattern.len() {
+            next[j + 1] = match pattern[j] {
+                '*' => next[j] || matches[j + 1],
+                '?' => matches[j],
+                p => matches[j] && p == c,
+            };
+        }
+        matches = next;
+    }
+    matches[pattern.len()]
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::documents::paragraph_text::collect_runs;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn field(instruction: &str) -> Vec<Run> {
+        vec![
+            Run::new().add_field_char(FieldCharType::Begin, false),
+            Run::new().add_instr_text(InstrText::Unsupported(instruction.to_owned())),
+            Run::new().add_field_char(FieldCharType::Separate, false),
+            Run::new().add_text(format!("«{}»", instruction.trim())),
+            Run::new().add_field_char(FieldCharType::End, false),
+        ]
+    }
+
+    fn paragraph(parts: Vec<Vec<Run>>) -> Paragraph {
+        parts
+            .into_iter()
+            .flatten()
+            .fold(Paragraph::new(), |p, run| p.add_run(run))
+    }
+
+    fn text(paragraph: &Paragraph) -> String {
+        let mut runs = vec![];
+        collect_runs(&paragraph.children, &mut runs);
+        runs_text(runs)
+    }
+
+    fn runs_text<'a>(runs: impl IntoIterator<Item = &'a Run>) -> String {
+        let mut text = String::new();
+        for run in runs {
+            for child in &run.children {
+                match child {
+                    RunChild::Text(t) => text.push_str(&t.text),
+                    RunChild::InstrTextString(i) => text.push_str(i),
+                    RunChild::Break(_) => text.push('\n'),
+                    _ => {}
+                }
+            }
+        }
+        text
+    }
+
+    fn body_text(docx: &Docx) -> Vec<String> {
+        docx.document
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(text(p)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_fields_with_switches() {
+        let template = Docx::new().add_paragraph(paragraph(vec![
+            field(r#" MERGEFIELD Name \* Upper \b "Dear " \f "," "#),
+            vec![Run::new().add_text(" due ")],
+            field(r##" MERGEFIELD Amount \# "#,##0.00" "##),
+            vec![Run::new().add_text(" on ")],
+            field(r#" MERGEFIELD Due \@ "d MMMM yyyy" \* MERGEFORMAT "#),
+        ]));
+        let data = MergeData::from_json(&serde_json::json!([
+            { "name": "Ada", "amount": 1234.5, "due": "2024-03-01" },
+            { "name": "", "amount": "12", "due": "soon" },
+        ]))
+        .unwrap();
+
+        let documents = template.mail_merge_each(&data);
+        assert_eq!(
+            documents.iter().map(body_text).collect::<Vec<_>>(),
+            vec![
+                vec!["Dear ADA, due 1,234.50 on 1 March 2024".to_owned()],
+                vec![" due 12.00 on soon".to_owned()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_if_with_nested_merge_field() {
+        let mut parts = vec![vec![
+            Run::new().add_field_char(FieldCharType::Begin, false),
+            Run::new().add_instr_text(InstrText::Unsupported(" IF ".to_owned())),
+        ]];
+        parts.push(field(" MERGEFIELD Title "));
+        parts.push(vec![
+            Run::new().add_instr_text(InstrText::Unsupported(
+                r#" = "Dr*" "Doctor" "Guest" "#.to_owned(),
+            )),
+            Run::new().add_field_char(FieldCharType::End, false),
+        ]);
+        let template = Docx::new().add_paragraph(paragraph(parts));
+        let data = MergeData::new()
+            .add_record([("Title", "Dr.")])
+            .add_record([("Title", "Mr.")]);
+
+        let documents = template.mail_merge_each(&data);
+        assert_eq!(body_text(&documents[0]), vec!["Doctor"]);
+        assert_eq!(body_text(&documents[1]), vec!["Guest"]);
+    }
+
+    #[test]
+    fn test_nextif_and_combined_document() {
+        let template = Docx::new()
+            .add_paragraph(paragraph(vec![
+                field(" MERGEFIELD Name "),
+                field(r#" NEXTIF "A" = "A" "#),
+                vec![Run::new().add_text(" and ")],
+                field(" MERGEFIELD Name "),
+            ]))
+            .add_paragraph(paragraph(vec![
+                vec![Run::new().add_text("Page ")],
+                field(" PAGE "),
+            ]));
+        let data = MergeData::new()
+            .add_record([("Name", "Ada")])
+            .add_record([("Name", "Bob")])
+            .add_record([("Name", "Cy")]);
+
+        let documents = template.mail_merge_each(&data);
+        assert_eq!(
+            documents.iter().map(body_text).collect::<Vec<_>>(),
+            vec![
+                vec!["Ada and Bob".to_owned(), "Page «PAGE»".to_owned()],
+                vec!["Cy and ".to_owned(), "Page «PAGE»".to_owned()],
+            ]
+        );
+
+        let merged = template.mail_merge(&data);
+        assert!(matches!(
+            merged.document.children[0],
+            DocumentChild::Section(_)
+        ));
+        assert_eq!(body_text(&merged), vec!["Cy and ", "Page «PAGE»"]);
+    }
+
+    #[test]
+    fn test_merge_fields_in_links_tags_and_changes() {
+        let link = field(" MERGEFIELD Name ").into_iter().fold(
+            Hyperlink::new("https://example.com", HyperlinkType::External),
+            |l, r| l.add_run(r),
+        );
+        let tag = field(" MERGEFIELD City ")
+            .into_iter()
+            .fold(StructuredDataTag::new(), |t, r| t.add_run(r));
+        let insert = field(" MERGEFIELD Name ")
+            .into_iter()
+            .fold(Insert::new_with_empty(), |i, r| i.add_run(r));
+        let moved = field(" MERGEFIELD City ")
+            .into_iter()
+            .fold(MoveTo::new_with_empty(), |m, r| m.add_run(r));
+        let template = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_hyperlink(link)
+                .add_run(Run::new().add_text(" in "))
+                .add_structured_data_tag(tag)
+                .add_insert(insert)
+                .add_move_to(moved),
+        );
+        let data = MergeData::new().add_record([("Name", "Ada"), ("City", "London")]);
+
+        let merged = template.mail_merge(&data);
+        let p = match &merged.document.children[0] {
+            DocumentChild::Paragraph(p) => p,
+            _ => panic!("expected a paragraph"),
+        };
+        assert_eq!(text(p), "Ada in LondonAda");
+        let ParagraphChild::MoveTo(moved) = &p.children[4] else {
+            panic!("expected a move");
+        };
+        let runs = moved.children.iter().filter_map(|c| match c {
+            MoveToChild::Run(run) => Some(&**run),
+            _ => None,
+        });
+        assert_eq!(runs_text(runs), "London");
+    }
+}
@@ -3,11 +3,17 @@ use std::{
     str::FromStr,
 };
 
+mod alt_chunk_collector;
+mod append;
 mod bookmark_id;
 mod build_xml;
+mod comment_threads;
 mod comments;
 mod comments_extended;
+mod comments_extensible;
+mod comments_ids;
 mod content_types;
+mod cursor;
 mod custom_item;
 mod custom_item_property;
 mod custom_item_rels;
@@ -16,28 +22,42 @@ mod document;
 mod document_rels;
 mod document_tree;
 mod elements;
+mod export;
+mod find;
 mod font_table;
 mod footer;
 mod footer_id;
 mod footer_rels;
 mod footnote_id;
 mod footnotes;
+mod formatting;
 mod header;
 mod header_id;
 mod header_rels;
 mod history_id;
 mod hyperlink_id;
 mod image_collector;
+mod import;
+mod list_label;
+mod mail_merge;
+mod number_text;
+mod numbering_rels;
 mod numberings;
+mod outline;
 mod paragraph_id;
 mod paragraph_property_change_id;
+mod paragraph_text;
+mod people;
 mod pic_id;
-mod preset_styles;
+mod placeholders;
+pub mod preset_styles;
 mod rels;
 mod settings;
+mod split;
 mod styles;
 mod taskpanes;
 mod taskpanes_rels;
+mod template;
 mod theme;
 mod toc_key;
 mod web_settings;
@@ -50,29 +70,46 @@ pub(crate) use hyperlink_id::*;
 pub(crate) use paragraph_property_change_id::ParagraphPropertyChangeId;
 pub(crate) use pic_id::*;
 
+pub use append::*;
 pub use bookmark_id::*;
+pub use comment_threads::*;
 pub use comments::*;
 pub use comments_extended::*;
+pub use comments_extensible::*;
+pub use comments_ids::*;
 pub use content_types::*;
+pub use cursor::*;
 pub use custom_item::*;
 pub use custom_item_property::*;
 pub use custom_item_rels::*;
 pub use doc_props::*;
 pub use document::*;
 pub use document_rels::*;
+pub use document_tree::*;
 pub use elements::*;
+pub use export::*;
+pub use find::*;
 pub use font_table::*;
 pub use footer::*;
 pub use footer_id::*;
 pub use footer_rels::*;
 pub use footnotes::*;
+pub use formatting::*;
 pub use header::*;
 pub use header_id::*;
 pub use header_rels::*;
+pub use import::*;
+pub use list_label::*;
+pub use mail_merge::*;
+pub use numbering_rels::*;
 pub use numberings::*;
+pub use outline::*;
 pub use paragraph_id::*;
+pub use people::*;
+pub use placeholders::*;
 pub use rels::*;
 pub use settings::*;
+pub use split::*;
 pub use styles::*;
 pub use taskpanes::*;
 pub use taskpanes_rels::*;
@@ -85,9 +122,10 @@ pub use xml_docx::*;
 use base64::Engine;
 use serde::{ser, Serialize};
 
+use self::alt_chunk_collector::{collect_alt_chunks, AltChunkPart};
 use self::image_collector::{
     collect_document_footnotes, collect_document_part, collect_footer_part, collect_header_part,
-    MediaRegistry,
+    collect_numbering_part, MediaRegistry,
 };
 
 #[derive(Debug, Clone)]
@@ -155,6 +193,12 @@ pub struct Docx {
     pub font_table: FontTable,
     pub media: Vec<(String, Vec<u8>)>,
     pub comments_extended: CommentsExtended,
+    #[serde(skip_serializing_if = "CommentsIds::is_empty")]
+    pub comments_ids: CommentsIds,
+    #[serde(skip_serializing_if = "CommentsExtensible::is_empty")]
+    pub comments_extensible: CommentsExtensible,
+    #[serde(skip_serializing_if = "People::is_empty")]
+    pub people: People,
     pub web_settings: WebSettings,
     pub taskpanes: Option<Taskpanes>,
     pub taskpanes_rels: TaskpanesRels,
@@ -171,6 +215,12 @@ pub struct Docx {
     /// crate. Unsupported formats such as EMF keep an empty preview for
     /// downstream consumers to populate. See [`Png`] for details.
     pub images: Vec<(String, String, Image, Png)>,
+    /// Reader-only image bytes of header and footer parts, keyed by the
+    /// relationship id of the part and then by the image relationship id.
+    /// Those ids are scoped to their part and may repeat ones of
+    /// [`Docx::images`].
+    #[serde(skip)]
+    pub header_footer_images: HashMap<String, HashMap<String, Vec<u8>>>,
     // reader only
     pub hyperlinks: Vec<(String, String, String)>,
     pub footnotes: Footnotes,
@@ -179,10 +229,14 @@ pub struct Docx {
 /// Package metadata derived while normalizing a document for output.
 pub(crate) struct PackageMetadata {
     pub(crate) media: Vec<ImageIdAndBuf>,
+    /// altChunk payloads keyed by their part name under `word/`.
+    pub(crate) alt_chunks: Vec<AltChunkPart>,
     /// Non-empty header relationships keyed by zero-based part index.
     pub(crate) header_rels: Vec<(usize, HeaderRels)>,
     /// Non-empty footer relationships keyed by zero-based part index.
     pub(crate) footer_rels: Vec<(usize, FooterRels)>,
+    /// Relationships of `numbering.xml`, present only for picture bullets.
+    pub(crate) numbering_rels: Option<NumberingRels>,
 }
 
 /// Expands sparse relationships only through the last part that needs them.
@@ -232,6 +286,9 @@ impl Default for Docx {
             font_table,
             media,
             comments_extended,
+            comments_ids: CommentsIds::new(),
+            comments_extensible: CommentsExtensible::new(),
+            people: People::new(),
             web_settings,
             taskpanes: None,
             taskpanes_rels: TaskpanesRels::new(),
@@ -241,6 +298,7 @@ impl Default for Docx {
             custom_item_rels: vec![],
             themes: vec![],
             images: vec![],
+            header_footer_images: HashMap::new(),
             hyperlinks: vec![],
             footnotes,
         }
@@ -369,6 +427,31 @@ impl Docx {
         self
     }
 
+    pub fn comments_ids(mut self, c: CommentsIds) -> Self {
+        self.comments_ids = c;
+        self
+    }
+
+    pub fn comments_extensible(mut self, c: CommentsExtensible) -> Self {
+        self.comments_extensible = c;
+        self
+    }
+
+    pub fn people(mut self, p: People) -> Self {
+        self.people = p;
+        self
+    }
+
+    /// Adds an author of comments, e.g. with the account Office 365 knows
+    /// them by.
+    pub fn add_person(mut self, p: Person) -> Self {
+        self.people
+            .people
+            .retain(|person| person.author != p.author);
+        self.people.people.push(p);
+        self
+    }
+
     pub fn add_paragraph(mut self, p: Paragraph) -> Docx {
         if p.has_numbering {
             // If this document has numbering, set numberings.xml to document_rels.
@@ -481,6 +564,13 @@ impl Docx {
         self
     }
 
+    /// Adds content in another format, such as HTML or RTF, that Word
+    /// converts when it opens the document.
+    pub fn add_alt_chunk(mut self, c: AltChunk) -> Docx {
+        self.document = self.document.add_alt_chunk(c);
+        self
+    }
+
     pub fn add_bookmark_start(mut self, id: usize, name: impl Into<String>) -> Docx {
         self.document = self.document.add_bookmark_start(id, name);
         self
@@ -592,6 +682,11 @@ impl Docx {
         self
     }
 
+    pub fn add_num_pic_bullet(mut self, bullet: NumPicBullet) -> Docx {
+        self.numberings = self.numberings.add_num_pic_bullet(bullet);
+        self
+    }
+
     pub fn add_numbering(mut self, num: Numbering) -> Docx {
         self.numberings = self.numberings.add_numbering(num);
         self
@@ -667,6 +762,17 @@ impl Docx {
         self
     }
 
+    pub fn latent_styles(mut self, latent_styles: LatentStyles) -> Self {
+        self.styles = self.styles.latent_styles(latent_styles);
+        self
+    }
+
+    /// See [`Styles::use_builtin_styles`].
+    pub fn use_builtin_styles(mut self) -> Self {
+        self.styles = self.styles.use_builtin_styles();
+        self
+    }
+
     pub fn taskpanes(mut self) -> Self {
         self.taskpanes = Some(Taskpanes::new());
         self.rels = self.rels.add_taskpanes_rel();
@@ -742,10 +848,19 @@ impl Docx {
             settings: self.settings.build(),
             font_table: self.font_table.build(),
             numberings: self.numberings.build(),
+            numbering_rels: package
+                .numbering_rels
+                .map(|rels| rels.build())
+                .unwrap_or_default(),
             media: package.media,
+            alt_chunks: package.alt_chunks,
             headers,
             footers,
             comments_extended: self.comments_extended.build(),
+            comments_ids: (!self.comments_ids.is_empty()).then(|| self.comments_ids.build()),
+            comments_extensible: (!self.comments_extensible.is_empty())
+                .then(|| self.comments_extensible.build()),
+            people: (!self.people.is_empty()).then(|| self.people.build()),
             taskpanes: self.taskpanes.map(|taskpanes| taskpanes.build()),
             taskpanes_rels: self.taskpanes_rels.build(),
             web_extensions,
@@ -753,6 +868,7 @@ impl Docx {
             custom_item_rels,
             custom_item_props,
             footnotes: self.footnotes.build(),
+            theme: self.theme_xml().map(|xml| xml.to_vec()),
         }
     }
 
@@ -769,6 +885,16 @@ impl Docx {
         crate::zipper::zip_docx(writer, self, package)
     }
 
+    /// Returns the label Word renders for every numbered paragraph in the
+    /// body, in document order.
+    pub fn list_labels(&self) -> Vec<ListLabel> {
+        let mut resolver = ListLabelResolver::new(&self.numberings, &self.styles);
+        paragraphs_in_order(&self.document)
+            .into_iter()
+            .filter_map(|p| resolver.resolve(p))
+            .collect()
+    }
+
     pub fn json(&self) -> String {
         self.reset();
 
@@ -849,7 +975,19 @@ impl Docx {
         let document_part = collect_document_part(&mut self.document, &mut media);
         let header_images = self.collect_header_images(&mut media);
         let footer_images = self.collect_footer_images(&mut media);
+        let numbering_part = collect_numbering_part(&mut self.numberings, &mut media);
         self.document_rels.images = document_part.relationships;
+        let alt_chunks = collect_alt_chunks(
+            &mut self.document,
+            &mut self.document_rels,
+            &mut self.content_type,
+        );
+
+        let numbering_rels = (!numbering_part.relationships.is_empty()).then(|| {
+            let mut rels = NumberingRels::new();
+            rels.set_images(numbering_part.relationships);
+            rels
+        });
 
         let header_rels = header_images
             .into_iter()
@@ -875,6 +1013,30 @@ impl Docx {
             self.document_rels.has_footnotes = true;
         }
 
+        if self.theme_xml().is_some() {
+            self.content_type = std::mem::take(&mut self.content_type).add_theme();
+            self.document_rels.has_theme = true;
+        }
+
+        if !self.comments_ids.is_empty() {
+            self.content_type = std::mem::take(&mut self.content_type).add_comments_ids();
+            self.document_rels.has_comments_ids = true;
+        }
+        if !self.comments_extensible.is_empty() {
+            self.content_type = std::mem::take(&mut self.content_type).add_comments_extensible();
+            self.document_rels.has_comments_extensible = true;
+        }
+        if !self.people.is_empty() {
+            self.content_type = std::mem::take(&mut self.content_type).add_people();
+            self.document_rels.has_people = true;
+        }
+
+        if self.styles.include_builtin_styles {
+            let ids = preset_styles::referenced_style_ids(self);
+            self.styles
+                .add_missing_builtin_styles(ids.iter().map(|id| id.as_str()));
+        }
+
         if has_toc {
             for level in 1..=9 {
                 if !self
@@ -891,11 +1053,22 @@ impl Docx {
 
         PackageMetadata {
             media: media.into_media(),
+            alt_chunks,
             header_rels,
             footer_rels,
+            numbering_rels,
         }
     }
 
+    /// The theme part written as `word/theme/theme1.xml`, if the document was
+    /// read from or created with a package that has one.
+    pub(crate) fn theme_xml(&self) -> Option<&[u8]> {
+        self.themes
+            .first()
+            .map(|theme| &theme.raw_xml[..])
+            .filter(|xml| !xml.is_empty())
+    }
+
     /// Replaces empty and duplicate paragraph IDs with unique values.
     fn refresh_duplicate_para_ids(&mut self) {
         let mut counts: HashMap<&str, usize> = HashMap::new();
@@ -1141,6 +1314,11 @@ impl Docx {
 
         self.comments.add_comments(comments);
 
+        self.comments_ids.update(&self.comments.comments);
+        self.comments_extensible
+            .update(&self.comments_ids, &self.comments.comments);
+        self.people.update(&self.comments.comments);
+
         for (id, d) in hyperlink_map {
             self.document_rels
                 .hyperlinks
@@ -2759,6 +2937,104 @@ mod pack_tests {
 
         assert_eq!(streamed.into_inner(), buffered.into_inner());
     }
+
+    #[test]
+    fn modern_comment_parts_round_trip() {
+        let comment = |id: usize, author: &str, para_id: &str| {
+            Comment::new(id)
+                .author(author)
+                .date("2024-01-01T00:00:00Z")
+                .add_paragraph(
+                    Paragraph::new()
+                        .id(para_id)
+                        .add_run(Run::new().add_text(author)),
+                )
+        };
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_comment_start(comment(1, "Alice", "0000000A"))
+                    .add_comment_start(comment(2, "Bob", "0000000B").parent_comment_id(1))
+                    .add_run(Run::new().add_text("Hello"))
+                    .add_comment_end(1)
+                    .add_comment_end(2),
+            )
+            .comments_ids(CommentsIds::new().add_comment_id(CommentId::new("0000000A", "3F2B6A1C")))
+            .add_person(Person::new("Alice").presence_info("AD", "S::alice@example.com::1"));
+
+        let mut buf = Cursor::new(vec![]);
+        docx.pack(&mut buf).unwrap();
+        let read = crate::reader::read_docx(buf.get_ref()).expect("should read docx");
+
+        let ids = vec![
+            CommentId::new("0000000A", "3F2B6A1C"),
+            CommentId::new("0000000B", "0000000B"),
+        ];
+        assert_eq!(read.comments_ids.children, ids);
+        assert_eq!(
+            read.comments_extensible.children,
+            vec![
+                CommentExtensible::new("3F2B6A1C").date_utc("2024-01-01T00:00:00Z"),
+                CommentExtensible::new("0000000B").date_utc("2024-01-01T00:00:00Z"),
+            ]
+        );
+        assert_eq!(
+            read.people.people,
+            vec![
+                Person::new("Alice").presence_info("AD", "S::alice@example.com::1"),
+                Person::new("Bob"),
+            ]
+        );
+
+        // Durable ids survive editing and saving again.
+        let mut buf = Cursor::new(vec![]);
+        read.build().pack(&mut buf).unwrap();
+        let read = crate::reader::read_docx(buf.get_ref()).expect("should read docx");
+        assert_eq!(read.comments_ids.children, ids);
+        assert_eq!(read.people.people.len(), 2);
+    }
+
+    #[test]
+    fn picture_bullets_round_trip_through_numbering_relationships() {
+        let bytes = vec![7, 7, 7, 7];
+        let docx = Docx::new()
+            .add_num_pic_bullet(NumPicBullet::new(0, bytes.clone()).pic_id("rIdBullet"))
+            .add_abstract_numbering(
+                AbstractNumbering::new(2).add_level(
+                    Level::new(
+                        0,
+                        Start::new(1),
+                        NumberFormat::new("bullet"),
+                        LevelText::new("o"),
+                        LevelJc::new("left"),
+                    )
+                    .pic_bullet(0),
+                ),
+            )
+            .add_numbering(Numbering::new(2, 2))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("item"))
+                    .numbering(NumberingId::new(2), IndentLevel::new(0)),
+            );
+
+        let mut buf = Cursor::new(vec![]);
+        docx.build().pack(&mut buf).unwrap();
+        let read = crate::reader::read_docx(buf.get_ref()).expect("should read docx");
+
+        let bullets = &read.numberings.num_pic_bullets;
+        assert_eq!(bullets.len(), 1);
+        assert_eq!(bullets[0].pic_id, "numberingrIdBullet");
+        assert_eq!(bullets[0].image, bytes);
+        let level = &read
+            .numberings
+            .abstract_nums
+            .iter()
+            .find(|n| n.id == 2)
+            .unwrap()
+            .levels[0];
+        assert_eq!(level.pic_bullet_id, Some(0));
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,364 @@
+//! Renders list counters in the `w:numFmt` formats Word supports.
+//!
+//! Formats that have no textual rendering here fall back to decimal, which is
+//! also what Word shows when a consumer does not know a format.
+
+const ONES: [&str; 20] = [
+    "Zero",
+    "One",
+    "Two",
+    "Three",
+    "Four",
+    "Five",
+    "Six",
+    "Seven",
+    "Eight",
+    "Nine",
+    "Ten",
+    "Eleven",
+    "Twelve",
+    "Thirteen",
+    "Fourteen",
+    "Fifteen",
+    "Sixteen",
+    "Seventeen",
+    "Eighteen",
+    "Nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "Twenty", "Thirty", "Forty", "Fifty", "Sixty", "Seventy", "Eighty", "Ninety",
+];
+
+const AIUEO: &str =
+    "アイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヲン";
+const AIUEO_HALF: &str = "ｱｲｳｴｵｶｷｸｹｺｻｼｽｾｿﾀﾁﾂﾃﾄﾅﾆﾇﾈﾉﾊﾋﾌﾍﾎﾏﾐﾑﾒﾓﾔﾕﾖﾗﾘﾙﾚﾛﾜｦﾝ";
+const IROHA: &str = "イロハニホヘトチリヌルヲワカヨタレソツネナラムウヰノオクヤマケフコエテアサキユメミシヱヒモセスン";
+const IROHA_HALF: &str = "ｲﾛﾊﾆﾎﾍﾄﾁﾘﾇﾙｦﾜｶﾖﾀﾚｿﾂﾈﾅﾗﾑｳｲﾉｵｸﾔﾏｹﾌｺｴﾃｱｻｷﾕﾒﾐｼｴﾋﾓｾｽﾝ";
+const IDEOGRAPH_TRADITIONAL: &str = "甲乙丙丁戊己庚辛壬癸";
+const IDEOGRAPH_ZODIAC: &str = "子丑寅卯辰巳午未申酉戌亥";
+
+/// Digits and place units used by one CJK counting system.
+struct Counting {
+    digits: [char; 10],
+    // 十, 百, 千
+    units: [char; 3],
+    ten_thousand: char,
+    // Whether 一 is written before 十/百/千 (一百 vs 百).
+    explicit_one: bool,
+    // Whether skipped places are marked with the zero digit (一百零一).
+    zero_fill: bool,
+}
+
+const JAPANESE: Counting = Counting {
+    digits: ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'],
+    units: ['十', '百', '千'],
+    ten_thousand: '万',
+    explicit_one: false,
+    zero_fill: false,
+};
+
+const JAPANESE_LEGAL: Counting = Counting {
+    digits: ['〇', '壱', '弐', '参', '四', '伍', '六', '七', '八', '九'],
+    units: ['拾', '百', '阡'],
+    ten_thousand: '萬',
+    explicit_one: true,
+    zero_fill: false,
+};
+
+const CHINESE: Counting = Counting {
+    digits: ['零', '一', '二', '三', '四', '五', '六', '七', '八', '九'],
+    units: ['十', '百', '千'],
+    ten_thousand: '万',
+    explicit_one: true,
+    zero_fill: true,
+};
+
+const CHINESE_LEGAL: Counting = Counting {
+    digits: ['零', '壹', '贰', '叁', '肆', '伍', '陆', '柒', '捌', '玖'],
+    units: ['拾', '佰', '仟'],
+    ten_thousand: '万',
+    explicit_one: true,
+    zero_fill: true,
+};
+
+const TAIWANESE: Counting = Counting {
+    digits: ['零', '一', '二', '三', '四', '五', '六', '七', '八', '九'],
+    units: ['十', '百', '千'],
+    ten_thousand: '萬',
+    explicit_one: true,
+    zero_fill: true,
+};
+
+/// Formats `value` as Word renders it for the given `w:numFmt` value.
+///
+/// `bullet` and `none` have no counter text; callers use `lvlText` as is.
+pub(crate) fn format_number(value: usize, format: &str) -> String {
+    match format {
+        "decimal" => value.to_string(),
+        "decimalZero" => format!("{value:02}"),
+        "decimalFullWidth" | "decimalFullWidth2" => full_width(value),
+        "decimalHalfWidth" => value.to_string(),
+        "decimalEnclosedCircle" | "decimalEnclosedCircleChinese" => enclosed_circle(value),
+        "decimalEnclosedFullstop" => offset_char(value, 1..=20, 0x2488),
+        "decimalEnclosedParen" => offset_char(value, 1..=20, 0x2474),
+        "upperRoman" => roman(value),
+        "lowerRoman" => roman(value).to_lowercase(),
+        "upperLetter" => letter(value),
+        "lowerLetter" => letter(value).to_lowercase(),
+        "ordinal" => ordinal(value),
+        "cardinalText" => cardinal_text(value),
+        "ordinalText" => ordinal_text(value),
+        "japaneseCounting" => counting(value, &JAPANESE),
+        "japaneseLegal" => counting(value, &JAPANESE_LEGAL),
+        "chineseCounting" | "chineseCountingThousand" => counting(value, &CHINESE),
+        "chineseLegalSimplified" => counting(value, &CHINESE_LEGAL),
+        "taiwaneseCounting" | "taiwaneseCountingThousand" => counting(value, &TAIWANESE),
+        "ideographDigital" | "japaneseDigitalTenThousand" | "taiwaneseDigital" => {
+            digit_by_digit(value, &JAPANESE.digits)
+        }
+        "ideographTraditional" => cycle(value, IDEOGRAPH_TRADITIONAL),
+        "ideographZodiac" => cycle(value, IDEOGRAPH_ZODIAC),
+        "aiueoFullWidth" => cycle(value, AIUEO),
+        "aiueo" => cycle(value, AIUEO_HALF),
+        "irohaFullWidth" => cycle(value, IROHA),
+        "iroha" => cycle(value, IROHA_HALF),
+        "bullet" | "none" => String::new(),
+        _ => value.to_string(),
+    }
+}
+
+fn full_width(value: usize) -> String {
+    value
+        .to_string()
+        .chars()
+        .map(|c| char::from_u32(c as u32 - '0' as u32 + 0xFF10).unwrap_or(c))
+        .collect()
+}
+
+fn offset_char(value: usize, range: std::ops::RangeInclusive<usize>, first: u32) -> String {
+    if !range.contains(&value) {
+        return value.to_string();
+    }
+    let offset = (value - range.start()) as u32;
+    char::from_u32(first + offset)
+        .map(String::from)
+        .unwrap_or_else(|| value.to_string())
+}
+
+fn enclosed_circle(value: usize) -> String {
+    match value {
+        1..=20 => offset_char(value, 1..=20, 0x2460),
+        21..=35 => offset_char(value, 21..=35, 0x3251),
+        36..=50 => offset_char(value, 36..=50, 0x32B1),
+        _ => value.to_string(),
+    }
+}
+
+fn roman(value: usize) -> String {
+    if value == 0 {
+        return value.to_string();
+    }
+    const NUMERALS: [(usize, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut rest = value;
+    let mut s = String::new();
+    for (n, numeral) in NUMERALS {
+        while rest >= n {
+            s.push_str(numeral);
+            rest -= n;
+        }
+    }
+    s
+}
+
+// Word repeats the letter past Z: A..Z, AA, BB, ..., ZZ, AAA.
+fn letter(value: usize) -> String {
+    if value == 0 {
+        return value.to_string();
+    }
+    let c = (b'A' + ((value - 1) % 26) as u8) as char;
+    c.to_string().repeat((value - 1) / 26 + 1)
+}
+
+fn ordinal(value: usize) -> String {
+    let suffix = match (value % 10, value % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{value}{suffix}")
+}
+
+fn cardinal_text(value: usize) -> String {
+    if value < 20 {
+        return ONES[value].to_owned();
+    }
+    if value < 100 {
+        let tens = TENS[value / 10];
+        return match value % 10 {
+            0 => tens.to_owned(),
+            ones => format!("{tens}-{}", ONES[ones]),
+        };
+    }
+    for (scale, name) in [(1_000_000, "Million"), (1000, "Thousand"), (100, "Hundred")] {
+        if value >= scale {
+            let head = format!("{} {name}", cardinal_text(value / scale));
+            return match value % scale {
+                0 => head,
+                rest => format!("{head} {}", cardinal_text(rest)),
+            };
+        }
+    }
+    unreachable!("values below 100 are handled above")
+}
+
+fn ordinal_text(value: usize) -> String {
+    let cardinal = cardinal_text(value);
+    // Only the last word takes the ordinal form: "Twenty-First", "One Hundredth".
+    let split = cardinal
+        .rfind([' ', '-'])
+        .map(|i| i + 1)
+        .unwrap_or_default();
+    let (head, last) = cardinal.split_at(split);
+    let last = match last {
+        "Zero" => "Zeroth".to_owned(),
+        "One" => "First".to_owned(),
+        "Two" => "Second".to_owned(),
+        "Three" => "Third".to_owned(),
+        "Five" => "Fifth".to_owned(),
+        "Eight" => "Eighth".to_owned(),
+        "Nine" => "Ninth".to_owned(),
+        "Twelve" => "Twelfth".to_owned(),
+        w if w.ends_with('y') => format!("{}ieth", &w[..w.len() - 1]),
+        w => format!("{w}th"),
+    };
+    format!("{head}{last}")
+}
+
+fn counting(value: usize, system: &Counting) -> String {
+    if value == 0 {
+        return system.digits[0].to_string();
+    }
+    let high = value / 10_000;
+    let low = value % 10_000;
+    let mut s = String::new();
+    if high > 0 {
+        s.push_str(&counting(high, system));
+        s.push(system.ten_thousand);
+        if low == 0 {
+            return s;
+        }
+        if system.zero_fill && low < 1000 {
+            s.push(system.digits[0]);
+        }
+    }
+    s.push_str(&counting_below_ten_thousand(low, system, high > 0));
+    s
+}
+
+fn counting_below_ten_thousand(value: usize, system: &Counting, has_higher: bool) -> String {
+    let mut s = String::new();
+    let mut pending_zero = false;
+    for (place, unit) in [(1000, 2), (100, 1), (10, 0)] {
+        let d = value / place % 10;
+        if d == 0 {
+            pending_zero = !s.is_empty();
+            continue;
+        }
+        if pending_zero && system.zero_fill {
+            s.push(system.digits[0]);
+        }
+        pending_zero = false;
+        // 十一 rather than 一十一 for values below twenty.
+        let leading_ten = place == 10 && s.is_empty() && !has_higher;
+        if d > 1 || (system.explicit_one && !leading_ten) {
+            s.push(system.digits[d]);
+        }
+        s.push(system.units[unit]);
+    }
+    let ones = value % 10;
+    if ones > 0 {
+        if pending_zero && system.zero_fill {
+            s.push(system.digits[0]);
+        }
+        s.push(system.digits[ones]);
+    }
+    s
+}
+
+fn digit_by_digit(value: usize, digits: &[char; 10]) -> String {
+    value
+        .to_string()
+        .chars()
+        .map(|c| digits[c as usize - '0' as usize])
+        .collect()
+}
+
+fn cycle(value: usize, chars: &str) -> String {
+    let chars: Vec<char> = chars.chars().collect();
+    if value == 0 {
+        return value.to_string();
+    }
+    chars[(value - 1) % chars.len()].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_latin_formats() {
+        assert_eq!(format_number(7, "decimalZero"), "07");
+        assert_eq!(format_number(1994, "upperRoman"), "MCMXCIV");
+        assert_eq!(format_number(4, "lowerRoman"), "iv");
+        assert_eq!(format_number(28, "lowerLetter"), "bb");
+        assert_eq!(format_number(26, "upperLetter"), "Z");
+        assert_eq!(format_number(12, "ordinal"), "12th");
+        assert_eq!(format_number(22, "ordinal"), "22nd");
+        assert_eq!(format_number(3, "unknownFormat"), "3");
+    }
+
+    #[test]
+    fn test_text_formats() {
+        assert_eq!(format_number(21, "cardinalText"), "Twenty-One");
+        assert_eq!(format_number(115, "cardinalText"), "One Hundred Fifteen");
+        assert_eq!(format_number(2, "ordinalText"), "Second");
+        assert_eq!(format_number(40, "ordinalText"), "Fortieth");
+        assert_eq!(format_number(101, "ordinalText"), "One Hundred First");
+    }
+
+    #[test]
+    fn test_cjk_formats() {
+        assert_eq!(format_number(11, "japaneseCounting"), "十一");
+        assert_eq!(format_number(2024, "japaneseCounting"), "二千二十四");
+        assert_eq!(format_number(10000, "japaneseCounting"), "一万");
+        assert_eq!(format_number(12, "chineseCounting"), "十二");
+        assert_eq!(format_number(101, "chineseCounting"), "一百零一");
+        assert_eq!(format_number(110, "chineseCountingThousand"), "一百一十");
+        assert_eq!(format_number(10010, "chineseCounting"), "一万零一十");
+        assert_eq!(format_number(305, "ideographDigital"), "三〇五");
+        assert_eq!(format_number(3, "decimalEnclosedCircle"), "③");
+        assert_eq!(format_number(12, "decimalFullWidth"), "１２");
+        assert_eq!(format_number(2, "aiueoFullWidth"), "イ");
+        assert_eq!(format_number(11, "ideographTraditional"), "甲");
+    }
+}
@@ -9,6 +9,8 @@ use serde::Serialize;
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Numberings {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub num_pic_bullets: Vec<NumPicBullet>,
     pub abstract_nums: Vec<AbstractNumbering>,
     pub numberings: Vec<Numbering>,
 }
@@ -23,6 +25,11 @@ impl Numberings {
         self
     }
 
+    pub fn add_num_pic_bullet(mut self, n: NumPicBullet) -> Self {
+        self.num_pic_bullets.push(n);
+        self
+    }
+
     pub fn add_numbering(mut self, n: Numbering) -> Self {
         self.numberings.push(n);
         self
@@ -37,6 +44,7 @@ impl BuildXML for Numberings {
         XMLBuilder::from(stream)
             .declaration(Some(true))?
             .open_numbering()?
+            .add_children(&self.num_pic_bullets)?
             .add_child(&create_default_numbering())?
             .add_children(&self.abstract_nums)?
             .add_child(&Numbering::new(1, 1))?
@@ -46,7 +54,7 @@ impl BuildXML for Numberings {
     }
 }
 
-fn create_default_numbering() -> AbstractNumbering {
+pub(crate) fn create_default_numbering() -> AbstractNumbering {
     AbstractNumbering::new(1)
         .add_level(
             Level::new(
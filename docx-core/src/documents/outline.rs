@@ -0,0 +1,440 @@
+//! The heading outline of a document, as a navigation pane shows it.
+
+use std::ops::Range;
+
+use super::find::BlockNode;
+use super::paragraph_text::*;
+use crate::documents::*;
+
+/// The headings and sections of the body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentOutline {
+    /// The top-level headings, each with the headings under it.
+    pub headings: Vec<OutlineHeading>,
+    pub sections: Vec<OutlineSection>,
+    /// The blocks at the top of the body, and of its sections, in order.
+    /// Bookmark and comment markers between blocks are left out. Ranges in
+    /// the outline are indices into this list.
+    pub blocks: Vec<BlockLocation>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineHeading {
+    /// The outline level, 0 for the top level.
+    pub level: usize,
+    pub text: String,
+    /// The names of the bookmarks that start in the heading.
+    pub bookmarks: Vec<String>,
+    pub location: BlockLocation,
+    /// The blocks after the heading up to the next heading at the same or a
+    /// higher level, subheadings included.
+    pub content: Range<usize>,
+    pub children: Vec<OutlineHeading>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineSection {
+    /// The blocks of the section.
+    pub blocks: Range<usize>,
+}
+
+impl Docx {
+    /// Returns the headings of the body as a tree, with the sections.
+    ///
+    /// The level of a paragraph is its outline level, set directly or by its
+    /// style, or else the number of its heading style. Paragraphs at outline
+    /// level 9 are body text.
+    pub fn outline(&self) -> DocumentOutline {
+        let mut collector = OutlineCollector {
+            docx: self,
+            headings: self.styles.create_heading_style_map(),
+            path: vec![],
+            blocks: vec![],
+            flat: vec![],
+            sections: vec![],
+            section_start: 0,
+        };
+        for (i, child) in self.document.children.iter().enumerate() {
+            collector.path.push(i);
+            match child {
+                DocumentChild::Section(section) => {
+                    for (j, child) in section.children.iter().enumerate() {
+                        collector.path.push(j);
+                        collector.block(BlockNode::from(child), !is_marker(child));
+                        collector.path.pop();
+                    }
+                    collector.end_section();
+                }
+                child => {
+                    collector.block(
+                        BlockNode::from(child),
+                        !matches!(
+                            child,
+                            DocumentChild::BookmarkStart(_)
+                                | DocumentChild::BookmarkEnd(_)
+                                | DocumentChild::CommentStart(_)
+                                | DocumentChild::CommentEnd(_)
+                        ),
+                    );
+                    if matches!(child, DocumentChild::Paragraph(p) if p.property.section_property.is_some())
+                    {
+                        collector.end_section();
+                    }
+                }
+            }
+            collector.path.pop();
+        }
+        if collector.section_start < collector.blocks.len() || collector.sections.is_empty() {
+            collector.end_section();
+        }
+
+        let OutlineCollector {
+            mut flat,
+            blocks,
+            sections,
+            ..
+        } = collector;
+        for i in 0..flat.len() {
+            let level = flat[i].0.level;
+            let end = flat[i + 1..]
+                .iter()
+                .find(|(h, _)| h.level <= level)
+                .map_or(blocks.len(), |(_, block)| *block);
+            let start = flat[i].1 + 1;
+            flat[i].0.content = start..end.max(start);
+        }
+        let mut flat = flat.into_iter().map(|(h, _)| h).peekable();
+        DocumentOutline {
+            headings: nest(&mut flat, None),
+            sections,
+            blocks,
+        }
+    }
+}
+
+fn is_marker(child: &SectionChild) -> bool {
+    matches!(
+        child,
+        SectionChild::BookmarkStart(_)
+            | SectionChild::BookmarkEnd(_)
+            | SectionChild::CommentStart(_)
+            | SectionChild::CommentEnd(_)
+    )
+}
+
+/// Puts the headings following one into its children, as long as they are
+/// at a lower level.
+fn nest(
+    flat: &mut std::iter::Peekable<impl Iterator<Item = OutlineHeading>>,
+    level: Option<usize>,
+) -> Vec<OutlineHeading> {
+    let mut headings = vec![];
+    while let Some(mut heading) = flat.next_if(|h| level.is_none_or(|l| h.level > l)) {
+        heading.children = nest(flat, Some(heading.level));
+        headings.push(heading);
+    }
+    headings
+}
+
+struct OutlineCollector<'a> {
+    docx: &'a Docx,
+    headings: std::collections::HashMap<String, usize>,
+    path: Vec<usize>,
+    blocks: Vec<BlockLocation>,
+    /// Headings in document order with the block they are in.
+    flat: Vec<(OutlineHeading, usize)>,
+    sections: Vec<OutlineSection>,
+    section_start: usize,
+}
+
+impl<'a> OutlineCollector<'a> {
+    fn block(&mut self, node: BlockNode<'a>, is_block: bool) {
+        if !is_block {
+            return;
+        }
+        self.blocks
+            .push(BlockLocation::new(DocumentPart::Body, self.path.clone()));
+        self.find_headings(node);
+    }
+
+    fn end_section(&mut self) {
+        self.sections.push(OutlineSection {
+            blocks: self.section_start..self.blocks.len(),
+        });
+        self.section_start = self.blocks.len();
+    }
+
+    fn find_headings(&mut self, node: BlockNode<'a>) {
+        match node {
+            BlockNode::Paragraph(paragraph) => self.paragraph(paragraph),
+            BlockNode::Table(table) => {
+                for (r, TableChild::TableRow(row)) in table.rows.iter().enumerate() {
+                    for (c, TableRowChild::TableCell(cell)) in row.cells.iter().enumerate() {
+                        self.path.extend([r, c]);
+                        self.nested(cell.children.iter().map(BlockNode::from));
+                        self.path.truncate(self.path.len() - 2);
+                    }
+                }
+            }
+            BlockNode::StructuredDataTag(tag) => {
+                self.nested(tag.children.iter().map(BlockNode::from))
+            }
+            BlockNode::TableOfContents(_)
+            | BlockNode::Section(_)
+            | BlockNode::CommentStart(_)
+            | BlockNode::CommentEnd(_)
+            | BlockNode::Other => {}
+        }
+    }
+
+    fn nested(&mut self, nodes: impl Iterator<Item = BlockNode<'a>>) {
+        for (i, node) in nodes.enumerate() {
+            self.path.push(i);
+            self.find_headings(node);
+            self.path.pop();
+        }
+    }
+
+    fn paragraph(&mut self, paragraph: &Paragraph) {
+        let level = self
+            .docx
+            .resolve_paragraph_properties(paragraph)
+            .outline_lvl
+            .map(|l| l.v)
+            .or_else(|| {
+                let style = paragraph.property.style.as_ref()?;
+                self.headings.get(&style.val).map(|n| n.saturating_sub(1))
+            });
+        let Some(level) = level.filter(|l| *l < 9) else {
+            return;
+        };
+        let mut runs = vec![];
+        collect_runs(&paragraph.children, &mut runs);
+        let text = text_segments(&runs).0;
+        let bookmarks = paragraph
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                ParagraphChild::BookmarkStart(b) => Some(b.name.clone()),
+                _ => None,
+            })
+            .collect();
+        let heading = OutlineHeading {
+            level,
+            text,
+            bookmarks,
+            location: BlockLocation::new(DocumentPart::Body, self.path.clone()),
+            content: 0..0,
+            children: vec![],
+        };
+        self.flat.push((heading, self.blocks.len() - 1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn heading(text: &str, style: &str) -> Paragraph {
+        Paragraph::new()
+            .style(style)
+            .add_run(Run::new().add_text(text))
+    }
+
+    #[test]
+    fn test_outline() {
+        let docx = Docx::new()
+            .add_style(Style::new("Heading1", StyleType::Paragraph).name("Heading 1"))
+            .add_style(Style::new("Heading2", StyleType::Paragraph).name("heading 2"))
+            .add_paragraph(
+                heading("Intro", "Heading1")
+                    .add_bookmark_start(1, "intro")
+                    .add_bookmark_end(1),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Body")))
+            .add_paragraph(heading("Details", "Heading2"))
+            .add_paragraph(
+                Paragraph::new()
+                    .section_property(SectionProperty::new())
+                    .add_run(Run::new().add_text("End of section")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .outline_lvl(0)
+                    .add_run(Run::new().add_text("Summary & more")),
+            );
+        let outline = docx.outline();
+        assert_eq!(outline.blocks.len(), 5);
+        assert_eq!(
+            outline.sections,
+            vec![
+                OutlineSection { blocks: 0..4 },
+                OutlineSection { blocks: 4..5 },
+            ]
+        );
+        assert_eq!(outline.headings.len(), 2);
+        let intro = &outline.headings[0];
+        assert_eq!(intro.level, 0);
+        assert_eq!(intro.text, "Intro");
+        assert_eq!(intro.bookmarks, vec!["intro".to_owned()]);
+        assert_eq!(intro.content, 1..4);
+        assert_eq!(intro.children.len(), 1);
+        assert_eq!(intro.children[0].text, "Details");
+        assert_eq!(intro.children[0].level, 1);
+        assert_eq!(intro.children[0].location, BlockLocation::body(2));
+        assert_eq!(intro.children[0].content, 3..4);
+        assert_eq!(outline.headings[1].text, "Summary & more");
+        assert_eq!(outline.headings[1].content, 5..5);
+    }
+
+    #[test]
+    fn test_outline_level_set_directly() {
+        let docx = Docx::new()
+            .add_style(Style::new("Heading1", StyleType::Paragraph).name("Heading 1"))
+            .add_paragraph(
+                Paragraph::new()
+                    .outline_lvl(2)
+                    .add_run(Run::new().add_text("Direct")),
+            )
+            .add_paragraph(heading("Demoted", "Heading1").outline_lvl(3))
+            .add_paragraph(heading("Body", "Heading1").outline_lvl(9));
+        let outline = docx.outline();
+        assert_eq!(outline.headings.len(), 1);
+        let direct = &outline.headings[0];
+        assert_eq!((direct.level, direct.text.as_str()), (2, "Direct"));
+        assert_eq!(direct.content, 1..3);
+        assert_eq!(direct.children.len(), 1);
+        assert_eq!(
+            (direct.children[0].level, direct.children[0].text.as_str()),
+            (3, "Demoted")
+        );
+    }
+
+    #[test]
+    fn test_outline_level_from_style() {
+        let docx = Docx::new()
+            .add_style(
+                Style::new("Chapter", StyleType::Paragraph)
+                    .name("Chapter")
+                    .outline_lvl(0),
+            )
+            .add_style(
+                Style::new("Part", StyleType::Paragraph)
+                    .name("Part")
+                    .based_on("Chapter"),
+            )
+            .add_style(
+                Style::new("Heading2", StyleType::Paragraph)
+                    .name("heading 2")
+                    .outline_lvl(4),
+            )
+            .add_paragraph(heading("Chapter", "Chapter"))
+            .add_paragraph(heading("Part", "Part"))
+            .add_paragraph(heading("Styled", "Heading2"));
+        let outline = docx.outline();
+        let levels: Vec<_> = outline.headings.iter().map(|h| h.level).collect();
+        assert_eq!(levels, vec![0, 0]);
+        assert_eq!(outline.headings[1].text, "Part");
+        // The style's outline level wins over the heading number.
+        assert_eq!(outline.headings[1].children[0].level, 4);
+    }
+
+    #[test]
+    fn test_outline_level_from_heading_styles() {
+        let docx = Docx::new()
+            .add_style(Style::new("Title1", StyleType::Paragraph).name("Heading 1"))
+            .add_style(Style::new("Title3", StyleType::Paragraph).name("heading 3"))
+            .add_style(Style::new("Quote", StyleType::Paragraph).name("Quote"))
+            .add_paragraph(heading("One", "Title1"))
+            .add_paragraph(heading("Three", "Title3"))
+            .add_paragraph(heading("Quoted", "Quote"));
+        let outline = docx.outline();
+        assert_eq!(outline.headings.len(), 1);
+        assert_eq!(outline.headings[0].level, 0);
+        assert_eq!(outline.headings[0].content, 1..3);
+        let three = &outline.headings[0].children[0];
+        assert_eq!((three.level, three.text.as_str()), (2, "Three"));
+        assert_eq!(three.content, 2..3);
+    }
+
+    #[test]
+    fn test_outline_bookmarks() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .outline_lvl(0)
+                    .add_bookmark_start(1, "_Toc1")
+                    .add_run(Run::new().add_text("Named"))
+                    .add_bookmark_start(2, "named")
+                    .add_bookmark_end(2)
+                    .add_bookmark_end(1),
+            )
+            .add_bookmark_start(3, "between")
+            .add_paragraph(
+                Paragraph::new()
+                    .outline_lvl(0)
+                    .add_run(Run::new().add_text("Plain")),
+            )
+            .add_bookmark_end(3);
+        let outline = docx.outline();
+        assert_eq!(
+            outline.headings[0].bookmarks,
+            vec!["_Toc1".to_owned(), "named".to_owned()]
+        );
+        // Bookmarks between blocks belong to no heading.
+        assert!(outline.headings[1].bookmarks.is_empty());
+        assert_eq!(outline.headings[1].location, BlockLocation::body(2));
+        assert_eq!(outline.blocks.len(), 2);
+    }
+
+    #[test]
+    fn test_outline_sections() {
+        let docx = Docx::new()
+            .add_paragraph(heading("First", "Heading1").outline_lvl(0))
+            .add_section(
+                Section::new()
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Body")))
+                    .add_bookmark_start(1, "inside")
+                    .add_paragraph(heading("Second", "Heading1").outline_lvl(0))
+                    .add_bookmark_end(1),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()
+                .add_paragraph(
+                    Paragraph::new()
+                        .outline_lvl(1)
+                        .add_run(Run::new().add_text("In a cell")),
+                )])]));
+        let outline = docx.outline();
+        assert_eq!(
+            outline.blocks,
+            vec![
+                BlockLocation::body(0),
+                BlockLocation::new(DocumentPart::Body, vec![1, 0]),
+                BlockLocation::new(DocumentPart::Body, vec![1, 2]),
+                BlockLocation::body(2),
+            ]
+        );
+        assert_eq!(
+            outline.sections,
+            vec![
+                OutlineSection { blocks: 0..3 },
+                OutlineSection { blocks: 3..4 },
+            ]
+        );
+        assert_eq!(outline.headings[0].content, 1..2);
+        let second = &outline.headings[1];
+        assert_eq!(
+            second.location,
+            BlockLocation::new(DocumentPart::Body, vec![1, 2])
+        );
+        assert_eq!(second.content, 3..4);
+        assert_eq!(
+            second.children[0].location,
+            BlockLocation::new(DocumentPart::Body, vec![2, 0, 0, 0])
+        );
+    }
+}
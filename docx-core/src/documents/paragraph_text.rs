@@ -0,0 +1,190 @@
+//! The text of a paragraph as a reader sees it.
+//!
+//! Word splits the text of a paragraph into runs, which may sit inside
+//! hyperlinks, content controls and insertions. Searching text has to look
+//! through all of them; deleted and moved-away text is not part of it.
+
+use crate::documents::*;
+use crate::escape::unescape_text;
+
+/// Collects the runs whose text is part of the paragraph text, in order.
+pub(crate) fn collect_runs<'a>(children: &'a [ParagraphChild], runs: &mut Vec<&'a Run>) {
+    for child in children {
+        match child {
+            ParagraphChild::Run(run) => runs.push(run),
+            ParagraphChild::Hyperlink(link) => collect_runs(&link.children, runs),
+            ParagraphChild::StructuredDataTag(tag) => collect_tag_runs(&tag.children, runs),
+            ParagraphChild::Insert(insert) => {
+                for child in &insert.children {
+                    if let InsertChild::Run(run) = child {
+                        runs.push(run);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn collect_tag_runs<'a>(children: &'a [StructuredDataTagChild], runs: &mut Vec<&'a Run>) {
+    for child in children {
+        match child {
+            StructuredDataTagChild::Run(run) => runs.push(run),
+            StructuredDataTagChild::StructuredDataTag(tag) => collect_tag_runs(&tag.children, runs),
+            _ => {}
+        }
+    }
+}
+
+/// Same as [`collect_runs`], for changing the runs in place.
+pub(crate) fn collect_runs_mut<'a>(
+    children: &'a mut [ParagraphChild],
+    runs: &mut Vec<&'a mut Run>,
+) {
+    for child in children {
+        match child {
+            ParagraphChild::Run(run) => runs.push(run),
+            ParagraphChild::Hyperlink(link) => collect_runs_mut(&mut link.children, runs),
+            ParagraphChild::StructuredDataTag(tag) => collect_tag_runs_mut(&mut tag.children, runs),
+            ParagraphChild::Insert(insert) => {
+                for child in &mut insert.children {
+                    if let InsertChild::Run(run) = child {
+                        runs.push(run);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn collect_tag_runs_mut<'a>(
+    children: &'a mut [StructuredDataTagChild],
+    runs: &mut Vec<&'a mut Run>,
+) {
+    for child in children {
+        match child {
+            StructuredDataTagChild::Run(run) => runs.push(run),
+            StructuredDataTagChild::StructuredDataTag(tag) => {
+                collect_tag_runs_mut(&mut tag.children, runs)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Replaces every run collected by [`collect_runs`] with the content
+/// `expand` returns for it.
+///
+/// Content controls and insertions only hold runs, so other content put
+/// there is reduced to the runs in it.
+pub(crate) fn expand_runs(
+    children: &mut Vec<ParagraphChild>,
+    expand: &mut impl FnMut(Run) -> Vec<ParagraphChild>,
+) {
+    for child in std::mem::take(children) {
+        match child {
+            ParagraphChild::Run(run) => children.extend(expand(*run)),
+            ParagraphChild::Hyperlink(mut link) => {
+                expand_runs(&mut link.children, expand);
+                children.push(ParagraphChild::Hyperlink(link));
+            }
+            ParagraphChild::StructuredDataTag(mut tag) => {
+                expand_tag_runs(&mut tag.children, expand);
+                children.push(ParagraphChild::StructuredDataTag(tag));
+            }
+            ParagraphChild::Insert(mut insert) => {
+                for child in std::mem::take(&mut insert.children) {
+                    let InsertChild::Run(run) = child else {
+                        insert.children.push(child);
+                        continue;
+                    };
+                    for child in expand(*run) {
+                        match child {
+                            ParagraphChild::Delete(delete) => {
+                                insert.children.push(InsertChild::Delete(delete))
+                            }
+                            child => insert
+                                .children
+                                .extend(into_runs(child).map(|r| InsertChild::Run(Box::new(r)))),
+                        }
+                    }
+                }
+                children.push(ParagraphChild::Insert(insert));
+            }
+            child => children.push(child),
+        }
+    }
+}
+
+fn expand_tag_runs(
+    children: &mut Vec<StructuredDataTagChild>,
+    expand: &mut impl FnMut(Run) -> Vec<ParagraphChild>,
+) {
+    for child in std::mem::take(children) {
+        match child {
+            StructuredDataTagChild::Run(run) => children.extend(
+                expand(*run)
+                    .into_iter()
+                    .flat_map(into_runs)
+                    .map(|r| StructuredDataTagChild::Run(Box::new(r))),
+            ),
+            StructuredDataTagChild::StructuredDataTag(mut tag) => {
+                expand_tag_runs(&mut tag.children, expand);
+                children.push(StructuredDataTagChild::StructuredDataTag(tag));
+            }
+            child => children.push(child),
+        }
+    }
+}
+
+/// The runs with text in paragraph content. Deletions have none.
+fn into_runs(child: ParagraphChild) -> Box<dyn Iterator<Item = Run>> {
+    match child {
+        ParagraphChild::Run(run) => Box::new(std::iter::once(*run)),
+        ParagraphChild::Hyperlink(link) => Box::new(link.children.into_iter().flat_map(into_runs)),
+        ParagraphChild::Insert(insert) => {
+            Box::new(insert.children.into_iter().filter_map(|c| match c {
+                InsertChild::Run(run) => Some(*run),
+                _ => None,
+            }))
+        }
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+/// A text node of the paragraph: the run, the child in the run and where its
+/// text starts in the paragraph text.
+///
+/// Text nodes are stored escaped while the paragraph text is not; use
+/// [`crate::escape::escaped_offset`] to find an offset of the paragraph text in the node.
+pub(crate) struct Segment {
+    pub run: usize,
+    pub child: usize,
+    pub start: usize,
+    pub len: usize,
+}
+
+/// Returns the unescaped paragraph text of the runs and where each part of it
+/// is.
+pub(crate) fn text_segments<R: std::ops::Deref<Target = Run>>(
+    runs: &[R],
+) -> (String, Vec<Segment>) {
+    let mut text = String::new();
+    let mut segments = vec![];
+    for (run, r) in runs.iter().enumerate() {
+        for (child, c) in r.children.iter().enumerate() {
+            if let RunChild::Text(t) = c {
+                let unescaped = unescape_text(&t.text);
+                segments.push(Segment {
+                    run,
+                    child,
+                    start: text.len(),
+                    len: unescaped.len(),
+                });
+                text.push_str(&unescaped);
+            }
+        }
+    }
+    (text, segments)
+}
@@ -0,0 +1,79 @@
+use serde::Serialize;
+use std::io::Write;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/// The authors of comments and tracked changes, with the accounts Office
+/// knows them by.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct People {
+    pub people: Vec<Person>,
+}
+
+impl People {
+    pub fn new() -> People {
+        Default::default()
+    }
+
+    pub fn add_person(mut self, p: Person) -> People {
+        self.people.push(p);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.people.is_empty()
+    }
+
+    /// Adds the comment authors not listed yet. Comments without an author
+    /// are skipped.
+    pub(crate) fn update(&mut self, comments: &[Comment]) {
+        for comment in comments {
+            if comment.author.is_empty() {
+                continue;
+            }
+            if !self.people.iter().any(|p| p.author == comment.author) {
+                self.people.push(Person::new(&comment.author));
+            }
+        }
+    }
+}
+
+impl BuildXML for People {
+    fn build_to<W: Write>(
+        &self,
+        stream: crate::xml::writer::EventWriter<W>,
+    ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .declaration(Some(true))?
+            .open_people()?
+            .add_children(&self.people)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_update_people() {
+        let mut people = People::new().add_person(Person::new("Alice"));
+        people.update(&[
+            Comment::new(1).author("Alice"),
+            Comment::new(2).author(""),
+            Comment::new(3).author("Bob"),
+            Comment::new(4).author("Bob"),
+        ]);
+        assert_eq!(
+            people.people,
+            vec![Person::new("Alice"), Person::new("Bob")]
+        );
+    }
+}
@@ -0,0 +1,974 @@
+//! Fills `{{name}}` placeholders in templates authored in Word.
+//!
+//! Word freely splits typed text into several runs, e.g. after a spell check
+//! or an edit, so a placeholder is matched against the text of the whole
+//! paragraph, including the runs inside hyperlinks, content controls and
+//! insertions. Replacement text goes into the run the placeholder starts in
+//! and so keeps its formatting.
+
+use std::collections::HashMap;
+
+use super::cursor::split_children;
+use super::document_tree::{
+    visit_document_part, visit_footer_part, visit_header_part, DocumentTreeVisitorMut,
+};
+use super::paragraph_text::*;
+use crate::documents::*;
+use crate::escape::escaped_offset;
+use crate::types::*;
+use crate::EditError;
+
+/// What a placeholder is replaced with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlaceholderValue {
+    /// Text in the formatting of the placeholder. Line breaks become `w:br`.
+    Text(String),
+    /// Runs in place of the placeholder. Runs without formatting of their
+    /// own take the formatting of the placeholder.
+    Runs(Vec<Run>),
+    /// Paragraphs in place of the placeholder. The text before and after it
+    /// stays in paragraphs of its own. Paragraphs and runs without
+    /// formatting of their own take the formatting of the placeholder.
+    Paragraphs(Vec<Paragraph>),
+    /// A table in place of the placeholder, splitting its paragraph like
+    /// [`PlaceholderValue::Paragraphs`].
+    Table(Box<Table>),
+    /// An inline image in the run of the placeholder.
+    Image(Box<Pic>),
+}
+
+impl From<&str> for PlaceholderValue {
+    fn from(text: &str) -> Self {
+        PlaceholderValue::Text(text.to_owned())
+    }
+}
+
+impl From<String> for PlaceholderValue {
+    fn from(text: String) -> Self {
+        PlaceholderValue::Text(text)
+    }
+}
+
+impl From<Run> for PlaceholderValue {
+    fn from(run: Run) -> Self {
+        PlaceholderValue::Runs(vec![run])
+    }
+}
+
+impl From<Vec<Run>> for PlaceholderValue {
+    fn from(runs: Vec<Run>) -> Self {
+        PlaceholderValue::Runs(runs)
+    }
+}
+
+impl From<Paragraph> for PlaceholderValue {
+    fn from(paragraph: Paragraph) -> Self {
+        PlaceholderValue::Paragraphs(vec![paragraph])
+    }
+}
+
+impl From<Vec<Paragraph>> for PlaceholderValue {
+    fn from(paragraphs: Vec<Paragraph>) -> Self {
+        PlaceholderValue::Paragraphs(paragraphs)
+    }
+}
+
+impl From<Table> for PlaceholderValue {
+    fn from(table: Table) -> Self {
+        PlaceholderValue::Table(Box::new(table))
+    }
+}
+
+impl From<Pic> for PlaceholderValue {
+    fn from(pic: Pic) -> Self {
+        PlaceholderValue::Image(Box::new(pic))
+    }
+}
+
+/// Placeholder names and their values for [`Docx::replace_placeholders`].
+///
+/// Placeholders are written as `{{name}}` unless other delimiters are set.
+/// Whitespace around the name is ignored and placeholders without a value
+/// are left as they are.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Placeholders {
+    open: String,
+    close: String,
+    values: HashMap<String, PlaceholderValue>,
+}
+
+impl Default for Placeholders {
+    fn default() -> Self {
+        Self {
+            open: "{{".to_owned(),
+            close: "}}".to_owned(),
+            values: HashMap::new(),
+        }
+    }
+}
+
+impl Placeholders {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn delimiters(mut self, open: impl Into<String>, close: impl Into<String>) -> Self {
+        self.open = open.into();
+        self.close = close.into();
+        self
+    }
+
+    pub fn set(mut self, name: impl Into<String>, value: impl Into<PlaceholderValue>) -> Self {
+        self.values.insert(name.into(), value.into());
+        self
+    }
+
+    /// Finds the first placeholder with a value at or after `from`, returning
+    /// its byte range.
+    fn find(&self, text: &str, mut from: usize) -> Option<(usize, usize, &PlaceholderValue)> {
+        if self.open.is_empty() || self.close.is_empty() {
+            return None;
+        }
+        while let Some(start) = text[from..].find(&self.open).map(|i| i + from) {
+            let name_start = start + self.open.len();
+            let name_end = text[name_start..].find(&self.close)? + name_start;
+            if let Some(value) = self.values.get(text[name_start..name_end].trim()) {
+                return Some((start, name_end + self.close.len(), value));
+            }
+            from = name_start;
+        }
+        None
+    }
+}
+
+impl Docx {
+    /// Replaces placeholders in the body, headers, footers, footnotes and
+    /// comments.
+    ///
+    /// Fails with [`EditError::OutOfRange`] when a placeholder cannot be cut
+    /// out of the runs holding it.
+    pub fn replace_placeholders(mut self, placeholders: &Placeholders) -> Result<Self, EditError> {
+        let mut replacer = Replacer {
+            placeholders,
+            has_numbering: false,
+            error: None,
+        };
+        replacer.replace_in_blocks(&mut self.document.children)?;
+        for (_, header) in self.document.headers_mut() {
+            replacer.replace_in_blocks(&mut header.children)?;
+        }
+        for (_, footer) in self.document.footers_mut() {
+            replacer.replace_in_blocks(&mut footer.children)?;
+        }
+
+        // Footnotes and comments live in the runs that refer to them.
+        visit_document_part(&mut self.document, &mut replacer);
+        for (_, header) in self.document.headers_mut() {
+            visit_header_part(header, &mut replacer);
+        }
+        for (_, footer) in self.document.footers_mut() {
+            visit_footer_part(footer, &mut replacer);
+        }
+        for comment in &mut self.comments.comments {
+            replacer.replace_in_blocks(&mut comment.children)?;
+        }
+        for footnote in &mut self.footnotes.footnotes {
+            replacer.replace_in_blocks(&mut footnote.content)?;
+        }
+        if let Some(error) = replacer.error {
+            return Err(error);
+        }
+
+        if replacer.has_numbering {
+            self.document_rels.has_numberings = true;
+        }
+        Ok(self)
+    }
+}
+
+/// Block-level content a placeholder paragraph is replaced with.
+enum Block {
+    Paragraph(Box<Paragraph>),
+    Table(Box<Table>),
+}
+
+/// A child of a container holding paragraphs, such as the body or a cell.
+trait BlockChild: Sized {
+    /// Replaces the placeholders in this child. Returns the blocks a
+    /// paragraph has to be replaced with.
+    fn replace(&mut self, replacer: &mut Replacer) -> Result<Option<Vec<Block>>, EditError>;
+
+    /// Wraps a block, or returns `None` when the container cannot hold it.
+    fn from_block(block: Block) -> Option<Self>;
+}
+
+impl BlockChild for DocumentChild {
+    fn replace(&mut self, replacer: &mut Replacer) -> Result<Option<Vec<Block>>, EditError> {
+        match self {
+            DocumentChild::Paragraph(p) => return replacer.replace_in_paragraph(p),
+            DocumentChild::Table(t) => replacer.replace_in_table(t)?,
+            DocumentChild::StructuredDataTag(t) => replacer.replace_in_blocks(&mut t.children)?,
+            DocumentChild::Section(s) => replacer.replace_in_blocks(&mut s.children)?,
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn from_block(block: Block) -> Option<Self> {
+        Some(match block {
+            Block::Paragraph(p) => DocumentChild::Paragraph(p),
+            Block::Table(t) => DocumentChild::Table(t),
+        })
+    }
+}
+
+impl BlockChild for SectionChild {
+    fn replace(&mut self, replacer: &mut Replacer) -> Result<Option<Vec<Block>>, EditError> {
+        match self {
+            SectionChild::Paragraph(p) => return replacer.replace_in_paragraph(p),
+            SectionChild::Table(t) => replacer.replace_in_table(t)?,
+            SectionChild::StructuredDataTag(t) => replacer.replace_in_blocks(&mut t.children)?,
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn from_block(block: Block) -> Option<Self> {
+        Some(match block {
+            Block::Paragraph(p) => SectionChild::Paragraph(p),
+            Block::Table(t) => SectionChild::Table(t),
+        })
+    }
+}
+
+impl BlockChild for TableCellContent {
+    fn replace(&mut self, replacer: &mut Replacer) -> Result<Option<Vec<Block>>, EditError> {
+        match self {
+            TableCellContent::Paragraph(p) => return replacer.replace_in_paragraph(p),
+            TableCellContent::Table(t) => replacer.replace_in_table(t)?,
+            TableCellContent::StructuredDataTag(t) => {
+                replacer.replace_in_blocks(&mut t.children)?
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn from_block(block: Block) -> Option<Self> {
+        Some(match block {
+            Block::Paragraph(p) => TableCellContent::Paragraph(p),
+            Block::Table(t) => TableCellContent::Table(t),
+        })
+    }
+}
+
+impl BlockChild for HeaderChild {
+    fn replace(&mut self, replacer: &mut Replacer) -> Result<Option<Vec<Block>>, EditError> {
+        match self {
+            HeaderChild::Paragraph(p) => return replacer.replace_in_paragraph(p),
+            HeaderChild::Table(t) => replacer.replace_in_table(t)?,
+            HeaderChild::StructuredDataTag(t) => replacer.replace_in_blocks(&mut t.children)?,
+        }
+        Ok(None)
+    }
+
+    fn from_block(block: Block) -> Option<Self> {
+        Some(match block {
+            Block::Paragraph(p) => HeaderChild::Paragraph(p),
+            Block::Table(t) => HeaderChild::Table(t),
+        })
+    }
+}
+
+impl BlockChild for FooterChild {
+    fn replace(&mut self, replacer: &mut Replacer) -> Result<Option<Vec<Block>>, EditError> {
+        match self {
+            FooterChild::Paragraph(p) => return replacer.replace_in_paragraph(p),
+            FooterChild::Table(t) => replacer.replace_in_table(t)?,
+            FooterChild::StructuredDataTag(t) => replacer.replace_in_blocks(&mut t.children)?,
+        }
+        Ok(None)
+    }
+
+    fn from_block(block: Block) -> Option<Self> {
+        Some(match block {
+            Block::Paragraph(p) => FooterChild::Paragraph(p),
+            Block::Table(t) => FooterChild::Table(t),
+        })
+    }
+}
+
+impl BlockChild for StructuredDataTagChild {
+    fn replace(&mut self, replacer: &mut Replacer) -> Result<Option<Vec<Block>>, EditError> {
+        match self {
+            StructuredDataTagChild::Paragraph(p) => return replacer.replace_in_paragraph(p),
+            StructuredDataTagChild::Table(t) => replacer.replace_in_table(t)?,
+            StructuredDataTagChild::StructuredDataTag(t) => {
+                replacer.replace_in_blocks(&mut t.children)?
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn from_block(block: Block) -> Option<Self> {
+        Some(match block {
+            Block::Paragraph(p) => StructuredDataTagChild::Paragraph(p),
+            Block::Table(t) => StructuredDataTagChild::Table(t),
+        })
+    }
+}
+
+impl BlockChild for CommentChild {
+    fn replace(&mut self, replacer: &mut Replacer) -> Result<Option<Vec<Block>>, EditError> {
+        match self {
+            CommentChild::Paragraph(p) => return replacer.replace_in_paragraph(p),
+            CommentChild::Table(t) => replacer.replace_in_table(t)?,
+        }
+        Ok(None)
+    }
+
+    fn from_block(block: Block) -> Option<Self> {
+        Some(match block {
+            Block::Paragraph(p) => CommentChild::Paragraph(p),
+            Block::Table(t) => CommentChild::Table(t),
+        })
+    }
+}
+
+/// Footnotes hold paragraphs only, so tables are dropped there.
+impl BlockChild for Paragraph {
+    fn replace(&mut self, replacer: &mut Replacer) -> Result<Option<Vec<Block>>, EditError> {
+        replacer.replace_in_paragraph(self)
+    }
+
+    fn from_block(block: Block) -> Option<Self> {
+        match block {
+            Block::Paragraph(p) => Some(*p),
+            Block::Table(_) => None,
+        }
+    }
+}
+
+struct Replacer<'a> {
+    placeholders: &'a Placeholders,
+    has_numbering: bool,
+    /// The first failure in the footnotes and comments the tree carries.
+    error: Option<EditError>,
+}
+
+impl Replacer<'_> {
+    fn replace_in_blocks<C: BlockChild>(&mut self, children: &mut Vec<C>) -> Result<(), EditError> {
+        let mut i = 0;
+        while i < children.len() {
+            match children[i].replace(self)? {
+                Some(blocks) => {
+                    let blocks: Vec<C> = blocks.into_iter().filter_map(C::from_block).collect();
+                    let len = blocks.len();
+                    children.splice(i..=i, blocks);
+                    i += len;
+                }
+                None => i += 1,
+            }
+        }
+        Ok(())
+    }
+
+    fn replace_in_table(&mut self, table: &mut Table) -> Result<(), EditError> {
+        for TableChild::TableRow(row) in &mut table.rows {
+            for TableRowChild::TableCell(cell) in &mut row.cells {
+                self.replace_in_blocks(&mut cell.children)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn replace_in_paragraph(
+        &mut self,
+        paragraph: &mut Paragraph,
+    ) -> Result<Option<Vec<Block>>, EditError> {
+        let mut from = 0;
+        // Runs to insert, as the position of the run among the paragraph's
+        // runs and the index of the placeholder text in it.
+        let mut inserted: Vec<(usize, usize, &Vec<Run>)> = vec![];
+        loop {
+            let mut runs = vec![];
+            collect_runs_mut(&mut paragraph.children, &mut runs);
+            let (text, segments) = text_segments(&runs);
+            let Some((start, end, value)) = self.placeholders.find(&text, from) else {
+                break;
+            };
+            if matches!(
+                value,
+                PlaceholderValue::Paragraphs(_) | PlaceholderValue::Table(_)
+            ) {
+                let start = run_position(&segments, start, false)?;
+                let end = run_position(&segments, end, true)?;
+                return self
+                    .split_paragraph(paragraph, start, end, value, &inserted)
+                    .map(Some);
+            }
+            let (run, child) = isolate(&mut runs, &segments, start, end)?;
+            match value {
+                PlaceholderValue::Text(value) => {
+                    let mut replaced = vec![];
+                    from = start;
+                    for (i, line) in value.split('\n').enumerate() {
+                        if i > 0 {
+                            replaced.push(RunChild::Break(Break::new(BreakType::TextWrapping)));
+                        }
+                        if !line.is_empty() {
+                            from += line.len();
+                            replaced.push(RunChild::Text(Text::new(line)));
+                        }
+                    }
+                    runs[run].children.splice(child..=child, replaced);
+                }
+                PlaceholderValue::Image(pic) => {
+                    runs[run].children[child] =
+                        RunChild::Drawing(Box::new(Drawing::new().pic((**pic).clone())));
+                    from = start;
+                }
+                PlaceholderValue::Runs(value) => {
+                    inserted.push((run, child, value));
+                    from = end;
+                }
+                PlaceholderValue::Paragraphs(_) | PlaceholderValue::Table(_) => {
+                    unreachable!("block values split the paragraph")
+                }
+            }
+        }
+
+        insert_runs(paragraph, &inserted);
+        Ok(None)
+    }
+
+    /// Replaces the placeholder between two positions in the paragraph text
+    /// with blocks. The content before and after the placeholder stays in
+    /// paragraphs of its own, unless there is none.
+    fn split_paragraph(
+        &mut self,
+        paragraph: &mut Paragraph,
+        start: TextPosition,
+        end: TextPosition,
+        value: &PlaceholderValue,
+        inserted: &[(usize, usize, &Vec<Run>)],
+    ) -> Result<Vec<Block>, EditError> {
+        let run_property = {
+            let mut runs = vec![];
+            collect_runs(&paragraph.children, &mut runs);
+            runs[start.run].run_property.clone()
+        };
+        let mut run = end.run;
+        let tail = split_children(&mut paragraph.children, &mut run, end.offset)?
+            .ok_or(EditError::OutOfRange)?;
+        let mut run = start.run;
+        split_children(&mut paragraph.children, &mut run, start.offset)?
+            .ok_or(EditError::OutOfRange)?;
+        let mut after = Paragraph {
+            id: generate_para_id(),
+            children: tail,
+            property: paragraph.property.clone(),
+            has_numbering: paragraph.has_numbering,
+        };
+        after.property.section_property = paragraph.property.section_property.take();
+        // Runs placeholders before this one are in the runs left in place.
+        insert_runs(paragraph, inserted);
+
+        let mut blocks = vec![];
+        if !is_blank(paragraph) {
+            blocks.push(Block::Paragraph(Box::new(paragraph.clone())));
+        }
+        match value {
+            PlaceholderValue::Paragraphs(value) => {
+                for p in value {
+                    let mut p = p.clone();
+                    if p.property == ParagraphProperty::default() {
+                        p.property = paragraph.property.clone();
+                        p.has_numbering = paragraph.has_numbering;
+                    }
+                    for child in &mut p.children {
+                        if let ParagraphChild::Run(r) = child {
+                            inherit_formatting(r, &run_property);
+                        }
+                    }
+                    self.has_numbering |= p.has_numbering;
+                    blocks.push(Block::Paragraph(Box::new(p)));
+                }
+            }
+            PlaceholderValue::Table(table) => {
+                self.has_numbering |= table.has_numbering;
+                blocks.push(Block::Table(table.clone()));
+            }
+            _ => unreachable!("only block values split the paragraph"),
+        }
+        // The text after the placeholder may hold more placeholders.
+        match self.replace_in_paragraph(&mut after)? {
+            Some(rest) => blocks.extend(rest),
+            None if !is_blank(&after) => blocks.push(Block::Paragraph(Box::new(after))),
+            None => {}
+        }
+        Ok(blocks)
+    }
+}
+
+/// Puts runs in place of the placeholder nodes [`isolate`] left, given as the
+/// position of the run among the paragraph's runs and the index of the node.
+fn insert_runs(paragraph: &mut Paragraph, inserted: &[(usize, usize, &Vec<Run>)]) {
+    if inserted.is_empty() {
+        return;
+    }
+    let mut position = 0;
+    expand_runs(&mut paragraph.children, &mut |run| {
+        let splits: Vec<_> = inserted
+            .iter()
+            .filter(|(r, _, _)| *r == position)
+            .map(|(_, child, runs)| (*child, *runs))
+            .collect();
+        position += 1;
+        let runs = if splits.is_empty() {
+            vec![run]
+        } else {
+            split_run(run, splits)
+        };
+        runs.into_iter()
+            .map(|r| ParagraphChild::Run(Box::new(r)))
+            .collect()
+    });
+}
+
+/// Whether a paragraph left by splitting has nothing but empty runs.
+fn is_blank(paragraph: &Paragraph) -> bool {
+    fn blank(children: &[ParagraphChild]) -> bool {
+        children.iter().all(|c| match c {
+            ParagraphChild::Run(r) => r.children.is_empty(),
+            ParagraphChild::Hyperlink(link) => blank(&link.children),
+            _ => false,
+        })
+    }
+    paragraph.property.section_property.is_none() && blank(&paragraph.children)
+}
+
+impl DocumentTreeVisitorMut for Replacer<'_> {
+    fn visit_footnote_reference(&mut self, reference: &mut FootnoteReference) {
+        if let Err(error) = self.replace_in_blocks(&mut reference.content) {
+            self.error.get_or_insert(error);
+        }
+    }
+
+    fn visit_comment_start(&mut self, comment: &mut CommentRangeStart) {
+        if let Err(error) = self.replace_in_blocks(&mut comment.comment.children) {
+            self.error.get_or_insert(error);
+        }
+    }
+}
+
+fn segment_text<'a>(runs: &'a mut [&mut Run], segment: &Segment) -> &'a mut String {
+    match &mut runs[segment.run].children[segment.child] {
+        RunChild::Text(t) => &mut t.text,
+        _ => unreachable!("segments point at text nodes"),
+    }
+}
+
+/// Finds the segment an offset in the unescaped paragraph text falls in. An
+/// `end` offset may be at the end of the segment, a start offset not.
+fn find_segment(segments: &[Segment], offset: usize, end: bool) -> Result<usize, EditError> {
+    let position = if end {
+        segments
+            .iter()
+            .rposition(|s| s.start < offset && offset <= s.start + s.len)
+    } else {
+        segments
+            .iter()
+            .position(|s| s.start <= offset && offset < s.start + s.len)
+    };
+    position.ok_or(EditError::OutOfRange)
+}
+
+/// Turns an offset in the unescaped paragraph text into a run and an offset
+/// in the text of that run.
+fn run_position(segments: &[Segment], offset: usize, end: bool) -> Result<TextPosition, EditError> {
+    let segment = &segments[find_segment(segments, offset, end)?];
+    let run_start = segments
+        .iter()
+        .find(|s| s.run == segment.run)
+        .map_or(segment.start, |s| s.start);
+    Ok(TextPosition {
+        run: segment.run,
+        offset: offset - run_start,
+    })
+}
+
+/// Moves the text between `start` and `end`, offsets in the unescaped
+/// paragraph text, into a text node of its own in
+/// the run it starts in, removing it from the following runs. Returns the
+/// run and the index of the new node.
+fn isolate(
+    runs: &mut [&mut Run],
+    segments: &[Segment],
+    start: usize,
+    end: usize,
+) -> Result<(usize, usize), EditError> {
+    let first = find_segment(segments, start, false)?;
+    let last = find_segment(segments, end, true)?;
+    if last < first {
+        return Err(EditError::OutOfRange);
+    }
+
+    let mut placeholder = String::new();
+    // Later nodes first, so the indices of earlier ones stay valid.
+    for segment in segments[first + 1..=last].iter().rev() {
+        let text = segment_text(runs, segment);
+        let taken = escaped_offset(text, (end - segment.start).min(segment.len));
+        placeholder.insert_str(0, &text[..taken]);
+        text.replace_range(..taken, "");
+        if text.is_empty() {
+            runs[segment.run].children.remove(segment.child);
+        }
+    }
+
+    let segment = &segments[first];
+    let text = segment_text(runs, segment);
+    let from = escaped_offset(text, start - segment.start);
+    let to = escaped_offset(text, (end - segment.start).min(segment.len));
+    placeholder.insert_str(0, &text[from..to]);
+    let suffix = text.split_off(to);
+    text.truncate(from);
+    let prefix = std::mem::take(text);
+
+    let mut nodes = vec![];
+    if !prefix.is_empty() {
+        nodes.push(RunChild::Text(Text::without_escape(prefix)));
+    }
+    let index = segment.child + nodes.len();
+    nodes.push(RunChild::Text(Text::without_escape(placeholder)));
+    if !suffix.is_empty() {
+        nodes.push(RunChild::Text(Text::without_escape(suffix)));
+    }
+    runs[segment.run]
+        .children
+        .splice(segment.child..=segment.child, nodes);
+    Ok((segment.run, index))
+}
+
+/// Splits `run` at the given placeholder nodes, putting runs in their place.
+/// `splits` is ordered by node index.
+fn split_run(run: Run, splits: Vec<(usize, &Vec<Run>)>) -> Vec<Run> {
+    let Run {
+        run_property,
+        children,
+    } = run;
+    let mut runs = vec![];
+    let mut children = children.into_iter().enumerate().peekable();
+    for (index, inserted) in splits {
+        let head: Vec<RunChild> = children
+            .by_ref()
+            .take_while(|(i, _)| *i < index)
+            .map(|(_, c)| c)
+            .collect();
+        // `take_while` has consumed the placeholder node itself.
+        if !head.is_empty() {
+            runs.push(Run {
+                run_property: run_property.clone(),
+                children: head,
+            });
+        }
+        for r in inserted {
+            let mut r = r.clone();
+            inherit_formatting(&mut r, &run_property);
+            runs.push(r);
+        }
+    }
+    let tail: Vec<RunChild> = children.map(|(_, c)| c).collect();
+    if !tail.is_empty() {
+        runs.push(Run {
+            run_property,
+            children: tail,
+        });
+    }
+    runs
+}
+
+fn inherit_formatting(run: &mut Run, run_property: &RunProperty) {
+    if run.run_property == RunProperty::default() {
+        run.run_property = run_property.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn paragraph_text(p: &Paragraph) -> String {
+        let mut runs = vec![];
+        collect_runs(&p.children, &mut runs);
+        text_segments(&runs).0
+    }
+
+    fn body(docx: &Docx) -> Vec<&Paragraph> {
+        docx.document
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(&**p),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn runs(p: &Paragraph) -> Vec<&Run> {
+        p.children
+            .iter()
+            .filter_map(|c| match c {
+                ParagraphChild::Run(r) => Some(&**r),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_replace_placeholder_split_across_runs() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Dear {").bold())
+                    .add_run(Run::new().add_text("{na"))
+                    .add_run(Run::new().add_text("me}").italic())
+                    .add_run(Run::new().add_text("}, welcome to {{place}}.")),
+            )
+            .replace_placeholders(
+                &Placeholders::new()
+                    .set("name", "Ada")
+                    .set("place", "London"),
+            )
+            .unwrap();
+
+        let p = body(&docx)[0];
+        assert_eq!(paragraph_text(p), "Dear Ada, welcome to London.");
+        let runs = runs(p);
+        assert_eq!(
+            runs[0].children,
+            vec![
+                RunChild::Text(Text::new("Dear ")),
+                RunChild::Text(Text::new("Ada"))
+            ]
+        );
+        assert_eq!(runs[0].run_property, RunProperty::new().bold());
+        assert!(runs[1].children.is_empty() && runs[2].children.is_empty());
+    }
+
+    #[test]
+    fn test_replace_placeholder_in_hyperlink_and_tag() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_hyperlink(
+                        Hyperlink::new("https://example.com", HyperlinkType::External)
+                            .add_run(Run::new().add_text("{{ si")),
+                    )
+                    .add_structured_data_tag(
+                        StructuredDataTag::new().add_run(Run::new().add_text("te }} and {{x}}")),
+                    ),
+            )
+            .replace_placeholders(&Placeholders::new().set("site", "docx-rs"))
+            .unwrap();
+
+        assert_eq!(paragraph_text(body(&docx)[0]), "docx-rs and {{x}}");
+    }
+
+    #[test]
+    fn test_replace_placeholder_with_runs_and_image() {
+        let image = include_bytes!("../../../images/cat_min.jpg").to_vec();
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new().add_run(Run::new().add_text("a {{runs}} b {{image}}").size(30)),
+            )
+            .replace_placeholders(
+                &Placeholders::new()
+                    .set(
+                        "runs",
+                        vec![Run::new().add_text("x"), Run::new().add_text("y").bold()],
+                    )
+                    .set("image", Pic::new(&image)),
+            )
+            .unwrap();
+
+        let p = body(&docx)[0];
+        assert_eq!(paragraph_text(p), "a xy b ");
+        let runs = runs(p);
+        assert_eq!(runs.len(), 4);
+        assert_eq!(runs[1].run_property, RunProperty::new().size(30));
+        assert_eq!(runs[2].run_property, RunProperty::new().bold());
+        assert_eq!(runs[3].run_property, RunProperty::new().size(30));
+        assert!(matches!(
+            runs[3].children.last(),
+            Some(RunChild::Drawing(_))
+        ));
+    }
+
+    #[test]
+    fn test_replace_placeholder_with_blocks() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .align(AlignmentType::Center)
+                    .add_run(Run::new().add_text("{{items}}")),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()
+                .add_paragraph(
+                    Paragraph::new().add_run(Run::new().add_text("{{table}}")),
+                )])]))
+            .replace_placeholders(
+                &Placeholders::new()
+                    .set(
+                        "items",
+                        vec![
+                            Paragraph::new().add_run(Run::new().add_text("one")),
+                            Paragraph::new().add_run(Run::new().add_text("two")),
+                        ],
+                    )
+                    .set("table", Table::new(vec![])),
+            )
+            .unwrap();
+
+        let children = &docx.document.children;
+        assert_eq!(children.len(), 3);
+        let DocumentChild::Paragraph(p) = &children[1] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(paragraph_text(p), "two");
+        assert_eq!(
+            p.property,
+            ParagraphProperty::new().align(AlignmentType::Center)
+        );
+        let DocumentChild::Table(table) = &children[2] else {
+            panic!("expected a table");
+        };
+        let TableChild::TableRow(row) = &table.rows[0];
+        let TableRowChild::TableCell(cell) = &row.cells[0];
+        assert!(matches!(cell.children[0], TableCellContent::Table(_)));
+    }
+
+    #[test]
+    fn test_replace_placeholder_with_blocks_keeps_surrounding_text() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Before {{runs}} "))
+                    .add_run(Run::new().add_text("{{items}} after {{table}}").bold()),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("{{items}}"))
+                    .section_property(SectionProperty::new()),
+            )
+            .replace_placeholders(
+                &Placeholders::new()
+                    .set("runs", vec![Run::new().add_text("x")])
+                    .set(
+                        "items",
+                        Paragraph::new().add_run(Run::new().add_text("item")),
+                    )
+                    .set("table", Table::new(vec![])),
+            )
+            .unwrap();
+
+        let children = &docx.document.children;
+        let texts: Vec<_> = children
+            .iter()
+            .map(|c| match c {
+                DocumentChild::Paragraph(p) => paragraph_text(p),
+                DocumentChild::Table(_) => "<table>".to_string(),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            texts,
+            vec!["Before x ", "item", " after ", "<table>", "item", ""]
+        );
+        let DocumentChild::Paragraph(item) = &children[1] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(runs(item)[0].run_property, RunProperty::new().bold());
+        let DocumentChild::Paragraph(last) = &children[5] else {
+            panic!("expected a paragraph");
+        };
+        assert!(last.property.section_property.is_some());
+    }
+
+    #[test]
+    fn test_replace_placeholder_in_header_footnote_and_comment() {
+        let footnote = Footnote::new()
+            .add_content(Paragraph::new().add_run(Run::new().add_text("see {{name}}")));
+        let docx = Docx::new()
+            .header(
+                Header::new()
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text("{{name}}"))),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_comment_start(Comment::new(1).add_paragraph(
+                        Paragraph::new().add_run(Run::new().add_text("by {{name}}")),
+                    ))
+                    .add_run(Run::new().add_text("body").add_footnote_reference(footnote))
+                    .add_comment_end(1),
+            )
+            .replace_placeholders(&Placeholders::new().set("name", "Ada"))
+            .unwrap();
+
+        let (_, header) = docx.document.section_property.header.as_ref().unwrap();
+        let HeaderChild::Paragraph(p) = &header.children[0] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(paragraph_text(p), "Ada");
+
+        let p = body(&docx)[0];
+        let ParagraphChild::CommentStart(comment) = &p.children[0] else {
+            panic!("expected a comment");
+        };
+        let CommentChild::Paragraph(c) = &comment.comment.children[0] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(paragraph_text(c), "by Ada");
+        let RunChild::FootnoteReference(reference) = &runs(p)[0].children[1] else {
+            panic!("expected a footnote reference");
+        };
+        assert_eq!(paragraph_text(&reference.content[0]), "see Ada");
+    }
+
+    #[test]
+    fn test_replace_placeholder_with_escaped_characters() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("re: <").bold())
+                    .add_run(Run::new().add_text("<R&D>> & <<Q&A>>")),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("«name» & «none»")))
+            .replace_placeholders(
+                &Placeholders::new()
+                    .delimiters("<<", ">>")
+                    .set("R&D", "research & <development>"),
+            )
+            .unwrap()
+            .replace_placeholders(&Placeholders::new().delimiters("«", "»").set("name", "AT&T"))
+            .unwrap();
+
+        let paragraphs = body(&docx);
+        assert_eq!(
+            paragraph_text(paragraphs[0]),
+            "re: research & <development> & <<Q&A>>"
+        );
+        assert_eq!(
+            runs(paragraphs[0])[0].children,
+            vec![
+                RunChild::Text(Text::new("re: ")),
+                RunChild::Text(Text::new("research & <development>"))
+            ]
+        );
+        assert_eq!(paragraph_text(paragraphs[1]), "AT&T & «none»");
+    }
+}
@@ -0,0 +1,271 @@
+//! Word's built-in styles with the definitions Word 2016 writes when one of
+//! them is first used in a blank document.
+
+use super::toc;
+use crate::documents::*;
+use crate::types::*;
+
+/// Returns the built-in style with the given id, e.g. `Heading1` or
+/// `TableGrid`, or `None` when Word has no such style. Ids are matched
+/// ignoring case and the style keeps the id as given.
+pub fn builtin_style(style_id: &str) -> Option<Style> {
+    let level = |prefix: &str| {
+        style_id
+            .get(..prefix.len())
+            .filter(|p| p.eq_ignore_ascii_case(prefix))
+            .and_then(|_| style_id[prefix.len()..].parse::<usize>().ok())
+            .filter(|l| (1..=9).contains(l))
+    };
+    let mut style = if let Some(l) = level("Heading") {
+        heading(l)
+    } else if let Some(l) = level("TOC") {
+        toc(l as i32)
+    } else {
+        match style_id.to_ascii_lowercase().as_str() {
+            "normal" => normal(),
+            "defaultparagraphfont" => default_paragraph_font(),
+            "tablenormal" => table_normal(),
+            "title" => title(),
+            "subtitle" => subtitle(),
+            "quote" => quote(),
+            "caption" => caption(),
+            "listparagraph" => list_paragraph(),
+            "hyperlink" => hyperlink(),
+            "footnotetext" => footnote_text(),
+            "footnotereference" => footnote_reference(),
+            "tablegrid" => table_grid(),
+            _ => return None,
+        }
+    };
+    style.style_id = style_id.to_owned();
+    Some(style)
+}
+
+fn major_fonts() -> RunFonts {
+    RunFonts::new()
+        .ascii_theme("majorHAnsi")
+        .hi_ansi_theme("majorHAnsi")
+        .east_asia_theme("majorEastAsia")
+        .cs_theme("majorBidi")
+}
+
+pub fn normal() -> Style {
+    Style::new("Normal", StyleType::Paragraph)
+        .name("Normal")
+        .as_default()
+}
+
+pub fn default_paragraph_font() -> Style {
+    Style::new("DefaultParagraphFont", StyleType::Character)
+        .name("Default Paragraph Font")
+        .as_default()
+        .q_format(false)
+        .ui_priority(1)
+        .semi_hidden()
+        .unhide_when_used()
+}
+
+pub fn table_normal() -> Style {
+    Style::new("TableNormal", StyleType::Table)
+        .name("Normal Table")
+        .as_default()
+        .q_format(false)
+        .ui_priority(99)
+        .semi_hidden()
+        .unhide_when_used()
+        .table_property(
+            TableProperty::without_borders().indent(0).set_margins(
+                TableCellMargins::new()
+                    .margin_left(108, WidthType::Dxa)
+                    .margin_right(108, WidthType::Dxa),
+            ),
+        )
+}
+
+/// `Heading1` to `Heading9`. Levels outside 1..=9 are clamped.
+pub fn heading(level: usize) -> Style {
+    let level = level.clamp(1, 9);
+    let style = Style::new(format!("Heading{level}"), StyleType::Paragraph)
+        .name(format!("heading {level}"))
+        .based_on("Normal")
+        .next("Normal")
+        .ui_priority(9)
+        .keep_next(true)
+        .keep_lines(true)
+        .outline_lvl(level - 1)
+        .fonts(major_fonts());
+    let style = if level == 1 {
+        style.line_spacing(LineSpacing::new().before(240).after(0))
+    } else {
+        style
+            .line_spacing(LineSpacing::new().before(40).after(0))
+            .semi_hidden()
+            .unhide_when_used()
+    };
+    let style = match level {
+        1 => style.size(32),
+        2 => style.size(26),
+        3 => style.size(24),
+        8 | 9 => style.size(21),
+        _ => style,
+    };
+    let style = match level {
+        4 | 7 | 9 => style.italic(),
+        _ => style,
+    };
+    match level {
+        1 | 2 | 4 | 5 => style
+            .color("2F5496")
+            .theme_color(ThemeColor::Accent1)
+            .theme_shade("BF"),
+        3 | 6 | 7 => style
+            .color("1F3763")
+            .theme_color(ThemeColor::Accent1)
+            .theme_shade("7F"),
+        _ => style
+            .color("272727")
+            .theme_color(ThemeColor::Text1)
+            .theme_tint("D8"),
+    }
+}
+
+pub fn title() -> Style {
+    Style::new("Title", StyleType::Paragraph)
+        .name("Title")
+        .based_on("Normal")
+        .next("Normal")
+        .ui_priority(10)
+        .contextual_spacing(true)
+        .line_spacing(
+            LineSpacing::new()
+                .after(0)
+                .line(240)
+                .line_rule(LineSpacingType::Auto),
+        )
+        .fonts(major_fonts())
+        .spacing(-10)
+        .kern(28)
+        .size(56)
+}
+
+pub fn subtitle() -> Style {
+    Style::new("Subtitle", StyleType::Paragraph)
+        .name("Subtitle")
+        .based_on("Normal")
+        .next("Normal")
+        .ui_priority(11)
+        .line_spacing(LineSpacing::new().after(160))
+        .color("5A5A5A")
+        .theme_color(ThemeColor::Text1)
+        .theme_tint("A5")
+        .spacing(15)
+        .size(22)
+}
+
+pub fn quote() -> Style {
+    Style::new("Quote", StyleType::Paragraph)
+        .name("Quote")
+        .based_on("Normal")
+        .next("Normal")
+        .ui_priority(29)
+        .line_spacing(LineSpacing::new().before(200).after(160))
+        .indent(Some(864), None, Some(864), None)
+        .align(AlignmentType::Center)
+        .italic()
+        .color("404040")
+        .theme_color(ThemeColor::Text1)
+        .theme_tint("BF")
+}
+
+pub fn caption() -> Style {
+    Style::new("Caption", StyleType::Paragraph)
+        .name("caption")
+        .based_on("Normal")
+        .next("Normal")
+        .ui_priority(35)
+        .semi_hidden()
+        .unhide_when_used()
+        .line_spacing(
+            LineSpacing::new()
+                .after(200)
+                .line(240)
+                .line_rule(LineSpacingType::Auto),
+        )
+        .italic()
+        .color("44546A")
+        .theme_color(ThemeColor::Text2)
+        .size(18)
+}
+
+pub fn list_paragraph() -> Style {
+    Style::new("ListParagraph", StyleType::Paragraph)
+        .name("List Paragraph")
+        .based_on("Normal")
+        .ui_priority(34)
+        .indent(Some(720), None, None, None)
+        .contextual_spacing(true)
+}
+
+pub fn hyperlink() -> Style {
+    Style::new("Hyperlink", StyleType::Character)
+        .name("Hyperlink")
+        .based_on("DefaultParagraphFont")
+        .q_format(false)
+        .ui_priority(99)
+        .unhide_when_used()
+        .color("0563C1")
+        .theme_color(ThemeColor::Hyperlink)
+        .underline("single")
+}
+
+pub fn footnote_text() -> Style {
+    Style::new("FootnoteText", StyleType::Paragraph)
+        .name("footnote text")
+        .based_on("Normal")
+        .q_format(false)
+        .ui_priority(99)
+        .semi_hidden()
+        .unhide_when_used()
+        .line_spacing(
+            LineSpacing::new()
+                .after(0)
+                .line(240)
+                .line_rule(LineSpacingType::Auto),
+        )
+        .size(20)
+}
+
+pub fn footnote_reference() -> Style {
+    Style::new("FootnoteReference", StyleType::Character)
+        .name("footnote reference")
+        .based_on("DefaultParagraphFont")
+        .q_format(false)
+        .ui_priority(99)
+        .semi_hidden()
+        .unhide_when_used()
+        .vert_align(VertAlignType::SuperScript)
+}
+
+pub fn table_grid() -> Style {
+    let border = |position| TableBorder::new(position).size(4).color("auto");
+    Style::new("TableGrid", StyleType::Table)
+        .name("Table Grid")
+        .based_on("TableNormal")
+        .q_format(false)
+        .ui_priority(39)
+        .line_spacing(
+            LineSpacing::new()
+                .after(0)
+                .line(240)
+                .line_rule(LineSpacingType::Auto),
+        )
+        .set_borders(
+            TableBorders::new()
+                .set(border(TableBorderPosition::Top))
+                .set(border(TableBorderPosition::Left))
+                .set(border(TableBorderPosition::Bottom))
+                .set(border(TableBorderPosition::Right))
+                .set(border(TableBorderPosition::InsideH))
+                .set(border(TableBorderPosition::InsideV)),
+        )
+}
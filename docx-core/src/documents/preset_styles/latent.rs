@@ -0,0 +1,125 @@
+use crate::documents::*;
+
+// (name, uiPriority, semiHidden and unhideWhenUsed, qFormat)
+type Entry = (&'static str, Option<usize>, bool, bool);
+
+const WORD_LATENT_STYLES: &[Entry] = &[
+    ("Normal", Some(0), false, true),
+    ("heading 1", Some(9), false, true),
+    ("heading 2", Some(9), true, true),
+    ("heading 3", Some(9), true, true),
+    ("heading 4", Some(9), true, true),
+    ("heading 5", Some(9), true, true),
+    ("heading 6", Some(9), true, true),
+    ("heading 7", Some(9), true, true),
+    ("heading 8", Some(9), true, true),
+    ("heading 9", Some(9), true, true),
+    ("index 1", None, true, false),
+    ("index 2", None, true, false),
+    ("index 3", None, true, false),
+    ("index 4", None, true, false),
+    ("index 5", None, true, false),
+    ("index 6", None, true, false),
+    ("index 7", None, true, false),
+    ("index 8", None, true, false),
+    ("index 9", None, true, false),
+    ("toc 1", Some(39), true, false),
+    ("toc 2", Some(39), true, false),
+    ("toc 3", Some(39), true, false),
+    ("toc 4", Some(39), true, false),
+    ("toc 5", Some(39), true, false),
+    ("toc 6", Some(39), true, false),
+    ("toc 7", Some(39), true, false),
+    ("toc 8", Some(39), true, false),
+    ("toc 9", Some(39), true, false),
+    ("Normal Indent", None, true, false),
+    ("footnote text", None, true, false),
+    ("annotation text", None, true, false),
+    ("header", None, true, false),
+    ("footer", None, true, false),
+    ("index heading", None, true, false),
+    ("caption", Some(35), true, true),
+    ("table of figures", None, true, false),
+    ("envelope address", None, true, false),
+    ("envelope return", None, true, false),
+    ("footnote reference", None, true, false),
+    ("annotation reference", None, true, false),
+    ("line number", None, true, false),
+    ("page number", None, true, false),
+    ("endnote reference", None, true, false),
+    ("endnote text", None, true, false),
+    ("table of authorities", None, true, false),
+    ("macro", None, true, false),
+    ("toa heading", None, true, false),
+    ("List", None, true, false),
+    ("List Bullet", None, true, false),
+    ("List Number", None, true, false),
+    ("Title", Some(10), false, true),
+    ("Closing", None, true, false),
+    ("Signature", None, true, false),
+    ("Default Paragraph Font", Some(1), true, false),
+    ("Body Text", None, true, false),
+    ("Body Text Indent", None, true, false),
+    ("Message Header", None, true, false),
+    ("Subtitle", Some(11), false, true),
+    ("Salutation", None, true, false),
+    ("Date", None, true, false),
+    ("Note Heading", None, true, false),
+    ("Block Text", None, true, false),
+    ("Hyperlink", None, true, false),
+    ("FollowedHyperlink", None, true, false),
+    ("Strong", Some(22), false, true),
+    ("Emphasis", Some(20), false, true),
+    ("Document Map", None, true, false),
+    ("Plain Text", None, true, false),
+    ("Normal (Web)", None, true, false),
+    ("Normal Table", None, true, false),
+    ("No List", None, true, false),
+    ("annotation subject", None, true, false),
+    ("Balloon Text", None, true, false),
+    ("Table Grid", Some(39), false, false),
+    ("Placeholder Text", None, true, false),
+    ("No Spacing", Some(1), false, true),
+    ("Revision", None, true, false),
+    ("List Paragraph", Some(34), false, true),
+    ("Quote", Some(29), false, true),
+    ("Intense Quote", Some(30), false, true),
+    ("Subtle Emphasis", Some(19), false, true),
+    ("Intense Emphasis", Some(21), false, true),
+    ("Subtle Reference", Some(31), false, true),
+    ("Intense Reference", Some(32), false, true),
+    ("Book Title", Some(33), false, true),
+    ("Bibliography", Some(37), true, false),
+    ("TOC Heading", Some(39), true, true),
+    ("Plain Table 1", Some(41), false, false),
+    ("Plain Table 2", Some(42), false, false),
+    ("Plain Table 3", Some(43), false, false),
+    ("Plain Table 4", Some(44), false, false),
+    ("Plain Table 5", Some(45), false, false),
+    ("Grid Table Light", Some(40), false, false),
+    ("Mention", None, true, false),
+    ("Smart Hyperlink", None, true, false),
+    ("Hashtag", None, true, false),
+    ("Unresolved Mention", None, true, false),
+];
+
+/// The `w:latentStyles` Word 2016 writes into a blank document: built-in
+/// styles are hidden from the gallery unless they are listed here.
+pub fn latent_styles() -> LatentStyles {
+    WORD_LATENT_STYLES.iter().fold(
+        LatentStyles::new().count(376),
+        |styles, (name, priority, hidden, q_format)| {
+            let mut e = LatentStyleException::new(*name);
+            if let Some(p) = priority {
+                e = e.ui_priority(*p);
+            }
+            if *hidden {
+                e = e.semi_hidden(true).unhide_when_used(true);
+            }
+            if *q_format {
+                e = e.q_format(true);
+            }
+            styles.add_exception(e)
+        },
+    )
+}
@@ -1,3 +1,161 @@
+mod builtin;
+mod latent;
 mod toc;
 
+pub use builtin::*;
+pub use latent::*;
 pub use toc::*;
+
+use std::collections::BTreeSet;
+
+use super::document_tree::{visit_comment, visit_footnote, DocumentTreeVisitor};
+use crate::documents::*;
+
+/// Style ids used by paragraphs, runs and tables anywhere in the document:
+/// the body, headers, footers, comments and footnotes.
+pub(crate) fn referenced_style_ids(docx: &Docx) -> BTreeSet<String> {
+    let mut ids = StyleIds::default();
+    docx.visit(&mut ids);
+    for comment in &docx.comments.comments {
+        visit_comment(comment, &mut ids);
+    }
+    for footnote in &docx.footnotes.footnotes {
+        visit_footnote(footnote, &mut ids);
+    }
+    ids.0
+}
+
+#[derive(Default)]
+struct StyleIds(BTreeSet<String>);
+
+impl DocumentTreeVisitor for StyleIds {
+    fn visit_paragraph(&mut self, paragraph: &Paragraph) {
+        if let Some(s) = &paragraph.property.style {
+            self.0.insert(s.val.clone());
+        }
+        if let Some(s) = &paragraph.property.run_property.style {
+            self.0.insert(s.val.clone());
+        }
+    }
+
+    fn visit_run(&mut self, run: &Run) {
+        if let Some(s) = &run.run_property.style {
+            self.0.insert(s.val.clone());
+        }
+    }
+
+    fn visit_table(&mut self, table: &Table) {
+        if let Some(s) = table.property.get_style() {
+            self.0.insert(s.get_val().to_owned());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_builtin_styles_are_added_when_referenced() {
+        let table = Table::new(vec![TableRow::new(vec![TableCell::new()])]).style("TableGrid");
+        let xml = Docx::new()
+            .use_builtin_styles()
+            .add_style(Style::new("Heading1", StyleType::Paragraph).name("My Heading"))
+            .add_paragraph(Paragraph::new().style("Heading1"))
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading2")
+                    .add_run(Run::new().style("Hyperlink").add_text("link")),
+            )
+            .add_paragraph(Paragraph::new().style("Unknown"))
+            .add_table(table)
+            .build()
+            .styles;
+        let xml = str::from_utf8(&xml).unwrap();
+
+        let ids: Vec<_> = xml
+            .split(r#"w:styleId=""#)
+            .skip(1)
+            .map(|s| &s[..s.find('"').unwrap()])
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                "Heading1",
+                "Normal",
+                "Heading2",
+                "DefaultParagraphFont",
+                "Hyperlink",
+                "TableNormal",
+                "TableGrid"
+            ]
+        );
+        assert!(xml.contains(r#"<w:style w:type="paragraph" w:default="1" w:styleId="Normal">"#));
+        assert!(
+            xml.contains(r#"<w:lsdException w:name="heading 1" w:uiPriority="9" w:qFormat="1" />"#)
+        );
+    }
+
+    #[test]
+    fn test_builtin_style_lookup() {
+        assert_eq!(builtin_style("Heading3"), Some(heading(3)));
+        assert_eq!(builtin_style("heading3").unwrap().style_id, "heading3");
+        assert_eq!(builtin_style("Heading10"), None);
+        assert_eq!(builtin_style("TOC2").unwrap().style_id, "TOC2");
+        assert_eq!(builtin_style("TOC2").unwrap().name, Name::new("toc 2"));
+        assert_eq!(builtin_style("Strong"), None);
+        assert_eq!(builtin_style("TableGrid"), Some(table_grid()));
+        let style = builtin_style("tablegrid").unwrap();
+        assert_eq!(style.style_id, "tablegrid");
+        assert_eq!(style.name, Name::new("Table Grid"));
+        assert_eq!(
+            builtin_style("LISTPARAGRAPH").unwrap().style_id,
+            "LISTPARAGRAPH"
+        );
+    }
+
+    #[test]
+    fn test_builtin_styles_are_added_when_referenced_outside_the_body() {
+        let footnote = Footnote::new().add_content(
+            Paragraph::new()
+                .style("FootnoteText")
+                .add_run(Run::new().add_text("note")),
+        );
+        let xml = Docx::new()
+            .use_builtin_styles()
+            .header(Header::new().add_paragraph(Paragraph::new().style("Title")))
+            .footer(Footer::new().add_paragraph(Paragraph::new().style("heading2")))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_comment_start(
+                        Comment::new(1).add_paragraph(Paragraph::new().style("Quote")),
+                    )
+                    .add_run(Run::new().add_text("body").add_footnote_reference(footnote))
+                    .add_comment_end(1),
+            )
+            .build()
+            .styles;
+        let xml = str::from_utf8(&xml).unwrap();
+
+        let ids: BTreeSet<_> = xml
+            .split(r#"w:styleId=""#)
+            .skip(1)
+            .map(|s| &s[..s.find('"').unwrap()])
+            .collect();
+        for id in [
+            "Title",
+            "heading2",
+            "Quote",
+            "FootnoteText",
+            "FootnoteReference",
+        ] {
+            assert!(ids.contains(id), "{id} should be added");
+        }
+        assert!(!ids.contains("Heading2"));
+    }
+}
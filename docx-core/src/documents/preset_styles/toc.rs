@@ -5,6 +5,11 @@ pub fn toc(level: i32) -> Style {
     let spacing = LineSpacing::new().after(100);
     Style::new(format!("ToC{level}"), StyleType::Paragraph)
         .name(format!("toc {level}"))
+        .based_on("Normal")
+        .next("Normal")
+        .q_format(false)
+        .ui_priority(39)
+        .unhide_when_used()
         .align(AlignmentType::Both)
         .snap_to_grid(false)
         .indent(Some((level - 1) * 200), None, None, Some((level - 1) * 100))
@@ -14,7 +14,7 @@ pub struct Settings {
     zoom: Zoom,
     doc_id: Option<DocId>,
     doc_vars: Vec<DocVar>,
-    even_and_odd_headers: bool,
+    pub(crate) even_and_odd_headers: bool,
     adjust_line_height_in_table: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     character_spacing_control: Option<CharacterSpacingValues>,
@@ -54,6 +54,14 @@ impl Settings {
         self.character_spacing_control = Some(val);
         self
     }
+
+    /// Drops the `w15:docId` that identifies the document these settings
+    /// were read from, for settings taken over by another document. Revision
+    /// ids (`w:rsids`) are not read, so there are none to drop.
+    pub(crate) fn without_doc_id(mut self) -> Self {
+        self.doc_id = None;
+        self
+    }
 }
 
 impl Default for Settings {
@@ -0,0 +1,601 @@
+//! Splits a document into several, e.g. to publish chapters separately.
+//!
+//! Every part is a complete document: it carries the styles, numbering
+//! definitions, comments, footnotes and images its content uses, and the
+//! page setup, headers and footers of the sections it was taken from.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use super::document_tree::{
+    visit_document_part, visit_footer_part, visit_header_part, DocumentTreeVisitorMut,
+};
+use super::image_collector::fill_header_footer_images;
+use crate::documents::*;
+
+/// Where [`Docx::split`] starts a new document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitBy {
+    /// One document per section.
+    Section,
+    /// A new document at every heading of this level or above, e.g.
+    /// `Heading(1)` splits at each `Heading 1`. Headings are recognized by
+    /// their outline level. Content before the first heading becomes a
+    /// document of its own. `Heading(0)` is taken as `Heading(1)`.
+    Heading(usize),
+}
+
+impl Docx {
+    /// Splits the body into several documents. Empty parts are skipped.
+    pub fn split(&self, by: SplitBy) -> Vec<Docx> {
+        let mut sections = vec![];
+        let mut children = vec![];
+        for child in &self.document.children {
+            match child {
+                DocumentChild::Section(section) => {
+                    for child in &section.children {
+                        children.push((sections.len(), document_child(child.clone())));
+                    }
+                    sections.push(&section.property);
+                }
+                child => children.push((sections.len(), child.clone())),
+            }
+        }
+        sections.push(&self.document.section_property);
+
+        let mut parts: Vec<Vec<(usize, DocumentChild)>> = vec![];
+        let mut last_section = None;
+        for (section, child) in children {
+            let starts_part = match by {
+                SplitBy::Section => last_section != Some(section),
+                SplitBy::Heading(level) => match &child {
+                    DocumentChild::Paragraph(p) => self
+                        .resolve_paragraph_properties(p)
+                        .outline_lvl
+                        .is_some_and(|l| l.v < level.max(1)),
+                    _ => false,
+                },
+            };
+            last_section = Some(section);
+            match parts.last_mut() {
+                Some(part) if !starts_part => part.push((section, child)),
+                _ => parts.push(vec![(section, child)]),
+            }
+        }
+
+        parts
+            .into_iter()
+            .map(|part| self.split_part(part, &sections))
+            .collect()
+    }
+
+    /// Builds one document from children tagged with the index of the section
+    /// they belong to.
+    fn split_part(
+        &self,
+        children: Vec<(usize, DocumentChild)>,
+        sections: &[&SectionProperty],
+    ) -> Docx {
+        let mut docx = Docx::new();
+        docx.settings = self.settings.clone().without_doc_id();
+        docx.font_table = self.font_table.clone();
+        docx.themes = self.themes.clone();
+        docx.doc_props.core = self.doc_props.core.clone();
+
+        let mut groups: Vec<(usize, Vec<DocumentChild>)> = vec![];
+        for (section, child) in children {
+            match groups.last_mut() {
+                Some((s, group)) if *s == section => group.push(child),
+                _ => groups.push((section, vec![child])),
+            }
+        }
+        let (last_section, last_children) = groups.pop().unwrap_or_default();
+        for (section, group) in groups {
+            let mut property = sections[section].clone();
+            fill_header_footer_images(&mut property, &self.header_footer_images);
+            let mut section = Section {
+                property,
+                children: group.into_iter().filter_map(section_child).collect(),
+                ..Default::default()
+            };
+            docx.attach_section_parts(&mut section);
+            docx.document
+                .children
+                .push(DocumentChild::Section(Box::new(section)));
+        }
+        docx.document.children.extend(last_children);
+        let mut property = sections[last_section].clone();
+        fill_header_footer_images(&mut property, &self.header_footer_images);
+        let mut last = Section {
+            property,
+            ..Default::default()
+        };
+        docx.attach_section_parts(&mut last);
+        docx.document.section_property = last.property;
+        docx.document.renumber_header_footer_rids();
+
+        let mut used = UsedResources {
+            images: self
+                .images
+                .iter()
+                .map(|(id, _, image, _)| (id.as_str(), &image.0))
+                .collect(),
+            hyperlinks: self
+                .hyperlinks
+                .iter()
+                .map(|(id, path, _)| (id.as_str(), path.as_str()))
+                .collect(),
+            ..Default::default()
+        };
+        visit_document_part(&mut docx.document, &mut used);
+        // Pictures of headers and footers were filled from their own parts.
+        used.images.clear();
+        for (_, header) in docx.document.headers_mut() {
+            visit_header_part(header, &mut used);
+        }
+        for (_, footer) in docx.document.footers_mut() {
+            visit_footer_part(footer, &mut used);
+        }
+
+        self.copy_used_definitions(&mut docx, used.styles, used.numberings);
+        docx.comments.comments = self
+            .comments
+            .comments
+            .iter()
+            .filter(|c| used.comments.contains(&c.id))
+            .cloned()
+            .collect();
+        let comment_paragraphs: HashSet<&str> = docx
+            .comments
+            .comments
+            .iter()
+            .flat_map(|c| &c.children)
+            .filter_map(|c| match c {
+                CommentChild::Paragraph(p) => Some(p.id.as_str()),
+                _ => None,
+            })
+            .collect();
+        docx.comments_extended.children = self
+            .comments_extended
+            .children
+            .iter()
+            .filter(|c| comment_paragraphs.contains(c.paragraph_id.as_str()))
+            .cloned()
+            .collect();
+        docx.comments_ids.children = self
+            .comments_ids
+            .children
+            .iter()
+            .filter(|c| comment_paragraphs.contains(c.paragraph_id.as_str()))
+            .cloned()
+            .collect();
+        let durable_ids: HashSet<&str> = docx
+            .comments_ids
+            .children
+            .iter()
+            .map(|c| c.durable_id.as_str())
+            .collect();
+        docx.comments_extensible.children = self
+            .comments_extensible
+            .children
+            .iter()
+            .filter(|c| durable_ids.contains(c.durable_id.as_str()))
+            .cloned()
+            .collect();
+        docx.people.people = self
+            .people
+            .people
+            .iter()
+            .filter(|p| used.authors.contains(&p.author))
+            .cloned()
+            .collect();
+        docx
+    }
+
+    /// Copies the styles and numbering definitions in use into `part`,
+    /// following `w:basedOn`, `w:link` and `w:next` as well as the styles
+    /// and numbering definitions they refer to in turn.
+    fn copy_used_definitions(
+        &self,
+        part: &mut Docx,
+        mut styles: BTreeSet<String>,
+        mut numberings: BTreeSet<usize>,
+    ) {
+        styles.extend(
+            self.styles
+                .styles
+                .iter()
+                .filter(|s| s.is_default)
+                .map(|s| s.style_id.clone()),
+        );
+        let mut abstract_nums = BTreeSet::new();
+        loop {
+            let count = (styles.len(), numberings.len(), abstract_nums.len());
+            for style in &self.styles.styles {
+                if !styles.contains(&style.style_id) {
+                    continue;
+                }
+                let referenced = [
+                    style.based_on.as_ref().map(|b| b.get_val()),
+                    style.link.as_ref().map(|l| l.get_val()),
+                    style.next.as_ref().map(|n| n.get_val()),
+                ];
+                for id in referenced.into_iter().flatten() {
+                    styles.insert(id.to_owned());
+                }
+                if let Some(id) = style
+                    .paragraph_property
+                    .numbering_property
+                    .as_ref()
+                    .and_then(|n| n.id.as_ref())
+                {
+                    numberings.insert(id.id);
+                }
+            }
+            for numbering in self
+                .numberings
+                .numberings
+                .iter()
+                .filter(|n| numberings.contains(&n.id))
+            {
+                abstract_nums.insert(numbering.abstract_num_id);
+            }
+            for abstract_num in self
+                .numberings
+                .abstract_nums
+                .iter()
+                .filter(|a| abstract_nums.contains(&a.id))
+            {
+                let linked = [&abstract_num.style_link, &abstract_num.num_style_link];
+                for id in linked.into_iter().flatten() {
+                    styles.insert(id.clone());
+                }
+                for level in &abstract_num.levels {
+                    if let Some(p) = &level.pstyle {
+                        styles.insert(p.val.clone());
+                    }
+                }
+            }
+            if count == (styles.len(), numberings.len(), abstract_nums.len()) {
+                break;
+            }
+        }
+
+        part.styles = Styles {
+            doc_defaults: self.styles.doc_defaults.clone(),
+            latent_styles: self.styles.latent_styles.clone(),
+            styles: self
+                .styles
+                .styles
+                .iter()
+                .filter(|s| styles.contains(&s.style_id))
+                .cloned()
+                .collect(),
+            include_builtin_styles: self.styles.include_builtin_styles,
+        };
+        let bullets: BTreeSet<usize> = self
+            .numberings
+            .abstract_nums
+            .iter()
+            .filter(|a| abstract_nums.contains(&a.id))
+            .flat_map(|a| a.levels.iter().filter_map(|l| l.pic_bullet_id))
+            .collect();
+        part.numberings = Numberings {
+            num_pic_bullets: self
+                .numberings
+                .num_pic_bullets
+                .iter()
+                .filter(|b| bullets.contains(&b.id))
+                .cloned()
+                .collect(),
+            abstract_nums: self
+                .numberings
+                .abstract_nums
+                .iter()
+                .filter(|a| abstract_nums.contains(&a.id))
+                .cloned()
+                .collect(),
+            numberings: self
+                .numberings
+                .numberings
+                .iter()
+                .filter(|n| numberings.contains(&n.id))
+                .cloned()
+                .collect(),
+        };
+        if !part.numberings.numberings.is_empty() {
+            part.document_rels.has_numberings = true;
+        }
+    }
+}
+
+fn document_child(child: SectionChild) -> DocumentChild {
+    match child {
+        SectionChild::Paragraph(p) => DocumentChild::Paragraph(p),
+        SectionChild::Table(t) => DocumentChild::Table(t),
+        SectionChild::BookmarkStart(b) => DocumentChild::BookmarkStart(b),
+        SectionChild::BookmarkEnd(b) => DocumentChild::BookmarkEnd(b),
+        SectionChild::CommentStart(c) => DocumentChild::CommentStart(c),
+        SectionChild::CommentEnd(c) => DocumentChild::CommentEnd(c),
+        SectionChild::StructuredDataTag(t) => DocumentChild::StructuredDataTag(t),
+        SectionChild::TableOfContents(t) => DocumentChild::TableOfContents(t),
+        SectionChild::AltChunk(c) => DocumentChild::AltChunk(c),
+    }
+}
+
+pub(crate) fn section_child(child: DocumentChild) -> Option<SectionChild> {
+    match child {
+        DocumentChild::Paragraph(p) => Some(SectionChild::Paragraph(p)),
+        DocumentChild::Table(t) => Some(SectionChild::Table(t)),
+        DocumentChild::BookmarkStart(b) => Some(SectionChild::BookmarkStart(b)),
+        DocumentChild::BookmarkEnd(b) => Some(SectionChild::BookmarkEnd(b)),
+        DocumentChild::CommentStart(c) => Some(SectionChild::CommentStart(c)),
+        DocumentChild::CommentEnd(c) => Some(SectionChild::CommentEnd(c)),
+        DocumentChild::StructuredDataTag(t) => Some(SectionChild::StructuredDataTag(t)),
+        DocumentChild::TableOfContents(t) => Some(SectionChild::TableOfContents(t)),
+        DocumentChild::AltChunk(c) => Some(SectionChild::AltChunk(c)),
+        DocumentChild::Section(_) => None,
+    }
+}
+
+/// Records what a part refers to and puts back the image bytes and
+/// hyperlink targets the reader keeps outside the document tree.
+#[derive(Default)]
+struct UsedResources<'a> {
+    images: HashMap<&'a str, &'a Vec<u8>>,
+    hyperlinks: HashMap<&'a str, &'a str>,
+    styles: BTreeSet<String>,
+    numberings: BTreeSet<usize>,
+    comments: HashSet<usize>,
+    authors: HashSet<String>,
+}
+
+impl DocumentTreeVisitorMut for UsedResources<'_> {
+    fn visit_paragraph(&mut self, paragraph: &mut Paragraph) {
+        let property = &paragraph.property;
+        if let Some(s) = &property.style {
+            self.styles.insert(s.val.clone());
+        }
+        if let Some(s) = &property.run_property.style {
+            self.styles.insert(s.val.clone());
+        }
+        if let Some(id) = property
+            .numbering_property
+            .as_ref()
+            .and_then(|n| n.id.as_ref())
+        {
+            self.numberings.insert(id.id);
+        }
+    }
+
+    fn visit_run(&mut self, run: &mut Run) {
+        if let Some(s) = &run.run_property.style {
+            self.styles.insert(s.val.clone());
+        }
+    }
+
+    fn visit_table(&mut self, table: &mut Table) {
+        if let Some(s) = table.property.get_style() {
+            self.styles.insert(s.get_val().to_owned());
+        }
+    }
+
+    fn visit_picture(&mut self, picture: &mut Pic) {
+        if picture.image.is_empty() {
+            if let Some(image) = self.images.get(picture.id.as_str()) {
+                picture.image = (*image).clone();
+            }
+        }
+    }
+
+    fn visit_hyperlink(&mut self, link: &mut Hyperlink) {
+        if let HyperlinkData::External { rid, path } = &mut link.link {
+            if path.is_empty() {
+                if let Some(p) = self.hyperlinks.get(rid.as_str()) {
+                    *path = (*p).to_owned();
+                }
+            }
+        }
+    }
+
+    fn visit_comment_start(&mut self, comment: &mut CommentRangeStart) {
+        self.comments.insert(comment.comment.id);
+        self.authors.insert(comment.comment.author.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::image_collector::packed_header_images;
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn text(docx: &Docx) -> Vec<String> {
+        fn paragraph_text(p: &Paragraph) -> String {
+            p.children
+                .iter()
+                .filter_map(|c| match c {
+                    ParagraphChild::Run(run) => Some(run),
+                    _ => None,
+                })
+                .flat_map(|run| &run.children)
+                .filter_map(|c| match c {
+                    RunChild::Text(t) => Some(t.text.clone()),
+                    _ => None,
+                })
+                .collect()
+        }
+        let mut texts = vec![];
+        for child in &docx.document.children {
+            match child {
+                DocumentChild::Paragraph(p) => texts.push(paragraph_text(p)),
+                DocumentChild::Section(s) => {
+                    for child in &s.children {
+                        if let SectionChild::Paragraph(p) = child {
+                            texts.push(paragraph_text(p));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        texts
+    }
+
+    fn paragraph(text: &str) -> Paragraph {
+        Paragraph::new().add_run(Run::new().add_text(text))
+    }
+
+    fn header(text: &str) -> Header {
+        Header::new().add_paragraph(paragraph(text))
+    }
+
+    #[test]
+    fn test_split_by_section() {
+        let docx = Docx::new()
+            .add_section(
+                Section::new()
+                    .header(header("cover header"))
+                    .add_paragraph(paragraph("cover")),
+            )
+            .header(header("body header"))
+            .add_paragraph(paragraph("body"))
+            .add_paragraph(paragraph("more body"));
+
+        let parts = docx.split(SplitBy::Section);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(text(&parts[0]), vec!["cover"]);
+        assert_eq!(text(&parts[1]), vec!["body", "more body"]);
+
+        let header_text = |d: &Docx| {
+            let (rid, header) = d.document.section_property.header.as_ref().unwrap();
+            let HeaderChild::Paragraph(p) = &header.children[0] else {
+                panic!("header should start with a paragraph");
+            };
+            let RunChild::Text(t) = &match &p.children[0] {
+                ParagraphChild::Run(run) => run,
+                _ => panic!("paragraph should start with a run"),
+            }
+            .children[0] else {
+                panic!("run should start with text");
+            };
+            (rid.clone(), t.text.clone())
+        };
+        assert_eq!(
+            header_text(&parts[0]),
+            (create_header_rid(1), "cover header".to_owned())
+        );
+        assert_eq!(
+            header_text(&parts[1]),
+            (create_header_rid(1), "body header".to_owned())
+        );
+        assert_eq!(parts[1].document_rels.header_count, 1);
+    }
+
+    #[test]
+    fn test_split_by_heading() {
+        let heading = |text: &str| paragraph(text).style("Heading1");
+        let docx = Docx::new()
+            .add_style(
+                Style::new("Heading1", StyleType::Paragraph)
+                    .name("Heading 1")
+                    .based_on("Base")
+                    .outline_lvl(0),
+            )
+            .add_style(Style::new("Base", StyleType::Paragraph).name("Base"))
+            .add_style(Style::new("Quote", StyleType::Paragraph).name("Quote"))
+            .add_style(Style::new("Unused", StyleType::Paragraph).name("Unused"))
+            .add_abstract_numbering(AbstractNumbering::new(2))
+            .add_abstract_numbering(AbstractNumbering::new(3))
+            .add_numbering(Numbering::new(2, 2))
+            .add_numbering(Numbering::new(3, 3))
+            .add_paragraph(paragraph("preface"))
+            .add_paragraph(heading("Chapter 1"))
+            .add_paragraph(paragraph("item").numbering(NumberingId::new(2), IndentLevel::new(0)))
+            .add_paragraph(heading("Chapter 2"))
+            .add_paragraph(paragraph("quote").style("Quote"));
+
+        let parts = docx.split(SplitBy::Heading(1));
+        let texts: Vec<Vec<String>> = parts.iter().map(text).collect();
+        assert_eq!(
+            texts,
+            vec![
+                vec!["preface".to_owned()],
+                vec!["Chapter 1".to_owned(), "item".to_owned()],
+                vec!["Chapter 2".to_owned(), "quote".to_owned()],
+            ]
+        );
+
+        let style_ids = |d: &Docx| -> Vec<String> {
+            d.styles.styles.iter().map(|s| s.style_id.clone()).collect()
+        };
+        assert!(style_ids(&parts[0]).is_empty());
+        assert_eq!(style_ids(&parts[1]), vec!["Heading1", "Base"]);
+        assert_eq!(style_ids(&parts[2]), vec!["Heading1", "Base", "Quote"]);
+
+        let numbering_ids: Vec<usize> = parts[1]
+            .numberings
+            .numberings
+            .iter()
+            .map(|n| n.id)
+            .collect();
+        assert_eq!(numbering_ids, vec![2]);
+        assert_eq!(parts[1].numberings.abstract_nums.len(), 1);
+        assert!(parts[1].document_rels.has_numberings);
+        assert!(parts[2].numberings.numberings.is_empty());
+
+        let texts_at_level_zero: Vec<Vec<String>> =
+            docx.split(SplitBy::Heading(0)).iter().map(text).collect();
+        assert_eq!(texts_at_level_zero, texts);
+    }
+
+    #[test]
+    fn test_split_does_not_copy_document_ids() {
+        let docx = Docx::new()
+            .doc_id("3F2B6A1C")
+            .default_tab_stop(720)
+            .add_person(Person::new("Alice"))
+            .add_person(Person::new("Bob"))
+            .add_section(Section::new().add_paragraph(paragraph("plain")))
+            .add_paragraph(
+                paragraph("commented")
+                    .add_comment_start(Comment::new(1).author("Bob"))
+                    .add_comment_end(1),
+            );
+
+        let parts = docx.split(SplitBy::Section);
+        assert_eq!(parts.len(), 2);
+        for part in &parts {
+            assert_eq!(part.settings, Settings::new().default_tab_stop(720));
+        }
+        assert!(parts[0].people.is_empty());
+        assert_eq!(parts[1].people.people, vec![Person::new("Bob")]);
+    }
+
+    #[test]
+    fn test_split_keeps_header_images() {
+        let picture = |image| {
+            Paragraph::new().add_run(Run::new().add_image(Pic::new_with_dimensions(image, 1, 1)))
+        };
+        let mut buf = std::io::Cursor::new(vec![]);
+        Docx::new()
+            .add_style(Style::new("Heading1", StyleType::Paragraph).outline_lvl(0))
+            .header(Header::new().add_paragraph(picture(vec![1; 3])))
+            .add_paragraph(paragraph("Chapter 1").style("Heading1"))
+            .add_paragraph(picture(vec![2; 3]))
+            .add_paragraph(paragraph("Chapter 2").style("Heading1"))
+            .build()
+            .pack(&mut buf)
+            .unwrap();
+        let docx = crate::read_docx(&buf.into_inner()).unwrap();
+
+        let images: Vec<Vec<Vec<u8>>> = docx
+            .split(SplitBy::Heading(1))
+            .into_iter()
+            .map(packed_header_images)
+            .collect();
+        assert_eq!(images, vec![vec![vec![1; 3]], vec![vec![1; 3]]]);
+    }
+}
@@ -10,7 +10,13 @@ use crate::xml_builder::*;
 #[serde(rename_all = "camelCase")]
 pub struct Styles {
     pub doc_defaults: DocDefaults,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latent_styles: Option<LatentStyles>,
     pub styles: Vec<Style>,
+    // Adds Word's definition of built-in styles the document uses but does
+    // not define when it is built.
+    #[serde(skip)]
+    pub(crate) include_builtin_styles: bool,
 }
 
 impl Styles {
@@ -43,6 +49,45 @@ impl Styles {
         self
     }
 
+    pub fn latent_styles(mut self, latent_styles: LatentStyles) -> Self {
+        self.latent_styles = Some(latent_styles);
+        self
+    }
+
+    /// Writes Word's latent styles and, when the document is built, adds the
+    /// definition of every built-in style (see [`preset_styles::builtin_style`])
+    /// that paragraphs, runs or tables reference without defining it.
+    pub fn use_builtin_styles(mut self) -> Self {
+        self.latent_styles = Some(preset_styles::latent_styles());
+        self.include_builtin_styles = true;
+        self
+    }
+
+    /// Adds the built-in definitions of the given style ids that are not
+    /// defined yet, each preceded by the styles it is based on.
+    pub(crate) fn add_missing_builtin_styles<'a>(
+        &mut self,
+        ids: impl IntoIterator<Item = &'a str>,
+    ) {
+        for id in ids {
+            self.add_missing_builtin_style(id);
+        }
+    }
+
+    fn add_missing_builtin_style(&mut self, id: &str) {
+        if self.find_style_by_id(id).is_some() {
+            return;
+        }
+        // Built-in styles are based on other built-in styles only, in chains
+        // of at most two.
+        if let Some(style) = preset_styles::builtin_style(id) {
+            if let Some(base) = &style.based_on {
+                self.add_missing_builtin_style(base.get_val());
+            }
+            self.styles.push(style);
+        }
+    }
+
     pub(crate) fn doc_defaults(mut self, doc_defaults: DocDefaults) -> Self {
         self.doc_defaults = doc_defaults;
         self
@@ -52,6 +97,13 @@ impl Styles {
         self.styles.iter().find(|s| s.style_id == id)
     }
 
+    /// Returns the style marked `w:default` for the given type.
+    pub fn default_style(&self, style_type: StyleType) -> Option<&Style> {
+        self.styles
+            .iter()
+            .find(|s| s.is_default && s.style_type == style_type)
+    }
+
     pub fn create_heading_style_map(&self) -> std::collections::HashMap<String, usize> {
         self.styles
             .iter()
@@ -71,7 +123,9 @@ impl Default for Styles {
     fn default() -> Self {
         Self {
             doc_defaults: DocDefaults::new(),
+            latent_styles: None,
             styles: vec![],
+            include_builtin_styles: false,
         }
     }
 }
@@ -82,10 +136,12 @@ impl BuildXML for Styles {
         stream: crate::xml::writer::EventWriter<W>,
     ) -> crate::xml::writer::Result<crate::xml::writer::EventWriter<W>> {
         let normal = Style::new("Normal", StyleType::Paragraph).name("Normal");
+        let has_normal = self.find_style_by_id("Normal").is_some();
         XMLBuilder::from(stream)
             .open_styles()?
             .add_child(&self.doc_defaults)?
-            .add_child(&normal)?
+            .add_optional_child(&self.latent_styles)?
+            .apply_if(!has_normal, |b| b.add_child(&normal))?
             .add_children(&self.styles)?
             .close()?
             .into_inner()
@@ -0,0 +1,275 @@
+//! Creates documents from the look of an existing `.docx` or `.dotx`.
+//!
+//! A template contributes everything that defines how a document looks but
+//! none of its body: styles with the document defaults, numbering
+//! definitions, the theme, the font table, settings, the page setup of the
+//! final section and its headers and footers.
+
+use super::image_collector::fill_header_footer_images;
+use crate::documents::*;
+use crate::reader::{read_docx_with_options, ReadDocxOptions};
+use crate::ReaderError;
+
+impl Docx {
+    /// Creates an empty document that uses the styles, numbering, theme, font
+    /// table, settings, page setup, headers and footers of the given
+    /// `.dotx` or `.docx` package.
+    pub fn from_template(buf: &[u8]) -> Result<Docx, ReaderError> {
+        let template =
+            read_docx_with_options(buf, ReadDocxOptions::default().with_image_previews(false))?;
+        let mut section = template.document.section_property.clone();
+        fill_header_footer_images(&mut section, &template.header_footer_images);
+
+        let mut docx = Docx::new().import_styles_from(&template);
+        docx.document.section_property = SectionProperty {
+            header_reference: None,
+            header: None,
+            first_header_reference: None,
+            first_header: None,
+            even_header_reference: None,
+            even_header: None,
+            footer_reference: None,
+            footer: None,
+            first_footer_reference: None,
+            first_footer: None,
+            even_footer_reference: None,
+            even_footer: None,
+            ..section.clone()
+        };
+
+        if let Some((_, header)) = section.header {
+            docx = docx.header(header);
+        }
+        if let Some((_, header)) = section.first_header {
+            docx = docx.first_header(header);
+        }
+        if let Some((_, header)) = section.even_header {
+            docx = docx.even_header(header);
+        }
+        if let Some((_, footer)) = section.footer {
+            docx = docx.footer(footer);
+        }
+        if let Some((_, footer)) = section.first_footer {
+            docx = docx.first_footer(footer);
+        }
+        if let Some((_, footer)) = section.even_footer {
+            docx = docx.even_footer(footer);
+        }
+        // Adding a first page header turns on a different first page; keep
+        // the template's choice instead.
+        docx.document.section_property.title_pg = section.title_pg;
+        docx.settings = template.settings.without_doc_id();
+        Ok(docx)
+    }
+
+    /// Imports the styles, document defaults, numbering definitions, theme
+    /// and font table of `template`.
+    ///
+    /// Styles and numbering definitions replace the ones with the same id and
+    /// are added otherwise, so content already referring to them picks up the
+    /// template's formatting.
+    pub fn import_styles_from(mut self, template: &Docx) -> Self {
+        self.styles.doc_defaults = template.styles.doc_defaults.clone();
+        if template.styles.latent_styles.is_some() {
+            self.styles.latent_styles = template.styles.latent_styles.clone();
+        }
+        for style in &template.styles.styles {
+            self.styles.styles.retain(|s| s.style_id != style.style_id);
+            self.styles.styles.push(style.clone());
+        }
+
+        let numberings = &mut self.numberings;
+        for bullet in &template.numberings.num_pic_bullets {
+            numberings.num_pic_bullets.retain(|b| b.id != bullet.id);
+            numberings.num_pic_bullets.push(bullet.clone());
+        }
+        for abstract_num in &template.numberings.abstract_nums {
+            numberings.abstract_nums.retain(|a| a.id != abstract_num.id);
+            numberings.abstract_nums.push(abstract_num.clone());
+        }
+        for numbering in &template.numberings.numberings {
+            numberings.numberings.retain(|n| n.id != numbering.id);
+            numberings.numberings.push(numbering.clone());
+        }
+        // Styles may refer to numbering definitions without any paragraph
+        // doing so, so the part is written whenever one was imported.
+        if !template.numberings.numberings.is_empty() {
+            self.document_rels.has_numberings = true;
+        }
+
+        if !template.themes.is_empty() {
+            self.themes = template.themes.clone();
+        }
+        if !template.font_table.fonts.is_empty() {
+            self.font_table = template.font_table.clone();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::io::Cursor;
+
+    const THEME: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Office Theme"><a:themeElements><a:fontScheme name="Office"><a:majorFont><a:latin typeface="Calibri Light" /><a:ea typeface="" /><a:cs typeface="" /></a:majorFont><a:minorFont><a:latin typeface="Calibri" /><a:ea typeface="" /><a:cs typeface="" /></a:minorFont></a:fontScheme></a:themeElements></a:theme>"#;
+
+    fn pack(docx: Docx) -> Vec<u8> {
+        let mut cursor = Cursor::new(Vec::new());
+        docx.build().pack(&mut cursor).unwrap();
+        cursor.into_inner()
+    }
+
+    fn template() -> Docx {
+        let mut template =
+            Docx::new()
+                .page_size(11906, 16838)
+                .doc_id("3F1A6B22-0B5C-4D0E-9F21-5C8A1E6D7B90")
+                .default_tab_stop(720)
+                .default_size(21)
+                .add_style(
+                    Style::new("Heading1", StyleType::Paragraph)
+                        .name("heading 1")
+                        .size(40),
+                )
+                .add_abstract_numbering(AbstractNumbering::new(2).add_level(Level::new(
+                    0,
+                    Start::new(1),
+                    NumberFormat::new("decimal"),
+                    LevelText::new("%1."),
+                    LevelJc::new("left"),
+                )))
+                .add_numbering(Numbering::new(2, 2))
+                .header(Header::new().add_paragraph(
+                    Paragraph::new().add_run(Run::new().add_text("Template header")),
+                ))
+                .first_footer(Footer::new().add_paragraph(
+                    Paragraph::new().add_run(Run::new().add_text("First page footer")),
+                ))
+                .add_paragraph(
+                    Paragraph::new()
+                        .add_run(Run::new().add_text("Template body"))
+                        .numbering(NumberingId::new(2), IndentLevel::new(0)),
+                );
+        template.font_table = FontTable::new().add_font(FontDeclaration::new(
+            "Calibri",
+            "00",
+            "swiss",
+            FontPitchType::Variable,
+        ));
+        template.themes = vec![Theme {
+            raw_xml: THEME.as_bytes().to_vec(),
+            ..Default::default()
+        }];
+        template
+    }
+
+    #[test]
+    fn test_from_template() {
+        let docx = Docx::from_template(&pack(template())).unwrap();
+
+        assert!(docx.document.children.is_empty());
+        assert_eq!(
+            docx.document.section_property.page_size,
+            PageSize::new().size(11906, 16838)
+        );
+        assert_eq!(docx.styles.doc_defaults, DocDefaults::new().size(21));
+        assert!(docx
+            .styles
+            .styles
+            .iter()
+            .any(|s| s.style_id == "Heading1" && s.run_property.sz == Some(Sz::new(40))));
+        assert!(docx.numberings.abstract_nums.iter().any(|a| a.id == 2));
+        assert!(docx.numberings.numberings.iter().any(|n| n.id == 2));
+        assert_eq!(
+            docx.font_table.fonts,
+            vec![FontDeclaration::new(
+                "Calibri",
+                "00",
+                "swiss",
+                FontPitchType::Variable
+            )]
+        );
+        assert!(docx.document.section_property.header.is_some());
+        assert!(docx.document.section_property.first_footer.is_some());
+        assert!(docx.document.section_property.title_pg);
+        assert_eq!(
+            docx.settings,
+            Settings::new().default_tab_stop(720),
+            "the template's docId should not be copied"
+        );
+
+        let copy = crate::read_docx(&pack(
+            docx.add_paragraph(Paragraph::new().add_run(Run::new().add_text("New body"))),
+        ))
+        .unwrap();
+        assert_eq!(
+            copy.themes[0].raw_xml,
+            THEME.as_bytes().to_vec(),
+            "the theme part should be written"
+        );
+        assert_eq!(copy.themes[0].font_schema.major_font.latin, "Calibri Light");
+        assert_eq!(copy.document.children.len(), 1);
+        assert!(copy.document.section_property.header.is_some());
+    }
+
+    #[test]
+    fn test_from_template_keeps_header_images() {
+        let image = include_bytes!("../../../images/cat_min.jpg").to_vec();
+        let template = Docx::new().header(
+            Header::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_image(Pic::new(&image)))),
+        );
+        let docx = Docx::from_template(&pack(template)).unwrap();
+        let (_, header) = docx.document.section_property.header.as_ref().unwrap();
+        let HeaderChild::Paragraph(p) = &header.children[0] else {
+            panic!("header should contain a paragraph");
+        };
+        let ParagraphChild::Run(run) = &p.children[0] else {
+            panic!("paragraph should contain a run");
+        };
+        let RunChild::Drawing(drawing) = &run.children[0] else {
+            panic!("run should contain a drawing");
+        };
+        let Some(DrawingData::Pic(pic)) = &drawing.data else {
+            panic!("drawing should contain a picture");
+        };
+        assert!(!pic.image.is_empty());
+    }
+
+    #[test]
+    fn test_import_styles_from_replaces_same_ids() {
+        let template = Docx::new()
+            .add_style(
+                Style::new("Quote", StyleType::Paragraph)
+                    .name("Quote")
+                    .italic(),
+            )
+            .add_style(
+                Style::new("Strong", StyleType::Character)
+                    .name("Strong")
+                    .bold(),
+            );
+        let docx = Docx::new()
+            .add_style(Style::new("Quote", StyleType::Paragraph).name("Quote"))
+            .add_style(Style::new("Own", StyleType::Paragraph).name("Own"))
+            .import_styles_from(&template);
+
+        let ids: Vec<&str> = docx
+            .styles
+            .styles
+            .iter()
+            .map(|s| s.style_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["Own", "Quote", "Strong"]);
+        assert_eq!(
+            docx.styles.styles[1].run_property.italic,
+            Some(Italic::new())
+        );
+    }
+}
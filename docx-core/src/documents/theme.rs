@@ -8,4 +8,8 @@ use super::*;
 #[serde(rename_all = "camelCase")]
 pub struct Theme {
     pub font_schema: FontScheme,
+    /// The theme part as read from the package. It is written back unchanged
+    /// so documents created from a template keep its colors and fonts.
+    #[serde(skip)]
+    pub(crate) raw_xml: Vec<u8>,
 }
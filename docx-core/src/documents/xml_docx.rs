@@ -17,12 +17,20 @@ pub struct XMLDocx {
     pub settings: Vec<u8>,
     pub font_table: Vec<u8>,
     pub numberings: Vec<u8>,
+    /// Empty when the numbering part has no relationships.
+    pub numbering_rels: Vec<u8>,
     pub media: Vec<(String, Vec<u8>)>,
+    /// altChunk payloads keyed by their part name under `word/`.
+    pub alt_chunks: Vec<(String, Vec<u8>)>,
     pub headers: Vec<Vec<u8>>,
     pub header_rels: Vec<Vec<u8>>,
     pub footers: Vec<Vec<u8>>,
     pub footer_rels: Vec<Vec<u8>>,
     pub comments_extended: Vec<u8>,
+    /// `commentsIds.xml`, when the document has comments.
+    pub comments_ids: Option<Vec<u8>>,
+    pub comments_extensible: Option<Vec<u8>>,
+    pub people: Option<Vec<u8>>,
     pub taskpanes: Option<Vec<u8>>,
     pub taskpanes_rels: Vec<u8>,
     pub web_extensions: Vec<Vec<u8>>,
@@ -30,6 +38,7 @@ pub struct XMLDocx {
     pub custom_item_rels: Vec<Vec<u8>>,
     pub custom_item_props: Vec<Vec<u8>>,
     pub footnotes: Vec<u8>,
+    pub theme: Option<Vec<u8>>,
 }
 
 impl XMLDocx {
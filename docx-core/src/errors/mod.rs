@@ -11,3 +11,23 @@ pub enum DocxError {
     #[error("Unknown error")]
     Unknown,
 }
+
+#[derive(Error, Debug)]
+pub enum MailMergeError {
+    #[error("Failed to parse CSV: unterminated quote on line {0}.")]
+    CsvError(usize),
+    #[error("Merge data must be a JSON object or an array of JSON objects.")]
+    JsonShapeError,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum EditError {
+    #[error("No block at the location.")]
+    NotFound,
+    #[error("The block is not a paragraph.")]
+    NotParagraph,
+    #[error("The range or text position is out of bounds.")]
+    OutOfRange,
+    #[error("The container cannot hold the content.")]
+    Unsupported,
+}
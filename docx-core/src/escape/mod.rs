@@ -63,6 +63,63 @@ pub(crate) fn replace_escaped(text: &str) -> String {
     decoded
 }
 
+/// Decodes the text of a text node as [`replace_escaped`] does, and also the
+/// line feeds [`escape`] writes as `&#xA;`, so that the text matches what
+/// [`escaped_offset`] counts. Used when searching and replacing paragraph
+/// text, not when reading documents.
+pub(crate) fn unescape_text(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(index) = rest.find('&') {
+        decoded.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let (replacement, consumed) = entity(rest).unwrap_or(("&", 1));
+        decoded.push_str(replacement);
+        rest = &rest[consumed..];
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// The character an entity at the start of escaped text stands for, and the
+/// length of the entity.
+fn entity(text: &str) -> Option<(&'static str, usize)> {
+    [
+        ("&lt;", "<"),
+        ("&gt;", ">"),
+        ("&amp;", "&"),
+        ("&quot;", "\""),
+        ("&#39;", "'"),
+        ("&apos;", "'"),
+        ("&nbsp;", " "),
+        ("&#xA;", "\n"),
+    ]
+    .into_iter()
+    .find(|(entity, _)| text.starts_with(entity))
+    .map(|(entity, decoded)| (decoded, entity.len()))
+}
+
+/// Converts a byte offset in the text [`unescape_text`] decodes to the
+/// offset in the escaped text, so that it never falls inside an entity.
+pub(crate) fn escaped_offset(text: &str, offset: usize) -> usize {
+    let mut decoded = 0;
+    let mut pos = 0;
+    while decoded < offset && pos < text.len() {
+        let rest = &text[pos..];
+        let (len, consumed) = match entity(rest) {
+            Some((decoded, consumed)) => (decoded.len(), consumed),
+            None => {
+                let len = rest.chars().next().map_or(1, char::len_utf8);
+                (len, len)
+            }
+        };
+        decoded += len;
+        pos += consumed;
+    }
+    pos
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +156,23 @@ mod tests {
         assert_eq!(replace_escaped("&amp;lt;"), "&lt;");
         assert_eq!(replace_escaped("plain &unknown;"), "plain &unknown;");
     }
+
+    #[test]
+    fn maps_unescaped_offsets_to_escaped_ones() {
+        let text = "AT&amp;T &lt;b&gt;";
+        assert_eq!(unescape_text(text), "AT&T <b>");
+        assert_eq!(escaped_offset(text, 2), 2);
+        assert_eq!(escaped_offset(text, 3), 7);
+        assert_eq!(escaped_offset(text, 5), 9);
+        assert_eq!(escaped_offset(text, 6), 13);
+        assert_eq!(escaped_offset(text, 8), text.len());
+    }
+
+    #[test]
+    fn decodes_line_feeds_only_in_unescaped_text() {
+        let text = escape("a\nb");
+        assert_eq!(replace_escaped(&text), "a&#xA;b");
+        assert_eq!(unescape_text(&text), "a\nb");
+        assert_eq!(escaped_offset(&text, 2), 6);
+    }
 }
@@ -1,5 +1,5 @@
 use crate::line_spacing_type::LineSpacingType;
-use crate::reader::OwnedAttribute;
+use crate::reader::{is_false, OwnedAttribute};
 use crate::LineSpacing;
 use crate::ReaderError;
 use std::str::FromStr;
@@ -27,6 +27,12 @@ pub fn read_line_spacing(attributes: &[OwnedAttribute]) -> Result<LineSpacing, R
             "afterLines" => {
                 spacing = spacing.after_lines(f64::from_str(&a.value)? as u32);
             }
+            "beforeAutospacing" => {
+                spacing = spacing.before_autospacing(!is_false(&a.value));
+            }
+            "afterAutospacing" => {
+                spacing = spacing.after_autospacing(!is_false(&a.value));
+            }
             _ => {}
         }
     }
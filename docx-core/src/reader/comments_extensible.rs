@@ -0,0 +1,40 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use super::*;
+
+impl FromXML for CommentsExtensible {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut comments = CommentsExtensible::new();
+        loop {
+            let e = r.next_event();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    if let Ok(XMLElement::CommentExtensible) =
+                        XMLElement::from_str(&name.local_name)
+                    {
+                        let Some(durable_id) = read(&attributes, "durableId") else {
+                            continue;
+                        };
+                        let mut c = CommentExtensible::new(durable_id);
+                        if let Some(date) = read(&attributes, "dateUtc") {
+                            c = c.date_utc(date);
+                        }
+                        if let Some(v) = read(&attributes, "intelligentPlaceholder") {
+                            if !is_false(&v) {
+                                c = c.intelligent_placeholder();
+                            }
+                        }
+                        comments = comments.add_comment_extensible(c);
+                    }
+                }
+                Ok(XmlEvent::EndDocument) => return Ok(comments),
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use super::*;
+
+impl FromXML for CommentsIds {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut ids = CommentsIds::new();
+        loop {
+            let e = r.next_event();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    if let Ok(XMLElement::CommentId) = XMLElement::from_str(&name.local_name) {
+                        if let (Some(para_id), Some(durable_id)) =
+                            (read(&attributes, "paraId"), read(&attributes, "durableId"))
+                        {
+                            ids = ids.add_comment_id(CommentId::new(para_id, durable_id));
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndDocument) => return Ok(ids),
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use std::io::Read;
 use std::str::FromStr;
 
 use crate::reader::*;
+use crate::types::AltChunkFormat;
 
 use super::{Paragraph, Table};
 
@@ -65,6 +66,15 @@ impl FromXML for Document {
                             }
                             continue;
                         }
+                        XMLElement::AltChunk => {
+                            // The payload is read from the part the id refers to.
+                            if let Some(id) = read(&attributes, "id") {
+                                let mut chunk = AltChunk::new(AltChunkFormat::Html, vec![]);
+                                chunk.id = id;
+                                doc = doc.add_alt_chunk(chunk);
+                            }
+                            continue;
+                        }
                         _ => {}
                     }
                 }
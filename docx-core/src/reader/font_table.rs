@@ -0,0 +1,105 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use super::*;
+use crate::types::*;
+
+impl FromXML for FontTable {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut parser = EventReader::new(reader);
+        let mut font_table = Self::default();
+        let mut font: Option<FontDeclaration> = None;
+        loop {
+            let e = parser.next_event();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    match e {
+                        XMLElement::Font => {
+                            font = read_name(&attributes).map(|n| {
+                                FontDeclaration::new(n, "00", "auto", FontPitchType::Default)
+                            });
+                        }
+                        XMLElement::Charset => {
+                            if let (Some(f), Some(v)) = (font.as_mut(), read_val(&attributes)) {
+                                f.charset = v;
+                            }
+                        }
+                        XMLElement::Family => {
+                            if let (Some(f), Some(v)) = (font.as_mut(), read_val(&attributes)) {
+                                f.family = v;
+                            }
+                        }
+                        XMLElement::Pitch => {
+                            if let (Some(f), Some(v)) = (font.as_mut(), read_val(&attributes)) {
+                                f.pitch = FontPitchType::from_str(&v)?;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    match e {
+                        XMLElement::Font => {
+                            if let Some(f) = font.take() {
+                                font_table = font_table.add_font(f);
+                            }
+                        }
+                        XMLElement::Fonts => break,
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndDocument) => break,
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+        Ok(font_table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_font_table_from_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:fonts xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:font w:name="Calibri">
+    <w:panose1 w:val="020F0502020204030204" />
+    <w:charset w:val="00" />
+    <w:family w:val="swiss" />
+    <w:pitch w:val="variable" />
+  </w:font>
+  <w:font w:name="MS Mincho">
+    <w:charset w:val="80" />
+    <w:family w:val="modern" />
+    <w:pitch w:val="fixed" />
+  </w:font>
+</w:fonts>"#;
+        let font_table = FontTable::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            font_table,
+            FontTable::new()
+                .add_font(FontDeclaration::new(
+                    "Calibri",
+                    "00",
+                    "swiss",
+                    FontPitchType::Variable
+                ))
+                .add_font(FontDeclaration::new(
+                    "MS Mincho",
+                    "80",
+                    "modern",
+                    FontPitchType::Fixed
+                ))
+        );
+    }
+}
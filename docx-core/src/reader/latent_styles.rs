@@ -0,0 +1,69 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use super::*;
+
+fn read_usize(attrs: &[OwnedAttribute], name: &str) -> Option<usize> {
+    read(attrs, name).and_then(|v| v.parse().ok())
+}
+
+fn read_on_off(attrs: &[OwnedAttribute], name: &str) -> Option<bool> {
+    read(attrs, name).map(|v| !is_false(&v))
+}
+
+impl ElementReader for LatentStyles {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut latent_styles = LatentStyles::new();
+        if let Some(v) = read_on_off(attrs, "defLockedState") {
+            latent_styles = latent_styles.def_locked_state(v);
+        }
+        if let Some(v) = read_usize(attrs, "defUIPriority") {
+            latent_styles = latent_styles.def_ui_priority(v);
+        }
+        if let Some(v) = read_on_off(attrs, "defSemiHidden") {
+            latent_styles = latent_styles.def_semi_hidden(v);
+        }
+        if let Some(v) = read_on_off(attrs, "defUnhideWhenUsed") {
+            latent_styles = latent_styles.def_unhide_when_used(v);
+        }
+        if let Some(v) = read_on_off(attrs, "defQFormat") {
+            latent_styles = latent_styles.def_q_format(v);
+        }
+        if let Some(v) = read_usize(attrs, "count") {
+            latent_styles = latent_styles.count(v);
+        }
+        loop {
+            let e = r.next_event();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if let XMLElement::LsdException = e {
+                        if let Some(name) = read_name(&attributes) {
+                            latent_styles = latent_styles.add_exception(LatentStyleException {
+                                name,
+                                locked: read_on_off(&attributes, "locked"),
+                                ui_priority: read_usize(&attributes, "uiPriority"),
+                                semi_hidden: read_on_off(&attributes, "semiHidden"),
+                                unhide_when_used: read_on_off(&attributes, "unhideWhenUsed"),
+                                q_format: read_on_off(&attributes, "qFormat"),
+                            });
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if let XMLElement::LatentStyles = e {
+                        return Ok(latent_styles);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -26,6 +26,7 @@ impl ElementReader for Level {
         let mut has_indent = false;
         let mut suffix = LevelSuffixType::Tab;
         let mut is_lgl = None;
+        let mut pic_bullet_id = None;
 
         loop {
             let e = r.next_event();
@@ -71,6 +72,10 @@ impl ElementReader for Level {
                                 level_restart = Some(LevelRestart::new(v));
                             }
                         }
+                        XMLElement::LevelPicBulletId => {
+                            pic_bullet_id =
+                                read_val(&attributes).and_then(|v| usize::from_str(&v).ok());
+                        }
                         XMLElement::LevelJustification => {
                             jc = LevelJc::new(attributes[0].value.clone());
                         }
@@ -100,6 +105,7 @@ impl ElementReader for Level {
                         l.run_property = rpr;
                         l.level_restart = level_restart;
                         l.is_lgl = is_lgl;
+                        l.pic_bullet_id = pic_bullet_id;
                         return Ok(l);
                     }
                 }
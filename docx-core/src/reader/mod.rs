@@ -9,6 +9,8 @@ mod comment;
 mod comment_extended;
 mod comments;
 mod comments_extended;
+mod comments_extensible;
+mod comments_ids;
 mod custom_properties;
 mod delete;
 mod div;
@@ -20,6 +22,7 @@ mod drawing;
 mod errors;
 mod font_group;
 mod font_scheme;
+mod font_table;
 mod footer;
 mod frame_property;
 mod from_xml;
@@ -28,12 +31,14 @@ mod header_or_footer_rels;
 mod hyperlink;
 mod ignore;
 mod insert;
+mod latent_styles;
 mod level;
 mod level_override;
 mod mc_fallback;
 mod move_from;
 mod move_to;
 mod namespace;
+mod num_pic_bullet;
 mod numbering_property;
 mod numberings;
 mod page_num_type;
@@ -41,6 +46,7 @@ mod paragraph;
 mod paragraph_borders;
 mod paragraph_property;
 mod paragraph_property_change;
+mod people;
 mod pic;
 mod positional_tab;
 mod read_docx;
@@ -49,6 +55,7 @@ mod read_zip;
 mod rels;
 mod run;
 mod run_property;
+mod run_property_change;
 mod section_property;
 mod settings;
 mod shading;
@@ -66,8 +73,10 @@ mod table_cell_property;
 mod table_position_property;
 mod table_property;
 mod table_row;
+mod table_style_override;
 mod tabs;
 mod text_box_content;
+mod text_effects;
 mod theme;
 mod web_settings;
 mod wp_anchor;
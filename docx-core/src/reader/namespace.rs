@@ -17,12 +17,24 @@ pub(crate) const HEADER_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/header";
 pub(crate) const FOOTER_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footer";
+pub(crate) const FONT_TABLE_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/fontTable";
 pub(crate) const THEME_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme";
 pub(crate) const IMAGE_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image";
 pub(crate) const HYPERLINK_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink";
+pub(crate) const ALT_CHUNK_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/aFChunk";
 // 2011
 pub(crate) const COMMENTS_EXTENDED_TYPE: &str =
     "http://schemas.microsoft.com/office/2011/relationships/commentsExtended";
+pub(crate) const PEOPLE_TYPE: &str =
+    "http://schemas.microsoft.com/office/2011/relationships/people";
+// 2016
+pub(crate) const COMMENTS_IDS_TYPE: &str =
+    "http://schemas.microsoft.com/office/2016/09/relationships/commentsIds";
+// 2018
+pub(crate) const COMMENTS_EXTENSIBLE_TYPE: &str =
+    "http://schemas.microsoft.com/office/2018/08/relationships/commentsExtensible";
@@ -0,0 +1,85 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use super::*;
+
+// Parse a VML length such as `9pt` or `0.125in` into points.
+fn parse_pt(v: &str) -> Option<f32> {
+    let v = v.trim();
+    let (num, factor) = if let Some(n) = v.strip_suffix("pt") {
+        (n, 1.0)
+    } else if let Some(n) = v.strip_suffix("in") {
+        (n, 72.0)
+    } else if let Some(n) = v.strip_suffix("px") {
+        (n, 0.75)
+    } else {
+        (v, 1.0)
+    };
+    f32::from_str(num.trim()).ok().map(|n| n * factor)
+}
+
+fn read_shape_size(style: &str) -> (Option<f32>, Option<f32>) {
+    let mut size = (None, None);
+    for decl in style.split(';') {
+        if let Some((k, v)) = decl.split_once(':') {
+            match k.trim() {
+                "width" => size.0 = parse_pt(v),
+                "height" => size.1 = parse_pt(v),
+                _ => {}
+            }
+        }
+    }
+    size
+}
+
+impl ElementReader for NumPicBullet {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let id = read(attrs, "numPicBulletId")
+            .and_then(|v| usize::from_str(&v).ok())
+            .unwrap_or_default();
+        let mut bullet = NumPicBullet::new(id, vec![]).pic_id("");
+        loop {
+            let e = r.next_event();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => match name.prefix.as_deref() {
+                    Some("v") => match VXMLElement::from_str(&name.local_name) {
+                        Ok(VXMLElement::Shape) => {
+                            if let Some(style) = read(&attributes, "style") {
+                                let (w, h) = read_shape_size(&style);
+                                bullet.size =
+                                    (w.unwrap_or(bullet.size.0), h.unwrap_or(bullet.size.1));
+                            }
+                        }
+                        Ok(VXMLElement::ImageData) => {
+                            if let Some(id) = read(&attributes, "id") {
+                                bullet = bullet.pic_id(id);
+                            }
+                        }
+                        _ => {}
+                    },
+                    // Word 2010+ may store the bullet as a DrawingML picture instead.
+                    Some("a") => {
+                        if let Ok(AXMLElement::Blip) = AXMLElement::from_str(&name.local_name) {
+                            if let Some(id) = read(&attributes, "embed") {
+                                bullet = bullet.pic_id(id);
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    if let Ok(XMLElement::NumPicBullet) = XMLElement::from_str(&name.local_name) {
+                        return Ok(bullet);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -10,6 +10,7 @@ impl ElementReader for NumberingProperty {
     ) -> Result<Self, ReaderError> {
         let mut id: Option<usize> = None;
         let mut level: Option<usize> = None;
+        let mut ins: Option<Insert> = None;
         loop {
             let e = r.next_event();
             match e {
@@ -26,27 +27,29 @@ impl ElementReader for NumberingProperty {
                             id = Some(usize::from_str(&attributes[0].value)?);
                             continue;
                         }
+                        XMLElement::Insert => {
+                            if let Ok(i) = Insert::read(r, &attributes) {
+                                ins = Some(i);
+                            }
+                            continue;
+                        }
                         _ => {}
                     }
                 }
                 Ok(XmlEvent::EndElement { name, .. }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
                     if e == XMLElement::NumberingProperty {
-                        match (id, level) {
-                            (Some(id), Some(level)) => {
-                                return Ok(NumberingProperty::new()
-                                    .add_num(NumberingId::new(id), IndentLevel::new(level)));
-                            }
-                            (Some(id), None) => {
-                                return Ok(NumberingProperty::new().id(NumberingId::new(id)));
-                            }
+                        let mut np = match (id, level) {
+                            (Some(id), Some(level)) => NumberingProperty::new()
+                                .add_num(NumberingId::new(id), IndentLevel::new(level)),
+                            (Some(id), None) => NumberingProperty::new().id(NumberingId::new(id)),
                             (None, Some(level)) => {
-                                return Ok(NumberingProperty::new().level(IndentLevel::new(level)));
-                            }
-                            (None, None) => {
-                                return Ok(NumberingProperty::new());
+                                NumberingProperty::new().level(IndentLevel::new(level))
                             }
-                        }
+                            (None, None) => NumberingProperty::new(),
+                        };
+                        np.ins = ins;
+                        return Ok(np);
                     }
                 }
                 Err(_) => return Err(ReaderError::XMLReadError),
@@ -16,6 +16,12 @@ impl FromXML for Numberings {
                 }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
                     match e {
+                        XMLElement::NumPicBullet => {
+                            if let Ok(b) = NumPicBullet::read(&mut parser, &attributes) {
+                                nums = nums.add_num_pic_bullet(b);
+                            }
+                            continue;
+                        }
                         XMLElement::AbstractNumbering => {
                             let mut id = 0;
                             for a in attributes {
@@ -245,4 +251,53 @@ mod tests {
             .add_numbering(num);
         assert_eq!(n, nums)
     }
+
+    #[test]
+    fn test_numberings_from_xml_with_pic_bullet() {
+        let xml = r##"<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+            xmlns:v="urn:schemas-microsoft-com:vml"
+            xmlns:o="urn:schemas-microsoft-com:office:office"
+            xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+    <w:numPicBullet w:numPicBulletId="0">
+        <w:pict>
+            <v:shape id="_x0000_i1025" type="#_x0000_t75" style="width:11.25pt;height:11.25pt" o:bullet="t">
+                <v:imagedata r:id="rId1" o:title="bullet"/>
+            </v:shape>
+        </w:pict>
+    </w:numPicBullet>
+    <w:abstractNum w:abstractNumId="0">
+        <w:lvl w:ilvl="0">
+            <w:start w:val="1"/>
+            <w:numFmt w:val="bullet"/>
+            <w:lvlText w:val="o"/>
+            <w:lvlPicBulletId w:val="0"/>
+            <w:lvlJc w:val="left"/>
+        </w:lvl>
+    </w:abstractNum>
+    <w:num w:numId="1">
+        <w:abstractNumId w:val="0"></w:abstractNumId>
+    </w:num>
+</w:numbering>"##;
+        let n = Numberings::from_xml(xml.as_bytes()).unwrap();
+        let nums = Numberings::new()
+            .add_num_pic_bullet(
+                NumPicBullet::new(0, vec![])
+                    .pic_id("rId1")
+                    .size(11.25, 11.25),
+            )
+            .add_abstract_numbering(
+                AbstractNumbering::new(0).add_level(
+                    Level::new(
+                        0,
+                        Start::new(1),
+                        NumberFormat::new("bullet"),
+                        LevelText::new("o"),
+                        LevelJc::new("left"),
+                    )
+                    .pic_bullet(0),
+                ),
+            )
+            .add_numbering(Numbering::new(1, 0));
+        assert_eq!(n, nums)
+    }
 }
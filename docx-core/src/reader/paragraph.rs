@@ -292,6 +292,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_extended_paragraph_property() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:p>
+        <w:pPr>
+            <w:numPr>
+                <w:ilvl w:val="0"/>
+                <w:numId w:val="1"/>
+                <w:ins w:id="3" w:author="bokuweb" w:date="2024-01-01T00:00:00Z"/>
+            </w:numPr>
+            <w:suppressLineNumbers/>
+            <w:suppressAutoHyphens/>
+            <w:wordWrap w:val="0"/>
+            <w:bidi/>
+            <w:spacing w:before="100" w:beforeAutospacing="1" w:afterAutospacing="1"/>
+            <w:contextualSpacing/>
+            <w:mirrorIndents/>
+            <w:suppressOverlap/>
+            <w:textboxTightWrap w:val="allLines"/>
+            <w:divId w:val="1234"/>
+            <w:cnfStyle w:val="100000000000"/>
+        </w:pPr>
+    </w:p>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let p = Paragraph::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            p.property,
+            ParagraphProperty::new()
+                .numbering_property(
+                    NumberingProperty::new()
+                        .add_num(NumberingId::new(1), IndentLevel::new(0))
+                        .insert(
+                            Insert::new_with_empty()
+                                .author("bokuweb")
+                                .date("2024-01-01T00:00:00Z")
+                        )
+                )
+                .suppress_line_numbers(true)
+                .suppress_auto_hyphens(true)
+                .word_wrap(false)
+                .bidi(true)
+                .line_spacing(
+                    LineSpacing::new()
+                        .before(100)
+                        .before_autospacing(true)
+                        .after_autospacing(true)
+                )
+                .contextual_spacing(true)
+                .mirror_indents(true)
+                .suppress_overlap(true)
+                .textbox_tight_wrap(TextboxTightWrapType::AllLines)
+                .div_id("1234")
+                .cnf_style(CnfStyle::new("100000000000"))
+        );
+    }
+
     #[test]
     fn test_read_insert() {
         let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
@@ -115,6 +115,61 @@ impl ElementReader for ParagraphProperty {
                                 p.widow_control = Some(true);
                             }
                         }
+                        XMLElement::ContextualSpacing => {
+                            p.contextual_spacing = Some(read_bool(&attributes));
+                        }
+                        XMLElement::MirrorIndents => {
+                            p.mirror_indents = Some(read_bool(&attributes));
+                        }
+                        XMLElement::SuppressAutoHyphens => {
+                            p.suppress_auto_hyphens = Some(read_bool(&attributes));
+                        }
+                        XMLElement::SuppressLineNumbers => {
+                            p.suppress_line_numbers = Some(read_bool(&attributes));
+                        }
+                        XMLElement::SuppressOverlap => {
+                            p.suppress_overlap = Some(read_bool(&attributes));
+                        }
+                        XMLElement::WordWrap => {
+                            p.word_wrap = Some(read_bool(&attributes));
+                        }
+                        XMLElement::Kinsoku => {
+                            p.kinsoku = Some(read_bool(&attributes));
+                        }
+                        XMLElement::OverflowPunct => {
+                            p.overflow_punct = Some(read_bool(&attributes));
+                        }
+                        XMLElement::TopLinePunct => {
+                            p.top_line_punct = Some(read_bool(&attributes));
+                        }
+                        XMLElement::AutoSpaceDE => {
+                            p.auto_space_de = Some(read_bool(&attributes));
+                        }
+                        XMLElement::AutoSpaceDN => {
+                            p.auto_space_dn = Some(read_bool(&attributes));
+                        }
+                        XMLElement::Bidi => {
+                            p.bidi = Some(read_bool(&attributes));
+                        }
+                        XMLElement::TextDirection => {
+                            if let Some(v) = read_val(&attributes) {
+                                if let Ok(t) = TextDirectionType::from_str(&v) {
+                                    p = p.text_direction(t);
+                                }
+                            }
+                        }
+                        XMLElement::TextboxTightWrap => {
+                            if let Some(v) = read_val(&attributes) {
+                                if let Ok(t) = TextboxTightWrapType::from_str(&v) {
+                                    p = p.textbox_tight_wrap(t);
+                                }
+                            }
+                        }
+                        XMLElement::CnfStyle => {
+                            if let Some(v) = read_val(&attributes) {
+                                p = p.cnf_style(CnfStyle::new(v));
+                            }
+                        }
                         XMLElement::ParagraphPropertyChange => {
                             if let Ok(ppr_change) = ParagraphPropertyChange::read(r, &attributes) {
                                 p.paragraph_property_change = Some(ppr_change);
@@ -0,0 +1,65 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use super::*;
+
+impl FromXML for People {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut people = People::new();
+        loop {
+            let e = r.next_event();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => match XMLElement::from_str(&name.local_name) {
+                    Ok(XMLElement::Person) => {
+                        if let Some(author) = read(&attributes, "author") {
+                            people = people.add_person(Person::new(author));
+                        }
+                    }
+                    Ok(XMLElement::PresenceInfo) => {
+                        if let (Some(person), Some(provider_id), Some(user_id)) = (
+                            people.people.last_mut(),
+                            read(&attributes, "providerId"),
+                            read(&attributes, "userId"),
+                        ) {
+                            person.presence_info = Some(PresenceInfo {
+                                provider_id,
+                                user_id,
+                            });
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(XmlEvent::EndDocument) => return Ok(people),
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_people() {
+        let xml = r#"<w15:people xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml">
+  <w15:person w15:author="Alice">
+    <w15:presenceInfo w15:providerId="AD" w15:userId="S::alice@example.com::1"/>
+  </w15:person>
+  <w15:person w15:author="Bob"/>
+</w15:people>"#;
+        assert_eq!(
+            People::from_xml(xml.as_bytes()).unwrap(),
+            People::new()
+                .add_person(Person::new("Alice").presence_info("AD", "S::alice@example.com::1"))
+                .add_person(Person::new("Bob"))
+        );
+    }
+}
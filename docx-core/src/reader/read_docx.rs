@@ -2,8 +2,11 @@ use super::header_or_footer_rels::{read_header_or_footer_rels, ReadHeaderOrFoote
 use super::namespace::*;
 use super::*;
 
+use std::path::Path;
 use zip::ZipArchive;
 
+use crate::types::AltChunkFormat;
+
 fn read_headers(
     rels: &ReadDocumentRels,
     archive: &mut ZipArchive<Cursor<&[u8]>>,
@@ -56,7 +59,8 @@ fn read_themes(rels: &ReadDocumentRels, archive: &mut ZipArchive<Cursor<&[u8]>>)
         .filter_map(|(_rid, path, ..)| {
             let data = read_zip(archive, path.to_str().expect("should have footer path."));
             if let Ok(d) = data {
-                if let Ok(h) = Theme::from_xml(&d[..]) {
+                if let Ok(mut h) = Theme::from_xml(&d[..]) {
+                    h.raw_xml = d;
                     return Some(h);
                 }
             }
@@ -65,6 +69,20 @@ fn read_themes(rels: &ReadDocumentRels, archive: &mut ZipArchive<Cursor<&[u8]>>)
         .collect()
 }
 
+/// Reads an optional part related to the document, falling back to an empty
+/// part when it is missing or malformed.
+fn read_optional_part<T: FromXML + Default>(
+    rels: &ReadDocumentRels,
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    rel_type: &str,
+) -> T {
+    rels.target_paths(rel_type)
+        .and_then(|paths| paths.iter().next().cloned())
+        .and_then(|(_, path, ..)| read_zip(archive, path.to_str()?).ok())
+        .and_then(|data| T::from_xml(&data[..]).ok())
+        .unwrap_or_default()
+}
+
 /// Controls optional work performed while reading a DOCX package.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[non_exhaustive]
@@ -102,7 +120,7 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
     let mut archive = zip::ZipArchive::new(cur)?;
     // First, the content type for relationship parts and the Main Document part
     // (the only required part) must be defined (physically located at /[Content_Types].xml in the package)
-    let _content_types = {
+    let content_types = {
         let data = read_zip(&mut archive, "[Content_Types].xml")?;
         ContentTypes::from_xml(&data[..])?
     };
@@ -142,6 +160,11 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
 
     docx.themes = read_themes(&rels, &mut archive);
 
+    let comments_ids: CommentsIds = read_optional_part(&rels, &mut archive, COMMENTS_IDS_TYPE);
+    let comments_extensible: CommentsExtensible =
+        read_optional_part(&rels, &mut archive, COMMENTS_EXTENSIBLE_TYPE);
+    let people: People = read_optional_part(&rels, &mut archive, PEOPLE_TYPE);
+
     // Read commentsExtended
     let comments_extended_path = rels.target_paths(COMMENTS_EXTENDED_TYPE);
     let comments_extended = if let Some(comments_extended_path) = comments_extended_path {
@@ -228,6 +251,7 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
         Document::from_xml(&data[..])?
     };
     docx = docx.document(document);
+    read_alt_chunks(&mut docx.document, &rels, &content_types, &mut archive);
 
     // assign headers
     if let Some(h) = docx.document.section_property.header_reference.clone() {
@@ -237,8 +261,13 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
             docx.document_rels.header_count = count;
             docx.content_type = docx.content_type.add_header();
             // Read media
-            let media = rels.target_paths(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive, options.generate_image_previews);
+            docx = add_part_images(
+                docx,
+                &h.id,
+                rels,
+                &mut archive,
+                options.generate_image_previews,
+            );
         }
     }
     if let Some(ref h) = docx
@@ -255,8 +284,13 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
             docx.document_rels.header_count = count;
             docx.content_type = docx.content_type.add_header();
             // Read media
-            let media = rels.target_paths(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive, options.generate_image_previews);
+            docx = add_part_images(
+                docx,
+                &h.id,
+                rels,
+                &mut archive,
+                options.generate_image_previews,
+            );
         }
     }
     if let Some(ref h) = docx.document.section_property.even_header_reference.clone() {
@@ -267,8 +301,13 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
             docx.content_type = docx.content_type.add_header();
 
             // Read media
-            let media = rels.target_paths(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive, options.generate_image_previews);
+            docx = add_part_images(
+                docx,
+                &h.id,
+                rels,
+                &mut archive,
+                options.generate_image_previews,
+            );
         }
     }
 
@@ -281,8 +320,13 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
             docx.content_type = docx.content_type.add_footer();
 
             // Read media
-            let media = rels.target_paths(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive, options.generate_image_previews);
+            docx = add_part_images(
+                docx,
+                &f.id,
+                rels,
+                &mut archive,
+                options.generate_image_previews,
+            );
         }
     }
 
@@ -301,8 +345,13 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
             docx.content_type = docx.content_type.add_footer();
 
             // Read media
-            let media = rels.target_paths(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive, options.generate_image_previews);
+            docx = add_part_images(
+                docx,
+                &f.id,
+                rels,
+                &mut archive,
+                options.generate_image_previews,
+            );
         }
     }
     if let Some(ref f) = docx.document.section_property.even_footer_reference.clone() {
@@ -313,8 +362,13 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
             docx.content_type = docx.content_type.add_footer();
 
             // Read media
-            let media = rels.target_paths(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive, options.generate_image_previews);
+            docx = add_part_images(
+                docx,
+                &f.id,
+                rels,
+                &mut archive,
+                options.generate_image_previews,
+            );
         }
     }
 
@@ -324,6 +378,10 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
         docx = docx.comments(comments);
         docx = docx.comments_extended(comments_extended);
     }
+    docx = docx
+        .comments_ids(comments_ids)
+        .comments_extensible(comments_extensible)
+        .people(people);
 
     // Read document relationships
     // Read styles
@@ -347,7 +405,11 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
                 &mut archive,
                 num_path.to_str().expect("should have numberings"),
             )?;
-            let nums = Numberings::from_xml(&data[..])?;
+            let mut nums = Numberings::from_xml(&data[..])?;
+            if !nums.num_pic_bullets.is_empty() {
+                let rels = read_header_or_footer_rels(&mut archive, num_path).unwrap_or_default();
+                read_num_pic_bullet_images(&mut nums, &rels, &mut archive);
+            }
             docx = docx.numberings(nums);
         }
     }
@@ -379,6 +441,18 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
             docx = docx.web_settings(web_settings);
         }
     }
+    // Read font table
+    let font_table_path = rels.target_paths(FONT_TABLE_TYPE);
+    if let Some(paths) = font_table_path {
+        if let Some((_, font_table_path, ..)) = paths.first() {
+            let data = read_zip(
+                &mut archive,
+                font_table_path.to_str().expect("should have font table"),
+            )?;
+            docx.font_table = FontTable::from_xml(&data[..])?;
+        }
+    }
+
     // Read media
     let media = rels.target_paths(IMAGE_TYPE);
     docx = add_images(docx, media, &mut archive, options.generate_image_previews);
@@ -400,6 +474,64 @@ pub fn read_docx_with_options(buf: &[u8], options: ReadDocxOptions) -> Result<Do
     Ok(docx)
 }
 
+/// Fills in the format and payload of the altChunks in the body from the
+/// parts their relationships point to.
+fn read_alt_chunks(
+    document: &mut Document,
+    rels: &ReadDocumentRels,
+    content_types: &ContentTypes,
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+) {
+    let Some(paths) = rels.target_paths(ALT_CHUNK_TYPE) else {
+        return;
+    };
+    for child in &mut document.children {
+        let DocumentChild::AltChunk(chunk) = child else {
+            continue;
+        };
+        let Some((_, path, ..)) = paths.iter().find(|(id, ..)| *id == chunk.id) else {
+            continue;
+        };
+        let Some(path) = path.to_str() else {
+            continue;
+        };
+        let format = content_types
+            .content_type(&format!("/{path}"))
+            .and_then(AltChunkFormat::from_content_type)
+            .or_else(|| {
+                Path::new(path)
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .and_then(AltChunkFormat::from_extension)
+            });
+        if let Some(format) = format {
+            chunk.format = format;
+        }
+        if let Ok(data) = read_zip(archive, path) {
+            chunk.data = data;
+        }
+    }
+}
+
+// Picture bullets keep their own bytes so they are written back with numbering.xml.
+fn read_num_pic_bullet_images(
+    nums: &mut Numberings,
+    rels: &ReadHeaderOrFooterRels,
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+) {
+    let Some(media) = rels.target_paths(IMAGE_TYPE) else {
+        return;
+    };
+    for bullet in &mut nums.num_pic_bullets {
+        let path = media.iter().find(|(id, ..)| *id == bullet.pic_id);
+        if let Some((_, path, ..)) = path {
+            if let Ok(data) = read_zip(archive, path.to_str().expect("should have media")) {
+                bullet.image = data;
+            }
+        }
+    }
+}
+
 fn add_images(
     mut docx: Docx,
     media: Option<&std::collections::BTreeSet<(RId, PathBuf, Option<String>)>>,
@@ -422,6 +554,33 @@ fn add_images(
     docx
 }
 
+/// Adds the images of a header or footer part like [`add_images`] and also
+/// keeps them by the part, since their relationship ids are scoped to it.
+fn add_part_images(
+    mut docx: Docx,
+    part: &str,
+    rels: &ReadHeaderOrFooterRels,
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    generate_previews: bool,
+) -> Docx {
+    let mut images = HashMap::new();
+    for (id, media, ..) in rels.target_paths(IMAGE_TYPE).into_iter().flatten() {
+        if let Ok(data) = read_zip(archive, media.to_str().expect("should have media")) {
+            images.insert(id.clone(), data.clone());
+            docx = docx.add_image_with_options(
+                id.clone(),
+                media.to_str().unwrap().to_string(),
+                data,
+                generate_previews,
+            );
+        }
+    }
+    if !images.is_empty() {
+        docx.header_footer_images.insert(part.to_owned(), images);
+    }
+    docx
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -442,6 +601,40 @@ mod tests {
         assert!(preview.0.is_empty());
     }
 
+    #[test]
+    fn read_alt_chunks_with_payload() {
+        let mut buf = Cursor::new(Vec::new());
+        Docx::new()
+            .add_alt_chunk(AltChunk::html("<p>Hi</p>"))
+            .add_paragraph(Paragraph::new())
+            .add_alt_chunk(AltChunk::rtf(b"{\\rtf1 Hi}".to_vec()))
+            .pack(&mut buf)
+            .unwrap();
+        let bytes = buf.into_inner();
+
+        let mut archive = ZipArchive::new(Cursor::new(&bytes[..])).unwrap();
+        let content_types = read_zip(&mut archive, "[Content_Types].xml").unwrap();
+        assert!(String::from_utf8(content_types).unwrap().contains(
+            r#"<Override PartName="/word/afchunk2.rtf" ContentType="application/rtf" />"#
+        ));
+
+        let docx = read_docx(&bytes).unwrap();
+        let chunks: Vec<&AltChunk> = docx
+            .document
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::AltChunk(chunk) => Some(chunk.as_ref()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].format, AltChunkFormat::Html);
+        assert_eq!(chunks[0].data, b"<p>Hi</p>");
+        assert_eq!(chunks[1].format, AltChunkFormat::Rtf);
+        assert_eq!(chunks[1].data, b"{\\rtf1 Hi}");
+    }
+
     #[cfg(feature = "image")]
     #[test]
     fn read_with_default_options_generates_image_preview() {
@@ -54,6 +54,23 @@ fn read_footers_from_xml(
     footers
 }
 
+/// Reads an optional part related to the document, falling back to an empty
+/// part when it is missing or malformed.
+fn read_optional_part_from_xml<T: FromXML + Default>(
+    rels: &Rels,
+    part_map: &HashMap<String, String>,
+    document_path: &str,
+    rel_type: &str,
+) -> T {
+    rels.find_target(rel_type)
+        .and_then(|(_, _, target)| {
+            let path = format!("{}/{}", document_path.replace("document.xml", ""), target);
+            part_map.get(&path)
+        })
+        .and_then(|data| T::from_xml(data.as_bytes()).ok())
+        .unwrap_or_default()
+}
+
 fn read_comments_from_xml(
     rels: &Rels,
     part_map: &HashMap<String, String>,
@@ -419,6 +436,16 @@ pub fn read_xml(xml_content: &str) -> Result<Docx, ReaderError> {
     // Read comments and comments extended
     let (comments, comments_extended) =
         read_comments_from_xml(&document_rels, &part_map, &document_path);
+    let comments_ids: CommentsIds =
+        read_optional_part_from_xml(&document_rels, &part_map, &document_path, COMMENTS_IDS_TYPE);
+    let comments_extensible: CommentsExtensible = read_optional_part_from_xml(
+        &document_rels,
+        &part_map,
+        &document_path,
+        COMMENTS_EXTENSIBLE_TYPE,
+    );
+    let people: People =
+        read_optional_part_from_xml(&document_rels, &part_map, &document_path, PEOPLE_TYPE);
 
     // Read the main document (try both with and without leading slash)
     let document = if let Some(doc_data) = part_map.get(&document_path) {
@@ -616,6 +643,10 @@ pub fn read_xml(xml_content: &str) -> Result<Docx, ReaderError> {
         docx = docx.comments(comments);
         docx = docx.comments_extended(comments_extended);
     }
+    docx = docx
+        .comments_ids(comments_ids)
+        .comments_extensible(comments_extensible)
+        .people(people);
 
     // Read and add images from XML package
     let media = document_rels
@@ -242,6 +242,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_extended_run_property() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml">
+  <w:r><w:rPr><w:smallCaps/><w:shadow w:val="0"/><w:noProof/><w:kern w:val="28"/><w:position w:val="-4"/><w:em w:val="dot"/><w:lang w:val="en-US" w:eastAsia="ja-JP"/><w:eastAsianLayout w:id="1" w:vert="1"/><w14:shadow w14:blurRad="38100" w14:dist="19050"><w14:srgbClr w14:val="000000"><w14:alpha w14:val="60000"/></w14:srgbClr></w14:shadow><w14:textOutline w14:w="9525"><w14:solidFill><w14:schemeClr w14:val="accent1"/></w14:solidFill><w14:prstDash w14:val="solid"/><w14:round/></w14:textOutline><w14:ligatures w14:val="standard"/><w14:stylisticSets><w14:styleSet w14:id="2"/></w14:stylisticSets><w:rPrChange w:id="1" w:author="bokuweb" w:date="2024-01-01T00:00:00Z"><w:rPr><w:b/></w:rPr></w:rPrChange></w:rPr><w:t>H</w:t></w:r>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let run = Run::read(&mut parser, &[]).unwrap();
+        let mut run_property = RunProperty::new()
+            .small_caps()
+            .no_proof()
+            .kern(28)
+            .position(-4)
+            .emphasis_mark(crate::EmphasisMarkType::Dot)
+            .lang(Lang::new().val("en-US").east_asia("ja-JP"))
+            .east_asian_layout(EastAsianLayout::new().id(1).vert(true))
+            .text_shadow(
+                TextShadow::new(EffectColor::srgb("000000").alpha(60000))
+                    .blur_rad(38100)
+                    .dist(19050),
+            )
+            .text_outline(
+                TextOutline::new()
+                    .width(9525)
+                    .fill(EffectFill::Solid(EffectColor::scheme("accent1")))
+                    .preset_dash("solid")
+                    .line_join("round"),
+            )
+            .ligatures(crate::LigaturesType::Standard)
+            .stylistic_sets(StylisticSets::new().add_set(2))
+            .run_property_change(
+                RunPropertyChange::new()
+                    .author("bokuweb")
+                    .date("2024-01-01T00:00:00Z")
+                    .property(RunProperty::new().bold()),
+            );
+        run_property.shadow = Some(Shadow::new().disable());
+        assert_eq!(
+            run,
+            Run {
+                children: vec![RunChild::Text(Text::new("H"))],
+                run_property,
+            }
+        );
+    }
+
     #[test]
     fn test_read_tab() {
         let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
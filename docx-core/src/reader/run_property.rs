@@ -1,7 +1,9 @@
 use std::io::Read;
 use std::str::FromStr;
 
-use crate::{ThemeColor, VertAlignType};
+use crate::types::*;
+
+use super::text_effects::read_text_reflection;
 
 use super::*;
 
@@ -66,6 +68,75 @@ fn read_run_fonts(attributes: &[OwnedAttribute]) -> Result<RunFonts, ReaderError
     Ok(f)
 }
 
+fn read_lang(attributes: &[OwnedAttribute]) -> Lang {
+    let mut lang = Lang::new();
+    lang.val = read_val(attributes);
+    lang.east_asia = read(attributes, "eastAsia");
+    lang.bidi = read(attributes, "bidi");
+    lang
+}
+
+fn read_east_asian_layout(attributes: &[OwnedAttribute]) -> EastAsianLayout {
+    let read_flag = |target: &str| read(attributes, target).map(|v| !is_false(&v));
+    let mut layout = EastAsianLayout::new();
+    layout.id = read(attributes, "id").and_then(|v| i32::from_str(&v).ok());
+    layout.combine = read_flag("combine");
+    layout.combine_brackets = read(attributes, "combineBrackets");
+    layout.vert = read_flag("vert");
+    layout.vert_compress = read_flag("vertCompress");
+    layout
+}
+
+// Word 2010 text effects and OpenType features (`w14:*`). Their local names
+// overlap with legacy `w:` properties (`w14:shadow` vs `w:shadow`), so they
+// are dispatched by prefix before the `XMLElement` match.
+fn read_w14<R: Read>(
+    rp: RunProperty,
+    r: &mut EventReader<R>,
+    local_name: &str,
+    attributes: &[OwnedAttribute],
+) -> Result<RunProperty, ReaderError> {
+    let val = read_val(attributes);
+    let rp = match W14XMLElement::from_str(local_name).unwrap() {
+        W14XMLElement::Glow => match TextGlow::read(r, attributes) {
+            Ok(g) => rp.glow(g),
+            Err(_) => rp,
+        },
+        W14XMLElement::Shadow => match TextShadow::read(r, attributes) {
+            Ok(s) => rp.text_shadow(s),
+            Err(_) => rp,
+        },
+        W14XMLElement::Reflection => rp.reflection(read_text_reflection(attributes)),
+        W14XMLElement::TextOutline => rp.text_outline(TextOutline::read(r, attributes)?),
+        W14XMLElement::TextFill => rp.text_fill(TextFill::read(r, attributes)?.fill),
+        W14XMLElement::Ligatures => match val.and_then(|v| LigaturesType::from_str(&v).ok()) {
+            Some(v) => rp.ligatures(v),
+            None => rp,
+        },
+        W14XMLElement::NumForm => match val.and_then(|v| NumberFormType::from_str(&v).ok()) {
+            Some(v) => rp.number_form(v),
+            None => rp,
+        },
+        W14XMLElement::NumSpacing => match val.and_then(|v| NumberSpacingType::from_str(&v).ok()) {
+            Some(v) => rp.number_spacing(v),
+            None => rp,
+        },
+        W14XMLElement::StylisticSets => rp.stylistic_sets(StylisticSets::read(r, attributes)?),
+        W14XMLElement::CntxtAlts => {
+            let mut rp = rp;
+            let v = ContextualAlternatives::new();
+            rp.contextual_alternatives = Some(if read_bool(attributes) {
+                v
+            } else {
+                v.disable()
+            });
+            rp
+        }
+        _ => rp,
+    };
+    Ok(rp)
+}
+
 impl ElementReader for RunProperty {
     fn read<R: Read>(
         r: &mut EventReader<R>,
@@ -78,10 +149,12 @@ impl ElementReader for RunProperty {
                 Ok(XmlEvent::StartElement {
                     attributes, name, ..
                 }) => {
-                    let e = XMLElement::from_str(&name.local_name).unwrap();
-
-                    ignore::ignore_element(e.clone(), XMLElement::RunPropertyChange, r);
+                    if name.prefix.as_deref() == Some("w14") {
+                        rp = read_w14(rp, r, &name.local_name, &attributes)?;
+                        continue;
+                    }
 
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
                     match e {
                         XMLElement::RunStyle => {
                             if let Some(v) = read_val(&attributes) {
@@ -179,6 +252,123 @@ impl ElementReader for RunProperty {
                                 continue;
                             }
                         }
+                        XMLElement::SmallCaps => {
+                            if !read_bool(&attributes) {
+                                rp = rp.disable_small_caps();
+                                continue;
+                            }
+                            rp = rp.small_caps();
+                        }
+                        XMLElement::Emboss => {
+                            let v = Emboss::new();
+                            rp.emboss = Some(if read_bool(&attributes) {
+                                v
+                            } else {
+                                v.disable()
+                            });
+                        }
+                        XMLElement::Imprint => {
+                            let v = Imprint::new();
+                            rp.imprint = Some(if read_bool(&attributes) {
+                                v
+                            } else {
+                                v.disable()
+                            });
+                        }
+                        XMLElement::Outline => {
+                            let v = Outline::new();
+                            rp.outline = Some(if read_bool(&attributes) {
+                                v
+                            } else {
+                                v.disable()
+                            });
+                        }
+                        XMLElement::Shadow => {
+                            let v = Shadow::new();
+                            rp.shadow = Some(if read_bool(&attributes) {
+                                v
+                            } else {
+                                v.disable()
+                            });
+                        }
+                        XMLElement::NoProof => {
+                            let v = NoProof::new();
+                            rp.no_proof = Some(if read_bool(&attributes) {
+                                v
+                            } else {
+                                v.disable()
+                            });
+                        }
+                        XMLElement::SnapToGrid => rp = rp.snap_to_grid(read_bool(&attributes)),
+                        XMLElement::WebHidden => {
+                            let v = WebHidden::new();
+                            rp.web_hidden = Some(if read_bool(&attributes) {
+                                v
+                            } else {
+                                v.disable()
+                            });
+                        }
+                        XMLElement::Rtl => {
+                            let v = Rtl::new();
+                            rp.rtl = Some(if read_bool(&attributes) {
+                                v
+                            } else {
+                                v.disable()
+                            });
+                        }
+                        XMLElement::Cs => {
+                            let v = ComplexScript::new();
+                            rp.complex_script = Some(if read_bool(&attributes) {
+                                v
+                            } else {
+                                v.disable()
+                            });
+                        }
+                        XMLElement::OMath => {
+                            let v = OMath::new();
+                            rp.o_math = Some(if read_bool(&attributes) {
+                                v
+                            } else {
+                                v.disable()
+                            });
+                        }
+                        XMLElement::Kern => {
+                            if let Some(v) = read_val(&attributes) {
+                                if let Ok(v) = usize::from_str(&v) {
+                                    rp = rp.kern(v);
+                                }
+                            }
+                        }
+                        XMLElement::Position => {
+                            if let Some(v) = read_val(&attributes) {
+                                if let Ok(v) = f64::from_str(&v) {
+                                    rp = rp.position(v as i32);
+                                }
+                            }
+                        }
+                        XMLElement::Effect => {
+                            if let Some(v) = read_val(&attributes) {
+                                if let Ok(v) = TextEffectType::from_str(&v) {
+                                    rp = rp.effect(v);
+                                }
+                            }
+                        }
+                        XMLElement::EmphasisMark => {
+                            if let Some(v) = read_val(&attributes) {
+                                if let Ok(v) = EmphasisMarkType::from_str(&v) {
+                                    rp = rp.emphasis_mark(v);
+                                }
+                            }
+                        }
+                        XMLElement::Lang => rp = rp.lang(read_lang(&attributes)),
+                        XMLElement::EastAsianLayout => {
+                            rp = rp.east_asian_layout(read_east_asian_layout(&attributes))
+                        }
+                        XMLElement::RunPropertyChange => {
+                            if let Ok(c) = RunPropertyChange::read(r, &attributes) {
+                                rp = rp.run_property_change(c);
+                            }
+                        }
                         XMLElement::Insert => {
                             if let Ok(ins) = Insert::read(r, &attributes) {
                                 rp = rp.insert(ins);
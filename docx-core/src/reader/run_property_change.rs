@@ -0,0 +1,45 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use super::*;
+
+impl ElementReader for RunPropertyChange {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut rc = RunPropertyChange::new();
+        for attr in attrs {
+            let local_name = &attr.name.local_name;
+            if local_name == "author" {
+                rc = rc.author(&attr.value);
+            } else if local_name == "date" {
+                rc = rc.date(&attr.value);
+            }
+        }
+        loop {
+            let e = r.next_event();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name)
+                        .expect("should convert to XMLElement");
+                    if let XMLElement::RunProperty = e {
+                        if let Ok(p) = RunProperty::read(r, &attributes) {
+                            rc = rc.property(p);
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::RunPropertyChange {
+                        return Ok(rc);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -21,6 +21,7 @@ impl ElementReader for Style {
             }
         }
         let mut style = Style::new(id, style_type);
+        style.is_default = read(attrs, "default").is_some_and(|v| !is_false(&v));
         loop {
             let e = r.next_event();
             match e {
@@ -67,6 +68,11 @@ impl ElementReader for Style {
                                 style = style.table_cell_property(p);
                             }
                         }
+                        XMLElement::TableStyleOverride => {
+                            if let Ok(o) = TableStyleOverride::read(r, &attributes) {
+                                style = style.add_table_style_override(o);
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -22,6 +22,12 @@ impl FromXML for Styles {
                             }
                             continue;
                         }
+                        XMLElement::LatentStyles => {
+                            if let Ok(l) = LatentStyles::read(&mut parser, &attributes) {
+                                styles = styles.latent_styles(l);
+                            }
+                            continue;
+                        }
                         XMLElement::DocDefaults => {
                             if let Ok(d) = DocDefaults::read(&mut parser, &attributes) {
                                 styles = styles.doc_defaults(d);
@@ -81,4 +87,34 @@ mod tests {
         );
         assert_eq!(s, styles);
     }
+
+    #[test]
+    fn test_read_latent_styles() {
+        let xml = r#"<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:latentStyles w:defLockedState="0" w:defUIPriority="99" w:defSemiHidden="0" w:defUnhideWhenUsed="0" w:defQFormat="0" w:count="376">
+        <w:lsdException w:name="Normal" w:uiPriority="0" w:qFormat="1"/>
+        <w:lsdException w:name="heading 2" w:semiHidden="1" w:unhideWhenUsed="1" w:uiPriority="9" w:qFormat="1"/>
+    </w:latentStyles>
+</w:styles>"#;
+        let s = Styles::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            s.latent_styles,
+            Some(
+                LatentStyles::new()
+                    .count(376)
+                    .add_exception(
+                        LatentStyleException::new("Normal")
+                            .ui_priority(0)
+                            .q_format(true)
+                    )
+                    .add_exception(
+                        LatentStyleException::new("heading 2")
+                            .ui_priority(9)
+                            .semi_hidden(true)
+                            .unhide_when_used(true)
+                            .q_format(true)
+                    )
+            )
+        );
+    }
 }
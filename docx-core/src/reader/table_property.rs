@@ -58,6 +58,9 @@ impl ElementReader for TableProperty {
                                 tp = tp.position(p);
                             }
                         }
+                        XMLElement::TableLook => {
+                            tp = tp.look(read_table_look(&attributes));
+                        }
                         _ => {}
                     }
                 }
@@ -73,3 +76,26 @@ impl ElementReader for TableProperty {
         }
     }
 }
+
+// The attributes override the legacy `w:val` bitmask when both are given.
+fn read_table_look(attrs: &[OwnedAttribute]) -> TableLook {
+    let mut look = attrs
+        .iter()
+        .find(|a| a.name.local_name == "val")
+        .and_then(|a| u16::from_str_radix(&a.value, 16).ok())
+        .map(TableLook::from_bits)
+        .unwrap_or_default();
+    for a in attrs {
+        let on = !is_false(&a.value);
+        look = match a.name.local_name.as_str() {
+            "firstRow" => look.first_row(on),
+            "lastRow" => look.last_row(on),
+            "firstColumn" => look.first_column(on),
+            "lastColumn" => look.last_column(on),
+            "noHBand" => look.no_h_band(on),
+            "noVBand" => look.no_v_band(on),
+            _ => look,
+        };
+    }
+    look
+}
@@ -0,0 +1,42 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use super::*;
+
+use crate::types::*;
+
+impl ElementReader for TableStyleOverride {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let override_type = read(attrs, "type")
+            .and_then(|v| TableStyleOverrideType::from_str(&v).ok())
+            .unwrap_or(TableStyleOverrideType::WholeTable);
+        let mut o = TableStyleOverride::new(override_type);
+        loop {
+            let e = r.next_event();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => match XMLElement::from_str(&name.local_name).unwrap() {
+                    XMLElement::ParagraphProperty => {
+                        o.paragraph_property = ParagraphProperty::read(r, &attributes)?;
+                    }
+                    XMLElement::RunProperty => {
+                        o.run_property = RunProperty::read(r, &attributes)?;
+                    }
+                    _ => {}
+                },
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if let XMLElement::TableStyleOverride = e {
+                        return Ok(o);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use super::*;
+
+// Readers for the Word 2010 (w14) text effects found in `<w:rPr>`.
+//
+// Every child of a w14 effect is itself in the w14 namespace, so these readers
+// dispatch on `W14XMLElement` only. Unknown children (gradient fills, 3D
+// scene properties, ...) are skipped.
+
+fn read_i64(attrs: &[OwnedAttribute], target: &str) -> Option<i64> {
+    read(attrs, target).and_then(|v| i64::from_str(&v).ok())
+}
+
+fn read_effect_color<R: Read>(
+    r: &mut EventReader<R>,
+    attrs: &[OwnedAttribute],
+    color_type: EffectColorType,
+) -> Result<EffectColor, ReaderError> {
+    let mut color = EffectColor::new(color_type, read_val(attrs).unwrap_or_default());
+    loop {
+        let e = r.next_event();
+        match e {
+            Ok(XmlEvent::StartElement {
+                attributes, name, ..
+            }) => {
+                let v = read_val(&attributes).and_then(|v| u32::from_str(&v).ok());
+                let Some(v) = v else {
+                    continue;
+                };
+                match W14XMLElement::from_str(&name.local_name).unwrap() {
+                    W14XMLElement::Tint => color = color.tint(v),
+                    W14XMLElement::Shade => color = color.shade(v),
+                    W14XMLElement::Alpha => color = color.alpha(v),
+                    W14XMLElement::SatMod => color = color.sat_mod(v),
+                    W14XMLElement::LumMod => color = color.lum_mod(v),
+                    W14XMLElement::LumOff => color = color.lum_off(v),
+                    _ => {}
+                }
+            }
+            Ok(XmlEvent::EndElement { name, .. }) => {
+                let e = W14XMLElement::from_str(&name.local_name).unwrap();
+                if e == W14XMLElement::SrgbClr || e == W14XMLElement::SchemeClr {
+                    return Ok(color);
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+// Read children until `end` closes, returning the last color found.
+fn read_color_in<R: Read>(
+    r: &mut EventReader<R>,
+    end: W14XMLElement,
+) -> Result<Option<EffectColor>, ReaderError> {
+    let mut color = None;
+    loop {
+        let e = r.next_event();
+        match e {
+            Ok(XmlEvent::StartElement {
+                attributes, name, ..
+            }) => match W14XMLElement::from_str(&name.local_name).unwrap() {
+                W14XMLElement::SrgbClr => {
+                    color = Some(read_effect_color(r, &attributes, EffectColorType::Srgb)?);
+                }
+                W14XMLElement::SchemeClr => {
+                    color = Some(read_effect_color(r, &attributes, EffectColorType::Scheme)?);
+                }
+                _ => {}
+            },
+            Ok(XmlEvent::EndElement { name, .. }) => {
+                if W14XMLElement::from_str(&name.local_name).unwrap() == end {
+                    return Ok(color);
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+// A w14 child element that the caller handles itself.
+type W14Child = (W14XMLElement, Vec<OwnedAttribute>);
+
+// Read the fill children of `end` (`<w14:textFill>` or `<w14:textOutline>`).
+fn read_fill_in<R: Read>(
+    r: &mut EventReader<R>,
+    end: W14XMLElement,
+) -> Result<(Option<EffectFill>, Vec<W14Child>), ReaderError> {
+    let mut fill = None;
+    let mut others = vec![];
+    loop {
+        let e = r.next_event();
+        match e {
+            Ok(XmlEvent::StartElement {
+                attributes, name, ..
+            }) => match W14XMLElement::from_str(&name.local_name).unwrap() {
+                W14XMLElement::NoFill => fill = Some(EffectFill::NoFill),
+                W14XMLElement::SolidFill => {
+                    if let Some(c) = read_color_in(r, W14XMLElement::SolidFill)? {
+                        fill = Some(EffectFill::Solid(c));
+                    }
+                }
+                e => others.push((e, attributes)),
+            },
+            Ok(XmlEvent::EndElement { name, .. }) => {
+                if W14XMLElement::from_str(&name.local_name).unwrap() == end {
+                    return Ok((fill, others));
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+impl ElementReader for TextGlow {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let radius = read(attrs, "rad")
+            .and_then(|v| u64::from_str(&v).ok())
+            .unwrap_or_default();
+        let color = read_color_in(r, W14XMLElement::Glow)?.ok_or(ReaderError::XMLReadError)?;
+        Ok(TextGlow::new(radius, color))
+    }
+}
+
+impl ElementReader for TextShadow {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let color = read_color_in(r, W14XMLElement::Shadow)?.ok_or(ReaderError::XMLReadError)?;
+        let mut shadow = TextShadow::new(color);
+        shadow.blur_rad = read_i64(attrs, "blurRad");
+        shadow.dist = read_i64(attrs, "dist");
+        shadow.dir = read_i64(attrs, "dir");
+        shadow.sx = read_i64(attrs, "sx");
+        shadow.sy = read_i64(attrs, "sy");
+        shadow.kx = read_i64(attrs, "kx");
+        shadow.ky = read_i64(attrs, "ky");
+        shadow.align = read(attrs, "algn");
+        Ok(shadow)
+    }
+}
+
+// `<w14:reflection>` carries everything in attributes.
+pub fn read_text_reflection(attrs: &[OwnedAttribute]) -> TextReflection {
+    let mut reflection = TextReflection::new();
+    reflection.blur_rad = read_i64(attrs, "blurRad");
+    reflection.start_alpha = read_i64(attrs, "stA");
+    reflection.start_pos = read_i64(attrs, "stPos");
+    reflection.end_alpha = read_i64(attrs, "endA");
+    reflection.end_pos = read_i64(attrs, "endPos");
+    reflection.dist = read_i64(attrs, "dist");
+    reflection.dir = read_i64(attrs, "dir");
+    reflection.fade_dir = read_i64(attrs, "fadeDir");
+    reflection.sx = read_i64(attrs, "sx");
+    reflection.sy = read_i64(attrs, "sy");
+    reflection.kx = read_i64(attrs, "kx");
+    reflection.ky = read_i64(attrs, "ky");
+    reflection.align = read(attrs, "algn");
+    reflection
+}
+
+impl ElementReader for TextOutline {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut outline = TextOutline::new();
+        outline.width = read(attrs, "w").and_then(|v| u64::from_str(&v).ok());
+        outline.cap = read(attrs, "cap");
+        outline.compound = read(attrs, "cmpd");
+        outline.align = read(attrs, "algn");
+        let (fill, others) = read_fill_in(r, W14XMLElement::TextOutline)?;
+        outline.fill = fill;
+        for (e, attributes) in others {
+            match e {
+                W14XMLElement::PrstDash => {
+                    if let Some(v) = read_val(&attributes) {
+                        outline = outline.preset_dash(v);
+                    }
+                }
+                W14XMLElement::Round => outline = outline.line_join("round"),
+                W14XMLElement::Bevel => outline = outline.line_join("bevel"),
+                W14XMLElement::Miter => outline = outline.line_join("miter"),
+                _ => {}
+            }
+        }
+        Ok(outline)
+    }
+}
+
+impl ElementReader for TextFill {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        _attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let (fill, _) = read_fill_in(r, W14XMLElement::TextFill)?;
+        Ok(TextFill::new(fill.unwrap_or(EffectFill::NoFill)))
+    }
+}
+
+impl ElementReader for StylisticSets {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        _attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut sets = StylisticSets::new();
+        loop {
+            let e = r.next_event();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    if W14XMLElement::from_str(&name.local_name).unwrap() == W14XMLElement::StyleSet
+                    {
+                        if let Some(id) =
+                            read(&attributes, "id").and_then(|v| usize::from_str(&v).ok())
+                        {
+                            sets = sets.add_set(id);
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    if W14XMLElement::from_str(&name.local_name).unwrap()
+                        == W14XMLElement::StylisticSets
+                    {
+                        return Ok(sets);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -22,6 +22,22 @@ pub enum XMLElement {
     FitText,
     Vanish,
     SpecVanish,
+    SmallCaps,
+    Emboss,
+    Imprint,
+    Outline,
+    Shadow,
+    NoProof,
+    WebHidden,
+    Kern,
+    Position,
+    Effect,
+    Rtl,
+    Cs,
+    EmphasisMark,
+    Lang,
+    EastAsianLayout,
+    OMath,
     TextBorder,
     Italic,
     ItalicCs,
@@ -62,6 +78,20 @@ pub enum XMLElement {
     KeepLines,
     PageBreakBefore,
     WidowControl,
+    Bidi,
+    ContextualSpacing,
+    MirrorIndents,
+    SuppressAutoHyphens,
+    SuppressLineNumbers,
+    SuppressOverlap,
+    WordWrap,
+    Kinsoku,
+    OverflowPunct,
+    TopLinePunct,
+    AutoSpaceDE,
+    AutoSpaceDN,
+    TextboxTightWrap,
+    CnfStyle,
     DivId,
     Div,
     DivsChild,
@@ -80,6 +110,13 @@ pub enum XMLElement {
     CommentExtended,
     Property,
     CommentsExtended,
+    CommentsIds,
+    CommentId,
+    CommentsExtensible,
+    CommentExtensible,
+    People,
+    Person,
+    PresenceInfo,
     VAlign,
     Shading,
     Strike,
@@ -102,6 +139,7 @@ pub enum XMLElement {
     TableCellMargin,
     TablePositionProperty,
     TableStyle,
+    TableLook,
     // Change
     TableGridChange,
     TablePropertyChange,
@@ -145,6 +183,9 @@ pub enum XMLElement {
     LevelText,
     LevelJustification,
     LevelRestart,
+    LevelPicBulletId,
+    TableStyleOverride,
+    NumPicBullet,
     StyleLink,
     NumStyleLink,
     Drawing,
@@ -155,6 +196,13 @@ pub enum XMLElement {
     DocVar,
     DocGrid,
     DocDefaults,
+    LatentStyles,
+    LsdException,
+    Fonts,
+    Font,
+    Charset,
+    Family,
+    Pitch,
     DefaultTabStop,
     RunPropertyDefault,
     AdjustLineHeightInTable,
@@ -168,6 +216,7 @@ pub enum XMLElement {
     TitlePg,
     EvenAndOddHeaders,
     StructuredDataTag,
+    AltChunk,
     Type,
     PageNumType,
     FrameProperty,
@@ -253,6 +302,36 @@ pub enum VXMLElement {
     Unsupported,
 }
 
+#[derive(PartialEq, Debug)]
+pub enum W14XMLElement {
+    Glow,
+    Shadow,
+    Reflection,
+    TextOutline,
+    TextFill,
+    SolidFill,
+    NoFill,
+    SrgbClr,
+    SchemeClr,
+    Tint,
+    Shade,
+    Alpha,
+    SatMod,
+    LumMod,
+    LumOff,
+    PrstDash,
+    Round,
+    Bevel,
+    Miter,
+    Ligatures,
+    NumForm,
+    NumSpacing,
+    StylisticSets,
+    StyleSet,
+    CntxtAlts,
+    Unsupported,
+}
+
 pub enum VtXMLElement {
     Lpwstr,
     Unsupported,
@@ -294,6 +373,22 @@ impl FromStr for XMLElement {
             "iCs" => Ok(XMLElement::ItalicCs),
             "vanish" => Ok(XMLElement::Vanish),
             "specVanish" => Ok(XMLElement::SpecVanish),
+            "smallCaps" => Ok(XMLElement::SmallCaps),
+            "emboss" => Ok(XMLElement::Emboss),
+            "imprint" => Ok(XMLElement::Imprint),
+            "outline" => Ok(XMLElement::Outline),
+            "shadow" => Ok(XMLElement::Shadow),
+            "noProof" => Ok(XMLElement::NoProof),
+            "webHidden" => Ok(XMLElement::WebHidden),
+            "kern" => Ok(XMLElement::Kern),
+            "position" => Ok(XMLElement::Position),
+            "effect" => Ok(XMLElement::Effect),
+            "rtl" => Ok(XMLElement::Rtl),
+            "cs" => Ok(XMLElement::Cs),
+            "em" => Ok(XMLElement::EmphasisMark),
+            "lang" => Ok(XMLElement::Lang),
+            "eastAsianLayout" => Ok(XMLElement::EastAsianLayout),
+            "oMath" => Ok(XMLElement::OMath),
             "italic" => Ok(XMLElement::Italic),
             "name" => Ok(XMLElement::Name),
             "tab" => Ok(XMLElement::Tab),
@@ -319,6 +414,13 @@ impl FromStr for XMLElement {
             "commentRangeEnd" => Ok(XMLElement::CommentRangeEnd),
             "commentEx" => Ok(XMLElement::CommentExtended),
             "commentsEx" => Ok(XMLElement::CommentsExtended),
+            "commentsIds" => Ok(XMLElement::CommentsIds),
+            "commentId" => Ok(XMLElement::CommentId),
+            "commentsExtensible" => Ok(XMLElement::CommentsExtensible),
+            "commentExtensible" => Ok(XMLElement::CommentExtensible),
+            "people" => Ok(XMLElement::People),
+            "person" => Ok(XMLElement::Person),
+            "presenceInfo" => Ok(XMLElement::PresenceInfo),
             "shd" => Ok(XMLElement::Shading),
             "property" => Ok(XMLElement::Property),
             "tbl" => Ok(XMLElement::Table),
@@ -343,6 +445,7 @@ impl FromStr for XMLElement {
             "tblCellMar" => Ok(XMLElement::TableCellMargin),
             "tblStyle" => Ok(XMLElement::TableStyle),
             "tblpPr" => Ok(XMLElement::TablePositionProperty),
+            "tblLook" => Ok(XMLElement::TableLook),
             "top" => Ok(XMLElement::Top),
             "right" => Ok(XMLElement::Right),
             "start" => Ok(XMLElement::Start),
@@ -384,6 +487,9 @@ impl FromStr for XMLElement {
             "isLgl" => Ok(XMLElement::IsLgl),
             "lvlText" => Ok(XMLElement::LevelText),
             "lvlRestart" => Ok(XMLElement::LevelRestart),
+            "lvlPicBulletId" => Ok(XMLElement::LevelPicBulletId),
+            "tblStylePr" => Ok(XMLElement::TableStyleOverride),
+            "numPicBullet" => Ok(XMLElement::NumPicBullet),
             "lvlJc" => Ok(XMLElement::LevelJustification),
             "outlineLvl" => Ok(XMLElement::OutlineLvl),
             "numStyleLink" => Ok(XMLElement::NumStyleLink),
@@ -404,6 +510,13 @@ impl FromStr for XMLElement {
             "rFonts" => Ok(XMLElement::RunFonts),
             "pgMar" => Ok(XMLElement::PageMargin),
             "docDefaults" => Ok(XMLElement::DocDefaults),
+            "latentStyles" => Ok(XMLElement::LatentStyles),
+            "lsdException" => Ok(XMLElement::LsdException),
+            "fonts" => Ok(XMLElement::Fonts),
+            "font" => Ok(XMLElement::Font),
+            "charset" => Ok(XMLElement::Charset),
+            "family" => Ok(XMLElement::Family),
+            "pitch" => Ok(XMLElement::Pitch),
             "docGrid" => Ok(XMLElement::DocGrid),
             "rPrDefault" => Ok(XMLElement::RunPropertyDefault),
             "adjustLineHeightInTable" => Ok(XMLElement::AdjustLineHeightInTable),
@@ -423,11 +536,26 @@ impl FromStr for XMLElement {
             "keepLines" => Ok(XMLElement::KeepLines),
             "pageBreakBefore" => Ok(XMLElement::PageBreakBefore),
             "widowControl" => Ok(XMLElement::WidowControl),
+            "bidi" => Ok(XMLElement::Bidi),
+            "contextualSpacing" => Ok(XMLElement::ContextualSpacing),
+            "mirrorIndents" => Ok(XMLElement::MirrorIndents),
+            "suppressAutoHyphens" => Ok(XMLElement::SuppressAutoHyphens),
+            "suppressLineNumbers" => Ok(XMLElement::SuppressLineNumbers),
+            "suppressOverlap" => Ok(XMLElement::SuppressOverlap),
+            "wordWrap" => Ok(XMLElement::WordWrap),
+            "kinsoku" => Ok(XMLElement::Kinsoku),
+            "overflowPunct" => Ok(XMLElement::OverflowPunct),
+            "topLinePunct" => Ok(XMLElement::TopLinePunct),
+            "autoSpaceDE" => Ok(XMLElement::AutoSpaceDE),
+            "autoSpaceDN" => Ok(XMLElement::AutoSpaceDN),
+            "textboxTightWrap" => Ok(XMLElement::TextboxTightWrap),
+            "cnfStyle" => Ok(XMLElement::CnfStyle),
             "headerReference" => Ok(XMLElement::HeaderReference),
             "footerReference" => Ok(XMLElement::FooterReference),
             "titlePg" => Ok(XMLElement::TitlePg),
             "evenAndOddHeaders" => Ok(XMLElement::EvenAndOddHeaders),
             "sdt" => Ok(XMLElement::StructuredDataTag),
+            "altChunk" => Ok(XMLElement::AltChunk),
             "pgNumType" => Ok(XMLElement::PageNumType),
             "framePr" => Ok(XMLElement::FrameProperty),
             "textAlignment" => Ok(XMLElement::TextAlignment),
@@ -535,6 +663,40 @@ impl FromStr for VXMLElement {
     }
 }
 
+impl FromStr for W14XMLElement {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "glow" => Ok(W14XMLElement::Glow),
+            "shadow" => Ok(W14XMLElement::Shadow),
+            "reflection" => Ok(W14XMLElement::Reflection),
+            "textOutline" => Ok(W14XMLElement::TextOutline),
+            "textFill" => Ok(W14XMLElement::TextFill),
+            "solidFill" => Ok(W14XMLElement::SolidFill),
+            "noFill" => Ok(W14XMLElement::NoFill),
+            "srgbClr" => Ok(W14XMLElement::SrgbClr),
+            "schemeClr" => Ok(W14XMLElement::SchemeClr),
+            "tint" => Ok(W14XMLElement::Tint),
+            "shade" => Ok(W14XMLElement::Shade),
+            "alpha" => Ok(W14XMLElement::Alpha),
+            "satMod" => Ok(W14XMLElement::SatMod),
+            "lumMod" => Ok(W14XMLElement::LumMod),
+            "lumOff" => Ok(W14XMLElement::LumOff),
+            "prstDash" => Ok(W14XMLElement::PrstDash),
+            "round" => Ok(W14XMLElement::Round),
+            "bevel" => Ok(W14XMLElement::Bevel),
+            "miter" => Ok(W14XMLElement::Miter),
+            "ligatures" => Ok(W14XMLElement::Ligatures),
+            "numForm" => Ok(W14XMLElement::NumForm),
+            "numSpacing" => Ok(W14XMLElement::NumSpacing),
+            "stylisticSets" => Ok(W14XMLElement::StylisticSets),
+            "styleSet" => Ok(W14XMLElement::StyleSet),
+            "cntxtAlts" => Ok(W14XMLElement::CntxtAlts),
+            _ => Ok(W14XMLElement::Unsupported),
+        }
+    }
+}
+
 impl FromStr for VtXMLElement {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, Self::Err> {
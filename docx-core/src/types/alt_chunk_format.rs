@@ -0,0 +1,71 @@
+use serde::Serialize;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// The kind of content an altChunk part holds, which Word converts when it
+/// opens the document.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum AltChunkFormat {
+    Html,
+    Mht,
+    Rtf,
+    Text,
+    Docx,
+}
+
+impl AltChunkFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            AltChunkFormat::Html => "text/html",
+            AltChunkFormat::Mht => "message/rfc822",
+            AltChunkFormat::Rtf => "application/rtf",
+            AltChunkFormat::Text => "text/plain",
+            AltChunkFormat::Docx => {
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"
+            }
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            AltChunkFormat::Html => "html",
+            AltChunkFormat::Mht => "mht",
+            AltChunkFormat::Rtf => "rtf",
+            AltChunkFormat::Text => "txt",
+            AltChunkFormat::Docx => "docx",
+        }
+    }
+
+    /// The format of a part from its content type, ignoring parameters such
+    /// as the charset.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next().unwrap_or_default().trim();
+        match mime.to_ascii_lowercase().as_str() {
+            "text/html" | "application/xhtml+xml" => Some(AltChunkFormat::Html),
+            "message/rfc822" | "multipart/related" => Some(AltChunkFormat::Mht),
+            "application/rtf" | "text/rtf" => Some(AltChunkFormat::Rtf),
+            "text/plain" => Some(AltChunkFormat::Text),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"
+            | "application/vnd.openxmlformats-officedocument.wordprocessingml.document" => {
+                Some(AltChunkFormat::Docx)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "htm" | "html" | "xhtml" => Some(AltChunkFormat::Html),
+            "mht" | "mhtml" => Some(AltChunkFormat::Mht),
+            "rtf" => Some(AltChunkFormat::Rtf),
+            "txt" => Some(AltChunkFormat::Text),
+            "docx" => Some(AltChunkFormat::Docx),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::Serialize;
+
+use super::errors;
+use std::str::FromStr;
+
+/// Emphasis mark drawn above or below East Asian text (`ST_Em`, ECMA-376 Part 1 §17.18.24).
+#[cfg_attr(feature = "wasm", wasm_bindgen, derive(ts_rs::TS), ts(export))]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EmphasisMarkType {
+    None,
+    Dot,
+    Comma,
+    Circle,
+    UnderDot,
+}
+
+impl fmt::Display for EmphasisMarkType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EmphasisMarkType::None => write!(f, "none"),
+            EmphasisMarkType::Dot => write!(f, "dot"),
+            EmphasisMarkType::Comma => write!(f, "comma"),
+            EmphasisMarkType::Circle => write!(f, "circle"),
+            EmphasisMarkType::UnderDot => write!(f, "underDot"),
+        }
+    }
+}
+
+impl FromStr for EmphasisMarkType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(EmphasisMarkType::None),
+            "dot" => Ok(EmphasisMarkType::Dot),
+            "comma" => Ok(EmphasisMarkType::Comma),
+            "circle" => Ok(EmphasisMarkType::Circle),
+            "underDot" => Ok(EmphasisMarkType::UnderDot),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
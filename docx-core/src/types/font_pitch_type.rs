@@ -1,9 +1,12 @@
+use serde::Serialize;
 use std::fmt;
+use std::str::FromStr;
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub enum FontPitchType {
     Default,
     Fixed,
@@ -19,3 +22,14 @@ impl fmt::Display for FontPitchType {
         }
     }
 }
+
+impl FromStr for FontPitchType {
+    type Err = super::errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fixed" => Ok(FontPitchType::Fixed),
+            "variable" => Ok(FontPitchType::Variable),
+            _ => Ok(FontPitchType::Default),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::Serialize;
+
+use super::errors;
+use std::str::FromStr;
+
+/// OpenType ligature classes enabled on a run (`w14:ST_Ligatures`).
+#[cfg_attr(feature = "wasm", wasm_bindgen, derive(ts_rs::TS), ts(export))]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LigaturesType {
+    None,
+    Standard,
+    Contextual,
+    Historical,
+    Discretional,
+    StandardContextual,
+    StandardHistorical,
+    ContextualHistorical,
+    StandardDiscretional,
+    ContextualDiscretional,
+    HistoricalDiscretional,
+    StandardContextualHistorical,
+    StandardContextualDiscretional,
+    StandardHistoricalDiscretional,
+    ContextualHistoricalDiscretional,
+    All,
+}
+
+impl fmt::Display for LigaturesType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LigaturesType::None => write!(f, "none"),
+            LigaturesType::Standard => write!(f, "standard"),
+            LigaturesType::Contextual => write!(f, "contextual"),
+            LigaturesType::Historical => write!(f, "historical"),
+            LigaturesType::Discretional => write!(f, "discretional"),
+            LigaturesType::StandardContextual => write!(f, "standardContextual"),
+            LigaturesType::StandardHistorical => write!(f, "standardHistorical"),
+            LigaturesType::ContextualHistorical => write!(f, "contextualHistorical"),
+            LigaturesType::StandardDiscretional => write!(f, "standardDiscretional"),
+            LigaturesType::ContextualDiscretional => write!(f, "contextualDiscretional"),
+            LigaturesType::HistoricalDiscretional => write!(f, "historicalDiscretional"),
+            LigaturesType::StandardContextualHistorical => {
+                write!(f, "standardContextualHistorical")
+            }
+            LigaturesType::StandardContextualDiscretional => {
+                write!(f, "standardContextualDiscretional")
+            }
+            LigaturesType::StandardHistoricalDiscretional => {
+                write!(f, "standardHistoricalDiscretional")
+            }
+            LigaturesType::ContextualHistoricalDiscretional => {
+                write!(f, "contextualHistoricalDiscretional")
+            }
+            LigaturesType::All => write!(f, "all"),
+        }
+    }
+}
+
+impl FromStr for LigaturesType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(LigaturesType::None),
+            "standard" => Ok(LigaturesType::Standard),
+            "contextual" => Ok(LigaturesType::Contextual),
+            "historical" => Ok(LigaturesType::Historical),
+            "discretional" => Ok(LigaturesType::Discretional),
+            "standardContextual" => Ok(LigaturesType::StandardContextual),
+            "standardHistorical" => Ok(LigaturesType::StandardHistorical),
+            "contextualHistorical" => Ok(LigaturesType::ContextualHistorical),
+            "standardDiscretional" => Ok(LigaturesType::StandardDiscretional),
+            "contextualDiscretional" => Ok(LigaturesType::ContextualDiscretional),
+            "historicalDiscretional" => Ok(LigaturesType::HistoricalDiscretional),
+            "standardContextualHistorical" => Ok(LigaturesType::StandardContextualHistorical),
+            "standardContextualDiscretional" => Ok(LigaturesType::StandardContextualDiscretional),
+            "standardHistoricalDiscretional" => Ok(LigaturesType::StandardHistoricalDiscretional),
+            "contextualHistoricalDiscretional" => {
+                Ok(LigaturesType::ContextualHistoricalDiscretional)
+            }
+            "all" => Ok(LigaturesType::All),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
@@ -1,10 +1,12 @@
 pub mod alignment_type;
+pub mod alt_chunk_format;
 pub mod border_position;
 pub mod border_type;
 pub mod break_type;
 pub mod character_spacing_values;
 pub mod doc_grid_type;
 pub mod drawing_position;
+pub mod emphasis_mark_type;
 pub mod emu;
 pub mod errors;
 pub mod field_char_type;
@@ -12,7 +14,10 @@ pub mod font_pitch_type;
 pub mod height_rule;
 pub mod hyperlink_type;
 pub mod level_suffix_type;
+pub mod ligatures_type;
 pub mod line_spacing_type;
+pub mod number_form_type;
+pub mod number_spacing_type;
 pub mod page_margin;
 pub mod page_orientation_type;
 pub mod positional_tab_alignment_type;
@@ -26,8 +31,11 @@ pub mod tab_leader_type;
 pub mod tab_value_type;
 pub mod table_alignment_type;
 pub mod table_layout_type;
+pub mod table_style_override_type;
 pub mod text_alignment_type;
 pub mod text_direction_type;
+pub mod text_effect_type;
+pub mod textbox_tight_wrap_type;
 pub mod theme_color;
 pub mod vert_align_type;
 pub mod vertical_align_type;
@@ -35,12 +43,14 @@ pub mod vertical_merge_type;
 pub mod width_type;
 
 pub use alignment_type::*;
+pub use alt_chunk_format::*;
 pub use border_position::*;
 pub use border_type::*;
 pub use break_type::*;
 pub use character_spacing_values::*;
 pub use doc_grid_type::*;
 pub use drawing_position::*;
+pub use emphasis_mark_type::*;
 pub use emu::*;
 pub use errors::*;
 pub use field_char_type::*;
@@ -48,7 +58,10 @@ pub use font_pitch_type::*;
 pub use height_rule::*;
 pub use hyperlink_type::*;
 pub use level_suffix_type::*;
+pub use ligatures_type::*;
 pub use line_spacing_type::*;
+pub use number_form_type::*;
+pub use number_spacing_type::*;
 pub use page_margin::*;
 pub use page_orientation_type::*;
 pub use positional_tab_alignment_type::*;
@@ -62,8 +75,11 @@ pub use tab_leader_type::*;
 pub use tab_value_type::*;
 pub use table_alignment_type::*;
 pub use table_layout_type::*;
+pub use table_style_override_type::*;
 pub use text_alignment_type::*;
 pub use text_direction_type::*;
+pub use text_effect_type::*;
+pub use textbox_tight_wrap_type::*;
 pub use theme_color::*;
 pub use vert_align_type::*;
 pub use vertical_align_type::*;
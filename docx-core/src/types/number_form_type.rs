@@ -0,0 +1,40 @@
+use std::fmt;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::Serialize;
+
+use super::errors;
+use std::str::FromStr;
+
+/// OpenType number form applied to digits (`w14:ST_NumForm`).
+#[cfg_attr(feature = "wasm", wasm_bindgen, derive(ts_rs::TS), ts(export))]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NumberFormType {
+    Default,
+    Lining,
+    OldStyle,
+}
+
+impl fmt::Display for NumberFormType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NumberFormType::Default => write!(f, "default"),
+            NumberFormType::Lining => write!(f, "lining"),
+            NumberFormType::OldStyle => write!(f, "oldStyle"),
+        }
+    }
+}
+
+impl FromStr for NumberFormType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(NumberFormType::Default),
+            "lining" => Ok(NumberFormType::Lining),
+            "oldStyle" => Ok(NumberFormType::OldStyle),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
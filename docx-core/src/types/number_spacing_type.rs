@@ -0,0 +1,40 @@
+use std::fmt;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::Serialize;
+
+use super::errors;
+use std::str::FromStr;
+
+/// OpenType number spacing applied to digits (`w14:ST_NumSpacing`).
+#[cfg_attr(feature = "wasm", wasm_bindgen, derive(ts_rs::TS), ts(export))]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NumberSpacingType {
+    Default,
+    Proportional,
+    Tabular,
+}
+
+impl fmt::Display for NumberSpacingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NumberSpacingType::Default => write!(f, "default"),
+            NumberSpacingType::Proportional => write!(f, "proportional"),
+            NumberSpacingType::Tabular => write!(f, "tabular"),
+        }
+    }
+}
+
+impl FromStr for NumberSpacingType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(NumberSpacingType::Default),
+            "proportional" => Ok(NumberSpacingType::Proportional),
+            "tabular" => Ok(NumberSpacingType::Tabular),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::fmt;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::Serialize;
+
+use super::errors;
+use std::str::FromStr;
+
+/// Animated text effect (`ST_TextEffect`, ECMA-376 Part 1 §17.18.92).
+#[cfg_attr(feature = "wasm", wasm_bindgen, derive(ts_rs::TS), ts(export))]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TextEffectType {
+    BlinkBackground,
+    Lights,
+    AntsBlack,
+    AntsRed,
+    Shimmer,
+    Sparkle,
+    None,
+}
+
+impl fmt::Display for TextEffectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TextEffectType::BlinkBackground => write!(f, "blinkBackground"),
+            TextEffectType::Lights => write!(f, "lights"),
+            TextEffectType::AntsBlack => write!(f, "antsBlack"),
+            TextEffectType::AntsRed => write!(f, "antsRed"),
+            TextEffectType::Shimmer => write!(f, "shimmer"),
+            TextEffectType::Sparkle => write!(f, "sparkle"),
+            TextEffectType::None => write!(f, "none"),
+        }
+    }
+}
+
+impl FromStr for TextEffectType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "blinkBackground" => Ok(TextEffectType::BlinkBackground),
+            "lights" => Ok(TextEffectType::Lights),
+            "antsBlack" => Ok(TextEffectType::AntsBlack),
+            "antsRed" => Ok(TextEffectType::AntsRed),
+            "shimmer" => Ok(TextEffectType::Shimmer),
+            "sparkle" => Ok(TextEffectType::Sparkle),
+            "none" => Ok(TextEffectType::None),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
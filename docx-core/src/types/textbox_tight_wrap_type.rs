@@ -0,0 +1,47 @@
+use std::fmt;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::Serialize;
+
+use super::errors;
+use std::str::FromStr;
+
+/// How tightly text wraps around a paragraph inside a text box
+/// (`w:textboxTightWrap`).
+#[cfg_attr(feature = "wasm", wasm_bindgen, derive(ts_rs::TS), ts(export))]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TextboxTightWrapType {
+    None,
+    AllLines,
+    FirstAndLastLine,
+    FirstLineOnly,
+    LastLineOnly,
+}
+
+impl fmt::Display for TextboxTightWrapType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TextboxTightWrapType::None => write!(f, "none"),
+            TextboxTightWrapType::AllLines => write!(f, "allLines"),
+            TextboxTightWrapType::FirstAndLastLine => write!(f, "firstAndLastLine"),
+            TextboxTightWrapType::FirstLineOnly => write!(f, "firstLineOnly"),
+            TextboxTightWrapType::LastLineOnly => write!(f, "lastLineOnly"),
+        }
+    }
+}
+
+impl FromStr for TextboxTightWrapType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(TextboxTightWrapType::None),
+            "allLines" => Ok(TextboxTightWrapType::AllLines),
+            "firstAndLastLine" => Ok(TextboxTightWrapType::FirstAndLastLine),
+            "firstLineOnly" => Ok(TextboxTightWrapType::FirstLineOnly),
+            "lastLineOnly" => Ok(TextboxTightWrapType::LastLineOnly),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
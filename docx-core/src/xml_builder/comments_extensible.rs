@@ -0,0 +1,21 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+use crate::xml::writer::Result;
+use std::io::Write;
+
+impl<W: Write> XMLBuilder<W> {
+    pub(crate) fn open_comments_extensible(self) -> Result<Self> {
+        self.write(
+            XmlEvent::start_element("w16cex:commentsExtensible")
+                .attr(
+                    "xmlns:mc",
+                    "http://schemas.openxmlformats.org/markup-compatibility/2006",
+                )
+                .attr(
+                    "xmlns:w16cex",
+                    "http://schemas.microsoft.com/office/word/2018/wordml/cex",
+                )
+                .attr("mc:Ignorable", "w16cex"),
+        )
+    }
+}
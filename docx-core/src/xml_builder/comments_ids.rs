@@ -0,0 +1,21 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+use crate::xml::writer::Result;
+use std::io::Write;
+
+impl<W: Write> XMLBuilder<W> {
+    pub(crate) fn open_comments_ids(self) -> Result<Self> {
+        self.write(
+            XmlEvent::start_element("w16cid:commentsIds")
+                .attr(
+                    "xmlns:mc",
+                    "http://schemas.openxmlformats.org/markup-compatibility/2006",
+                )
+                .attr(
+                    "xmlns:w16cid",
+                    "http://schemas.microsoft.com/office/word/2016/wordml/cid",
+                )
+                .attr("mc:Ignorable", "w16cid"),
+        )
+    }
+}
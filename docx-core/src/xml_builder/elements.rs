@@ -1,6 +1,7 @@
 use super::XMLBuilder;
 use super::XmlEvent;
 use crate::types::*;
+use crate::EastAsianLayout;
 use crate::FrameProperty;
 use crate::TablePositionProperty;
 
@@ -216,15 +217,79 @@ impl<W: Write> XMLBuilder<W> {
             .close()
     }
 
+    // i.e. <w:smallCaps ... >
+    closed_toggle!(small_caps, "w:smallCaps");
+    closed_toggle!(emboss, "w:emboss");
+    closed_toggle!(imprint, "w:imprint");
+    closed_toggle!(outline, "w:outline");
+    closed_toggle!(shadow, "w:shadow");
+    closed_toggle!(no_proof, "w:noProof");
+    closed_toggle!(web_hidden, "w:webHidden");
+    closed_toggle!(o_math, "w:oMath");
+    closed_toggle!(rtl, "w:rtl");
+    closed_toggle!(complex_script, "w:cs");
+    // i.e. <w:kern ... >
+    closed_with_usize!(kern, "w:kern");
+    // i.e. <w:position ... >
+    closed_with_isize!(position, "w:position");
+    // i.e. <w:effect ... >
+    closed_with_str!(effect, "w:effect");
+    // i.e. <w:em ... >
+    closed_with_str!(emphasis_mark, "w:em");
+
+    // i.e. <w:lang ... >
+    pub(crate) fn lang(
+        self,
+        val: Option<&String>,
+        east_asia: Option<&String>,
+        bidi: Option<&String>,
+    ) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w:lang");
+        if let Some(val) = val {
+            e = e.attr("w:val", val);
+        }
+        if let Some(east_asia) = east_asia {
+            e = e.attr("w:eastAsia", east_asia);
+        }
+        if let Some(bidi) = bidi {
+            e = e.attr("w:bidi", bidi);
+        }
+        self.write(e)?.close()
+    }
+
+    // i.e. <w:eastAsianLayout ... >
+    pub(crate) fn east_asian_layout(self, layout: &EastAsianLayout) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w:eastAsianLayout");
+        if let Some(id) = layout.id {
+            e = e.attr_display("w:id", id);
+        }
+        if let Some(combine) = layout.combine {
+            e = e.attr_display("w:combine", combine);
+        }
+        let combine_brackets: String = layout.combine_brackets.iter().cloned().collect();
+        if layout.combine_brackets.is_some() {
+            e = e.attr("w:combineBrackets", &combine_brackets);
+        }
+        if let Some(vert) = layout.vert {
+            e = e.attr_display("w:vert", vert);
+        }
+        if let Some(vert_compress) = layout.vert_compress {
+            e = e.attr_display("w:vertCompress", vert_compress);
+        }
+        self.write(e)?.close()
+    }
+
     // Build w:style element
     // i.e. <w:style ... >
-    pub(crate) fn open_style(self, style_type: StyleType, id: &str) -> Result<Self> {
-        self.write(
-            XmlEvent::start_element("w:style")
-                .attr_display("w:type", style_type)
-                .attr("w:styleId", id),
-        )
+    pub(crate) fn open_style(self, style_type: StyleType, id: &str, default: bool) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w:style").attr_display("w:type", style_type);
+        if default {
+            e = e.attr("w:default", "1");
+        }
+        self.write(e.attr("w:styleId", id))
     }
+
+    open!(open_table_style_override, "w:tblStylePr", "w:type");
     // i.e. <w:next ... >
     closed_with_str!(next, "w:next");
 
@@ -315,6 +380,7 @@ impl<W: Write> XMLBuilder<W> {
     }
 
     // i.e. <w:spacing ... >
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn line_spacing(
         self,
         before: Option<u32>,
@@ -323,14 +389,22 @@ impl<W: Write> XMLBuilder<W> {
         before_lines: Option<u32>,
         after_lines: Option<u32>,
         spacing: Option<LineSpacingType>,
+        before_autospacing: Option<bool>,
+        after_autospacing: Option<bool>,
     ) -> Result<Self> {
         let mut xml_event = XmlEvent::start_element("w:spacing");
         if let Some(before) = before {
             xml_event = xml_event.attr_display("w:before", before)
         }
+        if let Some(v) = before_autospacing {
+            xml_event = xml_event.attr("w:beforeAutospacing", if v { "1" } else { "0" })
+        }
         if let Some(after) = after {
             xml_event = xml_event.attr_display("w:after", after)
         }
+        if let Some(v) = after_autospacing {
+            xml_event = xml_event.attr("w:afterAutospacing", if v { "1" } else { "0" })
+        }
         if let Some(before_lines) = before_lines {
             xml_event = xml_event.attr_display("w:beforeLines", before_lines)
         }
@@ -372,6 +446,17 @@ impl<W: Write> XMLBuilder<W> {
     open!(open_cell_margins, "w:tcMar");
 
     closed!(table_layout, "w:tblLayout", "w:type");
+    closed!(
+        table_look,
+        "w:tblLook",
+        "w:val",
+        "w:firstRow",
+        "w:lastRow",
+        "w:firstColumn",
+        "w:lastColumn",
+        "w:noHBand",
+        "w:noVBand"
+    );
     closed_with_str!(table_style, "w:tblStyle");
     closed_w_with_type_el!(table_width, "w:tblW");
     closed_w_with_type_el!(table_indent, "w:tblInd");
@@ -419,6 +504,8 @@ impl<W: Write> XMLBuilder<W> {
     closed!(header_reference, "w:headerReference", "w:type", "r:id");
     closed!(footer_reference, "w:footerReference", "w:type", "r:id");
 
+    closed!(alt_chunk, "w:altChunk", "r:id");
+
     closed_with_str!(type_tag, "w:type");
     closed!(title_pg, "w:titlePg");
     closed!(even_and_odd_headers, "w:evenAndOddHeaders");
@@ -450,6 +537,13 @@ impl<W: Write> XMLBuilder<W> {
         "w:author",
         "w:date"
     );
+    open!(
+        open_run_property_change,
+        "w:rPrChange",
+        "w:id",
+        "w:author",
+        "w:date"
+    );
     // cantSplit for table row
     closed!(cant_split, "w:cantSplit");
 
@@ -486,6 +580,7 @@ impl<W: Write> XMLBuilder<W> {
     closed!(vanish, "w:vanish");
     closed!(spec_vanish, "w:specVanish");
     closed!(is_lgl, "w:isLgl");
+    closed_with_usize!(level_pic_bullet_id, "w:lvlPicBulletId");
 
     open!(open_drawing, "w:drawing");
     open!(open_anchor, "wp:anchor");
@@ -527,6 +622,20 @@ impl<W: Write> XMLBuilder<W> {
     closed!(page_break_before, "w:pageBreakBefore");
     closed!(widow_control, "w:widowControl", "w:val");
     closed!(bidi, "w:bidi");
+    closed_toggle!(contextual_spacing, "w:contextualSpacing");
+    closed_toggle!(mirror_indents, "w:mirrorIndents");
+    closed_toggle!(suppress_auto_hyphens, "w:suppressAutoHyphens");
+    closed_toggle!(suppress_line_numbers, "w:suppressLineNumbers");
+    closed_toggle!(suppress_overlap, "w:suppressOverlap");
+    closed_toggle!(word_wrap, "w:wordWrap");
+    closed_toggle!(kinsoku, "w:kinsoku");
+    closed_toggle!(overflow_punct, "w:overflowPunct");
+    closed_toggle!(top_line_punct, "w:topLinePunct");
+    closed_toggle!(auto_space_de, "w:autoSpaceDE");
+    closed_toggle!(auto_space_dn, "w:autoSpaceDN");
+    closed_with_str!(textbox_tight_wrap, "w:textboxTightWrap");
+    closed_with_str!(cnf_style, "w:cnfStyle");
+    closed_with_str!(div_id, "w:divId");
     /*
     <w:lvlOverride w:ilvl="0">
       <w:startOverride w:val="1"/>
@@ -597,6 +706,49 @@ impl<W: Write> XMLBuilder<W> {
         self.write(el)?.close()
     }
 
+    // CommentId
+    // w16cid:commentId w16cid:paraId="00000001" w16cid:durableId="3F2B6A1C"
+    pub(crate) fn comment_id(self, paragraph_id: &str, durable_id: &str) -> Result<Self> {
+        self.write(
+            XmlEvent::start_element("w16cid:commentId")
+                .attr("w16cid:paraId", paragraph_id)
+                .attr("w16cid:durableId", durable_id),
+        )?
+        .close()
+    }
+
+    // CommentExtensible
+    // w16cex:commentExtensible w16cex:durableId="3F2B6A1C" w16cex:dateUtc="2024-01-01T09:00:00Z"
+    pub(crate) fn comment_extensible(
+        self,
+        durable_id: &str,
+        date_utc: &Option<String>,
+        intelligent_placeholder: bool,
+    ) -> Result<Self> {
+        let mut el = XmlEvent::start_element("w16cex:commentExtensible")
+            .attr("w16cex:durableId", durable_id);
+        if let Some(date_utc) = date_utc {
+            el = el.attr("w16cex:dateUtc", date_utc);
+        }
+        if intelligent_placeholder {
+            el = el.attr("w16cex:intelligentPlaceholder", "1");
+        }
+        self.write(el)?.close()
+    }
+
+    pub(crate) fn open_person(self, author: &str) -> Result<Self> {
+        self.write(XmlEvent::start_element("w15:person").attr("w15:author", author))
+    }
+
+    pub(crate) fn presence_info(self, provider_id: &str, user_id: &str) -> Result<Self> {
+        self.write(
+            XmlEvent::start_element("w15:presenceInfo")
+                .attr("w15:providerId", provider_id)
+                .attr("w15:userId", user_id),
+        )?
+        .close()
+    }
+
     // docGrid
     pub(crate) fn doc_grid(
         self,
@@ -783,7 +935,7 @@ mod tests {
     fn test_declaration() -> Result<()> {
         let b = XMLBuilder::new(Vec::new());
         let r = b
-            .open_style(StyleType::Paragraph, "Heading")?
+            .open_style(StyleType::Paragraph, "Heading", false)?
             .close()?
             .into_inner()?
             .into_inner()?;
@@ -477,6 +477,19 @@ macro_rules! closed_with_str {
     };
 }
 
+macro_rules! closed_toggle {
+    ($name: ident, $el_name: expr) => {
+        pub(crate) fn $name(self, val: bool) -> crate::xml::writer::Result<Self> {
+            if val {
+                self.write(XmlEvent::start_element($el_name))?.close()
+            } else {
+                self.write(XmlEvent::start_element($el_name).attr("w:val", "false"))?
+                    .close()
+            }
+        }
+    };
+}
+
 macro_rules! closed_with_usize {
     ($name: ident, $el_name: expr) => {
         pub(crate) fn $name(self, val: usize) -> crate::xml::writer::Result<Self> {
@@ -3,6 +3,8 @@ mod macros;
 
 mod comments;
 mod comments_extended;
+mod comments_extensible;
+mod comments_ids;
 mod core_properties;
 mod custom_properties;
 mod declaration;
@@ -14,12 +16,14 @@ mod footer;
 mod footnotes;
 mod header;
 mod numbering;
+mod people;
 mod pic;
 mod properties;
 mod relationship;
 mod settings;
 
 mod styles;
+mod text_effects;
 
 use crate::BuildXML;
 
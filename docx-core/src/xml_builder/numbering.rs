@@ -17,7 +17,44 @@ impl<W: Write> XMLBuilder<W> {
                 .attr(
                     "xmlns:w",
                     "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
-                ),
+                )
+                .attr(
+                    "xmlns:mc",
+                    "http://schemas.openxmlformats.org/markup-compatibility/2006",
+                )
+                .attr(
+                    "xmlns:w14",
+                    "http://schemas.microsoft.com/office/word/2010/wordml",
+                )
+                .attr("mc:Ignorable", "w14"),
         )
     }
+
+    // i.e. <w:numPicBullet w:numPicBulletId="0">
+    pub(crate) fn open_num_pic_bullet(self, id: usize) -> Result<Self> {
+        self.write(XmlEvent::start_element("w:numPicBullet").attr_display("w:numPicBulletId", id))
+    }
+
+    open!(open_pict, "w:pict");
+
+    // i.e. <v:shape id="_x0000_i1025" style="width:9pt;height:9pt" o:bullet="t">
+    pub(crate) fn open_bullet_shape(self, id: &str, width_pt: f32, height_pt: f32) -> Result<Self> {
+        let style = format!("width:{width_pt}pt;height:{height_pt}pt");
+        self.write(
+            XmlEvent::start_element("v:shape")
+                .attr("id", id)
+                .attr("style", &style)
+                .attr("o:bullet", "t"),
+        )
+    }
+
+    // i.e. <v:imagedata r:id="rId1" o:title="" />
+    pub(crate) fn image_data(self, rid: &str) -> Result<Self> {
+        self.write(
+            XmlEvent::start_element("v:imagedata")
+                .attr("r:id", rid)
+                .attr("o:title", ""),
+        )?
+        .close()
+    }
 }
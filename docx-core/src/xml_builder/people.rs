@@ -0,0 +1,25 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+use crate::xml::writer::Result;
+use std::io::Write;
+
+impl<W: Write> XMLBuilder<W> {
+    pub(crate) fn open_people(self) -> Result<Self> {
+        self.write(
+            XmlEvent::start_element("w15:people")
+                .attr(
+                    "xmlns:mc",
+                    "http://schemas.openxmlformats.org/markup-compatibility/2006",
+                )
+                .attr(
+                    "xmlns:w",
+                    "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+                )
+                .attr(
+                    "xmlns:w15",
+                    "http://schemas.microsoft.com/office/word/2012/wordml",
+                )
+                .attr("mc:Ignorable", "w15"),
+        )
+    }
+}
@@ -32,6 +32,62 @@ impl<W: Write> XMLBuilder<W> {
                 .attr("mc:Ignorable", "w14 w15"),
         )
     }
+
+    pub(crate) fn open_latent_styles(
+        self,
+        def_locked_state: bool,
+        def_ui_priority: usize,
+        def_semi_hidden: bool,
+        def_unhide_when_used: bool,
+        def_q_format: bool,
+        count: usize,
+    ) -> Result<Self> {
+        self.write(
+            XmlEvent::start_element("w:latentStyles")
+                .attr("w:defLockedState", on_off(def_locked_state))
+                .attr_display("w:defUIPriority", def_ui_priority)
+                .attr("w:defSemiHidden", on_off(def_semi_hidden))
+                .attr("w:defUnhideWhenUsed", on_off(def_unhide_when_used))
+                .attr("w:defQFormat", on_off(def_q_format))
+                .attr_display("w:count", count),
+        )
+    }
+
+    pub(crate) fn lsd_exception(
+        self,
+        name: &str,
+        locked: Option<bool>,
+        ui_priority: Option<usize>,
+        semi_hidden: Option<bool>,
+        unhide_when_used: Option<bool>,
+        q_format: Option<bool>,
+    ) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w:lsdException").attr("w:name", name);
+        if let Some(v) = locked {
+            e = e.attr("w:locked", on_off(v));
+        }
+        if let Some(v) = ui_priority {
+            e = e.attr_display("w:uiPriority", v);
+        }
+        if let Some(v) = semi_hidden {
+            e = e.attr("w:semiHidden", on_off(v));
+        }
+        if let Some(v) = unhide_when_used {
+            e = e.attr("w:unhideWhenUsed", on_off(v));
+        }
+        if let Some(v) = q_format {
+            e = e.attr("w:qFormat", on_off(v));
+        }
+        self.write(e)?.close()
+    }
+}
+
+fn on_off(v: bool) -> &'static str {
+    if v {
+        "1"
+    } else {
+        "0"
+    }
 }
 
 #[cfg(test)]
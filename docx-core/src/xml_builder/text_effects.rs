@@ -0,0 +1,175 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+use crate::xml::writer::Result;
+use crate::{EffectColor, EffectColorType, EffectFill, TextOutline, TextReflection, TextShadow};
+use std::io::Write;
+
+// Word 2010 (w14) text effects. These elements live in the
+// `http://schemas.microsoft.com/office/word/2010/wordml` namespace, which is
+// declared on every part root that can contain run properties.
+impl<W: Write> XMLBuilder<W> {
+    // i.e. <w14:glow w14:rad="63500">
+    pub(crate) fn open_w14_glow(self, radius: u64) -> Result<Self> {
+        self.write(XmlEvent::start_element("w14:glow").attr_display("w14:rad", radius))
+    }
+
+    // i.e. <w14:shadow w14:blurRad="38100" w14:dist="19050" ...>
+    pub(crate) fn open_w14_shadow(self, shadow: &TextShadow) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w14:shadow");
+        let attrs = [
+            ("w14:blurRad", shadow.blur_rad),
+            ("w14:dist", shadow.dist),
+            ("w14:dir", shadow.dir),
+            ("w14:sx", shadow.sx),
+            ("w14:sy", shadow.sy),
+            ("w14:kx", shadow.kx),
+            ("w14:ky", shadow.ky),
+        ];
+        for (name, v) in attrs {
+            if let Some(v) = v {
+                e = e.attr_display(name, v);
+            }
+        }
+        let align: String = shadow.align.iter().cloned().collect();
+        if shadow.align.is_some() {
+            e = e.attr("w14:algn", &align);
+        }
+        self.write(e)
+    }
+
+    // i.e. <w14:reflection w14:blurRad="6350" w14:stA="55000" ... />
+    pub(crate) fn w14_reflection(self, reflection: &TextReflection) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w14:reflection");
+        let attrs = [
+            ("w14:blurRad", reflection.blur_rad),
+            ("w14:stA", reflection.start_alpha),
+            ("w14:stPos", reflection.start_pos),
+            ("w14:endA", reflection.end_alpha),
+            ("w14:endPos", reflection.end_pos),
+            ("w14:dist", reflection.dist),
+            ("w14:dir", reflection.dir),
+            ("w14:fadeDir", reflection.fade_dir),
+            ("w14:sx", reflection.sx),
+            ("w14:sy", reflection.sy),
+            ("w14:kx", reflection.kx),
+            ("w14:ky", reflection.ky),
+        ];
+        for (name, v) in attrs {
+            if let Some(v) = v {
+                e = e.attr_display(name, v);
+            }
+        }
+        let align: String = reflection.align.iter().cloned().collect();
+        if reflection.align.is_some() {
+            e = e.attr("w14:algn", &align);
+        }
+        self.write(e)?.close()
+    }
+
+    // i.e. <w14:textOutline w14:w="9525" w14:cap="rnd" ...>
+    pub(crate) fn open_w14_text_outline(self, outline: &TextOutline) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w14:textOutline");
+        if let Some(w) = outline.width {
+            e = e.attr_display("w14:w", w);
+        }
+        let cap: String = outline.cap.iter().cloned().collect();
+        if outline.cap.is_some() {
+            e = e.attr("w14:cap", &cap);
+        }
+        let compound: String = outline.compound.iter().cloned().collect();
+        if outline.compound.is_some() {
+            e = e.attr("w14:cmpd", &compound);
+        }
+        let align: String = outline.align.iter().cloned().collect();
+        if outline.align.is_some() {
+            e = e.attr("w14:algn", &align);
+        }
+        self.write(e)
+    }
+
+    open!(open_w14_text_fill, "w14:textFill");
+    open!(open_w14_stylistic_sets, "w14:stylisticSets");
+
+    // i.e. <w14:prstDash w14:val="solid" />
+    pub(crate) fn w14_preset_dash(self, val: &str) -> Result<Self> {
+        self.write(XmlEvent::start_element("w14:prstDash").attr("w14:val", val))?
+            .close()
+    }
+
+    // i.e. <w14:round />, <w14:bevel /> or <w14:miter />
+    pub(crate) fn w14_line_join(self, join: &str) -> Result<Self> {
+        self.write(XmlEvent::start_element(format!("w14:{join}").as_str()))?
+            .close()
+    }
+
+    // i.e. <w14:styleSet w14:id="1" />
+    pub(crate) fn w14_style_set(self, id: usize) -> Result<Self> {
+        self.write(XmlEvent::start_element("w14:styleSet").attr_display("w14:id", id))?
+            .close()
+    }
+
+    // i.e. <w14:ligatures w14:val="standard" />
+    pub(crate) fn w14_ligatures(self, val: &str) -> Result<Self> {
+        self.write(XmlEvent::start_element("w14:ligatures").attr("w14:val", val))?
+            .close()
+    }
+
+    // i.e. <w14:numForm w14:val="lining" />
+    pub(crate) fn w14_num_form(self, val: &str) -> Result<Self> {
+        self.write(XmlEvent::start_element("w14:numForm").attr("w14:val", val))?
+            .close()
+    }
+
+    // i.e. <w14:numSpacing w14:val="tabular" />
+    pub(crate) fn w14_num_spacing(self, val: &str) -> Result<Self> {
+        self.write(XmlEvent::start_element("w14:numSpacing").attr("w14:val", val))?
+            .close()
+    }
+
+    // i.e. <w14:cntxtAlts />
+    pub(crate) fn w14_contextual_alternatives(self, val: bool) -> Result<Self> {
+        if val {
+            self.write(XmlEvent::start_element("w14:cntxtAlts"))?
+                .close()
+        } else {
+            self.write(XmlEvent::start_element("w14:cntxtAlts").attr("w14:val", "false"))?
+                .close()
+        }
+    }
+
+    // i.e. <w14:noFill /> or <w14:solidFill><w14:srgbClr ... /></w14:solidFill>
+    pub(crate) fn w14_effect_fill(self, fill: &EffectFill) -> Result<Self> {
+        match fill {
+            EffectFill::NoFill => self.write(XmlEvent::start_element("w14:noFill"))?.close(),
+            EffectFill::Solid(color) => self
+                .write(XmlEvent::start_element("w14:solidFill"))?
+                .w14_effect_color(color)?
+                .close(),
+        }
+    }
+
+    // i.e. <w14:schemeClr w14:val="accent1"><w14:alpha w14:val="60000" /></w14:schemeClr>
+    pub(crate) fn w14_effect_color(self, color: &EffectColor) -> Result<Self> {
+        let el = match color.color_type {
+            EffectColorType::Srgb => "w14:srgbClr",
+            EffectColorType::Scheme => "w14:schemeClr",
+        };
+        let modifiers = [
+            ("w14:tint", color.tint),
+            ("w14:shade", color.shade),
+            ("w14:alpha", color.alpha),
+            ("w14:satMod", color.sat_mod),
+            ("w14:lumMod", color.lum_mod),
+            ("w14:lumOff", color.lum_off),
+        ];
+        let mut b = self.write(XmlEvent::start_element(el).attr("w14:val", &color.val))?;
+        for (name, v) in modifiers {
+            if let Some(v) = v {
+                b = b
+                    .write(XmlEvent::start_element(name).attr_display("w14:val", v))?
+                    .close()?;
+            }
+        }
+        b.close()
+    }
+}
@@ -1,5 +1,5 @@
 use crate::xml_builder::XMLBuilder;
-use crate::{BuildXML, Docx, PackageMetadata, XMLDocx};
+use crate::{BuildXML, Docx, OdtPackage, PackageMetadata, XMLDocx, ODT_MIMETYPE};
 
 use std::io::prelude::*;
 use std::io::Seek;
@@ -79,10 +79,31 @@ where
     zip.write_all(&xml.comments)?;
     zip.start_file("word/numbering.xml", options)?;
     zip.write_all(&xml.numberings)?;
+    if !xml.numbering_rels.is_empty() {
+        zip.start_file("word/_rels/numbering.xml.rels", options)?;
+        zip.write_all(&xml.numbering_rels)?;
+    }
     zip.start_file("word/commentsExtended.xml", options)?;
     zip.write_all(&xml.comments_extended)?;
+    if let Some(comments_ids) = &xml.comments_ids {
+        zip.start_file("word/commentsIds.xml", options)?;
+        zip.write_all(comments_ids)?;
+    }
+    if let Some(comments_extensible) = &xml.comments_extensible {
+        zip.start_file("word/commentsExtensible.xml", options)?;
+        zip.write_all(comments_extensible)?;
+    }
+    if let Some(people) = &xml.people {
+        zip.start_file("word/people.xml", options)?;
+        zip.write_all(people)?;
+    }
     zip.start_file("word/footnotes.xml", options)?;
     zip.write_all(&xml.footnotes)?;
+    if let Some(theme) = &xml.theme {
+        zip.add_directory("word/theme/", directory_options)?;
+        zip.start_file("word/theme/theme1.xml", options)?;
+        zip.write_all(theme)?;
+    }
 
     for (i, h) in xml.headers.iter().enumerate() {
         zip.start_file(format!("word/header{}.xml", i + 1), options)?;
@@ -113,6 +134,11 @@ where
         }
     }
 
+    for (path, bytes) in xml.alt_chunks {
+        zip.start_file(format!("word/{path}"), options)?;
+        zip.write_all(&bytes)?;
+    }
+
     // For now support only taskpanes
     if let Some(taskpanes) = xml.taskpanes {
         zip.add_directory("word/webextensions/", directory_options)?;
@@ -257,6 +283,15 @@ where
         options,
         &docx.numberings,
     )?;
+    if let Some(rels) = &package.numbering_rels {
+        write_xml(
+            &mut zip,
+            &mut xml_buffer,
+            "word/_rels/numbering.xml.rels",
+            options,
+            rels,
+        )?;
+    }
     write_xml(
         &mut zip,
         &mut xml_buffer,
@@ -264,6 +299,33 @@ where
         options,
         &docx.comments_extended,
     )?;
+    if !docx.comments_ids.is_empty() {
+        write_xml(
+            &mut zip,
+            &mut xml_buffer,
+            "word/commentsIds.xml",
+            options,
+            &docx.comments_ids,
+        )?;
+    }
+    if !docx.comments_extensible.is_empty() {
+        write_xml(
+            &mut zip,
+            &mut xml_buffer,
+            "word/commentsExtensible.xml",
+            options,
+            &docx.comments_extensible,
+        )?;
+    }
+    if !docx.people.is_empty() {
+        write_xml(
+            &mut zip,
+            &mut xml_buffer,
+            "word/people.xml",
+            options,
+            &docx.people,
+        )?;
+    }
     write_xml(
         &mut zip,
         &mut xml_buffer,
@@ -271,6 +333,11 @@ where
         options,
         &docx.footnotes,
     )?;
+    if let Some(theme) = docx.theme_xml() {
+        zip.add_directory("word/theme/", directory_options)?;
+        zip.start_file("word/theme/theme1.xml", options)?;
+        zip.write_all(theme)?;
+    }
 
     let mut header_rels = package.header_rels.iter().peekable();
     for (index, (_, header)) in docx.document.headers().enumerate() {
@@ -334,6 +401,11 @@ where
         }
     }
 
+    for (path, bytes) in package.alt_chunks {
+        zip.start_file(format!("word/{path}"), options)?;
+        zip.write_all(&bytes)?;
+    }
+
     if let Some(taskpanes) = &docx.taskpanes {
         zip.add_directory("word/webextensions/", directory_options)?;
         write_xml(
@@ -395,3 +467,42 @@ where
     zip.finish()?;
     Ok(())
 }
+
+/// Writes an OpenDocument Text package. `mimetype` is stored uncompressed as
+/// the first entry so the format can be told from the leading bytes.
+pub(crate) fn zip_odt<W>(writer: W, package: OdtPackage) -> zip::result::ZipResult<()>
+where
+    W: Write + Seek,
+{
+    let mut zip = zip::ZipWriter::new(writer);
+
+    zip.start_file(
+        "mimetype",
+        SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored),
+    )?;
+    zip.write_all(ODT_MIMETYPE.as_bytes())?;
+
+    let directory_options = SimpleFileOptions::default();
+    let options = SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .unix_permissions(0o755);
+
+    zip.add_directory("META-INF/", directory_options)?;
+    zip.start_file("META-INF/manifest.xml", options)?;
+    zip.write_all(package.manifest.as_bytes())?;
+    zip.start_file("content.xml", options)?;
+    zip.write_all(package.content.as_bytes())?;
+    zip.start_file("styles.xml", options)?;
+    zip.write_all(package.styles.as_bytes())?;
+
+    if !package.pictures.is_empty() {
+        zip.add_directory("Pictures/", directory_options)?;
+        for (path, bytes) in package.pictures {
+            zip.start_file(path, options)?;
+            zip.write_all(&bytes)?;
+        }
+    }
+
+    zip.finish()?;
+    Ok(())
+}
@@ -11,7 +11,17 @@ export type DocumentChildJSON =
   | CommentRangeEndJSON
   | BookmarkStartJSON
   | BookmarkEndJSON
-  | StructuredTagJSON;
+  | StructuredTagJSON
+  | AltChunkJSON;
+
+export type AltChunkJSON = {
+  type: "altChunk";
+  data: {
+    id: string;
+    format: "html" | "mht" | "rtf" | "text" | "docx";
+    data?: number[];
+  };
+};
 
 export type DocumentJSON = {
   children: DocumentChildJSON[];
@@ -7,6 +7,32 @@ import { WebSettingsJSON } from "./web-settings";
 import { Theme as ThemeJSON } from "./bindings/Theme";
 import { CharacterSpacingValues } from "../settings";
 
+export type FontJSON = {
+  name: string;
+  charset: string;
+  family: string;
+  pitch: "fixed" | "variable" | "default";
+};
+
+export type CommentIdJSON = {
+  paragraphId: string;
+  durableId: string;
+};
+
+export type CommentExtensibleJSON = {
+  durableId: string;
+  dateUtc: string | null;
+  intelligentPlaceholder: boolean;
+};
+
+export type PersonJSON = {
+  author: string;
+  presenceInfo: {
+    providerId: string;
+    userId: string;
+  } | null;
+};
+
 export type DocxJSON = {
   contentType: {
     types: {
@@ -46,7 +72,19 @@ export type DocxJSON = {
   numberings: NumberingsJSON;
   settings: SettingsJSON;
   webSettings: WebSettingsJSON;
-  fontTable: {};
+  fontTable: {
+    fonts?: FontJSON[];
+  };
+  // Omitted when the document has no such part.
+  commentsIds?: {
+    children: CommentIdJSON[];
+  };
+  commentsExtensible?: {
+    children: CommentExtensibleJSON[];
+  };
+  people?: {
+    people: PersonJSON[];
+  };
   themes: ThemeJSON[];
   /**
    * `[id, path, base64 original bytes, base64 preview bytes]`.
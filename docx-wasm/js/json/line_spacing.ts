@@ -5,4 +5,6 @@ export type LineSpacingJSON = {
   afterLines?: number | null;
   line?: number | null;
   lineRule?: "atLeast" | "auto" | "exact" | null;
+  beforeAutospacing?: boolean | null;
+  afterAutospacing?: boolean | null;
 };
@@ -13,6 +13,13 @@ export type LevelJSON = {
   pstyle: string | null;
   levelRestart: number | null;
   isLgl?: boolean | null; 
+  picBulletId?: number;
+};
+
+export type NumPicBulletJSON = {
+  id: number;
+  picId: string;
+  size: [number, number];
 };
 
 export type AbstractNumberingJSON = {
@@ -35,6 +42,7 @@ export type LevelOverrideJSON = {
 };
 
 export type NumberingsJSON = {
+  numPicBullets?: NumPicBulletJSON[];
   abstractNums: AbstractNumberingJSON[];
   numberings: NumberingJSON[];
 };
@@ -51,6 +51,7 @@ export type CustomTabStopJSON = {
 export type NumberingPropertyJSON = {
   id: number | null;
   level: number | null;
+  ins?: InsertJSONData;
 };
 
 export type ParagraphPropertyJSON = {
@@ -77,6 +78,21 @@ export type ParagraphPropertyJSON = {
   sectionProperty?: SectionPropertyJSON;
   tabs: CustomTabStopJSON[];
   frameProperty?: FramePropertyJSON;
+  bidi?: boolean;
+  contextualSpacing?: boolean;
+  mirrorIndents?: boolean;
+  suppressAutoHyphens?: boolean;
+  suppressLineNumbers?: boolean;
+  suppressOverlap?: boolean;
+  wordWrap?: boolean;
+  kinsoku?: boolean;
+  overflowPunct?: boolean;
+  topLinePunct?: boolean;
+  autoSpaceDe?: boolean;
+  autoSpaceDn?: boolean;
+  textDirection?: string;
+  textboxTightWrap?: string;
+  cnfStyle?: string;
 };
 
 export type ParagraphJSON = {
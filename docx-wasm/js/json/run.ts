@@ -64,6 +64,112 @@ export type RunPropertyJSON = {
   strike?: boolean;
   dstrike?: boolean;
   shading?: ShadingJSON | null;
+  smallCaps?: boolean | null;
+  emboss?: boolean | null;
+  imprint?: boolean | null;
+  outline?: boolean | null;
+  shadow?: boolean | null;
+  noProof?: boolean | null;
+  snapToGrid?: boolean | null;
+  webHidden?: boolean | null;
+  kern?: number | null;
+  position?: number | null;
+  effect?: string | null;
+  rtl?: boolean | null;
+  complexScript?: boolean | null;
+  emphasisMark?: string | null;
+  lang?: LangJSON | null;
+  eastAsianLayout?: EastAsianLayoutJSON | null;
+  oMath?: boolean | null;
+  glow?: TextGlowJSON | null;
+  textShadow?: TextShadowJSON | null;
+  reflection?: TextReflectionJSON | null;
+  textOutline?: TextOutlineJSON | null;
+  textFill?: EffectFillJSON | null;
+  ligatures?: string | null;
+  numberForm?: string | null;
+  numberSpacing?: string | null;
+  stylisticSets?: number[] | null;
+  contextualAlternatives?: boolean | null;
+  runPropertyChange?: RunPropertyChangeJSON | null;
+};
+
+export type LangJSON = {
+  val?: string | null;
+  eastAsia?: string | null;
+  bidi?: string | null;
+};
+
+export type EastAsianLayoutJSON = {
+  id?: number | null;
+  combine?: boolean | null;
+  combineBrackets?: string | null;
+  vert?: boolean | null;
+  vertCompress?: boolean | null;
+};
+
+export type EffectColorJSON = {
+  colorType: "srgb" | "scheme";
+  val: string;
+  tint?: number | null;
+  shade?: number | null;
+  alpha?: number | null;
+  satMod?: number | null;
+  lumMod?: number | null;
+  lumOff?: number | null;
+};
+
+export type EffectFillJSON =
+  | { type: "noFill" }
+  | { type: "solid"; data: EffectColorJSON };
+
+export type TextGlowJSON = {
+  radius: number;
+  color: EffectColorJSON;
+};
+
+export type TextShadowJSON = {
+  blurRad?: number | null;
+  dist?: number | null;
+  dir?: number | null;
+  sx?: number | null;
+  sy?: number | null;
+  kx?: number | null;
+  ky?: number | null;
+  align?: string | null;
+  color: EffectColorJSON;
+};
+
+export type TextReflectionJSON = {
+  blurRad?: number | null;
+  startAlpha?: number | null;
+  startPos?: number | null;
+  endAlpha?: number | null;
+  endPos?: number | null;
+  dist?: number | null;
+  dir?: number | null;
+  fadeDir?: number | null;
+  sx?: number | null;
+  sy?: number | null;
+  kx?: number | null;
+  ky?: number | null;
+  align?: string | null;
+};
+
+export type TextOutlineJSON = {
+  width?: number | null;
+  cap?: string | null;
+  compound?: string | null;
+  align?: string | null;
+  fill?: EffectFillJSON | null;
+  presetDash?: string | null;
+  lineJoin?: string | null;
+};
+
+export type RunPropertyChangeJSON = {
+  author: string;
+  date: string;
+  property: RunPropertyJSON;
 };
 
 export type RunChildJSON =
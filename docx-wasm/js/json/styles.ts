@@ -15,6 +15,48 @@ export type StyleJSON = {
   basedOn: string | null;
   link?: string | null | undefined;
   next?: string | null;
+  isDefault?: boolean;
+  tableStyleOverrides?: TableStyleOverrideJSON[];
+};
+
+export type TableStyleOverrideType =
+  | "wholeTable"
+  | "firstRow"
+  | "lastRow"
+  | "firstCol"
+  | "lastCol"
+  | "band1Vert"
+  | "band2Vert"
+  | "band1Horz"
+  | "band2Horz"
+  | "neCell"
+  | "nwCell"
+  | "seCell"
+  | "swCell";
+
+export type TableStyleOverrideJSON = {
+  overrideType: TableStyleOverrideType;
+  paragraphProperty: ParagraphPropertyJSON;
+  runProperty: RunPropertyJSON;
+};
+
+export type LatentStyleExceptionJSON = {
+  name: string;
+  locked?: boolean;
+  uiPriority?: number;
+  semiHidden?: boolean;
+  unhideWhenUsed?: boolean;
+  qFormat?: boolean;
+};
+
+export type LatentStylesJSON = {
+  defLockedState: boolean;
+  defUiPriority: number;
+  defSemiHidden: boolean;
+  defUnhideWhenUsed: boolean;
+  defQFormat: boolean;
+  count: number;
+  exceptions: LatentStyleExceptionJSON[];
 };
 
 export type StylesJSON = {
@@ -26,5 +68,6 @@ export type StylesJSON = {
       paragraphProperty: ParagraphPropertyJSON;
     };
   };
+  latentStyles?: LatentStylesJSON;
   styles: StyleJSON[];
 };
@@ -79,6 +79,15 @@ export type CellMarginsJSON = {
   right: TableCellMarginJSON;
 };
 
+export type TableLookJSON = {
+  firstRow: boolean;
+  lastRow: boolean;
+  firstColumn: boolean;
+  lastColumn: boolean;
+  noHBand: boolean;
+  noVBand: boolean;
+};
+
 export type TablePropertyJSON = {
   width: {
     width: number;
@@ -101,6 +110,7 @@ export type TablePropertyJSON = {
   style?: string | null;
   layout?: TableLayoutType | null;
   position?: TablePositionPropertyJSON;
+  look?: TableLookJSON;
 };
 
 export type TableJSON = {